@@ -1,7 +1,7 @@
 use criterion::{BatchSize, Bencher};
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::date;
-use time::{Date, Time, Weekday};
+use time::{Date, Month, Time, Weekday};
 
 setup_benchmark! {
     "Date",
@@ -9,10 +9,10 @@ setup_benchmark! {
     // region: constructors
     fn from_calendar_date(ben: &mut Bencher<'_>) {
         ben.iter(|| (
-            Date::from_calendar_date(2019, 1, 1),
-            Date::from_calendar_date(2019, 12, 31),
-            Date::from_calendar_date(2020, 1, 1),
-            Date::from_calendar_date(2020, 12, 31),
+            Date::from_calendar_date(2019, Month::January, 1),
+            Date::from_calendar_date(2019, Month::December, 31),
+            Date::from_calendar_date(2020, Month::January, 1),
+            Date::from_calendar_date(2020, Month::December, 31),
         ));
     }
 
@@ -0,0 +1,50 @@
+#!/bin/sh
+# Regenerate the time zone database embedded by the `tzdb-embedded` feature.
+#
+# Usage: scripts/generate-tzdb.sh [path/to/tzdata.zi]
+#
+# The zones are compiled with `zic -b fat`, matching the files most systems install. Links are not
+# stored separately; they refer to the data of their target.
+
+set -eu
+
+tzdata=${1:-/usr/share/zoneinfo/tzdata.zi}
+out=src/time_zone/tzdb
+version=$(sed -n 's/^# version //p' "$tzdata")
+
+rm -rf "$out/data"
+mkdir -p "$out/data"
+zic -b fat -d "$out/data" "$tzdata"
+
+# `zic` also writes a file for each link, which are not needed.
+awk '$1 == "L" { print $3 }' "$tzdata" | while read -r link; do
+    rm "$out/data/$link"
+done
+find "$out/data" -type d -empty -delete
+
+zones=$(awk '$1 == "Z" { print $2 }' "$tzdata" | LC_ALL=C sort)
+
+{
+    echo "//! The IANA time zone database. This file is generated by \`scripts/generate-tzdb.sh\`."
+    echo
+    echo "/// The release of the time zone database."
+    echo "pub(crate) const VERSION: &str = \"$version\";"
+    echo
+    echo "/// The \`TZif\` data of each zone."
+    echo "pub(crate) static DATA: &[&[u8]] = &["
+    for zone in $zones; do
+        echo "    include_bytes!(\"data/$zone\"),"
+    done
+    echo "];"
+    echo
+    echo "/// The name of every zone and link in ascending order, along with the index of its data."
+    echo "pub(crate) static NAMES: &[(&str, usize)] = &["
+    {
+        echo "$zones" | awk '{ print $1, NR - 1 }'
+        awk -v zones="$zones" '
+            BEGIN { count = split(zones, names, "\n"); for (i = 1; i <= count; i++) index_of[names[i]] = i - 1 }
+            $1 == "L" { print $3, index_of[$2] }
+        ' "$tzdata"
+    } | LC_ALL=C sort | awk '{ printf "    (\"%s\", %s),\n", $1, $2 }'
+    echo "];"
+} > "$out/zones.rs"
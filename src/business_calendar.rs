@@ -0,0 +1,304 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::BuildHasher;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+use crate::{Date, Weekday};
+
+/// A set of dates that are not business days, regardless of the day of the week.
+///
+/// This is implemented for the standard collections of [`Date`]s, as well as `()` for the empty
+/// set. Implement it for your own types to compute holidays on demand.
+///
+/// ```rust
+/// # use time::{macros::date, HolidaySet};
+/// let holidays = [date!("2021-12-25"), date!("2022-01-01")];
+/// assert!(holidays[..].is_holiday(date!("2021-12-25")));
+/// assert!(!holidays[..].is_holiday(date!("2021-12-24")));
+/// assert!(!().is_holiday(date!("2021-12-25")));
+/// ```
+pub trait HolidaySet {
+    /// Whether the date is a holiday.
+    fn is_holiday(&self, date: Date) -> bool;
+}
+
+impl HolidaySet for () {
+    fn is_holiday(&self, _: Date) -> bool {
+        false
+    }
+}
+
+impl<T: HolidaySet + ?Sized> HolidaySet for &T {
+    fn is_holiday(&self, date: Date) -> bool {
+        (**self).is_holiday(date)
+    }
+}
+
+impl HolidaySet for [Date] {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.contains(&date)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl HolidaySet for Vec<Date> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.contains(&date)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl HolidaySet for BTreeSet<Date> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.contains(&date)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+impl<S: BuildHasher> HolidaySet for HashSet<Date, S> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.contains(&date)
+    }
+}
+
+/// A calendar of business days, consisting of a weekend and a set of holidays.
+///
+/// A date is a business day if it falls on neither a weekend day nor a holiday.
+///
+/// ```rust
+/// # use time::{macros::date, BusinessCalendar, Weekday::*};
+/// let holidays = [date!("2021-12-24"), date!("2021-12-31")];
+/// let calendar = BusinessCalendar::new(&[Saturday, Sunday], &holidays[..]);
+/// // Settlement two business days after a trade on 2021-12-22.
+/// assert_eq!(
+///     calendar.add_business_days(date!("2021-12-22"), 2),
+///     Some(date!("2021-12-27"))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BusinessCalendar<H> {
+    /// The days of the week that are not business days, with the bit at
+    /// [`Weekday::number_days_from_monday`] set for each.
+    weekend: u8,
+    /// The dates that are not business days regardless of the day of the week.
+    holidays: H,
+}
+
+impl BusinessCalendar<()> {
+    /// A calendar with a Saturday and Sunday weekend and no holidays.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, BusinessCalendar};
+    /// assert!(BusinessCalendar::SATURDAY_SUNDAY.is_business_day(date!("2021-12-24")));
+    /// assert!(!BusinessCalendar::SATURDAY_SUNDAY.is_business_day(date!("2021-12-25")));
+    /// ```
+    pub const SATURDAY_SUNDAY: Self = Self::new(&[Weekday::Saturday, Weekday::Sunday], ());
+}
+
+impl<H> BusinessCalendar<H> {
+    /// Create a `BusinessCalendar` with the provided weekend days and holidays.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, BusinessCalendar, Weekday::*};
+    /// let calendar = BusinessCalendar::new(&[Friday, Saturday], ());
+    /// assert!(calendar.is_business_day(date!("2021-12-26")));
+    /// assert!(!calendar.is_business_day(date!("2021-12-24")));
+    /// ```
+    pub const fn new(weekend: &[Weekday], holidays: H) -> Self {
+        let mut mask = 0;
+        let mut i = 0;
+        while i < weekend.len() {
+            mask |= 1 << weekend[i].number_days_from_monday();
+            i += 1;
+        }
+        Self {
+            weekend: mask,
+            holidays,
+        }
+    }
+
+    /// Replace the holidays of the calendar, retaining its weekend.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, BusinessCalendar};
+    /// let holidays = [date!("2021-12-24")];
+    /// let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(&holidays[..]);
+    /// assert!(!calendar.is_business_day(date!("2021-12-24")));
+    /// ```
+    pub fn with_holidays<T>(self, holidays: T) -> BusinessCalendar<T> {
+        BusinessCalendar {
+            weekend: self.weekend,
+            holidays,
+        }
+    }
+
+    /// Get the holidays of the calendar.
+    ///
+    /// ```rust
+    /// # use time::BusinessCalendar;
+    /// assert_eq!(BusinessCalendar::SATURDAY_SUNDAY.holidays(), &());
+    /// ```
+    pub const fn holidays(&self) -> &H {
+        &self.holidays
+    }
+
+    /// Whether the weekday is part of the weekend.
+    ///
+    /// ```rust
+    /// # use time::{BusinessCalendar, Weekday};
+    /// assert!(BusinessCalendar::SATURDAY_SUNDAY.is_weekend(Weekday::Sunday));
+    /// assert!(!BusinessCalendar::SATURDAY_SUNDAY.is_weekend(Weekday::Monday));
+    /// ```
+    pub const fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend & (1 << weekday.number_days_from_monday()) != 0
+    }
+}
+
+impl<H: HolidaySet> BusinessCalendar<H> {
+    /// Whether the date is a business day, falling on neither a weekend nor a holiday.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, BusinessCalendar};
+    /// let holidays = [date!("2021-12-24")];
+    /// let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(&holidays[..]);
+    /// assert!(calendar.is_business_day(date!("2021-12-23")));
+    /// assert!(!calendar.is_business_day(date!("2021-12-24")));
+    /// assert!(!calendar.is_business_day(date!("2021-12-25")));
+    /// ```
+    pub fn is_business_day(&self, date: Date) -> bool {
+        !self.is_weekend(date.weekday()) && !self.holidays.is_holiday(date)
+    }
+
+    /// Get the first business day after the provided date, returning `None` if there is no such
+    /// day before [`Date::MAX`].
+    ///
+    /// ```rust
+    /// # use time::{macros::date, BusinessCalendar};
+    /// assert_eq!(
+    ///     BusinessCalendar::SATURDAY_SUNDAY.next_business_day(date!("2021-12-23")),
+    ///     Some(date!("2021-12-24"))
+    /// );
+    /// assert_eq!(
+    ///     BusinessCalendar::SATURDAY_SUNDAY.next_business_day(date!("2021-12-24")),
+    ///     Some(date!("2021-12-27"))
+    /// );
+    /// ```
+    pub fn next_business_day(&self, date: Date) -> Option<Date> {
+        // With no weekday available, there is no business day to find.
+        if self.weekend == 0b111_1111 {
+            return None;
+        }
+
+        let mut date = date.next_day()?;
+        while !self.is_business_day(date) {
+            date = date.next_day()?;
+        }
+        Some(date)
+    }
+
+    /// Get the last business day before the provided date, returning `None` if there is no such
+    /// day after [`Date::MIN`].
+    ///
+    /// ```rust
+    /// # use time::{macros::date, BusinessCalendar};
+    /// assert_eq!(
+    ///     BusinessCalendar::SATURDAY_SUNDAY.previous_business_day(date!("2021-12-27")),
+    ///     Some(date!("2021-12-24"))
+    /// );
+    /// ```
+    pub fn previous_business_day(&self, date: Date) -> Option<Date> {
+        if self.weekend == 0b111_1111 {
+            return None;
+        }
+
+        let mut date = date.previous_day()?;
+        while !self.is_business_day(date) {
+            date = date.previous_day()?;
+        }
+        Some(date)
+    }
+
+    /// Move the provided number of business days forward (or backward, if negative), returning
+    /// `None` if the result is out of range. The date itself need not be a business day, and is
+    /// returned unchanged when `days` is zero.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, BusinessCalendar};
+    /// let calendar = BusinessCalendar::SATURDAY_SUNDAY;
+    /// assert_eq!(
+    ///     calendar.add_business_days(date!("2021-12-23"), 2),
+    ///     Some(date!("2021-12-27"))
+    /// );
+    /// assert_eq!(
+    ///     calendar.add_business_days(date!("2021-12-25"), 1),
+    ///     Some(date!("2021-12-27"))
+    /// );
+    /// assert_eq!(
+    ///     calendar.add_business_days(date!("2021-12-27"), -1),
+    ///     Some(date!("2021-12-24"))
+    /// );
+    /// ```
+    pub fn add_business_days(&self, mut date: Date, days: i64) -> Option<Date> {
+        if days >= 0 {
+            for _ in 0..days {
+                date = self.next_business_day(date)?;
+            }
+        } else {
+            for _ in days..0 {
+                date = self.previous_business_day(date)?;
+            }
+        }
+        Some(date)
+    }
+
+    /// Get the number of business days from `start` to `end`.
+    ///
+    /// When `end` is later, this counts the business days after `start`, up to and including
+    /// `end`. When `end` is earlier, this is the negated count of business days from `end`, up
+    /// to but excluding `start`. Either way, adding the result to `start` with
+    /// [`add_business_days`](Self::add_business_days) produces `end` whenever `end` is a business
+    /// day.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, BusinessCalendar};
+    /// let calendar = BusinessCalendar::SATURDAY_SUNDAY;
+    /// assert_eq!(
+    ///     calendar.business_days_between(date!("2021-12-23"), date!("2021-12-27")),
+    ///     2
+    /// );
+    /// assert_eq!(
+    ///     calendar.business_days_between(date!("2021-12-27"), date!("2021-12-23")),
+    ///     -2
+    /// );
+    /// ```
+    pub fn business_days_between(&self, start: Date, end: Date) -> i64 {
+        let (first, last, sign) = if start <= end {
+            (start, end, 1)
+        } else {
+            (end, start, -1)
+        };
+
+        // Count every business day in `first..=last`, then exclude `start`, which is at whichever
+        // end of the range it is.
+        let mut count = 0;
+        let mut date = Some(first);
+        while let Some(current) = date {
+            count += self.is_business_day(current) as i64;
+            date = if current == last {
+                None
+            } else {
+                current.next_day()
+            };
+        }
+        count -= self.is_business_day(start) as i64;
+
+        sign * count
+    }
+}
@@ -0,0 +1,799 @@
+//! Cron expressions.
+//!
+//! A [`Cron`] expression describes a schedule as a set of allowed values for each component of a
+//! date and time. Given a moment, it can find the next or previous time the schedule fires, or
+//! lazily yield every time it fires after that moment.
+//!
+//! Times are always computed in the offset of the provided [`OffsetDateTime`]. Changes in offset,
+//! such as those caused by daylight saving time, are not accounted for.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::iter::FusedIterator;
+
+use crate::date::{MAX_YEAR, MIN_YEAR};
+use crate::util::days_in_year_month;
+use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// The three-letter names of the months, starting with January.
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// The three-letter names of the days of the week, starting with Sunday.
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A range of years, possibly with a step between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct YearRange {
+    /// The first year in the range.
+    first: i32,
+    /// The last year in the range. This is always `first` plus a multiple of `step`.
+    last: i32,
+    /// The number of years between each year in the range.
+    step: i32,
+}
+
+impl YearRange {
+    /// The first year in the range that is at or after the provided year.
+    const fn next(self, year: i32) -> Option<i32> {
+        if year > self.last {
+            None
+        } else if year <= self.first {
+            Some(self.first)
+        } else {
+            let remainder = (year - self.first) % self.step;
+            if remainder == 0 {
+                Some(year)
+            } else {
+                // Because `last` is a multiple of `step` from `first`, this never overflows.
+                Some(year + (self.step - remainder))
+            }
+        }
+    }
+
+    /// The last year in the range that is at or before the provided year.
+    const fn previous(self, year: i32) -> Option<i32> {
+        if year < self.first {
+            None
+        } else if year >= self.last {
+            Some(self.last)
+        } else {
+            Some(year - (year - self.first) % self.step)
+        }
+    }
+}
+
+/// A cron expression, describing when a recurring job should run.
+///
+/// Expressions have five, six, or seven fields separated by whitespace:
+///
+/// | Field        | Values            | Special characters |
+/// |--------------|-------------------|--------------------|
+/// | Second       | `0`–`59`          | `*` `,` `-` `/`    |
+/// | Minute       | `0`–`59`          | `*` `,` `-` `/`    |
+/// | Hour         | `0`–`23`          | `*` `,` `-` `/`    |
+/// | Day of month | `1`–`31`          | `*` `,` `-` `/` `?` `L` `W` |
+/// | Month        | `1`–`12`, `JAN`–`DEC` | `*` `,` `-` `/` |
+/// | Day of week  | `0`–`7`, `SUN`–`SAT` | `*` `,` `-` `/` `?` `L` `#` |
+/// | Year         | `0`–`9999`        | `*` `,` `-` `/`    |
+///
+/// With five fields, the second is omitted and is always zero. With six fields, the year is
+/// omitted and every year is allowed. Both zero and seven refer to Sunday. Names are
+/// case-insensitive.
+///
+/// - `*` allows every value, and `?` is a synonym for it in the day fields.
+/// - `a-b` allows every value from `a` to `b`. Other than for the year, the range wraps around if
+///   `a` is greater than `b`, so `FRI-MON` allows Friday, Saturday, Sunday, and Monday.
+/// - `/n` allows every `n`th value of the range it follows. `a/n` is the same as `a-max/n`, while
+///   `*/n` in the year field allows years that are a multiple of `n`.
+/// - `L` in the day of month field is the last day of the month, and `L-n` is `n` days before
+///   that. Following a day of the week, such as `5L` or `FRIL`, it is the last such weekday of the
+///   month.
+/// - `nW` is the weekday (Monday to Friday) nearest to day `n` of the same month, and `LW` is the
+///   last weekday of the month.
+/// - `d#n` is the `n`th weekday `d` of the month, so `MON#2` is the second Monday.
+///
+/// If both the day of month and day of week fields are restricted (neither is `*` or `?`), a day
+/// is allowed if it matches _either_ field, as is the case for most cron implementations.
+///
+/// The macros `@yearly`, `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight`, and `@hourly`
+/// are also accepted.
+///
+/// The year field allows years up to 999,999 when the `large-dates` feature is enabled.
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cron {
+    /// The allowed seconds, as a bitmask.
+    seconds: u64,
+    /// The allowed minutes, as a bitmask.
+    minutes: u64,
+    /// The allowed hours, as a bitmask.
+    hours: u64,
+    /// The allowed days of the month, as a bitmask.
+    days_of_month: u64,
+    /// The allowed number of days before the last day of the month, as a bitmask.
+    days_before_last: u64,
+    /// The days of the month whose nearest weekday is allowed, as a bitmask.
+    nearest_weekdays: u64,
+    /// Whether the last weekday of the month is allowed.
+    last_weekday_of_month: bool,
+    /// Whether the day of month field is unrestricted.
+    any_day_of_month: bool,
+    /// The allowed months, as a bitmask.
+    months: u64,
+    /// The allowed days of the week, as a bitmask indexed from Sunday.
+    weekdays: u64,
+    /// The allowed occurrences within the month of each day of the week, as bitmasks.
+    nth_weekdays: [u8; 7],
+    /// The days of the week whose last occurrence within the month is allowed, as a bitmask.
+    last_weekdays: u64,
+    /// Whether the day of week field is unrestricted.
+    any_weekday: bool,
+    /// The allowed years. Every year is allowed if this is empty.
+    years: Vec<YearRange>,
+}
+
+impl Cron {
+    /// Parse a cron expression.
+    ///
+    /// ```rust
+    /// # use time::Cron;
+    /// assert!(Cron::parse("*/15 9-17 * * MON-FRI").is_ok());
+    /// assert!(Cron::parse("0 0 12 L * ? 2030").is_ok());
+    /// assert!(Cron::parse("@weekly").is_ok());
+    /// assert!(Cron::parse("60 * * * *").is_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, error::Cron> {
+        let s = s.trim();
+        if s.starts_with('@') {
+            let expanded = match s.to_ascii_lowercase().as_str() {
+                "@yearly" | "@annually" => "0 0 1 1 *",
+                "@monthly" => "0 0 1 * *",
+                "@weekly" => "0 0 * * 0",
+                "@daily" | "@midnight" => "0 0 * * *",
+                "@hourly" => "0 * * * *",
+                _ => return Err(error::Cron::UnknownMacro),
+            };
+            return Self::parse(expanded);
+        }
+
+        let fields: Vec<_> = s.split_ascii_whitespace().collect();
+        let (second, rest) = match fields.len() {
+            5 => ("0", &fields[..]),
+            6 | 7 => (fields[0], &fields[1..]),
+            _ => return Err(error::Cron::InvalidFieldCount),
+        };
+
+        let mut cron = Self {
+            seconds: parse_field(second, 0, 59, &[]).ok_or(error::Cron::InvalidField("second"))?,
+            minutes: parse_field(rest[0], 0, 59, &[]).ok_or(error::Cron::InvalidField("minute"))?,
+            hours: parse_field(rest[1], 0, 23, &[]).ok_or(error::Cron::InvalidField("hour"))?,
+            days_of_month: 0,
+            days_before_last: 0,
+            nearest_weekdays: 0,
+            last_weekday_of_month: false,
+            any_day_of_month: matches!(rest[2], "*" | "?"),
+            months: parse_field(rest[3], 1, 12, &MONTH_NAMES)
+                .ok_or(error::Cron::InvalidField("month"))?,
+            weekdays: 0,
+            nth_weekdays: [0; 7],
+            last_weekdays: 0,
+            any_weekday: matches!(rest[4], "*" | "?"),
+            years: match rest.get(5) {
+                Some(&field) => parse_years(field).ok_or(error::Cron::InvalidField("year"))?,
+                None => Vec::new(),
+            },
+        };
+        cron.parse_days_of_month(rest[2])
+            .ok_or(error::Cron::InvalidField("day of month"))?;
+        cron.parse_weekdays(rest[4])
+            .ok_or(error::Cron::InvalidField("day of week"))?;
+        Ok(cron)
+    }
+
+    /// Parse the day of month field, which may contain `L` and `W` in addition to ranges.
+    fn parse_days_of_month(&mut self, field: &str) -> Option<()> {
+        if field == "?" {
+            return parse_field("*", 1, 31, &[]).map(|days| self.days_of_month = days);
+        }
+
+        for item in field.split(',') {
+            if item.eq_ignore_ascii_case("L") {
+                self.days_before_last |= 1;
+            } else if item.eq_ignore_ascii_case("LW") {
+                self.last_weekday_of_month = true;
+            } else if let Some(days) = strip_prefix_ignore_case(item, "L-") {
+                self.days_before_last |= 1 << parse_value(days, 0, 30, &[])?;
+            } else if let Some(day) = strip_suffix_ignore_case(item, "W") {
+                self.nearest_weekdays |= 1 << parse_value(day, 1, 31, &[])?;
+            } else {
+                self.days_of_month |= parse_range(item, 1, 31, &[])?;
+            }
+        }
+        Some(())
+    }
+
+    /// Parse the day of week field, which may contain `L` and `#` in addition to ranges.
+    fn parse_weekdays(&mut self, field: &str) -> Option<()> {
+        if field == "?" {
+            self.weekdays = 0x7F;
+            return Some(());
+        }
+
+        for item in field.split(',') {
+            let mut split = item.splitn(2, '#');
+            let weekday = split.next().unwrap_or_default();
+            if let Some(nth) = split.next() {
+                let weekday = parse_value(weekday, 0, 7, &WEEKDAY_NAMES)? % 7;
+                self.nth_weekdays[weekday as usize] |= 1 << parse_value(nth, 1, 5, &[])?;
+            } else if let Some(weekday) = strip_suffix_ignore_case(item, "L") {
+                self.last_weekdays |= 1 << (parse_value(weekday, 0, 7, &WEEKDAY_NAMES)? % 7);
+            } else {
+                self.weekdays |= parse_range(item, 0, 7, &WEEKDAY_NAMES)?;
+            }
+        }
+        // Seven is a synonym for Sunday.
+        self.weekdays = (self.weekdays | self.weekdays >> 7) & 0x7F;
+        Some(())
+    }
+
+    /// Get the next time the expression fires, strictly after the provided moment.
+    ///
+    /// The returned value has the same offset as the one provided. `None` is returned if the
+    /// expression never fires again before the end of the representable range.
+    ///
+    /// ```rust
+    /// # use time::{Cron, macros::datetime};
+    /// let cron = Cron::parse("0 9 * * MON-FRI")?;
+    /// assert_eq!(
+    ///     cron.next_after(datetime!("2021-01-01 9:00 +1")),
+    ///     Some(datetime!("2021-01-04 9:00 +1"))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn next_after(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        let (hour, minute, second) = datetime.to_hms();
+        self.next_at_or_after(datetime.date(), hour, minute, second + 1)
+            .map(|next| next.assume_offset(datetime.offset()))
+    }
+
+    /// Get the previous time the expression fired, strictly before the provided moment.
+    ///
+    /// The returned value has the same offset as the one provided. `None` is returned if the
+    /// expression never fired after the start of the representable range.
+    ///
+    /// ```rust
+    /// # use time::{Cron, macros::datetime};
+    /// let cron = Cron::parse("0 9 * * MON-FRI")?;
+    /// assert_eq!(
+    ///     cron.previous_before(datetime!("2021-01-04 9:00 +1")),
+    ///     Some(datetime!("2021-01-01 9:00 +1"))
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn previous_before(&self, datetime: OffsetDateTime) -> Option<OffsetDateTime> {
+        let (mut date, time) = (datetime.date(), datetime.time());
+        let (mut hour, mut minute, mut second) = time.as_hms();
+        if time.nanosecond() == 0 {
+            if second != 0 {
+                second -= 1;
+            } else if minute != 0 {
+                minute -= 1;
+                second = 59;
+            } else if hour != 0 {
+                hour -= 1;
+                minute = 59;
+                second = 59;
+            } else {
+                date = date.previous_day()?;
+                hour = 23;
+                minute = 59;
+                second = 59;
+            }
+        }
+        self.previous_at_or_before(date, hour, minute, second)
+            .map(|previous| previous.assume_offset(datetime.offset()))
+    }
+
+    /// Get an iterator over the times the expression fires, strictly after the provided moment.
+    ///
+    /// ```rust
+    /// # use time::{Cron, macros::datetime};
+    /// let cron = Cron::parse("0 0 L * ?")?;
+    /// assert_eq!(
+    ///     cron.occurrences(datetime!("2021-01-01 0:00 UTC"))
+    ///         .take(3)
+    ///         .collect::<Vec<_>>(),
+    ///     [
+    ///         datetime!("2021-01-31 0:00 UTC"),
+    ///         datetime!("2021-02-28 0:00 UTC"),
+    ///         datetime!("2021-03-31 0:00 UTC"),
+    ///     ]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn occurrences(&self, start: OffsetDateTime) -> Occurrences {
+        Occurrences {
+            cron: self.clone(),
+            current: Some(start),
+        }
+    }
+
+    // region: search
+    /// The first time the expression fires at or after the provided date and time of day. Any
+    /// component of the time may be one past its maximum value.
+    fn next_at_or_after(
+        &self,
+        mut date: Date,
+        mut hour: u8,
+        mut minute: u8,
+        mut second: u8,
+    ) -> Option<PrimitiveDateTime> {
+        loop {
+            let next = self.next_date(date)?;
+            if next != date {
+                date = next;
+                hour = 0;
+                minute = 0;
+                second = 0;
+            }
+            if let Some(time) = self.next_time(hour, minute, second) {
+                return Some(date.with_time(time));
+            }
+            date = date.next_day()?;
+            hour = 0;
+            minute = 0;
+            second = 0;
+        }
+    }
+
+    /// The last time the expression fired at or before the provided date and time of day.
+    fn previous_at_or_before(
+        &self,
+        mut date: Date,
+        mut hour: u8,
+        mut minute: u8,
+        mut second: u8,
+    ) -> Option<PrimitiveDateTime> {
+        loop {
+            let previous = self.previous_date(date)?;
+            if previous != date {
+                date = previous;
+                hour = 23;
+                minute = 59;
+                second = 59;
+            }
+            if let Some(time) = self.previous_time(hour, minute, second) {
+                return Some(date.with_time(time));
+            }
+            date = date.previous_day()?;
+            hour = 23;
+            minute = 59;
+            second = 59;
+        }
+    }
+
+    /// The first allowed time of day at or after the provided one.
+    fn next_time(&self, mut hour: u8, mut minute: u8, mut second: u8) -> Option<Time> {
+        loop {
+            let next_hour = next_bit(self.hours, hour)?;
+            if next_hour != hour {
+                minute = 0;
+                second = 0;
+            }
+            while let Some(next_minute) = next_bit(self.minutes, minute) {
+                if next_minute != minute {
+                    second = 0;
+                }
+                if let Some(next_second) = next_bit(self.seconds, second) {
+                    return Some(Time::__from_hms_nanos_unchecked(
+                        next_hour,
+                        next_minute,
+                        next_second,
+                        0,
+                    ));
+                }
+                minute = next_minute + 1;
+                second = 0;
+            }
+            hour = next_hour + 1;
+            minute = 0;
+            second = 0;
+        }
+    }
+
+    /// The last allowed time of day at or before the provided one.
+    fn previous_time(&self, mut hour: u8, mut minute: u8, mut second: u8) -> Option<Time> {
+        loop {
+            let previous_hour = previous_bit(self.hours, hour)?;
+            if previous_hour != hour {
+                minute = 59;
+                second = 59;
+            }
+            while let Some(previous_minute) = previous_bit(self.minutes, minute) {
+                if previous_minute != minute {
+                    second = 59;
+                }
+                if let Some(previous_second) = previous_bit(self.seconds, second) {
+                    return Some(Time::__from_hms_nanos_unchecked(
+                        previous_hour,
+                        previous_minute,
+                        previous_second,
+                        0,
+                    ));
+                }
+                if previous_minute == 0 {
+                    break;
+                }
+                minute = previous_minute - 1;
+                second = 59;
+            }
+            if previous_hour == 0 {
+                return None;
+            }
+            hour = previous_hour - 1;
+            minute = 59;
+            second = 59;
+        }
+    }
+
+    /// The first allowed date at or after the provided one.
+    fn next_date(&self, date: Date) -> Option<Date> {
+        let (mut year, mut month, mut day) = date.to_calendar_date();
+        let mut years_searched = 0;
+        loop {
+            let next_year = self.next_year(year)?;
+            if next_year != year {
+                year = next_year;
+                month = Month::January;
+                day = 1;
+            }
+            loop {
+                if self.months & (1 << month as u8) != 0 {
+                    let days = self.days(year, month) >> day << day;
+                    if days != 0 {
+                        return Date::from_calendar_date(year, month, days.trailing_zeros() as _)
+                            .ok();
+                    }
+                }
+                if month == Month::December {
+                    break;
+                }
+                month = month.next();
+                day = 1;
+            }
+            // The calendar repeats every 400 years, so there is no need to search any further.
+            years_searched += 1;
+            if self.years.is_empty() && years_searched > 400 {
+                return None;
+            }
+            year += 1;
+            month = Month::January;
+            day = 1;
+        }
+    }
+
+    /// The last allowed date at or before the provided one.
+    fn previous_date(&self, date: Date) -> Option<Date> {
+        let (mut year, mut month, mut day) = date.to_calendar_date();
+        let mut years_searched = 0;
+        loop {
+            let previous_year = self.previous_year(year)?;
+            if previous_year != year {
+                year = previous_year;
+                month = Month::December;
+                day = 31;
+            }
+            loop {
+                if self.months & (1 << month as u8) != 0 {
+                    let days = self.days(year, month) & (u64::MAX >> (63 - day));
+                    if days != 0 {
+                        return Date::from_calendar_date(
+                            year,
+                            month,
+                            (63 - days.leading_zeros()) as _,
+                        )
+                        .ok();
+                    }
+                }
+                if month == Month::January {
+                    break;
+                }
+                month = month.previous();
+                day = 31;
+            }
+            // The calendar repeats every 400 years, so there is no need to search any further.
+            years_searched += 1;
+            if self.years.is_empty() && years_searched > 400 {
+                return None;
+            }
+            year -= 1;
+            month = Month::December;
+            day = 31;
+        }
+    }
+
+    /// The first allowed year at or after the provided one.
+    fn next_year(&self, year: i32) -> Option<i32> {
+        if self.years.is_empty() {
+            if year > MAX_YEAR {
+                None
+            } else {
+                Some(year)
+            }
+        } else {
+            self.years.iter().filter_map(|range| range.next(year)).min()
+        }
+    }
+
+    /// The last allowed year at or before the provided one.
+    fn previous_year(&self, year: i32) -> Option<i32> {
+        if self.years.is_empty() {
+            if year < MIN_YEAR {
+                None
+            } else {
+                Some(year)
+            }
+        } else {
+            self.years
+                .iter()
+                .filter_map(|range| range.previous(year))
+                .max()
+        }
+    }
+
+    /// The allowed days of the provided month, as a bitmask.
+    fn days(&self, year: i32, month: Month) -> u64 {
+        let first_weekday = match Date::from_calendar_date(year, month, 1) {
+            Ok(first) => first.weekday().number_days_from_sunday(),
+            Err(_) => return 0,
+        };
+        let last = days_in_year_month(year, month);
+        let all = (u64::MAX >> (63 - last)) & !1;
+        if self.any_day_of_month && self.any_weekday {
+            return all;
+        }
+        let weekday = |day: u8| (first_weekday + day - 1) % 7;
+
+        let mut days_of_month = self.days_of_month & all;
+        for days_before_last in bits(self.days_before_last) {
+            if days_before_last < last {
+                days_of_month |= 1 << (last - days_before_last);
+            }
+        }
+        for day in bits(self.nearest_weekdays).filter(|&day| day <= last) {
+            let nearest = match weekday(day) {
+                6 if day == 1 => 3,
+                6 => day - 1,
+                0 if day == last => day - 2,
+                0 => day + 1,
+                _ => day,
+            };
+            days_of_month |= 1 << nearest;
+        }
+        if self.last_weekday_of_month {
+            days_of_month |= 1
+                << match weekday(last) {
+                    6 => last - 1,
+                    0 => last - 2,
+                    _ => last,
+                };
+        }
+
+        let mut weekdays = 0;
+        for day in 1..=last {
+            let weekday = weekday(day);
+            if self.weekdays & (1 << weekday) != 0
+                || self.nth_weekdays[weekday as usize] & (1 << ((day - 1) / 7 + 1)) != 0
+                || (self.last_weekdays & (1 << weekday) != 0 && day + 7 > last)
+            {
+                weekdays |= 1 << day;
+            }
+        }
+
+        match (self.any_day_of_month, self.any_weekday) {
+            (true, _) => weekdays,
+            (_, true) => days_of_month,
+            _ => days_of_month | weekdays,
+        }
+    }
+    // endregion search
+}
+
+// region: parsing helpers
+/// Strip a prefix from the string, ignoring ASCII case.
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
+
+/// Strip a suffix from the string, ignoring ASCII case.
+fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = s.len().checked_sub(suffix.len())?;
+    match s.get(split..) {
+        Some(end) if end.eq_ignore_ascii_case(suffix) => Some(&s[..split]),
+        _ => None,
+    }
+}
+
+/// Parse an unsigned integer consisting only of ASCII digits.
+fn parse_number(s: &str) -> Option<u32> {
+    if !s.is_empty() && s.bytes().all(|byte| byte.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parse a single value, which may be a number or one of the provided names. The first name
+/// corresponds to `min`.
+fn parse_value(s: &str, min: u8, max: u8, names: &[&str]) -> Option<u8> {
+    if let Some(index) = names.iter().position(|name| name.eq_ignore_ascii_case(s)) {
+        return Some(min + index as u8);
+    }
+    parse_number(s)
+        .and_then(|value| u8::try_from(value).ok())
+        .filter(|value| (min..=max).contains(value))
+}
+
+/// Parse a field that contains a list of ranges, returning the allowed values as a bitmask.
+fn parse_field(field: &str, min: u8, max: u8, names: &[&str]) -> Option<u64> {
+    field.split(',').try_fold(0, |mask, item| {
+        Some(mask | parse_range(item, min, max, names)?)
+    })
+}
+
+/// Parse a single value, range, or stepped range, returning the allowed values as a bitmask.
+fn parse_range(item: &str, min: u8, max: u8, names: &[&str]) -> Option<u64> {
+    let mut split = item.splitn(2, '/');
+    let range = split.next().unwrap_or_default();
+    let step = match split.next() {
+        Some(step) => parse_number(step).filter(|&step| step != 0)?,
+        None => 1,
+    };
+
+    let (start, end) = if range == "*" {
+        (min, max)
+    } else {
+        let mut bounds = range.splitn(2, '-');
+        let start = parse_value(bounds.next().unwrap_or_default(), min, max, names)?;
+        match bounds.next() {
+            Some(end) => (start, parse_value(end, min, max, names)?),
+            None if item.contains('/') => (start, max),
+            None => (start, start),
+        }
+    };
+
+    // Ranges wrap around when the end is before the start.
+    let len = u32::from(max - min) + 1;
+    let span = if end >= start {
+        u32::from(end - start)
+    } else {
+        u32::from(end) + len - u32::from(start)
+    };
+
+    let mut mask = 0;
+    let mut offset = 0;
+    while offset <= span {
+        let mut value = u32::from(start) + offset;
+        if value > u32::from(max) {
+            value -= len;
+        }
+        mask |= 1 << value;
+        offset = match offset.checked_add(step) {
+            Some(offset) => offset,
+            None => break,
+        };
+    }
+    Some(mask)
+}
+
+/// Parse the year field. An empty list is returned if every year is allowed.
+fn parse_years(field: &str) -> Option<Vec<YearRange>> {
+    if field == "*" {
+        return Some(Vec::new());
+    }
+
+    let year = |s: &str| {
+        parse_number(s)
+            .and_then(|year| i32::try_from(year).ok())
+            .filter(|&year| year <= MAX_YEAR)
+    };
+
+    field
+        .split(',')
+        .map(|item| {
+            let mut split = item.splitn(2, '/');
+            let range = split.next().unwrap_or_default();
+            let step = match split.next() {
+                Some(step) => parse_number(step)
+                    .and_then(|step| i32::try_from(step).ok())
+                    .filter(|&step| step != 0)?,
+                None => 1,
+            };
+
+            let (first, last) = if range == "*" {
+                (MIN_YEAR + (-MIN_YEAR).rem_euclid(step), MAX_YEAR)
+            } else {
+                let mut bounds = range.splitn(2, '-');
+                let first = year(bounds.next().unwrap_or_default())?;
+                match bounds.next() {
+                    Some(last) => (first, year(last)?),
+                    None if item.contains('/') => (first, MAX_YEAR),
+                    None => (first, first),
+                }
+            };
+            if first > last {
+                return None;
+            }
+
+            Some(YearRange {
+                first,
+                last: last - (last - first) % step,
+                step,
+            })
+        })
+        .collect()
+}
+// endregion parsing helpers
+
+// region: bit helpers
+/// The lowest set bit of the mask at or above the provided position.
+const fn next_bit(mask: u64, from: u8) -> Option<u8> {
+    if from >= 64 {
+        return None;
+    }
+    let mask = mask & (u64::MAX << from);
+    if mask == 0 {
+        None
+    } else {
+        Some(mask.trailing_zeros() as _)
+    }
+}
+
+/// The highest set bit of the mask at or below the provided position.
+const fn previous_bit(mask: u64, to: u8) -> Option<u8> {
+    let mask = if to >= 63 {
+        mask
+    } else {
+        mask & (u64::MAX >> (63 - to))
+    };
+    if mask == 0 {
+        None
+    } else {
+        Some((63 - mask.leading_zeros()) as _)
+    }
+}
+
+/// An iterator over the positions of the set bits in the mask, in ascending order.
+fn bits(mask: u64) -> impl Iterator<Item = u8> {
+    (0..64).filter(move |&bit| mask & (1 << bit) != 0)
+}
+// endregion bit helpers
+
+/// An iterator over the times a [`Cron`] expression fires.
+///
+/// This struct is created by [`Cron::occurrences`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    /// The expression being iterated over.
+    cron: Cron,
+    /// The most recently yielded value, or the start of the iterator. This is `None` once the
+    /// iterator is exhausted.
+    current: Option<OffsetDateTime>,
+}
+
+impl Iterator for Occurrences {
+    type Item = OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.current = self.cron.next_after(self.current?);
+        self.current
+    }
+}
+
+impl FusedIterator for Occurrences {}
@@ -10,8 +10,9 @@ use crate::format_description::{modifier, Component, FormatItem};
 use crate::formatting::formattable::sealed::Formattable;
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
+use crate::range::DateRange;
 use crate::util::{days_in_year, days_in_year_month, is_leap_year, weeks_in_year};
-use crate::{error, Duration, PrimitiveDateTime, Time, Weekday};
+use crate::{error, DayOverflow, Duration, Month, Period, PrimitiveDateTime, Time, Weekday};
 
 /// The minimum valid year.
 #[cfg(feature = "large-dates")]
@@ -75,18 +76,19 @@ impl Date {
     /// Attempt to create a `Date` from the year, month, and day.
     ///
     /// ```rust
-    /// # use time::Date;
-    /// assert!(Date::from_calendar_date(2019, 1, 1).is_ok());
-    /// assert!(Date::from_calendar_date(2019, 12, 31).is_ok());
+    /// # use time::{Date, Month};
+    /// assert!(Date::from_calendar_date(2019, Month::January, 1).is_ok());
+    /// assert!(Date::from_calendar_date(2019, Month::December, 31).is_ok());
     /// ```
     ///
     /// ```rust
-    /// # use time::Date;
-    /// assert!(Date::from_calendar_date(2019, 2, 29).is_err()); // 2019 isn't a leap year.
+    /// # use time::{Date, Month};
+    /// // 2019 isn't a leap year.
+    /// assert!(Date::from_calendar_date(2019, Month::February, 29).is_err());
     /// ```
     pub const fn from_calendar_date(
         year: i32,
-        month: u8,
+        month: Month,
         day: u8,
     ) -> Result<Self, error::ComponentRange> {
         /// Cumulative days through the beginning of a month in both common and leap years.
@@ -96,7 +98,6 @@ impl Date {
         ];
 
         ensure_value_in_range!(year in MIN_YEAR => MAX_YEAR);
-        ensure_value_in_range!(month in 1 => 12);
         ensure_value_in_range!(day conditionally in 1 => days_in_year_month(year, month));
 
         Ok(Self::__from_ordinal_date_unchecked(
@@ -246,14 +247,12 @@ impl Date {
 
     /// Get the month.
     ///
-    /// The returned value will always be in the range `1..=12`.
-    ///
     /// ```rust
-    /// # use time::macros::date;
-    /// assert_eq!(date!("2019-01-01").month(), 1);
-    /// assert_eq!(date!("2019-12-31").month(), 12);
+    /// # use time::{macros::date, Month};
+    /// assert_eq!(date!("2019-01-01").month(), Month::January);
+    /// assert_eq!(date!("2019-12-31").month(), Month::December);
     /// ```
-    pub const fn month(self) -> u8 {
+    pub const fn month(self) -> Month {
         self.month_day().0
     }
 
@@ -270,10 +269,24 @@ impl Date {
         self.month_day().1
     }
 
+    /// Get the quarter of the year, where the first quarter begins in January.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!("2019-01-01").quarter(), 1);
+    /// assert_eq!(date!("2019-06-30").quarter(), 2);
+    /// assert_eq!(date!("2019-12-31").quarter(), 4);
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        (self.month() as u8 - 1) / 3 + 1
+    }
+
     /// Get the month and day. This is more efficient than fetching the components individually.
     // For whatever reason, rustc has difficulty optimizing this function. It's significantly faster
     // to write the statements out by hand.
-    pub(crate) const fn month_day(self) -> (u8, u8) {
+    pub(crate) const fn month_day(self) -> (Month, u8) {
         /// The number of days up to and including the given month. Common years
         /// are first, followed by leap years.
         const CUMULATIVE_DAYS_IN_MONTH_COMMON_LEAP: [[u16; 11]; 2] = [
@@ -285,29 +298,29 @@ impl Date {
         let ordinal = self.ordinal();
 
         if ordinal > days[10] {
-            (12, (ordinal - days[10]) as _)
+            (Month::December, (ordinal - days[10]) as _)
         } else if ordinal > days[9] {
-            (11, (ordinal - days[9]) as _)
+            (Month::November, (ordinal - days[9]) as _)
         } else if ordinal > days[8] {
-            (10, (ordinal - days[8]) as _)
+            (Month::October, (ordinal - days[8]) as _)
         } else if ordinal > days[7] {
-            (9, (ordinal - days[7]) as _)
+            (Month::September, (ordinal - days[7]) as _)
         } else if ordinal > days[6] {
-            (8, (ordinal - days[6]) as _)
+            (Month::August, (ordinal - days[6]) as _)
         } else if ordinal > days[5] {
-            (7, (ordinal - days[5]) as _)
+            (Month::July, (ordinal - days[5]) as _)
         } else if ordinal > days[4] {
-            (6, (ordinal - days[4]) as _)
+            (Month::June, (ordinal - days[4]) as _)
         } else if ordinal > days[3] {
-            (5, (ordinal - days[3]) as _)
+            (Month::May, (ordinal - days[3]) as _)
         } else if ordinal > days[2] {
-            (4, (ordinal - days[2]) as _)
+            (Month::April, (ordinal - days[2]) as _)
         } else if ordinal > days[1] {
-            (3, (ordinal - days[1]) as _)
+            (Month::March, (ordinal - days[1]) as _)
         } else if ordinal > days[0] {
-            (2, (ordinal - days[0]) as _)
+            (Month::February, (ordinal - days[0]) as _)
         } else {
-            (1, ordinal as _)
+            (Month::January, ordinal as _)
         }
     }
 
@@ -384,10 +397,13 @@ impl Date {
     /// Get the year, month, and day.
     ///
     /// ```rust
-    /// # use time::macros::date;
-    /// assert_eq!(date!("2019-01-01").to_calendar_date(), (2019, 1, 1));
+    /// # use time::{macros::date, Month};
+    /// assert_eq!(
+    ///     date!("2019-01-01").to_calendar_date(),
+    ///     (2019, Month::January, 1)
+    /// );
     /// ```
-    pub const fn to_calendar_date(self) -> (i32, u8, u8) {
+    pub const fn to_calendar_date(self) -> (i32, Month, u8) {
         let (month, day) = self.month_day();
         (self.year(), month, day)
     }
@@ -531,6 +547,697 @@ impl Date {
     // endregion getters
 }
 
+// region: replacement
+/// Methods that replace part of the `Date`.
+impl Date {
+    /// Replace the year. The month and day will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!("2022-02-18").replace_year(2019), Ok(date!("2019-02-18")));
+    /// assert!(date!("2022-02-18").replace_year(-1_000_000_000).is_err()); // Not a valid year.
+    /// assert!(date!("2020-02-29").replace_year(2019).is_err()); // 2019 isn't a leap year.
+    /// ```
+    pub const fn replace_year(self, year: i32) -> Result<Self, error::ComponentRange> {
+        let (month, day) = self.month_day();
+        Self::from_calendar_date(year, month, day)
+    }
+
+    /// Replace the month of the year. The year and day will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Month};
+    /// assert_eq!(
+    ///     date!("2022-02-18").replace_month(Month::January),
+    ///     Ok(date!("2022-01-18"))
+    /// );
+    /// assert!(date!("2022-01-30").replace_month(Month::February).is_err()); // No February 30.
+    /// ```
+    pub const fn replace_month(self, month: Month) -> Result<Self, error::ComponentRange> {
+        Self::from_calendar_date(self.year(), month, self.day())
+    }
+
+    /// Replace the day of the month. The year and month will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!("2022-02-18").replace_day(1), Ok(date!("2022-02-01")));
+    /// assert!(date!("2022-02-18").replace_day(0).is_err()); // 0 isn't a valid day.
+    /// assert!(date!("2022-02-18").replace_day(30).is_err()); // February 30 doesn't exist.
+    /// ```
+    pub const fn replace_day(self, day: u8) -> Result<Self, error::ComponentRange> {
+        Self::from_calendar_date(self.year(), self.month(), day)
+    }
+
+    /// Replace the day of the year. The year will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!("2022-02-18").replace_ordinal(1), Ok(date!("2022-001")));
+    /// assert_eq!(date!("2020-02-18").replace_ordinal(366), Ok(date!("2020-366")));
+    /// assert!(date!("2022-02-18").replace_ordinal(366).is_err()); // 2022 isn't a leap year.
+    /// ```
+    pub const fn replace_ordinal(self, ordinal: u16) -> Result<Self, error::ComponentRange> {
+        Self::from_ordinal_date(self.year(), ordinal)
+    }
+}
+// endregion replacement
+
+// region: calendar arithmetic
+/// Methods that move a `Date` by calendar months and years.
+impl Date {
+    /// Add the provided number of months, preserving the day of the month. If that day does not
+    /// exist in the resulting month, `overflow` determines the result. Returns `None` if the
+    /// resulting date is out of range or the day is rejected.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, DayOverflow};
+    /// assert_eq!(
+    ///     date!("2021-01-15").checked_add_months(1, DayOverflow::Error),
+    ///     Some(date!("2021-02-15"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2021-01-31").checked_add_months(1, DayOverflow::Clamp),
+    ///     Some(date!("2021-02-28"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2021-01-31").checked_add_months(1, DayOverflow::Rollover),
+    ///     Some(date!("2021-03-03"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2021-01-31").checked_add_months(1, DayOverflow::Error),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     date!("2021-11-30").checked_add_months(-13, DayOverflow::Error),
+    ///     Some(date!("2020-10-30"))
+    /// );
+    /// ```
+    pub const fn checked_add_months(self, months: i32, overflow: DayOverflow) -> Option<Self> {
+        self.checked_add_months_wide(months as i64, overflow)
+    }
+
+    /// Subtract the provided number of months, preserving the day of the month. If that day does
+    /// not exist in the resulting month, `overflow` determines the result. Returns `None` if the
+    /// resulting date is out of range or the day is rejected.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, DayOverflow};
+    /// assert_eq!(
+    ///     date!("2021-03-31").checked_sub_months(1, DayOverflow::Clamp),
+    ///     Some(date!("2021-02-28"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2021-01-15").checked_sub_months(1, DayOverflow::Error),
+    ///     Some(date!("2020-12-15"))
+    /// );
+    /// ```
+    pub const fn checked_sub_months(self, months: i32, overflow: DayOverflow) -> Option<Self> {
+        self.checked_add_months_wide(-(months as i64), overflow)
+    }
+
+    /// Add the provided number of years, preserving the month and day. If the day does not exist
+    /// in the resulting year (February 29 in a common year), `overflow` determines the result.
+    /// Returns `None` if the resulting date is out of range or the day is rejected.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, DayOverflow};
+    /// assert_eq!(
+    ///     date!("2020-02-28").checked_add_years(1, DayOverflow::Error),
+    ///     Some(date!("2021-02-28"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2020-02-29").checked_add_years(1, DayOverflow::Clamp),
+    ///     Some(date!("2021-02-28"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2020-02-29").checked_add_years(1, DayOverflow::Rollover),
+    ///     Some(date!("2021-03-01"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2020-02-29").checked_add_years(4, DayOverflow::Error),
+    ///     Some(date!("2024-02-29"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2020-02-29").checked_add_years(1, DayOverflow::Error),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_add_years(self, years: i32, overflow: DayOverflow) -> Option<Self> {
+        let (year, month, day) = self.to_calendar_date();
+        Self::from_calendar_date_with_overflow(year as i64 + years as i64, month, day, overflow)
+    }
+
+    /// Subtract the provided number of years, preserving the month and day. If the day does not
+    /// exist in the resulting year (February 29 in a common year), `overflow` determines the
+    /// result. Returns `None` if the resulting date is out of range or the day is rejected.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, DayOverflow};
+    /// assert_eq!(
+    ///     date!("2020-02-29").checked_sub_years(1, DayOverflow::Clamp),
+    ///     Some(date!("2019-02-28"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2020-02-29").checked_sub_years(1, DayOverflow::Error),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_sub_years(self, years: i32, overflow: DayOverflow) -> Option<Self> {
+        let (year, month, day) = self.to_calendar_date();
+        Self::from_calendar_date_with_overflow(year as i64 - years as i64, month, day, overflow)
+    }
+
+    /// Get the calendar difference between two dates, such that adding it to `start` yields `end`.
+    ///
+    /// The number of whole months between the dates is determined first, with the remainder
+    /// expressed in days. If `end` is before `start`, all components are zero or negative.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date, Period};
+    /// assert_eq!(
+    ///     Date::period_between(date!("2021-01-15"), date!("2022-03-20")),
+    ///     Period::new(1, 2, 5)
+    /// );
+    /// assert_eq!(
+    ///     Date::period_between(date!("2021-01-31"), date!("2021-03-01")),
+    ///     Period::new(0, 1, 1)
+    /// );
+    /// assert_eq!(
+    ///     Date::period_between(date!("2021-03-10"), date!("2021-01-15")),
+    ///     Period::new(0, -1, -26)
+    /// );
+    /// ```
+    pub const fn period_between(start: Self, end: Self) -> Period {
+        let (start_year, start_month, start_day) = start.to_calendar_date();
+        let (end_year, end_month, end_day) = end.to_calendar_date();
+
+        let mut months = (end_year - start_year) * 12 + (end_month as i32 - start_month as i32);
+        let mut days = end_day as i32 - start_day as i32;
+
+        if months > 0 && days < 0 {
+            // Stop a month short. The day of the month is clamped when adding the months, so the
+            // remaining days are counted from the clamped day.
+            months -= 1;
+            let (year, month) = match end_month {
+                Month::January => (end_year - 1, Month::December),
+                month => (end_year, month.previous()),
+            };
+            let days_in_month = days_in_year_month(year, month) as i32;
+            days = end_day as i32;
+            if start_day as i32 <= days_in_month {
+                days += days_in_month - start_day as i32;
+            }
+        } else if months < 0 {
+            // The day of the month is clamped when subtracting the months, so the remaining days
+            // are counted from the clamped day. If that is before `end`, stop a month short.
+            let days_in_month = days_in_year_month(end_year, end_month) as i32;
+            days = end_day as i32;
+            if start_day as i32 <= days_in_month {
+                days -= start_day as i32;
+            } else {
+                days -= days_in_month;
+            }
+
+            if days > 0 {
+                months += 1;
+                let (year, month) = match end_month {
+                    Month::December => (end_year + 1, Month::January),
+                    month => (end_year, month.next()),
+                };
+                let days_in_next_month = days_in_year_month(year, month) as i32;
+                days = end_day as i32 - days_in_month;
+                if start_day as i32 <= days_in_next_month {
+                    days -= start_day as i32;
+                } else {
+                    days -= days_in_next_month;
+                }
+            }
+        }
+
+        Period::new(months / 12, months % 12, days)
+    }
+
+    /// Add the provided number of months. The number is wide enough that the caller need not check
+    /// for overflow.
+    pub(crate) const fn checked_add_months_wide(
+        self,
+        months: i64,
+        overflow: DayOverflow,
+    ) -> Option<Self> {
+        let (year, month, day) = self.to_calendar_date();
+        let month_index = year as i64 * 12 + (month as i64 - 1) + months;
+        Self::from_calendar_date_with_overflow(
+            div_floor!(month_index, 12),
+            Month::__from_number_unchecked(rem_euclid!(month_index, 12) as u8 + 1),
+            day,
+            overflow,
+        )
+    }
+
+    /// Add the provided [`Period`], returning `None` if the result is out of range.
+    pub(crate) const fn checked_add_period(self, period: Period) -> Option<Self> {
+        let date =
+            const_try_opt!(self.checked_add_months_wide(period.total_months(), DayOverflow::Clamp));
+        date.checked_add_days_wide(period.days() as i64)
+    }
+
+    /// Subtract the provided [`Period`], returning `None` if the result is out of range.
+    pub(crate) const fn checked_sub_period(self, period: Period) -> Option<Self> {
+        let date = const_try_opt!(
+            self.checked_add_months_wide(-period.total_months(), DayOverflow::Clamp)
+        );
+        date.checked_add_days_wide(-(period.days() as i64))
+    }
+
+    /// Add the provided number of days. The number is wide enough that the caller need not check
+    /// for overflow.
+    const fn checked_add_days_wide(self, days: i64) -> Option<Self> {
+        let julian_day = self.to_julian_day() as i64 + days;
+        if julian_day < Self::MIN.to_julian_day() as i64
+            || julian_day > Self::MAX.to_julian_day() as i64
+        {
+            None
+        } else {
+            Some(Self::from_julian_day_unchecked(julian_day as i32))
+        }
+    }
+
+    /// Create a `Date` from the year, month, and day, resolving a day past the end of the month
+    /// according to `overflow`. The year is wide enough that the caller need not check for
+    /// overflow.
+    const fn from_calendar_date_with_overflow(
+        year: i64,
+        month: Month,
+        day: u8,
+        overflow: DayOverflow,
+    ) -> Option<Self> {
+        if year < MIN_YEAR as i64 || year > MAX_YEAR as i64 {
+            return None;
+        }
+        let year = year as i32;
+        let days_in_month = days_in_year_month(year, month);
+
+        let (day, excess_days) = if day <= days_in_month {
+            (day, 0)
+        } else {
+            match overflow {
+                DayOverflow::Clamp => (days_in_month, 0),
+                DayOverflow::Rollover => (days_in_month, day - days_in_month),
+                DayOverflow::Error => return None,
+            }
+        };
+
+        match Self::from_calendar_date(year, month, day) {
+            Ok(date) => match Self::from_julian_day(date.to_julian_day() + excess_days as i32) {
+                Ok(date) => Some(date),
+                Err(_) => None,
+            },
+            Err(_) => None,
+        }
+    }
+}
+// endregion calendar arithmetic
+
+// region: quarters
+/// Methods that find the boundaries of the quarter containing a date.
+impl Date {
+    /// Get the first day of the quarter containing the date.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!("2021-05-17").quarter_start(), date!("2021-04-01"));
+    /// assert_eq!(date!("2021-10-01").quarter_start(), date!("2021-10-01"));
+    /// ```
+    #[must_use = "This method does not mutate the original `Date`."]
+    pub const fn quarter_start(self) -> Self {
+        let year = self.year();
+        let (mut month, day) = self.month_day();
+        let mut ordinal = self.ordinal() - day as u16 + 1;
+        while !matches!(
+            month,
+            Month::January | Month::April | Month::July | Month::October
+        ) {
+            month = month.previous();
+            ordinal -= month.length(year) as u16;
+        }
+        Self::__from_ordinal_date_unchecked(year, ordinal)
+    }
+
+    /// Get the last day of the quarter containing the date.
+    ///
+    /// ```rust
+    /// # use time::macros::date;
+    /// assert_eq!(date!("2020-01-15").quarter_end(), date!("2020-03-31"));
+    /// assert_eq!(date!("2021-12-31").quarter_end(), date!("2021-12-31"));
+    /// ```
+    #[must_use = "This method does not mutate the original `Date`."]
+    pub const fn quarter_end(self) -> Self {
+        let year = self.year();
+        let start = self.quarter_start();
+        let month = start.month();
+        Self::__from_ordinal_date_unchecked(
+            year,
+            start.ordinal() - 1
+                + month.length(year) as u16
+                + month.next().length(year) as u16
+                + month.next().next().length(year) as u16,
+        )
+    }
+}
+// endregion quarters
+
+// region: weekday occurrences
+/// Methods that find dates falling on a given day of the week.
+impl Date {
+    /// Get the first date after this one that falls on the provided weekday, returning `None` if
+    /// the result is out of range. The result is always between one and seven days later.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Weekday};
+    /// assert_eq!(
+    ///     date!("2021-06-02").next_occurrence(Weekday::Monday),
+    ///     Some(date!("2021-06-07"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2021-06-07").next_occurrence(Weekday::Monday),
+    ///     Some(date!("2021-06-14"))
+    /// );
+    /// ```
+    pub const fn next_occurrence(self, weekday: Weekday) -> Option<Self> {
+        let days = rem_euclid!(
+            weekday.number_days_from_monday() as i8
+                - self.weekday().number_days_from_monday() as i8
+                - 1,
+            7
+        ) + 1;
+        self.checked_add_days_wide(days as _)
+    }
+
+    /// Get the last date before this one that falls on the provided weekday, returning `None` if
+    /// the result is out of range. The result is always between one and seven days earlier.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Weekday};
+    /// assert_eq!(
+    ///     date!("2021-06-02").previous_occurrence(Weekday::Monday),
+    ///     Some(date!("2021-05-31"))
+    /// );
+    /// assert_eq!(
+    ///     date!("2021-05-31").previous_occurrence(Weekday::Monday),
+    ///     Some(date!("2021-05-24"))
+    /// );
+    /// ```
+    pub const fn previous_occurrence(self, weekday: Weekday) -> Option<Self> {
+        let days = rem_euclid!(
+            self.weekday().number_days_from_monday() as i8
+                - weekday.number_days_from_monday() as i8
+                - 1,
+            7
+        ) + 1;
+        self.checked_add_days_wide(-(days as i64))
+    }
+
+    /// Get the `n`th occurrence of the provided weekday in the given month, with `n` starting at
+    /// one. Depending on the month, the weekday occurs either four or five times.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date, Month, Weekday};
+    /// // The third Tuesday of June 2021.
+    /// assert_eq!(
+    ///     Date::nth_weekday_of_month(2021, Month::June, 3, Weekday::Tuesday),
+    ///     Ok(date!("2021-06-15"))
+    /// );
+    /// assert!(Date::nth_weekday_of_month(2021, Month::June, 5, Weekday::Tuesday).is_ok());
+    /// assert!(Date::nth_weekday_of_month(2021, Month::June, 5, Weekday::Thursday).is_err());
+    /// assert!(Date::nth_weekday_of_month(2021, Month::June, 0, Weekday::Tuesday).is_err());
+    /// ```
+    pub const fn nth_weekday_of_month(
+        year: i32,
+        month: Month,
+        n: u8,
+        weekday: Weekday,
+    ) -> Result<Self, error::ComponentRange> {
+        let first = const_try!(Self::from_calendar_date(year, month, 1));
+        let offset = rem_euclid!(
+            weekday.number_days_from_monday() as i8
+                - first.weekday().number_days_from_monday() as i8,
+            7
+        ) as u8;
+        ensure_value_in_range!(
+            n conditionally in 1 => (days_in_year_month(year, month) - 1 - offset) / 7 + 1
+        );
+        Ok(Self::__from_ordinal_date_unchecked(
+            year,
+            first.ordinal() + offset as u16 + (n as u16 - 1) * 7,
+        ))
+    }
+
+    /// Get the last occurrence of the provided weekday in the given month.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date, Month, Weekday};
+    /// assert_eq!(
+    ///     Date::last_weekday_of_month(2021, Month::May, Weekday::Monday),
+    ///     Ok(date!("2021-05-31"))
+    /// );
+    /// assert_eq!(
+    ///     Date::last_weekday_of_month(2021, Month::May, Weekday::Tuesday),
+    ///     Ok(date!("2021-05-25"))
+    /// );
+    /// ```
+    pub const fn last_weekday_of_month(
+        year: i32,
+        month: Month,
+        weekday: Weekday,
+    ) -> Result<Self, error::ComponentRange> {
+        let last = const_try!(Self::from_calendar_date(
+            year,
+            month,
+            days_in_year_month(year, month)
+        ));
+        let offset = rem_euclid!(
+            last.weekday().number_days_from_monday() as i8
+                - weekday.number_days_from_monday() as i8,
+            7
+        ) as u16;
+        Ok(Self::__from_ordinal_date_unchecked(
+            year,
+            last.ordinal() - offset,
+        ))
+    }
+}
+// endregion weekday occurrences
+
+// region: checked and saturating arithmetic
+/// Methods that add or subtract a duration without panicking. Only whole days of the duration are
+/// taken into account.
+impl Date {
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalDuration, macros::date};
+    /// assert_eq!(Date::MAX.checked_add(1.days()), None);
+    /// assert_eq!(Date::MIN.checked_add((-2).days()), None);
+    /// assert_eq!(
+    ///     date!("2020-12-31").checked_add(2.days()),
+    ///     Some(date!("2021-01-02"))
+    /// );
+    /// ```
+    pub const fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.checked_add_days_wide(duration.whole_days())
+    }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration, macros::date};
+    /// assert_eq!(Date::MAX.checked_add_std(1.std_days()), None);
+    /// assert_eq!(
+    ///     date!("2020-12-31").checked_add_std(2.std_days()),
+    ///     Some(date!("2021-01-02"))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        self.checked_add_days_wide((duration.as_secs() / 86_400) as _)
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalDuration, macros::date};
+    /// assert_eq!(Date::MAX.checked_sub((-2).days()), None);
+    /// assert_eq!(Date::MIN.checked_sub(1.days()), None);
+    /// assert_eq!(
+    ///     date!("2020-12-31").checked_sub(2.days()),
+    ///     Some(date!("2020-12-29"))
+    /// );
+    /// ```
+    pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.checked_add_days_wide(-duration.whole_days())
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration, macros::date};
+    /// assert_eq!(Date::MIN.checked_sub_std(1.std_days()), None);
+    /// assert_eq!(
+    ///     date!("2020-12-31").checked_sub_std(2.std_days()),
+    ///     Some(date!("2020-12-29"))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        self.checked_add_days_wide(-((duration.as_secs() / 86_400) as i64))
+    }
+
+    /// Computes `self + duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalDuration, macros::date};
+    /// assert_eq!(Date::MAX.saturating_add(1.days()), Date::MAX);
+    /// assert_eq!(Date::MIN.saturating_add((-2).days()), Date::MIN);
+    /// assert_eq!(
+    ///     date!("2020-12-31").saturating_add(2.days()),
+    ///     date!("2021-01-02")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `Date`."]
+    pub const fn saturating_add(self, duration: Duration) -> Self {
+        match self.checked_add(duration) {
+            Some(date) => date,
+            None if duration.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Computes `self + duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration, macros::date};
+    /// assert_eq!(Date::MAX.saturating_add_std(1.std_days()), Date::MAX);
+    /// assert_eq!(
+    ///     date!("2020-12-31").saturating_add_std(2.std_days()),
+    ///     date!("2021-01-02")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `Date`."]
+    pub const fn saturating_add_std(self, duration: StdDuration) -> Self {
+        match self.checked_add_std(duration) {
+            Some(date) => date,
+            None => Self::MAX,
+        }
+    }
+
+    /// Computes `self - duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalDuration, macros::date};
+    /// assert_eq!(Date::MAX.saturating_sub((-2).days()), Date::MAX);
+    /// assert_eq!(Date::MIN.saturating_sub(1.days()), Date::MIN);
+    /// assert_eq!(
+    ///     date!("2020-12-31").saturating_sub(2.days()),
+    ///     date!("2020-12-29")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `Date`."]
+    pub const fn saturating_sub(self, duration: Duration) -> Self {
+        match self.checked_sub(duration) {
+            Some(date) => date,
+            None if duration.is_negative() => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+
+    /// Computes `self - duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration, macros::date};
+    /// assert_eq!(Date::MIN.saturating_sub_std(1.std_days()), Date::MIN);
+    /// assert_eq!(
+    ///     date!("2020-12-31").saturating_sub_std(2.std_days()),
+    ///     date!("2020-12-29")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `Date`."]
+    pub const fn saturating_sub_std(self, duration: StdDuration) -> Self {
+        match self.checked_sub_std(duration) {
+            Some(date) => date,
+            None => Self::MIN,
+        }
+    }
+}
+// endregion checked and saturating arithmetic
+
+// region: ranges and stepping
+/// Methods for iterating over and stepping between dates. The stepping methods mirror the unstable
+/// `Step` trait in the standard library.
+impl Date {
+    /// Create an iterator over every date from `start` up to but not including `end`. If `end` is
+    /// not after `start`, the iterator is empty.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// let mut dates = Date::range(date!("2021-12-31"), date!("2022-01-02"));
+    /// assert_eq!(dates.len(), 2);
+    /// assert_eq!(dates.next(), Some(date!("2021-12-31")));
+    /// assert_eq!(dates.next_back(), Some(date!("2022-01-01")));
+    /// assert_eq!(dates.next(), None);
+    /// ```
+    pub const fn range(start: Self, end: Self) -> DateRange {
+        DateRange::new(start, end)
+    }
+
+    /// Get the number of days needed to get from `start` to `end`, returning `None` if `end` is
+    /// before `start`.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// assert_eq!(Date::steps_between(date!("2021-01-01"), date!("2021-03-01")), Some(59));
+    /// assert_eq!(Date::steps_between(date!("2021-03-01"), date!("2021-03-01")), Some(0));
+    /// assert_eq!(Date::steps_between(date!("2021-03-01"), date!("2021-01-01")), None);
+    /// ```
+    pub const fn steps_between(start: Self, end: Self) -> Option<usize> {
+        let days = end.to_julian_day() - start.to_julian_day();
+        if days < 0 {
+            None
+        } else {
+            Some(days as _)
+        }
+    }
+
+    /// Advance `start` by the provided number of days, returning `None` if the result is out of
+    /// range.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// assert_eq!(Date::forward_checked(date!("2021-01-01"), 59), Some(date!("2021-03-01")));
+    /// assert_eq!(Date::forward_checked(Date::MAX, 1), None);
+    /// ```
+    pub const fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        if count > i32::MAX as usize {
+            return None;
+        }
+        start.checked_add_days_wide(count as i64)
+    }
+
+    /// Move `start` back by the provided number of days, returning `None` if the result is out of
+    /// range.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// assert_eq!(Date::backward_checked(date!("2021-03-01"), 59), Some(date!("2021-01-01")));
+    /// assert_eq!(Date::backward_checked(Date::MIN, 1), None);
+    /// ```
+    pub const fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        if count > i32::MAX as usize {
+            return None;
+        }
+        start.checked_add_days_wide(-(count as i64))
+    }
+}
+// endregion ranges and stepping
+
 // region: attach time
 /// Methods to add a [`Time`] component, resulting in a [`PrimitiveDateTime`].
 impl Date {
@@ -651,7 +1358,7 @@ impl Date {
         output: &mut impl io::Write,
         format: &F,
     ) -> Result<usize, F::Error> {
-        format.format_into(output, Some(self), None, None)
+        format.format_into(output, Some(self), None, None, None)
     }
 
     /// Format the `Date` using the provided format description. The format description will
@@ -665,7 +1372,7 @@ impl Date {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
-        format.format(Some(self), None, None)
+        format.format(Some(self), None, None, None)
     }
 }
 
@@ -711,7 +1418,9 @@ impl fmt::Display for Date {
         ];
         match self.format(&FORMAT) {
             Ok(ref s) => f.write_str(s),
-            Err(error::Format::InvalidComponent(_)) => {
+            Err(error::Format::InvalidComponent(_))
+            | Err(error::Format::Rfc2822YearBefore1900)
+            | Err(error::Format::Rfc2822OffsetSecond) => {
                 unreachable!("A well-known format is not used")
             }
             Err(error::Format::InsufficientTypeInformation) => {
@@ -728,7 +1437,7 @@ impl Add<Duration> for Date {
     type Output = Self;
 
     fn add(self, duration: Duration) -> Self::Output {
-        Self::from_julian_day(self.to_julian_day() + duration.whole_days() as i32)
+        self.checked_add(duration)
             .expect("overflow adding duration to date")
     }
 }
@@ -737,7 +1446,7 @@ impl Add<StdDuration> for Date {
     type Output = Self;
 
     fn add(self, duration: StdDuration) -> Self::Output {
-        Self::from_julian_day(self.to_julian_day() + (duration.as_secs() / 86_400) as i32)
+        self.checked_add_std(duration)
             .expect("overflow adding duration to date")
     }
 }
@@ -758,7 +1467,8 @@ impl Sub<Duration> for Date {
     type Output = Self;
 
     fn sub(self, duration: Duration) -> Self::Output {
-        self + -duration
+        self.checked_sub(duration)
+            .expect("overflow subtracting duration from date")
     }
 }
 
@@ -766,7 +1476,7 @@ impl Sub<StdDuration> for Date {
     type Output = Self;
 
     fn sub(self, duration: StdDuration) -> Self::Output {
-        Self::from_julian_day(self.to_julian_day() - (duration.as_secs() / 86_400) as i32)
+        self.checked_sub_std(duration)
             .expect("overflow subtracting duration from date")
     }
 }
@@ -783,6 +1493,36 @@ impl SubAssign<StdDuration> for Date {
     }
 }
 
+impl Add<Period> for Date {
+    type Output = Self;
+
+    fn add(self, period: Period) -> Self::Output {
+        self.checked_add_period(period)
+            .expect("overflow adding period to date")
+    }
+}
+
+impl AddAssign<Period> for Date {
+    fn add_assign(&mut self, period: Period) {
+        *self = *self + period;
+    }
+}
+
+impl Sub<Period> for Date {
+    type Output = Self;
+
+    fn sub(self, period: Period) -> Self::Output {
+        self.checked_sub_period(period)
+            .expect("overflow subtracting period from date")
+    }
+}
+
+impl SubAssign<Period> for Date {
+    fn sub_assign(&mut self, period: Period) {
+        *self = *self - period;
+    }
+}
+
 impl Sub<Date> for Date {
     type Output = Duration;
 
@@ -0,0 +1,18 @@
+/// How to handle a day of the month that does not exist after calendar arithmetic.
+///
+/// Adding months or years to a date preserves the day of the month. When that day does not exist
+/// in the resulting month, such as one month after January 31 or one year after February 29, this
+/// policy determines the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayOverflow {
+    /// Use the last day of the resulting month.
+    ///
+    /// One month after 2021-01-31 is 2021-02-28.
+    Clamp,
+    /// Carry the excess days into the following month.
+    ///
+    /// One month after 2021-01-31 is 2021-03-03.
+    Rollover,
+    /// Treat the result as invalid, returning `None`.
+    Error,
+}
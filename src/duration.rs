@@ -618,6 +618,14 @@ impl Duration {
 
         Some(Self::new_unchecked(seconds, nanoseconds))
     }
+
+    /// Computes the least non-negative remainder of `self / rhs`. `rhs` must be nonzero.
+    pub(crate) const fn rem_euclid(self, rhs: Self) -> Self {
+        Self::nanoseconds_i128(rem_euclid!(
+            self.whole_nanoseconds(),
+            rhs.whole_nanoseconds()
+        ))
+    }
     // endregion checked arithmetic
 
     // region: saturating arithmetic
@@ -0,0 +1,40 @@
+//! Error parsing a cron expression
+
+use core::fmt;
+
+/// An error occurred when parsing a [`Cron`](crate::Cron) expression.
+#[non_exhaustive]
+#[allow(missing_copy_implementations)]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cron {
+    /// The expression does not have five, six, or seven fields.
+    InvalidFieldCount,
+    /// A field of the expression is not valid. The name of the field is included.
+    InvalidField(&'static str),
+    /// The expression is a macro, such as `@daily`, that is not known.
+    UnknownMacro,
+}
+
+impl fmt::Display for Cron {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFieldCount => {
+                f.write_str("cron expressions must have five, six, or seven fields")
+            }
+            Self::InvalidField(field) => write!(f, "invalid {} field in cron expression", field),
+            Self::UnknownMacro => f.write_str("unknown cron macro"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+impl std::error::Error for Cron {}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl From<Cron> for crate::Error {
+    fn from(original: Cron) -> Self {
+        Self::Cron(original)
+    }
+}
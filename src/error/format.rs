@@ -14,8 +14,15 @@ pub enum Format {
     InsufficientTypeInformation,
     /// The component named has a value that cannot be formatted into the requested format.
     ///
-    /// This variant is only returned when using well-known formats.
+    /// This variant is only returned when using well-known formats, or when formatting the name of a
+    /// time zone that does not have one.
     InvalidComponent(&'static str),
+    /// The year is before 1900, which cannot be formatted as
+    /// [RFC 2822](crate::format_description::well_known::Rfc2822).
+    Rfc2822YearBefore1900,
+    /// The offset has a nonzero second, which cannot be formatted as
+    /// [RFC 2822](crate::format_description::well_known::Rfc2822).
+    Rfc2822OffsetSecond,
     /// A value of `std::io::Error` was returned internally.
     StdIo(io::Error),
 }
@@ -32,6 +39,12 @@ impl fmt::Display for Format {
                 "The {} component cannot be formatted into the requested format.",
                 component
             ),
+            Self::Rfc2822YearBefore1900 => {
+                f.write_str("Years before 1900 cannot be formatted as RFC 2822.")
+            }
+            Self::Rfc2822OffsetSecond => {
+                f.write_str("Offsets with a nonzero second cannot be formatted as RFC 2822.")
+            }
             Self::StdIo(err) => err.fmt(f),
         }
     }
@@ -48,7 +61,10 @@ impl From<io::Error> for Format {
 impl std::error::Error for Format {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
-            Self::InsufficientTypeInformation | Self::InvalidComponent(_) => None,
+            Self::InsufficientTypeInformation
+            | Self::InvalidComponent(_)
+            | Self::Rfc2822YearBefore1900
+            | Self::Rfc2822OffsetSecond => None,
             Self::StdIo(ref err) => Some(err),
         }
     }
@@ -34,6 +34,14 @@ pub enum InvalidFormatDescription {
         /// The zero-based index where the component name should start.
         index: usize,
     },
+    /// A required part of the format description is missing, such as the nested description of an
+    /// `[optional]` item.
+    Expected {
+        /// What was expected.
+        what: &'static str,
+        /// The zero-based index where it was expected.
+        index: usize,
+    },
 }
 
 #[cfg_attr(
@@ -64,6 +72,7 @@ impl fmt::Display for InvalidFormatDescription {
             MissingComponentName { index } => {
                 write!(f, "missing component name at byte index {}", index)
             }
+            Expected { what, index } => write!(f, "expected {} at byte index {}", what, index),
         }
     }
 }
@@ -1,5 +1,7 @@
 mod component_range;
 mod conversion_range;
+#[cfg(feature = "alloc")]
+mod cron;
 #[cfg(feature = "formatting")]
 mod format;
 #[cfg(feature = "local-offset")]
@@ -10,6 +12,10 @@ mod invalid_format_description;
 mod parse;
 #[cfg(feature = "parsing")]
 mod parse_from_description;
+#[cfg(feature = "alloc")]
+mod recurrence;
+#[cfg(feature = "alloc")]
+mod time_zone;
 #[cfg(feature = "parsing")]
 mod try_from_parsed;
 
@@ -17,6 +23,8 @@ use core::fmt;
 
 pub use component_range::ComponentRange;
 pub use conversion_range::ConversionRange;
+#[cfg(feature = "alloc")]
+pub use cron::Cron;
 #[cfg(feature = "formatting")]
 pub use format::Format;
 #[cfg(feature = "local-offset")]
@@ -27,6 +35,10 @@ pub use invalid_format_description::InvalidFormatDescription;
 pub use parse::Parse;
 #[cfg(feature = "parsing")]
 pub use parse_from_description::ParseFromDescription;
+#[cfg(feature = "alloc")]
+pub use recurrence::Recurrence;
+#[cfg(feature = "alloc")]
+pub use time_zone::TimeZone;
 #[cfg(feature = "parsing")]
 pub use try_from_parsed::TryFromParsed;
 
@@ -63,6 +75,15 @@ pub enum Error {
         doc(cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc")))
     )]
     InvalidFormatDescription(InvalidFormatDescription),
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    TimeZone(TimeZone),
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    Recurrence(Recurrence),
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    Cron(Cron),
 }
 
 impl fmt::Display for Error {
@@ -82,6 +103,12 @@ impl fmt::Display for Error {
             Self::TryFromParsed(e) => e.fmt(f),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(e) => e.fmt(f),
+            #[cfg(feature = "alloc")]
+            Self::TimeZone(e) => e.fmt(f),
+            #[cfg(feature = "alloc")]
+            Self::Recurrence(e) => e.fmt(f),
+            #[cfg(feature = "alloc")]
+            Self::Cron(e) => e.fmt(f),
         }
     }
 }
@@ -105,6 +132,12 @@ impl std::error::Error for Error {
             Self::TryFromParsed(err) => Some(err),
             #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Self::TimeZone(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Self::Recurrence(err) => Some(err),
+            #[cfg(feature = "alloc")]
+            Self::Cron(err) => Some(err),
         }
     }
 }
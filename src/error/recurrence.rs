@@ -0,0 +1,36 @@
+//! Error parsing a recurrence
+
+use core::fmt;
+
+/// An error occurred when parsing an [`RRule`](crate::RRule) or
+/// [`RecurrenceSet`](crate::rrule::RecurrenceSet).
+#[non_exhaustive]
+#[allow(missing_copy_implementations)]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recurrence {
+    /// The provided string is not a valid recurrence rule. The reason it was rejected is included.
+    InvalidRRule(&'static str),
+    /// The provided string is not a valid recurrence set. The reason it was rejected is included.
+    InvalidRecurrenceSet(&'static str),
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRRule(reason) => write!(f, "invalid recurrence rule: {}", reason),
+            Self::InvalidRecurrenceSet(reason) => write!(f, "invalid recurrence set: {}", reason),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+impl std::error::Error for Recurrence {}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl From<Recurrence> for crate::Error {
+    fn from(original: Recurrence) -> Self {
+        Self::Recurrence(original)
+    }
+}
@@ -0,0 +1,63 @@
+//! Error loading a time zone
+
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+/// An error occurred when loading a [`TimeZone`](crate::TimeZone).
+#[non_exhaustive]
+#[allow(missing_copy_implementations)]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+pub enum TimeZone {
+    /// The provided data is not a valid `TZif` file. The reason it was rejected is included.
+    InvalidTzif(&'static str),
+    /// The provided string is not a valid POSIX TZ string. The reason it was rejected is included.
+    InvalidPosixTz(&'static str),
+    /// The provided name is not a valid time zone name.
+    ///
+    /// Names must be relative paths that do not contain `.` or `..` components.
+    InvalidName,
+    /// A value of `std::io::Error` was returned internally.
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    StdIo(io::Error),
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTzif(reason) => write!(f, "invalid TZif data: {}", reason),
+            Self::InvalidPosixTz(reason) => write!(f, "invalid POSIX TZ string: {}", reason),
+            Self::InvalidName => f.write_str("invalid time zone name"),
+            #[cfg(feature = "std")]
+            Self::StdIo(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+impl From<io::Error> for TimeZone {
+    fn from(err: io::Error) -> Self {
+        Self::StdIo(err)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+impl std::error::Error for TimeZone {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Self::InvalidTzif(_) | Self::InvalidPosixTz(_) | Self::InvalidName => None,
+            Self::StdIo(ref err) => Some(err),
+        }
+    }
+}
+
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl From<TimeZone> for crate::Error {
+    fn from(original: TimeZone) -> Self {
+        Self::TimeZone(original)
+    }
+}
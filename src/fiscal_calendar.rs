@@ -0,0 +1,168 @@
+use crate::date::{MAX_YEAR, MIN_YEAR};
+use crate::{error, Date, Month};
+
+/// How a fiscal year is named when it spans two calendar years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FiscalYearNaming {
+    /// The fiscal year is named after the calendar year in which it begins.
+    ///
+    /// With a fiscal year beginning in April, 2021-04-01 through 2022-03-31 is fiscal year 2021.
+    StartYear,
+    /// The fiscal year is named after the calendar year in which it ends.
+    ///
+    /// With a fiscal year beginning in October, 2020-10-01 through 2021-09-30 is fiscal year 2021.
+    EndYear,
+}
+
+/// A fiscal year beginning on the first day of a given month.
+///
+/// Fiscal quarters and periods are numbered from the start of the fiscal year. Each quarter is
+/// three periods, and each period is one calendar month.
+///
+/// ```rust
+/// # use time::{macros::date, FiscalCalendar, FiscalYearNaming, Month};
+/// let calendar = FiscalCalendar::new(Month::October, FiscalYearNaming::EndYear);
+/// assert_eq!(calendar.year(date!("2020-10-01")), 2021);
+/// assert_eq!(calendar.quarter(date!("2020-10-01")), 1);
+/// assert_eq!(calendar.period(date!("2021-09-30")), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    /// The month in which the fiscal year begins.
+    start: Month,
+    /// How the fiscal year is named.
+    naming: FiscalYearNaming,
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> Self {
+        Self::CALENDAR_YEAR
+    }
+}
+
+impl FiscalCalendar {
+    /// A fiscal year that is identical to the calendar year. This is the default.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, FiscalCalendar};
+    /// assert_eq!(FiscalCalendar::CALENDAR_YEAR.year(date!("2021-08-19")), 2021);
+    /// assert_eq!(FiscalCalendar::CALENDAR_YEAR.quarter(date!("2021-08-19")), 3);
+    /// ```
+    pub const CALENDAR_YEAR: Self = Self::new(Month::January, FiscalYearNaming::EndYear);
+
+    /// Create a `FiscalCalendar` whose years begin in the provided month.
+    ///
+    /// ```rust
+    /// # use time::{FiscalCalendar, FiscalYearNaming, Month};
+    /// let calendar = FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear);
+    /// assert_eq!(calendar.start(), Month::April);
+    /// ```
+    pub const fn new(start: Month, naming: FiscalYearNaming) -> Self {
+        Self { start, naming }
+    }
+
+    /// Get the month in which the fiscal year begins.
+    ///
+    /// ```rust
+    /// # use time::FiscalCalendar;
+    /// assert_eq!(FiscalCalendar::CALENDAR_YEAR.start(), time::Month::January);
+    /// ```
+    pub const fn start(self) -> Month {
+        self.start
+    }
+
+    /// Get how the fiscal year is named.
+    ///
+    /// ```rust
+    /// # use time::{FiscalCalendar, FiscalYearNaming};
+    /// assert_eq!(
+    ///     FiscalCalendar::CALENDAR_YEAR.naming(),
+    ///     FiscalYearNaming::EndYear
+    /// );
+    /// ```
+    pub const fn naming(self) -> FiscalYearNaming {
+        self.naming
+    }
+
+    /// Get the fiscal year containing the date.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, FiscalCalendar, FiscalYearNaming, Month};
+    /// let start_year = FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear);
+    /// assert_eq!(start_year.year(date!("2021-03-31")), 2020);
+    /// assert_eq!(start_year.year(date!("2021-04-01")), 2021);
+    ///
+    /// let end_year = FiscalCalendar::new(Month::April, FiscalYearNaming::EndYear);
+    /// assert_eq!(end_year.year(date!("2021-03-31")), 2021);
+    /// assert_eq!(end_year.year(date!("2021-04-01")), 2022);
+    /// ```
+    pub const fn year(self, date: Date) -> i32 {
+        let start_year = date.year() - ((date.month() as u8) < self.start as u8) as i32;
+        start_year + self.year_offset()
+    }
+
+    /// Get the fiscal quarter containing the date.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, FiscalCalendar, FiscalYearNaming, Month};
+    /// let calendar = FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear);
+    /// assert_eq!(calendar.quarter(date!("2021-04-01")), 1);
+    /// assert_eq!(calendar.quarter(date!("2021-12-31")), 3);
+    /// assert_eq!(calendar.quarter(date!("2022-01-01")), 4);
+    /// ```
+    pub const fn quarter(self, date: Date) -> u8 {
+        (self.period(date) - 1) / 3 + 1
+    }
+
+    /// Get the fiscal period containing the date. Each period is one calendar month.
+    ///
+    /// The returned value will always be in the range `1..=12`.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, FiscalCalendar, FiscalYearNaming, Month};
+    /// let calendar = FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear);
+    /// assert_eq!(calendar.period(date!("2021-04-30")), 1);
+    /// assert_eq!(calendar.period(date!("2022-03-01")), 12);
+    /// ```
+    pub const fn period(self, date: Date) -> u8 {
+        (date.month() as u8 + 12 - self.start as u8) % 12 + 1
+    }
+
+    /// Get the first day of the provided fiscal year.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, FiscalCalendar, FiscalYearNaming, Month};
+    /// let calendar = FiscalCalendar::new(Month::October, FiscalYearNaming::EndYear);
+    /// assert_eq!(calendar.first_day(2021), Ok(date!("2020-10-01")));
+    /// ```
+    pub const fn first_day(self, fiscal_year: i32) -> Result<Date, error::ComponentRange> {
+        ensure_value_in_range!(
+            fiscal_year in MIN_YEAR + self.year_offset() => MAX_YEAR + self.year_offset()
+        );
+        Date::from_calendar_date(fiscal_year - self.year_offset(), self.start, 1)
+    }
+
+    /// Get the last day of the provided fiscal year.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, FiscalCalendar, FiscalYearNaming, Month};
+    /// let calendar = FiscalCalendar::new(Month::October, FiscalYearNaming::EndYear);
+    /// assert_eq!(calendar.last_day(2021), Ok(date!("2021-09-30")));
+    /// ```
+    pub const fn last_day(self, fiscal_year: i32) -> Result<Date, error::ComponentRange> {
+        let first_day = const_try!(self.first_day(fiscal_year));
+        let month = self.start.previous();
+        let year = first_day.year() + (self.start as u8 != 1) as i32;
+        Date::from_calendar_date(year, month, month.length(year))
+    }
+
+    /// The difference between the fiscal year and the calendar year in which it begins.
+    const fn year_offset(self) -> i32 {
+        match self.naming {
+            FiscalYearNaming::StartYear => 0,
+            FiscalYearNaming::EndYear => (self.start as u8 != 1) as i32,
+        }
+    }
+}
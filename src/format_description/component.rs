@@ -5,7 +5,10 @@ use alloc::string::String;
 
 use crate::format_description::modifier;
 #[cfg(feature = "alloc")]
-use crate::{error::InvalidFormatDescription, format_description::modifier::Modifiers};
+use crate::{
+    error::InvalidFormatDescription, format_description::modifier::Modifiers, FiscalCalendar,
+    FiscalYearNaming, Month,
+};
 
 /// A component of a larger format description.
 #[non_exhaustive]
@@ -23,6 +26,10 @@ pub enum Component {
     WeekNumber(modifier::WeekNumber),
     /// Year of the date.
     Year(modifier::Year),
+    /// Quarter of the year.
+    Quarter(modifier::Quarter),
+    /// Fiscal year of the date.
+    FiscalYear(modifier::FiscalYear),
     /// Hour of the day.
     Hour(modifier::Hour),
     /// Minute within the hour.
@@ -39,6 +46,10 @@ pub enum Component {
     OffsetMinute(modifier::OffsetMinute),
     /// Second within the minute of the UTC offset.
     OffsetSecond(modifier::OffsetSecond),
+    /// Abbreviation of the local time type in the time zone.
+    TzAbbrev(modifier::TzAbbrev),
+    /// IANA name of the time zone.
+    TzName(modifier::TzName),
 }
 
 /// A component with no modifiers present.
@@ -57,6 +68,10 @@ pub(crate) enum NakedComponent {
     WeekNumber,
     /// Year of the date.
     Year,
+    /// Quarter of the year.
+    Quarter,
+    /// Fiscal year of the date.
+    FiscalYear,
     /// Hour of the day.
     Hour,
     /// Minute within the hour.
@@ -73,6 +88,10 @@ pub(crate) enum NakedComponent {
     OffsetMinute,
     /// Second within the minute of the UTC offset.
     OffsetSecond,
+    /// Abbreviation of the local time type in the time zone.
+    TzAbbrev,
+    /// IANA name of the time zone.
+    TzName,
 }
 
 #[cfg(feature = "alloc")]
@@ -90,6 +109,8 @@ impl NakedComponent {
             b"weekday" => Ok(Self::Weekday),
             b"week_number" => Ok(Self::WeekNumber),
             b"year" => Ok(Self::Year),
+            b"quarter" => Ok(Self::Quarter),
+            b"fiscal_year" => Ok(Self::FiscalYear),
             b"hour" => Ok(Self::Hour),
             b"minute" => Ok(Self::Minute),
             b"period" => Ok(Self::Period),
@@ -98,6 +119,8 @@ impl NakedComponent {
             b"offset_hour" => Ok(Self::OffsetHour),
             b"offset_minute" => Ok(Self::OffsetMinute),
             b"offset_second" => Ok(Self::OffsetSecond),
+            b"tz_abbrev" => Ok(Self::TzAbbrev),
+            b"tz_name" => Ok(Self::TzName),
             b"" => Err(InvalidFormatDescription::MissingComponentName {
                 index: component_index,
             }),
@@ -135,6 +158,23 @@ impl NakedComponent {
                 iso_week_based: modifiers.year_is_iso_week_based.unwrap_or_default(),
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
             }),
+            Self::Quarter => Component::Quarter(modifier::Quarter {
+                calendar: FiscalCalendar::new(
+                    modifiers.fiscal_year_start.unwrap_or(Month::January),
+                    FiscalYearNaming::EndYear,
+                ),
+            }),
+            Self::FiscalYear => Component::FiscalYear(modifier::FiscalYear {
+                padding: modifiers.padding.unwrap_or_default(),
+                repr: modifiers.year_repr.unwrap_or_default(),
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+                calendar: FiscalCalendar::new(
+                    modifiers.fiscal_year_start.unwrap_or(Month::January),
+                    modifiers
+                        .fiscal_year_naming
+                        .unwrap_or(FiscalYearNaming::EndYear),
+                ),
+            }),
             Self::Hour => Component::Hour(modifier::Hour {
                 padding: modifiers.padding.unwrap_or_default(),
                 is_12_hour_clock: modifiers.hour_is_12_hour_clock.unwrap_or_default(),
@@ -161,6 +201,8 @@ impl NakedComponent {
             Self::OffsetSecond => Component::OffsetSecond(modifier::OffsetSecond {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
+            Self::TzAbbrev => Component::TzAbbrev(modifier::TzAbbrev),
+            Self::TzName => Component::TzName(modifier::TzName),
         }
     }
 }
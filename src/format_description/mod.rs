@@ -3,8 +3,12 @@
 mod component;
 pub mod modifier;
 #[cfg(feature = "alloc")]
+mod owned_format_item;
+#[cfg(feature = "alloc")]
 pub(crate) mod parse;
 
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
 #[cfg(feature = "alloc")]
 use alloc::string::String;
 #[cfg(feature = "alloc")]
@@ -12,7 +16,9 @@ use core::fmt;
 
 pub use self::component::Component;
 #[cfg(feature = "alloc")]
-pub use self::parse::parse;
+pub use self::owned_format_item::OwnedFormatItem;
+#[cfg(feature = "alloc")]
+pub use self::parse::{parse, parse_owned};
 
 /// Helper methods.
 #[cfg(feature = "alloc")]
@@ -31,9 +37,83 @@ mod helper {
 
 /// Well-known formats, typically RFCs.
 pub mod well_known {
+    pub mod iso8601;
+
     /// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6).
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct Rfc3339;
+
+    /// The format described in [RFC 2822](https://tools.ietf.org/html/rfc2822#section-3.3), as
+    /// used in email headers and other legacy protocols.
+    ///
+    /// Values are formatted as `Tue, 01 Jul 2003 10:52:37 +0200`, without any subsecond. Years
+    /// before 1900 and offsets with a nonzero second cannot be formatted, returning
+    /// `error::Format::Rfc2822YearBefore1900` and `error::Format::Rfc2822OffsetSecond`
+    /// respectively. When parsing, the obsolete syntax is also accepted. This includes comments,
+    /// folding whitespace, two and three-digit years, and the
+    /// named time zones `UT`, `GMT`, `EST`, `EDT`, `CST`, `CDT`, `MST`, `MDT`, `PST`, and `PDT`. As
+    /// their meaning was never well-defined, military time zones other than `Z` are treated as UTC,
+    /// as is `-0000`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Rfc2822;
+
+    /// The `HTTP-date` format described in
+    /// [RFC 7231](https://tools.ietf.org/html/rfc7231#section-7.1.1.1), as used in HTTP headers
+    /// such as `Date` and `Last-Modified`.
+    ///
+    /// Values are converted to UTC and formatted as `Sun, 06 Nov 1994 08:49:37 GMT` (the preferred
+    /// IMF-fixdate format), without any subsecond. Years outside the range `0..=9999` cannot be
+    /// formatted. When parsing, the obsolete RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime
+    /// (`Sun Nov  6 08:49:37 1994`) formats are also accepted. Per the RFC, a two-digit year that
+    /// appears to be more than 50 years in the future is placed in the previous century; without
+    /// the `std` feature, the current year is not known, so years 00 through 49 are placed in the
+    /// 2000s instead.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct HttpDate;
+
+    /// The format described in [ISO 8601](https://www.iso.org/iso-8601-date-and-time-format.html),
+    /// configured by an [`iso8601::Config`].
+    ///
+    /// Formatting follows the configuration exactly. When parsing, only the components in the
+    /// configuration are significant: the basic and extended formats, calendar, week, and ordinal
+    /// dates, times of any precision with a decimal fraction (using `.` or `,`) on the smallest
+    /// unit, and offsets of `Z`, `±hh`, `±hhmm`, or `±hh:mm` are all accepted. Six-digit years
+    /// must be signed. The end-of-day time `24:00` is not accepted, as it cannot be represented
+    /// without changing the date; `00:00` of the following day must be used instead.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2021-03-01 12:30 +01:00").format(&Iso8601::DEFAULT)?,
+    ///     "2021-03-01T12:30:00.000000000+01:00"
+    /// );
+    /// assert_eq!(
+    ///     time::OffsetDateTime::parse("2021-W09-1T12:30+01", &Iso8601::DEFAULT)?,
+    ///     datetime!("2021-03-01 12:30 +01:00")
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Iso8601 {
+        /// The configuration used when formatting and parsing.
+        pub(crate) config: iso8601::Config,
+    }
+
+    impl Iso8601 {
+        /// The format with the [default configuration](iso8601::Config::DEFAULT).
+        pub const DEFAULT: Self = Self::with_config(iso8601::Config::DEFAULT);
+
+        /// Create a format with the provided configuration.
+        pub const fn with_config(config: iso8601::Config) -> Self {
+            Self { config }
+        }
+
+        /// Obtain the configuration of the format.
+        pub const fn config(self) -> iso8601::Config {
+            self.config
+        }
+    }
 }
 
 /// A complete description of how to format and parse a type.
@@ -51,6 +131,21 @@ pub enum FormatItem<'a> {
     /// A series of literals or components that collectively form a partial or complete
     /// description.
     Compound(&'a [Self]),
+    /// An item that may or may not be present when parsing. If parsing the item fails, no input is
+    /// consumed and parsing continues with the next item.
+    ///
+    /// When formatting, the item is always formatted.
+    Optional(&'a Self),
+    /// A series of items where, when parsing, the first item that successfully parses is used. If
+    /// none of the items parse, the error from the first item is returned.
+    ///
+    /// When formatting, the first item is formatted. If there are no items, nothing is formatted.
+    First(&'a [Self]),
+    /// An item that owns its data. This is used by [`parse`] for `[optional [...]]` and
+    /// `[first [...] [...]]` items, whose nested descriptions cannot be borrowed from the input.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    Owned(Box<OwnedFormatItem>),
 }
 
 #[cfg(feature = "alloc")]
@@ -60,6 +155,9 @@ impl fmt::Debug for FormatItem<'_> {
             FormatItem::Literal(literal) => f.write_str(&String::from_utf8_lossy(literal)),
             FormatItem::Component(component) => component.fmt(f),
             FormatItem::Compound(compound) => compound.fmt(f),
+            FormatItem::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
+            FormatItem::First(items) => f.debug_tuple("First").field(items).finish(),
+            FormatItem::Owned(item) => item.fmt(f),
         }
     }
 }
@@ -5,6 +5,7 @@ use alloc::string::String;
 
 #[cfg(feature = "alloc")]
 use crate::{error::InvalidFormatDescription, format_description::helper};
+use crate::{FiscalCalendar, FiscalYearNaming};
 
 // region: date modifiers
 /// Day of the month.
@@ -112,6 +113,26 @@ pub struct Year {
     /// Whether the `+` sign is present when a positive year contains fewer than five digits.
     pub sign_is_mandatory: bool,
 }
+
+/// Quarter of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quarter {
+    /// The fiscal calendar whose quarters are used. Calendar quarters are used by default.
+    pub calendar: FiscalCalendar,
+}
+
+/// Fiscal year of the date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalYear {
+    /// The padding to obtain the minimum width.
+    pub padding: Padding,
+    /// What kind of representation should be used?
+    pub repr: YearRepr,
+    /// Whether the `+` sign is present when a positive year contains fewer than five digits.
+    pub sign_is_mandatory: bool,
+    /// When the fiscal year begins and how it is named.
+    pub calendar: FiscalCalendar,
+}
 // endregion date modifiers
 
 // region: time modifiers
@@ -204,6 +225,22 @@ pub struct OffsetSecond {
 }
 // endregion offset modifiers
 
+// region: time zone modifiers
+/// Abbreviation of the local time type in the time zone, such as `CEST`.
+///
+/// When parsing, the abbreviation is checked for validity but otherwise ignored, as it does not
+/// uniquely identify a time zone or offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TzAbbrev;
+
+/// IANA name of the time zone, such as `Europe/Paris`.
+///
+/// Parsing requires the `tzdb-embedded` feature, as only names in the embedded time zone database
+/// are recognized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TzName;
+// endregion time zone modifiers
+
 /// Type of padding to ensure a minimum width.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -251,6 +288,8 @@ pub(crate) struct Modifiers {
     pub(crate) year_repr: Option<YearRepr>,
     pub(crate) year_is_iso_week_based: Option<bool>,
     pub(crate) sign_is_mandatory: Option<bool>,
+    pub(crate) fiscal_year_start: Option<crate::Month>,
+    pub(crate) fiscal_year_naming: Option<FiscalYearNaming>,
 }
 
 impl Modifiers {
@@ -284,6 +323,7 @@ impl Modifiers {
 
             match (component_name, modifier) {
                 (b"day", b"padding:space")
+                | (b"fiscal_year", b"padding:space")
                 | (b"hour", b"padding:space")
                 | (b"minute", b"padding:space")
                 | (b"month", b"padding:space")
@@ -295,6 +335,7 @@ impl Modifiers {
                 | (b"week_number", b"padding:space")
                 | (b"year", b"padding:space") => modifiers.padding = Some(Padding::Space),
                 (b"day", b"padding:zero")
+                | (b"fiscal_year", b"padding:zero")
                 | (b"hour", b"padding:zero")
                 | (b"minute", b"padding:zero")
                 | (b"month", b"padding:zero")
@@ -306,6 +347,7 @@ impl Modifiers {
                 | (b"week_number", b"padding:zero")
                 | (b"year", b"padding:zero") => modifiers.padding = Some(Padding::Zero),
                 (b"day", b"padding:none")
+                | (b"fiscal_year", b"padding:none")
                 | (b"hour", b"padding:none")
                 | (b"minute", b"padding:none")
                 | (b"month", b"padding:none")
@@ -321,12 +363,12 @@ impl Modifiers {
                 (b"month", b"repr:numerical") => modifiers.month_repr = Some(MonthRepr::Numerical),
                 (b"month", b"repr:long") => modifiers.month_repr = Some(MonthRepr::Long),
                 (b"month", b"repr:short") => modifiers.month_repr = Some(MonthRepr::Short),
-                (b"offset_hour", b"sign:automatic") | (b"year", b"sign:automatic") => {
-                    modifiers.sign_is_mandatory = Some(false)
-                }
-                (b"offset_hour", b"sign:mandatory") | (b"year", b"sign:mandatory") => {
-                    modifiers.sign_is_mandatory = Some(true)
-                }
+                (b"fiscal_year", b"sign:automatic")
+                | (b"offset_hour", b"sign:automatic")
+                | (b"year", b"sign:automatic") => modifiers.sign_is_mandatory = Some(false),
+                (b"fiscal_year", b"sign:mandatory")
+                | (b"offset_hour", b"sign:mandatory")
+                | (b"year", b"sign:mandatory") => modifiers.sign_is_mandatory = Some(true),
                 (b"period", b"case:upper") => modifiers.period_is_uppercase = Some(true),
                 (b"period", b"case:lower") => modifiers.period_is_uppercase = Some(false),
                 (b"subsecond", b"digits:1") => {
@@ -376,10 +418,56 @@ impl Modifiers {
                 (b"week_number", b"repr:monday") => {
                     modifiers.week_number_repr = Some(WeekNumberRepr::Monday)
                 }
-                (b"year", b"repr:full") => modifiers.year_repr = Some(YearRepr::Full),
-                (b"year", b"repr:last_two") => modifiers.year_repr = Some(YearRepr::LastTwo),
+                (b"fiscal_year", b"repr:full") | (b"year", b"repr:full") => {
+                    modifiers.year_repr = Some(YearRepr::Full)
+                }
+                (b"fiscal_year", b"repr:last_two") | (b"year", b"repr:last_two") => {
+                    modifiers.year_repr = Some(YearRepr::LastTwo)
+                }
                 (b"year", b"base:calendar") => modifiers.year_is_iso_week_based = Some(false),
                 (b"year", b"base:iso_week") => modifiers.year_is_iso_week_based = Some(true),
+                (b"fiscal_year", b"start:january") | (b"quarter", b"start:january") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::January)
+                }
+                (b"fiscal_year", b"start:february") | (b"quarter", b"start:february") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::February)
+                }
+                (b"fiscal_year", b"start:march") | (b"quarter", b"start:march") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::March)
+                }
+                (b"fiscal_year", b"start:april") | (b"quarter", b"start:april") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::April)
+                }
+                (b"fiscal_year", b"start:may") | (b"quarter", b"start:may") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::May)
+                }
+                (b"fiscal_year", b"start:june") | (b"quarter", b"start:june") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::June)
+                }
+                (b"fiscal_year", b"start:july") | (b"quarter", b"start:july") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::July)
+                }
+                (b"fiscal_year", b"start:august") | (b"quarter", b"start:august") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::August)
+                }
+                (b"fiscal_year", b"start:september") | (b"quarter", b"start:september") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::September)
+                }
+                (b"fiscal_year", b"start:october") | (b"quarter", b"start:october") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::October)
+                }
+                (b"fiscal_year", b"start:november") | (b"quarter", b"start:november") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::November)
+                }
+                (b"fiscal_year", b"start:december") | (b"quarter", b"start:december") => {
+                    modifiers.fiscal_year_start = Some(crate::Month::December)
+                }
+                (b"fiscal_year", b"naming:start") => {
+                    modifiers.fiscal_year_naming = Some(FiscalYearNaming::StartYear)
+                }
+                (b"fiscal_year", b"naming:end") => {
+                    modifiers.fiscal_year_naming = Some(FiscalYearNaming::EndYear)
+                }
                 _ => {
                     return Err(InvalidFormatDescription::InvalidModifier {
                         value: String::from_utf8_lossy(modifier).into_owned(),
@@ -0,0 +1,85 @@
+//! A format item with owned data.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::format_description::{Component, FormatItem};
+
+/// A complete description of how to format and parse a type, owning all of its data.
+///
+/// Unlike [`FormatItem`], this can hold nested descriptions that were parsed at runtime, such as
+/// `[optional [...]]` and `[first [...] [...]]`. It is obtained from
+/// [`format_description::parse_owned`](crate::format_description::parse_owned) or by converting a
+/// [`FormatItem`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+pub enum OwnedFormatItem {
+    /// Bytes that are formatted as-is.
+    ///
+    /// **Note**: If you call the `format` method that returns a `String`, these bytes will be
+    /// passed through `String::from_utf8_lossy`.
+    Literal(Box<[u8]>),
+    /// A minimal representation of a single non-literal item.
+    Component(Component),
+    /// A series of literals or components that collectively form a partial or complete
+    /// description.
+    Compound(Box<[Self]>),
+    /// An item that may or may not be present when parsing. If parsing the item fails, no input is
+    /// consumed and parsing continues with the next item.
+    ///
+    /// When formatting, the item is always formatted.
+    Optional(Box<Self>),
+    /// A series of items where, when parsing, the first item that successfully parses is used. If
+    /// none of the items parse, the error from the first item is returned.
+    ///
+    /// When formatting, the first item is formatted. If there are no items, nothing is formatted.
+    First(Box<[Self]>),
+}
+
+impl fmt::Debug for OwnedFormatItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => f.write_str(&String::from_utf8_lossy(literal)),
+            Self::Component(component) => component.fmt(f),
+            Self::Compound(compound) => compound.fmt(f),
+            Self::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
+            Self::First(items) => f.debug_tuple("First").field(items).finish(),
+        }
+    }
+}
+
+impl From<FormatItem<'_>> for OwnedFormatItem {
+    fn from(item: FormatItem<'_>) -> Self {
+        (&item).into()
+    }
+}
+
+impl From<&FormatItem<'_>> for OwnedFormatItem {
+    fn from(item: &FormatItem<'_>) -> Self {
+        match *item {
+            FormatItem::Literal(literal) => Self::Literal(literal.into()),
+            FormatItem::Component(component) => Self::Component(component),
+            FormatItem::Compound(compound) => compound.into(),
+            FormatItem::Optional(item) => Self::Optional(Box::new(item.into())),
+            FormatItem::First(items) => {
+                Self::First(items.iter().map(Into::into).collect::<Vec<_>>().into())
+            }
+            FormatItem::Owned(ref item) => (**item).clone(),
+        }
+    }
+}
+
+impl From<&[FormatItem<'_>]> for OwnedFormatItem {
+    fn from(items: &[FormatItem<'_>]) -> Self {
+        Self::Compound(items.iter().map(Into::into).collect::<Vec<_>>().into())
+    }
+}
+
+impl From<Vec<Self>> for OwnedFormatItem {
+    fn from(items: Vec<Self>) -> Self {
+        Self::Compound(items.into())
+    }
+}
@@ -1,16 +1,75 @@
 //! Parse a format description into a standardized representation.
 
+use alloc::boxed::Box;
 use alloc::vec::Vec;
 
 use crate::error::InvalidFormatDescription;
 use crate::format_description::component::{Component, NakedComponent};
-use crate::format_description::{helper, modifier, FormatItem};
+use crate::format_description::{helper, modifier, FormatItem, OwnedFormatItem};
+
+/// An item parsed from a format description, before it is converted to a [`FormatItem`] or
+/// [`OwnedFormatItem`]. Nested descriptions are owned so that either conversion is possible.
+#[derive(Debug)]
+enum Item<'a> {
+    /// Bytes that are formatted as-is.
+    Literal(&'a [u8]),
+    /// A single component.
+    Component(Component),
+    /// An `[optional [...]]` item.
+    Optional {
+        /// The items of the nested description.
+        items: Vec<Self>,
+    },
+    /// A `[first [...] [...]]` item.
+    First {
+        /// The items of each nested description, in order.
+        branches: Vec<Vec<Self>>,
+    },
+}
+
+impl<'a> Item<'a> {
+    /// Convert the item to a [`FormatItem`]. Nested descriptions are owned by the resulting item.
+    fn into_borrowed(self) -> FormatItem<'a> {
+        match self {
+            Self::Literal(literal) => FormatItem::Literal(literal),
+            Self::Component(component) => FormatItem::Component(component),
+            item => FormatItem::Owned(Box::new(item.into_owned())),
+        }
+    }
+
+    /// Convert the item to an [`OwnedFormatItem`].
+    fn into_owned(self) -> OwnedFormatItem {
+        match self {
+            Self::Literal(literal) => OwnedFormatItem::Literal(literal.into()),
+            Self::Component(component) => OwnedFormatItem::Component(component),
+            Self::Optional { items } => {
+                OwnedFormatItem::Optional(Box::new(Self::into_owned_compound(items)))
+            }
+            Self::First { branches } => OwnedFormatItem::First(
+                branches
+                    .into_iter()
+                    .map(Self::into_owned_compound)
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+        }
+    }
+
+    /// Convert a series of items to a single [`OwnedFormatItem`].
+    fn into_owned_compound(items: Vec<Self>) -> OwnedFormatItem {
+        items
+            .into_iter()
+            .map(Self::into_owned)
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
 
 /// The item parsed and remaining chunk of the format description after one iteration.
 #[derive(Debug)]
 struct ParsedItem<'a> {
     /// The item that was parsed.
-    item: FormatItem<'a>,
+    item: Item<'a>,
     /// What is left of the input string after the item was parsed.
     remaining: &'a [u8],
 }
@@ -36,34 +95,123 @@ fn parse_component(mut s: &[u8], index: &mut usize) -> Result<Component, Invalid
         .attach_modifiers(&modifier::Modifiers::parse(component_name, s, index)?))
 }
 
-/// Parse a literal string from the format description.
-fn parse_literal<'a>(s: &'a [u8], index: &mut usize) -> ParsedItem<'a> {
-    let loc = s.iter().position(|&c| c == b'[').unwrap_or(s.len());
+/// Parse an `[optional [...]]` or `[first [...] [...]]` item from the format description. The
+/// leading bracket should be present in the parameter. If the item is neither of these, `None` is
+/// returned and nothing is consumed.
+fn parse_nested_item<'a>(
+    s: &'a [u8],
+    index: &mut usize,
+) -> Result<Option<ParsedItem<'a>>, InvalidFormatDescription> {
+    let opening_index = *index;
+    let mut name_index = opening_index + 1;
+    let s_after_bracket = helper::consume_whitespace(&s[1..], &mut name_index);
+    let name_len = s_after_bracket
+        .iter()
+        .position(|&c| c.is_ascii_whitespace() || c == b'[' || c == b']')
+        .unwrap_or(s_after_bracket.len());
+    let is_optional = match &s_after_bracket[..name_len] {
+        b"optional" => true,
+        b"first" => false,
+        _ => return Ok(None),
+    };
+
+    *index = name_index + name_len;
+    let mut s = helper::consume_whitespace(&s_after_bracket[name_len..], index);
+
+    let mut branches = Vec::new();
+    while let [b'[', remaining @ ..] = s {
+        // An optional item has exactly one nested description.
+        if is_optional && !branches.is_empty() {
+            break;
+        }
+
+        let branch_index = *index;
+        *index += 1;
+        let (items, remaining) = parse_items(remaining, index, true)?;
+        s = match remaining {
+            [b']', remaining @ ..] => remaining,
+            _ => {
+                return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: branch_index,
+                });
+            }
+        };
+        *index += 1;
+        branches.push(items);
+        s = helper::consume_whitespace(s, index);
+    }
+
+    if branches.is_empty() {
+        return Err(InvalidFormatDescription::Expected {
+            what: "nested format description",
+            index: *index,
+        });
+    }
+    let remaining = match s {
+        [b']', remaining @ ..] => remaining,
+        [] => {
+            return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                index: opening_index,
+            });
+        }
+        _ => {
+            return Err(InvalidFormatDescription::Expected {
+                what: "closing bracket",
+                index: *index,
+            });
+        }
+    };
+    *index += 1;
+
+    Ok(Some(ParsedItem {
+        item: if is_optional {
+            Item::Optional {
+                items: branches.remove(0),
+            }
+        } else {
+            Item::First { branches }
+        },
+        remaining,
+    }))
+}
+
+/// Parse a literal string from the format description. Within a nested description, a closing
+/// bracket also ends the literal.
+fn parse_literal<'a>(s: &'a [u8], index: &mut usize, is_nested: bool) -> ParsedItem<'a> {
+    let loc = s
+        .iter()
+        .position(|&c| c == b'[' || (is_nested && c == b']'))
+        .unwrap_or(s.len());
     *index += loc;
     ParsedItem {
-        item: FormatItem::Literal(&s[..loc]),
+        item: Item::Literal(&s[..loc]),
         remaining: &s[loc..],
     }
 }
 
-/// Parse either a literal or a component from the format description.
+/// Parse either a literal, a component, or a nested item from the format description.
 fn parse_item<'a>(
     s: &'a [u8],
     index: &mut usize,
+    is_nested: bool,
 ) -> Result<ParsedItem<'a>, InvalidFormatDescription> {
     if let [b'[', b'[', remaining @ ..] = s {
         *index += 2;
         return Ok(ParsedItem {
-            item: FormatItem::Literal(&[b'[']),
+            item: Item::Literal(&[b'[']),
             remaining,
         });
     };
 
     if s.starts_with(&[b'[']) {
+        if let Some(item) = parse_nested_item(s, index)? {
+            return Ok(item);
+        }
+
         if let Some(bracket_index) = s.iter().position(|&c| c == b']') {
             *index += 1; // opening bracket
             let ret_val = ParsedItem {
-                item: FormatItem::Component(parse_component(&s[1..bracket_index], index)?),
+                item: Item::Component(parse_component(&s[1..bracket_index], index)?),
                 remaining: &s[bracket_index + 1..],
             };
             *index += 1; // closing bracket
@@ -72,23 +220,67 @@ fn parse_item<'a>(
             Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
         }
     } else {
-        Ok(parse_literal(s, index))
+        Ok(parse_literal(s, index, is_nested))
     }
 }
 
-/// Parse a sequence of items from the format description.
-#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
-pub fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
-    let mut compound = Vec::new();
-    let mut loc = 0;
+/// Parse a sequence of items from the format description. Within a nested description, parsing
+/// stops at the first unmatched closing bracket, which is left in the remaining input.
+fn parse_items<'a>(
+    mut s: &'a [u8],
+    index: &mut usize,
+    is_nested: bool,
+) -> Result<(Vec<Item<'a>>, &'a [u8]), InvalidFormatDescription> {
+    let mut items = Vec::new();
 
-    let mut s = s.as_bytes();
+    while let Some(&first) = s.first() {
+        if is_nested && first == b']' {
+            break;
+        }
 
-    while !s.is_empty() {
-        let ParsedItem { item, remaining } = parse_item(s, &mut loc)?;
+        let ParsedItem { item, remaining } = parse_item(s, index, is_nested)?;
         s = remaining;
-        compound.push(item);
+        items.push(item);
     }
 
-    Ok(compound)
+    Ok((items, s))
+}
+
+/// Parse a sequence of items from the format description.
+///
+/// The returned items borrow from the provided string where possible. `[optional [...]]` and
+/// `[first [...] [...]]` items are returned as [`FormatItem::Owned`]. Within these nested
+/// descriptions, a literal `]` cannot be used.
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time::macros::time;
+/// let format = format_description::parse("[hour]:[minute][optional [:[second]]]")?;
+/// assert_eq!(time::Time::parse("12:00", &format)?, time!("12:00"));
+/// assert_eq!(time::Time::parse("12:00:05", &format)?, time!("12:00:05"));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, InvalidFormatDescription> {
+    let (items, _) = parse_items(s.as_bytes(), &mut 0, false)?;
+    Ok(items.into_iter().map(Item::into_borrowed).collect())
+}
+
+/// Parse a sequence of items from the format description into an [`OwnedFormatItem`].
+///
+/// This supports everything that [`parse`] does. Unlike the result of [`parse`], the result does not
+/// borrow from the provided string.
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time::macros::time;
+/// let format = format_description::parse_owned("[hour]:[minute][optional [:[second]]]")?;
+/// assert_eq!(time::Time::parse("12:00", &format)?, time!("12:00"));
+/// assert_eq!(time::Time::parse("12:00:05", &format)?, time!("12:00:05"));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub fn parse_owned(s: &str) -> Result<OwnedFormatItem, InvalidFormatDescription> {
+    let (items, _) = parse_items(s.as_bytes(), &mut 0, false)?;
+    Ok(Item::into_owned_compound(items))
 }
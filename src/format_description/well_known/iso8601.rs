@@ -0,0 +1,179 @@
+//! Configuration for the [`Iso8601`](super::Iso8601) well-known format.
+
+/// Which components are present in the formatted or parsed value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormattedComponents {
+    /// The date only.
+    Date,
+    /// The time only.
+    Time,
+    /// The UTC offset only.
+    Offset,
+    /// The date and the time.
+    DateTime,
+    /// The date, the time, and the UTC offset.
+    DateTimeOffset,
+    /// The time and the UTC offset.
+    TimeOffset,
+}
+
+impl FormattedComponents {
+    /// Whether the date, time, and offset are present, in that order.
+    pub(crate) const fn components(self) -> (bool, bool, bool) {
+        match self {
+            Self::Date => (true, false, false),
+            Self::Time => (false, true, false),
+            Self::Offset => (false, false, true),
+            Self::DateTime => (true, true, false),
+            Self::DateTimeOffset => (true, true, true),
+            Self::TimeOffset => (false, true, true),
+        }
+    }
+}
+
+/// The way the date is represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateKind {
+    /// The year, month, and day of the month, such as `2021-03-01`.
+    Calendar,
+    /// The ISO year, week number, and weekday, such as `2021-W09-1`.
+    Week,
+    /// The year and day of the year, such as `2021-060`.
+    Ordinal,
+}
+
+/// The smallest unit of the time that is present.
+///
+/// The smallest unit may be followed by a decimal fraction with the provided number of digits.
+/// When the number of digits is zero, no fraction is present and any smaller units are truncated.
+/// More than nine digits are treated as nine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimePrecision {
+    /// The hour, such as `12` or `12.5`.
+    Hour {
+        /// The number of digits in the fraction of the hour.
+        decimal_digits: u8,
+    },
+    /// The hour and minute, such as `12:30` or `12:30.5`.
+    Minute {
+        /// The number of digits in the fraction of the minute.
+        decimal_digits: u8,
+    },
+    /// The hour, minute, and second, such as `12:30:15` or `12:30:15.5`.
+    Second {
+        /// The number of digits in the fraction of the second.
+        decimal_digits: u8,
+    },
+}
+
+/// The smallest unit of the UTC offset that is present.
+///
+/// Offsets with a nonzero value in a smaller unit cannot be formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetPrecision {
+    /// The hour, such as `+01`.
+    Hour,
+    /// The hour and minute, such as `+01:00`.
+    Minute,
+}
+
+/// The configuration of an [`Iso8601`](super::Iso8601) format.
+///
+/// All methods are `const fn`, so a configuration can be built in a `const` context.
+///
+/// ```rust
+/// # use time::format_description::well_known::{iso8601, Iso8601};
+/// # use time::macros::datetime;
+/// const FORMAT: Iso8601 = Iso8601::with_config(
+///     iso8601::Config::DEFAULT
+///         .set_use_separators(false)
+///         .set_time_precision(iso8601::TimePrecision::Second { decimal_digits: 0 }),
+/// );
+/// assert_eq!(
+///     datetime!("2021-03-01 12:30:15 UTC").format(&FORMAT)?,
+///     "20210301T123015Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Config {
+    /// Which components are present.
+    pub(crate) formatted_components: FormattedComponents,
+    /// Whether the extended format (with separators) is used instead of the basic format.
+    pub(crate) use_separators: bool,
+    /// Whether the year is signed and has six digits instead of four.
+    pub(crate) year_is_six_digits: bool,
+    /// The way the date is represented.
+    pub(crate) date_kind: DateKind,
+    /// The smallest unit of the time that is present.
+    pub(crate) time_precision: TimePrecision,
+    /// The smallest unit of the UTC offset that is present.
+    pub(crate) offset_precision: OffsetPrecision,
+}
+
+impl Config {
+    /// The default configuration: an extended calendar date, a time with nine decimal digits on
+    /// the second, and an offset with minute precision. This is
+    /// `2021-03-01T12:30:15.000000000+01:00`.
+    pub const DEFAULT: Self = Self {
+        formatted_components: FormattedComponents::DateTimeOffset,
+        use_separators: true,
+        year_is_six_digits: false,
+        date_kind: DateKind::Calendar,
+        time_precision: TimePrecision::Second { decimal_digits: 9 },
+        offset_precision: OffsetPrecision::Minute,
+    };
+
+    /// Set which components are present.
+    #[must_use = "This method does not mutate the original `Config`."]
+    pub const fn set_formatted_components(self, formatted_components: FormattedComponents) -> Self {
+        Self {
+            formatted_components,
+            ..self
+        }
+    }
+
+    /// Set whether the extended format (`2021-03-01T12:30:15`) is used instead of the basic format
+    /// (`20210301T123015`).
+    #[must_use = "This method does not mutate the original `Config`."]
+    pub const fn set_use_separators(self, use_separators: bool) -> Self {
+        Self {
+            use_separators,
+            ..self
+        }
+    }
+
+    /// Set whether the year is always signed and has six digits (`+002021`) instead of four
+    /// (`2021`). Years outside the range `0..=9999` can only be formatted with six digits.
+    #[must_use = "This method does not mutate the original `Config`."]
+    pub const fn set_year_is_six_digits(self, year_is_six_digits: bool) -> Self {
+        Self {
+            year_is_six_digits,
+            ..self
+        }
+    }
+
+    /// Set the way the date is represented.
+    #[must_use = "This method does not mutate the original `Config`."]
+    pub const fn set_date_kind(self, date_kind: DateKind) -> Self {
+        Self { date_kind, ..self }
+    }
+
+    /// Set the smallest unit of the time that is present.
+    #[must_use = "This method does not mutate the original `Config`."]
+    pub const fn set_time_precision(self, time_precision: TimePrecision) -> Self {
+        Self {
+            time_precision,
+            ..self
+        }
+    }
+
+    /// Set the smallest unit of the UTC offset that is present.
+    #[must_use = "This method does not mutate the original `Config`."]
+    pub const fn set_offset_precision(self, offset_precision: OffsetPrecision) -> Self {
+        Self {
+            offset_precision,
+            ..self
+        }
+    }
+}
@@ -3,10 +3,16 @@
 use std::io;
 
 use crate::format_description::modifier::Padding;
-use crate::format_description::well_known::Rfc3339;
+use crate::format_description::well_known::iso8601::{
+    Config, DateKind, OffsetPrecision, TimePrecision,
+};
+use crate::format_description::well_known::{HttpDate, Iso8601, Rfc2822, Rfc3339};
 use crate::format_description::FormatItem;
-use crate::formatting::{format_component, format_number};
-use crate::{error, Date, Time, UtcOffset};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::formatting::{format_component, format_number, WEEKDAY_NAMES};
+use crate::month::MONTH_NAMES;
+use crate::{error, Date, Time, TimeZone, UtcOffset};
 
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
 /// exist in generic bounds.
@@ -27,6 +33,7 @@ pub(crate) mod sealed {
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
+            time_zone: Option<&TimeZone>,
         ) -> Result<usize, Self::Error>;
 
         /// Format the item directly to a `String`.
@@ -35,9 +42,10 @@ pub(crate) mod sealed {
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
+            time_zone: Option<&TimeZone>,
         ) -> Result<String, Self::Error> {
             let mut buf = Vec::new();
-            self.format_into(&mut buf, date, time, offset)?;
+            self.format_into(&mut buf, date, time, offset, time_zone)?;
             io::Write::flush(&mut buf)?;
             Ok(String::from_utf8_lossy(&buf).into_owned())
         }
@@ -54,11 +62,21 @@ impl<'a> sealed::Formattable for FormatItem<'a> {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        time_zone: Option<&TimeZone>,
     ) -> Result<usize, Self::Error> {
         Ok(match *self {
             Self::Literal(literal) => output.write(literal)?,
-            Self::Component(component) => format_component(output, component, date, time, offset)?,
-            Self::Compound(items) => items.format_into(output, date, time, offset)?,
+            Self::Component(component) => {
+                format_component(output, component, date, time, offset, time_zone)?
+            }
+            Self::Compound(items) => items.format_into(output, date, time, offset, time_zone)?,
+            Self::Optional(item) => item.format_into(output, date, time, offset, time_zone)?,
+            Self::First(items) => match items {
+                [] => 0,
+                [item, ..] => item.format_into(output, date, time, offset, time_zone)?,
+            },
+            #[cfg(feature = "alloc")]
+            Self::Owned(ref item) => item.format_into(output, date, time, offset, time_zone)?,
         })
     }
 }
@@ -72,10 +90,11 @@ impl<'a> sealed::Formattable for &[FormatItem<'a>] {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        time_zone: Option<&TimeZone>,
     ) -> Result<usize, Self::Error> {
         let mut bytes = 0;
         for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
+            bytes += item.format_into(output, date, time, offset, time_zone)?;
         }
         Ok(bytes)
     }
@@ -92,8 +111,44 @@ impl<'a> sealed::Formattable for Vec<FormatItem<'a>> {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        time_zone: Option<&TimeZone>,
+    ) -> Result<usize, Self::Error> {
+        self.as_slice()
+            .format_into(output, date, time, offset, time_zone)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl sealed::Formattable for OwnedFormatItem {
+    type Error = error::Format;
+
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        time_zone: Option<&TimeZone>,
     ) -> Result<usize, Self::Error> {
-        self.as_slice().format_into(output, date, time, offset)
+        Ok(match self {
+            Self::Literal(literal) => output.write(literal)?,
+            Self::Component(component) => {
+                format_component(output, *component, date, time, offset, time_zone)?
+            }
+            Self::Compound(items) => {
+                let mut bytes = 0;
+                for item in items.iter() {
+                    bytes += item.format_into(output, date, time, offset, time_zone)?;
+                }
+                bytes
+            }
+            Self::Optional(item) => item.format_into(output, date, time, offset, time_zone)?,
+            Self::First(items) => match &**items {
+                [] => 0,
+                [item, ..] => item.format_into(output, date, time, offset, time_zone)?,
+            },
+        })
     }
 }
 // endregion custom formats
@@ -108,6 +163,7 @@ impl sealed::Formattable for Rfc3339 {
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        _: Option<&TimeZone>,
     ) -> Result<usize, Self::Error> {
         let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
         let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
@@ -126,7 +182,7 @@ impl sealed::Formattable for Rfc3339 {
 
         bytes += format_number(output, year as u32, Padding::Zero, 4)?;
         bytes += output.write(&[b'-'])?;
-        bytes += format_number(output, date.month(), Padding::Zero, 2)?;
+        bytes += format_number(output, date.month() as u8, Padding::Zero, 2)?;
         bytes += output.write(&[b'-'])?;
         bytes += format_number(output, date.day(), Padding::Zero, 2)?;
         bytes += output.write(&[b'T'])?;
@@ -175,4 +231,291 @@ impl sealed::Formattable for Rfc3339 {
         Ok(bytes)
     }
 }
+
+impl sealed::Formattable for Rfc2822 {
+    type Error = error::Format;
+
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _: Option<&TimeZone>,
+    ) -> Result<usize, Self::Error> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        let mut bytes = 0;
+
+        let (year, month, day) = date.to_calendar_date();
+
+        if year < 1900 {
+            return Err(error::Format::Rfc2822YearBefore1900);
+        }
+        if offset.seconds_past_minute() != 0 {
+            return Err(error::Format::Rfc2822OffsetSecond);
+        }
+
+        bytes +=
+            output.write(&WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize][..3])?;
+        bytes += output.write(b", ")?;
+        bytes += format_number(output, day, Padding::Zero, 2)?;
+        bytes += output.write(&[b' '])?;
+        bytes += output.write(&MONTH_NAMES[month as usize - 1].as_bytes()[..3])?;
+        bytes += output.write(&[b' '])?;
+        bytes += format_number(output, year as u32, Padding::Zero, 4)?;
+        bytes += output.write(&[b' '])?;
+        bytes += format_number(output, time.hour(), Padding::Zero, 2)?;
+        bytes += output.write(&[b':'])?;
+        bytes += format_number(output, time.minute(), Padding::Zero, 2)?;
+        bytes += output.write(&[b':'])?;
+        bytes += format_number(output, time.second(), Padding::Zero, 2)?;
+        bytes += output.write(&[b' '])?;
+        bytes += output.write(if offset.is_negative() {
+            &[b'-']
+        } else {
+            &[b'+']
+        })?;
+        bytes += format_number(output, offset.whole_hours().abs() as u8, Padding::Zero, 2)?;
+        bytes += format_number(
+            output,
+            offset.minutes_past_hour().abs() as u8,
+            Padding::Zero,
+            2,
+        )?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Formattable for HttpDate {
+    type Error = error::Format;
+
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _: Option<&TimeZone>,
+    ) -> Result<usize, Self::Error> {
+        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+
+        // HTTP dates are always in UTC.
+        let date_time = date
+            .with_time(time)
+            .assume_offset(offset)
+            .to_offset(UtcOffset::UTC);
+        let (year, month, day) = date_time.to_calendar_date();
+
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+
+        let mut bytes = 0;
+
+        bytes += output
+            .write(&WEEKDAY_NAMES[date_time.weekday().number_days_from_monday() as usize][..3])?;
+        bytes += output.write(b", ")?;
+        bytes += format_number(output, day, Padding::Zero, 2)?;
+        bytes += output.write(&[b' '])?;
+        bytes += output.write(&MONTH_NAMES[month as usize - 1].as_bytes()[..3])?;
+        bytes += output.write(&[b' '])?;
+        bytes += format_number(output, year as u32, Padding::Zero, 4)?;
+        bytes += output.write(&[b' '])?;
+        bytes += format_number(output, date_time.hour(), Padding::Zero, 2)?;
+        bytes += output.write(&[b':'])?;
+        bytes += format_number(output, date_time.minute(), Padding::Zero, 2)?;
+        bytes += output.write(&[b':'])?;
+        bytes += format_number(output, date_time.second(), Padding::Zero, 2)?;
+        bytes += output.write(b" GMT")?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Formattable for Iso8601 {
+    type Error = error::Format;
+
+    fn format_into(
+        &self,
+        output: &mut impl io::Write,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+        _: Option<&TimeZone>,
+    ) -> Result<usize, Self::Error> {
+        let config = self.config;
+        let (format_date, format_time, format_offset) = config.formatted_components.components();
+
+        let mut bytes = 0;
+
+        if format_date {
+            let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+            bytes += format_iso8601_date(output, date, config)?;
+        }
+        if format_time {
+            let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+            if format_date {
+                bytes += output.write(&[b'T'])?;
+            }
+            bytes += format_iso8601_time(output, time, config)?;
+        }
+        if format_offset {
+            let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+            bytes += format_iso8601_offset(output, offset, config)?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Format the date portion of an ISO 8601 value.
+fn format_iso8601_date(
+    output: &mut impl io::Write,
+    date: Date,
+    config: Config,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+
+    let year = match config.date_kind {
+        DateKind::Week => date.to_iso_week_date().0,
+        DateKind::Calendar | DateKind::Ordinal => date.year(),
+    };
+    if config.year_is_six_digits {
+        bytes += output.write(if year < 0 { &[b'-'] } else { &[b'+'] })?;
+        bytes += format_number(output, year.abs() as u32, Padding::Zero, 6)?;
+    } else if (0..10_000).contains(&year) {
+        bytes += format_number(output, year as u32, Padding::Zero, 4)?;
+    } else {
+        return Err(error::Format::InvalidComponent("year"));
+    }
+
+    if config.use_separators {
+        bytes += output.write(&[b'-'])?;
+    }
+    match config.date_kind {
+        DateKind::Calendar => {
+            bytes += format_number(output, date.month() as u8, Padding::Zero, 2)?;
+            if config.use_separators {
+                bytes += output.write(&[b'-'])?;
+            }
+            bytes += format_number(output, date.day(), Padding::Zero, 2)?;
+        }
+        DateKind::Week => {
+            let (_, week, weekday) = date.to_iso_week_date();
+            bytes += output.write(&[b'W'])?;
+            bytes += format_number(output, week, Padding::Zero, 2)?;
+            if config.use_separators {
+                bytes += output.write(&[b'-'])?;
+            }
+            bytes += format_number(output, weekday.number_from_monday(), Padding::Zero, 1)?;
+        }
+        DateKind::Ordinal => {
+            bytes += format_number(output, date.ordinal(), Padding::Zero, 3)?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Format the time portion of an ISO 8601 value, including any decimal fraction.
+fn format_iso8601_time(
+    output: &mut impl io::Write,
+    time: Time,
+    config: Config,
+) -> Result<usize, error::Format> {
+    let (hour, minute, second, nanosecond) = time.as_hms_nano();
+    let mut bytes = 0;
+
+    bytes += format_number(output, hour, Padding::Zero, 2)?;
+
+    // The remainder is the number of nanoseconds past the smallest unit, which is `unit`
+    // nanoseconds long.
+    let (decimal_digits, remainder, unit): (_, u64, u64) = match config.time_precision {
+        TimePrecision::Hour { decimal_digits } => (
+            decimal_digits,
+            (minute as u64 * 60 + second as u64) * 1_000_000_000 + nanosecond as u64,
+            3_600_000_000_000,
+        ),
+        TimePrecision::Minute { decimal_digits } => {
+            if config.use_separators {
+                bytes += output.write(&[b':'])?;
+            }
+            bytes += format_number(output, minute, Padding::Zero, 2)?;
+            (
+                decimal_digits,
+                second as u64 * 1_000_000_000 + nanosecond as u64,
+                60_000_000_000,
+            )
+        }
+        TimePrecision::Second { decimal_digits } => {
+            if config.use_separators {
+                bytes += output.write(&[b':'])?;
+            }
+            bytes += format_number(output, minute, Padding::Zero, 2)?;
+            if config.use_separators {
+                bytes += output.write(&[b':'])?;
+            }
+            bytes += format_number(output, second, Padding::Zero, 2)?;
+            (decimal_digits, nanosecond as u64, 1_000_000_000)
+        }
+    };
+
+    if decimal_digits != 0 {
+        let decimal_digits = decimal_digits.min(9);
+        let fraction = remainder as u128 * 10_u128.pow(decimal_digits as u32) / unit as u128;
+        bytes += output.write(&[b'.'])?;
+        bytes += format_number(output, fraction as u32, Padding::Zero, decimal_digits)?;
+    }
+
+    Ok(bytes)
+}
+
+/// Format the offset portion of an ISO 8601 value.
+fn format_iso8601_offset(
+    output: &mut impl io::Write,
+    offset: UtcOffset,
+    config: Config,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+
+    if offset.seconds_past_minute() != 0 {
+        return Err(error::Format::InvalidComponent("offset_second"));
+    }
+    if offset == UtcOffset::UTC {
+        bytes += output.write(&[b'Z'])?;
+        return Ok(bytes);
+    }
+
+    bytes += output.write(if offset.is_negative() {
+        &[b'-']
+    } else {
+        &[b'+']
+    })?;
+    bytes += format_number(output, offset.whole_hours().abs() as u8, Padding::Zero, 2)?;
+    match config.offset_precision {
+        OffsetPrecision::Hour if offset.minutes_past_hour() != 0 => {
+            return Err(error::Format::InvalidComponent("offset_minute"));
+        }
+        OffsetPrecision::Hour => {}
+        OffsetPrecision::Minute => {
+            if config.use_separators {
+                bytes += output.write(&[b':'])?;
+            }
+            bytes += format_number(
+                output,
+                offset.minutes_past_hour().abs() as u8,
+                Padding::Zero,
+                2,
+            )?;
+        }
+    }
+
+    Ok(bytes)
+}
 // endregion well-known formats
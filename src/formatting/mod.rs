@@ -5,23 +5,8 @@ pub(crate) mod formattable;
 use std::io;
 
 use crate::format_description::{modifier, Component};
-use crate::{error, Date, Time, UtcOffset};
-
-#[allow(clippy::clippy::missing_docs_in_private_items)]
-const MONTH_NAMES: [&[u8]; 12] = [
-    b"January",
-    b"February",
-    b"March",
-    b"April",
-    b"May",
-    b"June",
-    b"July",
-    b"August",
-    b"September",
-    b"October",
-    b"November",
-    b"December",
-];
+use crate::month::MONTH_NAMES;
+use crate::{error, Date, PrimitiveDateTime, Time, TimeZone, UtcOffset};
 
 #[allow(clippy::missing_docs_in_private_items)]
 const WEEKDAY_NAMES: [&[u8]; 7] = [
@@ -135,23 +120,34 @@ pub(crate) fn format_component(
     date: Option<Date>,
     time: Option<Time>,
     offset: Option<UtcOffset>,
+    time_zone: Option<&TimeZone>,
 ) -> Result<usize, error::Format> {
     use Component::*;
-    Ok(match (component, date, time, offset) {
+    Ok(match (component, date, time, offset, time_zone) {
         (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier)?,
         (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier)?,
         (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier)?,
         (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
         (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
         (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
-        (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
-        (Minute(modifier), _, Some(time), _) => fmt_minute(output, time, modifier)?,
-        (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier)?,
-        (Second(modifier), _, Some(time), _) => fmt_second(output, time, modifier)?,
-        (Subsecond(modifier), _, Some(time), _) => fmt_subsecond(output, time, modifier)?,
-        (OffsetHour(modifier), .., Some(offset)) => fmt_offset_hour(output, offset, modifier)?,
-        (OffsetMinute(modifier), .., Some(offset)) => fmt_offset_minute(output, offset, modifier)?,
-        (OffsetSecond(modifier), .., Some(offset)) => fmt_offset_second(output, offset, modifier)?,
+        (Quarter(modifier), Some(date), ..) => fmt_quarter(output, date, modifier)?,
+        (FiscalYear(modifier), Some(date), ..) => fmt_fiscal_year(output, date, modifier)?,
+        (Hour(modifier), _, Some(time), ..) => fmt_hour(output, time, modifier)?,
+        (Minute(modifier), _, Some(time), ..) => fmt_minute(output, time, modifier)?,
+        (Period(modifier), _, Some(time), ..) => fmt_period(output, time, modifier)?,
+        (Second(modifier), _, Some(time), ..) => fmt_second(output, time, modifier)?,
+        (Subsecond(modifier), _, Some(time), ..) => fmt_subsecond(output, time, modifier)?,
+        (OffsetHour(modifier), .., Some(offset), _) => fmt_offset_hour(output, offset, modifier)?,
+        (OffsetMinute(modifier), .., Some(offset), _) => {
+            fmt_offset_minute(output, offset, modifier)?
+        }
+        (OffsetSecond(modifier), .., Some(offset), _) => {
+            fmt_offset_second(output, offset, modifier)?
+        }
+        (TzAbbrev(modifier), Some(date), Some(time), Some(offset), Some(time_zone)) => {
+            fmt_tz_abbrev(output, date, time, offset, time_zone, modifier)?
+        }
+        (TzName(modifier), .., Some(time_zone)) => fmt_tz_name(output, time_zone, modifier)?,
         _ => return Err(error::Format::InsufficientTypeInformation),
     })
 }
@@ -173,9 +169,13 @@ fn fmt_month(
     modifier::Month { padding, repr }: modifier::Month,
 ) -> Result<usize, io::Error> {
     match repr {
-        modifier::MonthRepr::Numerical => format_number(output, date.month(), padding, 2),
-        modifier::MonthRepr::Long => output.write(MONTH_NAMES[date.month() as usize - 1]),
-        modifier::MonthRepr::Short => output.write(&MONTH_NAMES[date.month() as usize - 1][..3]),
+        modifier::MonthRepr::Numerical => format_number(output, date.month() as u8, padding, 2),
+        modifier::MonthRepr::Long => {
+            output.write(MONTH_NAMES[date.month() as usize - 1].as_bytes())
+        }
+        modifier::MonthRepr::Short => {
+            output.write(&MONTH_NAMES[date.month() as usize - 1].as_bytes()[..3])
+        }
     }
 }
 
@@ -250,6 +250,41 @@ fn fmt_year(
     } else {
         date.year()
     };
+    fmt_year_value(output, full_year, padding, repr, sign_is_mandatory)
+}
+
+/// Format the quarter into the designated output.
+fn fmt_quarter(
+    output: &mut impl io::Write,
+    date: Date,
+    modifier::Quarter { calendar }: modifier::Quarter,
+) -> Result<usize, io::Error> {
+    format_number(output, calendar.quarter(date), modifier::Padding::None, 1)
+}
+
+/// Format the fiscal year into the designated output.
+fn fmt_fiscal_year(
+    output: &mut impl io::Write,
+    date: Date,
+    modifier::FiscalYear {
+        padding,
+        repr,
+        sign_is_mandatory,
+        calendar,
+    }: modifier::FiscalYear,
+) -> Result<usize, io::Error> {
+    let full_year = calendar.year(date);
+    fmt_year_value(output, full_year, padding, repr, sign_is_mandatory)
+}
+
+/// Format a calendar, ISO week-based, or fiscal year into the designated output.
+fn fmt_year_value(
+    output: &mut impl io::Write,
+    full_year: i32,
+    padding: modifier::Padding,
+    repr: modifier::YearRepr,
+    sign_is_mandatory: bool,
+) -> Result<usize, io::Error> {
     let value = match repr {
         modifier::YearRepr::Full => full_year,
         modifier::YearRepr::LastTwo => (full_year % 100).abs(),
@@ -396,3 +431,35 @@ fn fmt_offset_second(
     format_number(output, offset.seconds_past_minute().abs() as u8, padding, 2)
 }
 // endregion offset formatters
+
+// region: time zone formatters
+/// Format the time zone abbreviation into the designated output.
+fn fmt_tz_abbrev(
+    output: &mut impl io::Write,
+    date: Date,
+    time: Time,
+    offset: UtcOffset,
+    time_zone: &TimeZone,
+    modifier::TzAbbrev: modifier::TzAbbrev,
+) -> Result<usize, io::Error> {
+    let datetime = PrimitiveDateTime::new(date, time).assume_offset(offset);
+    output.write(
+        time_zone
+            .local_time_type_at(datetime)
+            .abbreviation()
+            .as_bytes(),
+    )
+}
+
+/// Format the time zone name into the designated output.
+fn fmt_tz_name(
+    output: &mut impl io::Write,
+    time_zone: &TimeZone,
+    modifier::TzName: modifier::TzName,
+) -> Result<usize, error::Format> {
+    let name = time_zone
+        .name()
+        .ok_or(error::Format::InvalidComponent("tz_name"))?;
+    Ok(output.write(name.as_bytes())?)
+}
+// endregion time zone formatters
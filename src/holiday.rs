@@ -0,0 +1,346 @@
+//! Rules that determine the date of a holiday in any year.
+//!
+//! A [`Holiday`] pairs a [`HolidayRule`], which determines the date on which the holiday falls,
+//! with an [`Observance`], which moves it off a weekend where applicable. A slice of holidays is a
+//! [`HolidaySet`], so it can be used directly in a [`BusinessCalendar`](crate::BusinessCalendar).
+//!
+//! A few rule sets are provided as examples: [`US_FEDERAL`], [`GERMANY`], and [`GREECE`]. Each
+//! reflects the current rules, which are applied to every year.
+//!
+//! ```rust
+//! # use time::{holiday, macros::date, BusinessCalendar};
+//! let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(holiday::US_FEDERAL);
+//! // Independence Day 2021 fell on a Sunday, so it was observed on Monday.
+//! assert!(!calendar.is_business_day(date!("2021-07-05")));
+//! ```
+
+use crate::{error, Date, HolidaySet, Month, Weekday};
+
+/// A method of calculating the date of Easter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Computus {
+    /// The method used by Western churches, based on the Gregorian calendar.
+    Gregorian,
+    /// The method used by Eastern Orthodox churches, based on the Julian calendar. The resulting
+    /// date is converted to the (proleptic) Gregorian calendar.
+    Orthodox,
+}
+
+impl Computus {
+    /// Get the date of Easter Sunday in the provided year.
+    ///
+    /// ```rust
+    /// # use time::{holiday::Computus, macros::date};
+    /// assert_eq!(Computus::Gregorian.easter(2021), Ok(date!("2021-04-04")));
+    /// assert_eq!(Computus::Orthodox.easter(2021), Ok(date!("2021-05-02")));
+    /// ```
+    #[allow(clippy::many_single_char_names)] // as named in the algorithm
+    pub const fn easter(self, year: i32) -> Result<Date, error::ComponentRange> {
+        match self {
+            Self::Gregorian => {
+                // The anonymous Gregorian algorithm, as given by Jean Meeus.
+                let a = rem_euclid!(year, 19);
+                let b = div_floor!(year, 100);
+                let c = rem_euclid!(year, 100);
+                let h = rem_euclid!(
+                    19 * a + b - div_floor!(b, 4) - div_floor!(b - div_floor!(b + 8, 25) + 1, 3)
+                        + 15,
+                    30
+                );
+                let l = (32 + 2 * rem_euclid!(b, 4) + 2 * (c / 4) - h - c % 4) % 7;
+                let m = (a + 11 * h + 22 * l) / 451;
+                let n = h + l - 7 * m + 114;
+                let month = if n / 31 == 3 {
+                    Month::March
+                } else {
+                    Month::April
+                };
+                Date::from_calendar_date(year, month, (n % 31 + 1) as u8)
+            }
+            Self::Orthodox => {
+                // Meeus's Julian algorithm, giving the number of days after March 21 in the Julian
+                // calendar.
+                let d = (19 * rem_euclid!(year, 19) + 15) % 30;
+                let e = (2 * rem_euclid!(year, 4) + 4 * rem_euclid!(year, 7) - d + 34) % 7;
+                // The number of days the Julian calendar is behind the Gregorian calendar after
+                // the end of February.
+                let difference = div_floor!(year, 100) - div_floor!(year, 400) - 2;
+                let march_1 = const_try!(Date::from_calendar_date(year, Month::March, 1));
+                Date::from_julian_day(march_1.to_julian_day() + 21 + d + e + difference)
+            }
+        }
+    }
+}
+
+/// How the date on which a holiday falls is determined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HolidayRule {
+    /// The same day of the same month every year.
+    Fixed {
+        /// The month in which the holiday falls.
+        month: Month,
+        /// The day of the month on which the holiday falls.
+        day: u8,
+    },
+    /// The `n`th occurrence of a weekday in a month.
+    NthWeekday {
+        /// The month in which the holiday falls.
+        month: Month,
+        /// Which occurrence of the weekday the holiday falls on, starting at one.
+        n: u8,
+        /// The weekday on which the holiday falls.
+        weekday: Weekday,
+    },
+    /// The last occurrence of a weekday in a month.
+    LastWeekday {
+        /// The month in which the holiday falls.
+        month: Month,
+        /// The weekday on which the holiday falls.
+        weekday: Weekday,
+    },
+    /// A number of days after (or before, if negative) Easter Sunday.
+    Easter {
+        /// How the date of Easter is calculated.
+        computus: Computus,
+        /// The number of days from Easter Sunday.
+        offset: i16,
+    },
+}
+
+impl HolidayRule {
+    /// Get the date on which the holiday falls in the provided year. This returns an error if the
+    /// year is out of range or the holiday does not occur that year, such as a fixed holiday on
+    /// February 29.
+    ///
+    /// ```rust
+    /// # use time::{holiday::HolidayRule, macros::date, Month, Weekday};
+    /// let thanksgiving = HolidayRule::NthWeekday {
+    ///     month: Month::November,
+    ///     n: 4,
+    ///     weekday: Weekday::Thursday,
+    /// };
+    /// assert_eq!(thanksgiving.date(2021), Ok(date!("2021-11-25")));
+    /// ```
+    pub const fn date(self, year: i32) -> Result<Date, error::ComponentRange> {
+        match self {
+            Self::Fixed { month, day } => Date::from_calendar_date(year, month, day),
+            Self::NthWeekday { month, n, weekday } => {
+                Date::nth_weekday_of_month(year, month, n, weekday)
+            }
+            Self::LastWeekday { month, weekday } => {
+                Date::last_weekday_of_month(year, month, weekday)
+            }
+            Self::Easter { computus, offset } => {
+                let easter = const_try!(computus.easter(year));
+                Date::from_julian_day(easter.to_julian_day() + offset as i32)
+            }
+        }
+    }
+}
+
+/// Whether a holiday that falls on a weekend is observed on a nearby weekday instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Observance {
+    /// The holiday is observed on the day it falls, even on a weekend.
+    Actual,
+    /// A holiday on Saturday is observed on Friday, and one on Sunday is observed on Monday.
+    NearestWeekday,
+    /// A holiday on Saturday or Sunday is observed on the following Monday.
+    NextMonday,
+    /// A holiday on Sunday is observed on the following Monday.
+    SundayToMonday,
+}
+
+impl Observance {
+    /// Get the date on which a holiday falling on the provided date is observed, returning `None`
+    /// if the result is out of range.
+    ///
+    /// ```rust
+    /// # use time::{holiday::Observance, macros::date};
+    /// assert_eq!(
+    ///     Observance::NearestWeekday.apply(date!("2021-12-25")),
+    ///     Some(date!("2021-12-24"))
+    /// );
+    /// assert_eq!(
+    ///     Observance::NextMonday.apply(date!("2021-12-25")),
+    ///     Some(date!("2021-12-27"))
+    /// );
+    /// ```
+    pub const fn apply(self, date: Date) -> Option<Date> {
+        match (self, date.weekday()) {
+            (Self::NearestWeekday, Weekday::Saturday) => date.previous_day(),
+            (Self::NearestWeekday, Weekday::Sunday)
+            | (Self::NextMonday, Weekday::Sunday)
+            | (Self::SundayToMonday, Weekday::Sunday) => date.next_day(),
+            (Self::NextMonday, Weekday::Saturday) => date.next_occurrence(Weekday::Monday),
+            _ => Some(date),
+        }
+    }
+}
+
+/// A named holiday, consisting of the rule determining its date and how it is observed.
+///
+/// ```rust
+/// # use time::{holiday::{Holiday, HolidayRule, Observance}, macros::date, Month};
+/// const CHRISTMAS: Holiday = Holiday {
+///     name: "Christmas Day",
+///     rule: HolidayRule::Fixed {
+///         month: Month::December,
+///         day: 25,
+///     },
+///     observance: Observance::NearestWeekday,
+/// };
+/// assert_eq!(CHRISTMAS.actual_date(2021), Ok(date!("2021-12-25")));
+/// assert_eq!(CHRISTMAS.observed_date(2021), Some(date!("2021-12-24")));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Holiday {
+    /// The name of the holiday.
+    pub name: &'static str,
+    /// How the date on which the holiday falls is determined.
+    pub rule: HolidayRule,
+    /// How the holiday is observed when it falls on a weekend.
+    pub observance: Observance,
+}
+
+impl Holiday {
+    /// Get the date on which the holiday falls in the provided year, before applying its
+    /// observance.
+    ///
+    /// ```rust
+    /// # use time::{holiday, macros::date};
+    /// let new_years_day = holiday::US_FEDERAL[0];
+    /// assert_eq!(new_years_day.actual_date(2022), Ok(date!("2022-01-01")));
+    /// ```
+    pub const fn actual_date(self, year: i32) -> Result<Date, error::ComponentRange> {
+        self.rule.date(year)
+    }
+
+    /// Get the date on which the holiday is observed in the provided year, returning `None` if the
+    /// holiday does not occur that year or the result is out of range. The observed date may fall
+    /// in an adjacent year.
+    ///
+    /// ```rust
+    /// # use time::{holiday, macros::date};
+    /// let new_years_day = holiday::US_FEDERAL[0];
+    /// assert_eq!(new_years_day.observed_date(2022), Some(date!("2021-12-31")));
+    /// ```
+    pub const fn observed_date(self, year: i32) -> Option<Date> {
+        match self.actual_date(year) {
+            Ok(date) => self.observance.apply(date),
+            Err(_) => None,
+        }
+    }
+}
+
+impl HolidaySet for [Holiday] {
+    fn is_holiday(&self, date: Date) -> bool {
+        let year = date.year();
+        let month = date.month();
+        self.iter().any(|holiday| {
+            holiday.observed_date(year) == Some(date)
+                // An observed date can only cross the boundary of the year by a few days.
+                || (month == Month::January && holiday.observed_date(year - 1) == Some(date))
+                || (month == Month::December && holiday.observed_date(year + 1) == Some(date))
+        })
+    }
+}
+
+// region: rule sets
+/// Create a [`Holiday`] falling on a fixed date.
+macro_rules! fixed {
+    ($name:literal, $month:ident $day:literal, $observance:ident) => {
+        Holiday {
+            name: $name,
+            rule: HolidayRule::Fixed {
+                month: Month::$month,
+                day: $day,
+            },
+            observance: Observance::$observance,
+        }
+    };
+}
+
+/// Create a [`Holiday`] falling on the `n`th or last occurrence of a weekday in a month.
+macro_rules! weekday {
+    ($name:literal, last $weekday:ident of $month:ident) => {
+        Holiday {
+            name: $name,
+            rule: HolidayRule::LastWeekday {
+                month: Month::$month,
+                weekday: Weekday::$weekday,
+            },
+            observance: Observance::Actual,
+        }
+    };
+    ($name:literal, $n:literal $weekday:ident of $month:ident) => {
+        Holiday {
+            name: $name,
+            rule: HolidayRule::NthWeekday {
+                month: Month::$month,
+                n: $n,
+                weekday: Weekday::$weekday,
+            },
+            observance: Observance::Actual,
+        }
+    };
+}
+
+/// Create a [`Holiday`] falling a number of days from Easter Sunday.
+macro_rules! easter {
+    ($name:literal, $computus:ident, $offset:literal) => {
+        Holiday {
+            name: $name,
+            rule: HolidayRule::Easter {
+                computus: Computus::$computus,
+                offset: $offset,
+            },
+            observance: Observance::Actual,
+        }
+    };
+}
+
+/// Federal holidays in the United States.
+pub const US_FEDERAL: &[Holiday] = &[
+    fixed!("New Year's Day", January 1, NearestWeekday),
+    weekday!("Birthday of Martin Luther King, Jr.", 3 Monday of January),
+    weekday!("Washington's Birthday", 3 Monday of February),
+    weekday!("Memorial Day", last Monday of May),
+    fixed!("Juneteenth National Independence Day", June 19, NearestWeekday),
+    fixed!("Independence Day", July 4, NearestWeekday),
+    weekday!("Labor Day", 1 Monday of September),
+    weekday!("Columbus Day", 2 Monday of October),
+    fixed!("Veterans Day", November 11, NearestWeekday),
+    weekday!("Thanksgiving Day", 4 Thursday of November),
+    fixed!("Christmas Day", December 25, NearestWeekday),
+];
+
+/// Public holidays observed nationwide in Germany.
+pub const GERMANY: &[Holiday] = &[
+    fixed!("New Year's Day", January 1, Actual),
+    easter!("Good Friday", Gregorian, -2),
+    easter!("Easter Monday", Gregorian, 1),
+    fixed!("Labour Day", May 1, Actual),
+    easter!("Ascension Day", Gregorian, 39),
+    easter!("Whit Monday", Gregorian, 50),
+    fixed!("German Unity Day", October 3, Actual),
+    fixed!("Christmas Day", December 25, Actual),
+    fixed!("Second Day of Christmas", December 26, Actual),
+];
+
+/// Public holidays observed nationwide in Greece.
+pub const GREECE: &[Holiday] = &[
+    fixed!("New Year's Day", January 1, Actual),
+    fixed!("Epiphany", January 6, Actual),
+    easter!("Clean Monday", Orthodox, -48),
+    fixed!("Independence Day", March 25, Actual),
+    easter!("Good Friday", Orthodox, -2),
+    easter!("Easter Monday", Orthodox, 1),
+    fixed!("Labour Day", May 1, Actual),
+    easter!("Whit Monday", Orthodox, 50),
+    fixed!("Assumption of Mary", August 15, Actual),
+    fixed!("Ohi Day", October 28, Actual),
+    fixed!("Christmas Day", December 25, Actual),
+    fixed!("Synaxis of the Mother of God", December 26, Actual),
+];
+// endregion rule sets
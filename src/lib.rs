@@ -32,6 +32,11 @@
 //!
 //!   This feature enables a number of methods that allow obtaining the system's UTC offset.
 //!
+//! - `tzdb-embedded` (_implicitly enables `alloc`_)
+//!
+//!   Embeds the IANA time zone database, allowing [`TimeZone::get`] to be used without access to
+//!   the file system. This adds roughly half a megabyte to the binary.
+//!
 //! - `large-dates`
 //!
 //!   By default, only years within the ±9999 range (inclusive) are supported. If you need support
@@ -64,7 +69,9 @@
 //!   Enables [quickcheck](https://docs.rs/quickcheck) support for all types except [`Instant`].
 //!
 //! One pseudo-feature flag that is only available to end users is the `unsound_local_offset` cfg.
-//! As the name indicates, using the feature is unsound, and [may cause unexpected segmentation
+//! On Unix-like platforms, it allows falling back to `localtime_r` when the local offset cannot be
+//! determined from the `TZ` environment variable or `/etc/localtime`. As the name indicates, using
+//! the feature is unsound, and [may cause unexpected segmentation
 //! faults](https://github.com/time-rs/time/issues/293). Unlike other flags, this is deliberately
 //! only available to end users; this is to ensure that a user doesn't have unsound behavior without
 //! knowing it. To enable this behavior, you must use `RUSTFLAGS="--cfg unsound_local_offset" cargo
@@ -257,14 +264,23 @@ macro_rules! const_try_opt {
 }
 // endregion macros
 
+/// The [`BusinessCalendar`] struct and its associated `impl`s.
+mod business_calendar;
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub mod cron;
 /// The [`Date`] struct and its associated `impl`s.
 mod date;
+/// How to handle nonexistent days after calendar arithmetic.
+mod day_overflow;
 /// The [`Duration`] struct and its associated `impl`s.
 mod duration;
 /// Various error types returned by methods in the time crate.
 pub mod error;
 /// Extension traits.
 pub mod ext;
+/// The [`FiscalCalendar`] struct and its associated `impl`s.
+mod fiscal_calendar;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 #[cfg_attr(
     __time_03_docs,
@@ -274,6 +290,8 @@ pub mod format_description;
 #[cfg(feature = "formatting")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
 mod formatting;
+/// Rule-based holidays and sets of holidays.
+pub mod holiday;
 /// The [`Instant`] struct and its associated `impl`s.
 #[cfg(feature = "std")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
@@ -282,11 +300,15 @@ mod instant;
 #[cfg(feature = "macros")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "macros")))]
 pub mod macros;
+/// Months of the year.
+mod month;
 /// The [`OffsetDateTime`] struct and its associated `impl`s.
 mod offset_date_time;
 #[cfg(feature = "parsing")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
 pub mod parsing;
+/// The [`Period`] struct and its associated `impl`s.
+mod period;
 /// The [`PrimitiveDateTime`] struct and its associated `impl`s.
 mod primitive_date_time;
 #[cfg(feature = "quickcheck")]
@@ -295,6 +317,11 @@ mod quickcheck;
 #[cfg(feature = "rand")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "rand")))]
 mod rand;
+/// Iterators over ranges of dates and datetimes.
+pub mod range;
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub mod rrule;
 #[cfg(feature = "serde")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "serde")))]
 #[allow(missing_copy_implementations, missing_debug_implementations)]
@@ -303,22 +330,44 @@ pub mod serde;
 mod tests;
 /// The [`Time`] struct and its associated `impl`s.
 mod time;
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+pub mod time_zone;
+/// The [`Unit`] enum and its associated `impl`s.
+mod unit;
 /// The [`UtcOffset`] struct and its associated `impl`s.
 mod utc_offset;
 pub mod util;
 /// Days of the week.
 mod weekday;
+#[cfg(feature = "alloc")]
+mod zoned_date_time;
 
+pub use crate::business_calendar::{BusinessCalendar, HolidaySet};
+#[cfg(feature = "alloc")]
+pub use crate::cron::Cron;
 pub use crate::date::Date;
+pub use crate::day_overflow::DayOverflow;
 pub use crate::duration::Duration;
 pub use crate::error::Error;
+pub use crate::fiscal_calendar::{FiscalCalendar, FiscalYearNaming};
 #[cfg(feature = "std")]
 pub use crate::instant::Instant;
+pub use crate::month::Month;
 pub use crate::offset_date_time::OffsetDateTime;
+pub use crate::period::Period;
 pub use crate::primitive_date_time::PrimitiveDateTime;
+pub use crate::range::DateRange;
+#[cfg(feature = "alloc")]
+pub use crate::rrule::RRule;
 pub use crate::time::Time;
+#[cfg(feature = "alloc")]
+pub use crate::time_zone::TimeZone;
+pub use crate::unit::Unit;
 pub use crate::utc_offset::UtcOffset;
 pub use crate::weekday::Weekday;
+#[cfg(feature = "alloc")]
+pub use crate::zoned_date_time::ZonedDateTime;
 
 /// An alias for [`std::result::Result`] with a generic error from the time crate.
 pub type Result<T> = core::result::Result<T, Error>;
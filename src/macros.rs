@@ -5,13 +5,16 @@
 /// Three formats are supported: year-week-weekday, year-ordinal, and year-month-day.
 ///
 /// ```rust
-/// # use time::{Date, Weekday::*, macros::date};
+/// # use time::{Date, Month, Weekday::*, macros::date};
 /// assert_eq!(
 ///     date!("2020-W01-3"),
 ///     Date::from_iso_week_date(2020, 1, Wednesday)?
 /// );
 /// assert_eq!(date!("2020-001"), Date::from_ordinal_date(2020, 1)?);
-/// assert_eq!(date!("2020-01-01"), Date::from_calendar_date(2020, 1, 1)?);
+/// assert_eq!(
+///     date!("2020-01-01"),
+///     Date::from_calendar_date(2020, Month::January, 1)?
+/// );
 /// # Ok::<_, time::Error>(())
 /// ```
 pub use time_macros::date;
@@ -0,0 +1,156 @@
+use core::convert::TryFrom;
+use core::fmt::{self, Display};
+
+use Month::*;
+
+use crate::error;
+use crate::util::days_in_year_month;
+
+/// The English names of the months, starting with January.
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Months of the year.
+///
+/// As order is dependent on context (January could be either one month after or eleven months
+/// before December), this type does not implement `PartialOrd` or `Ord`. Converting to a `u8`
+/// yields the one-indexed number of the month.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Month {
+    #[allow(clippy::missing_docs_in_private_items)]
+    January = 1,
+    #[allow(clippy::missing_docs_in_private_items)]
+    February = 2,
+    #[allow(clippy::missing_docs_in_private_items)]
+    March = 3,
+    #[allow(clippy::missing_docs_in_private_items)]
+    April = 4,
+    #[allow(clippy::missing_docs_in_private_items)]
+    May = 5,
+    #[allow(clippy::missing_docs_in_private_items)]
+    June = 6,
+    #[allow(clippy::missing_docs_in_private_items)]
+    July = 7,
+    #[allow(clippy::missing_docs_in_private_items)]
+    August = 8,
+    #[allow(clippy::missing_docs_in_private_items)]
+    September = 9,
+    #[allow(clippy::missing_docs_in_private_items)]
+    October = 10,
+    #[allow(clippy::missing_docs_in_private_items)]
+    November = 11,
+    #[allow(clippy::missing_docs_in_private_items)]
+    December = 12,
+}
+
+impl Month {
+    /// Create a `Month` from its one-indexed number, without checking its validity.
+    pub(crate) const fn __from_number_unchecked(month: u8) -> Self {
+        match month {
+            1 => January,
+            2 => February,
+            3 => March,
+            4 => April,
+            5 => May,
+            6 => June,
+            7 => July,
+            8 => August,
+            9 => September,
+            10 => October,
+            11 => November,
+            _ => December,
+        }
+    }
+
+    /// Get the previous month.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.previous(), Month::December);
+    /// ```
+    pub const fn previous(self) -> Self {
+        match self {
+            January => December,
+            February => January,
+            March => February,
+            April => March,
+            May => April,
+            June => May,
+            July => June,
+            August => July,
+            September => August,
+            October => September,
+            November => October,
+            December => November,
+        }
+    }
+
+    /// Get the next month.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::December.next(), Month::January);
+    /// ```
+    pub const fn next(self) -> Self {
+        match self {
+            January => February,
+            February => March,
+            March => April,
+            April => May,
+            May => June,
+            June => July,
+            July => August,
+            August => September,
+            September => October,
+            October => November,
+            November => December,
+            December => January,
+        }
+    }
+
+    /// Get the number of days in the month of the provided year.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::February.length(2019), 28);
+    /// assert_eq!(Month::February.length(2020), 29);
+    /// assert_eq!(Month::April.length(2020), 30);
+    /// ```
+    pub const fn length(self, year: i32) -> u8 {
+        days_in_year_month(year, self)
+    }
+}
+
+impl Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(MONTH_NAMES[*self as usize - 1])
+    }
+}
+
+impl From<Month> for u8 {
+    fn from(month: Month) -> Self {
+        month as _
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = error::ComponentRange;
+
+    fn try_from(month: u8) -> Result<Self, Self::Error> {
+        ensure_value_in_range!(month in 1 => 12);
+        Ok(Self::__from_number_unchecked(month))
+    }
+}
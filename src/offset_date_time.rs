@@ -11,11 +11,18 @@ use std::io;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+use crate::date::{MAX_YEAR, MIN_YEAR};
 #[cfg(feature = "formatting")]
 use crate::formatting::formattable::sealed::Formattable;
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
-use crate::{error, Date, Duration, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use crate::range::DateTimeStep;
+#[cfg(feature = "alloc")]
+use crate::{TimeZone, ZonedDateTime};
+use crate::{
+    error, Date, DayOverflow, Duration, Month, Period, PrimitiveDateTime, Time, Unit, UtcOffset,
+    Weekday,
+};
 
 /// The Julian day of the Unix epoch.
 const UNIX_EPOCH_JULIAN_DAY: i32 = Date::__from_ordinal_date_unchecked(1970, 1).to_julian_day();
@@ -69,8 +76,8 @@ impl OffsetDateTime {
     /// # }
     /// ```
     ///
-    /// Due to a [soundness bug](https://github.com/time-rs/time/issues/293),
-    /// the error value is currently always returned on Unix-like platforms.
+    /// The offset is determined as in [`UtcOffset::local_offset_at`], which describes how the `TZ`
+    /// environment variable is interpreted on Unix-like platforms.
     #[cfg(feature = "local-offset")]
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "local-offset")))]
     pub fn now_local() -> Result<Self, error::IndeterminateOffset> {
@@ -79,6 +86,7 @@ impl OffsetDateTime {
     }
     // endregion now
 
+    // region: offset conversion
     /// Convert the `OffsetDateTime` from the current [`UtcOffset`] to the provided [`UtcOffset`].
     ///
     /// ```rust
@@ -107,6 +115,44 @@ impl OffsetDateTime {
         }
     }
 
+    /// Convert the `OffsetDateTime` from the current [`UtcOffset`] to the [`UtcOffset`] in effect
+    /// in the provided [`TimeZone`] at the same moment.
+    ///
+    /// ```rust,no_run
+    /// # use time::{TimeZone, macros::{datetime, offset}};
+    /// let new_york = TimeZone::load("America/New_York")?;
+    /// let datetime = datetime!("2021-07-01 12:00 UTC").to_timezone(&new_york);
+    /// assert_eq!(datetime.hour(), 8);
+    /// assert_eq!(datetime.offset(), offset!("-4"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn to_timezone(self, time_zone: &TimeZone) -> Self {
+        self.to_offset(time_zone.offset_at(self))
+    }
+
+    /// Convert the `OffsetDateTime` to a [`ZonedDateTime`] in the provided [`TimeZone`], preserving
+    /// the moment.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, Duration, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-11-06 12:00 -4").to_zoned(&new_york);
+    /// assert_eq!(datetime.offset(), offset!("-4"));
+    /// assert_eq!((datetime + Duration::days(1)).offset(), offset!("-5"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn to_zoned(self, time_zone: &TimeZone) -> ZonedDateTime<'_> {
+        ZonedDateTime {
+            datetime: self.to_timezone(time_zone),
+            time_zone,
+        }
+    }
+    // endregion offset conversion
+
     // region: constructors
     /// Create an `OffsetDateTime` from the provided Unix timestamp. Calling `.offset()` on the
     /// resulting value is guaranteed to return UTC.
@@ -320,19 +366,17 @@ impl OffsetDateTime {
 
     /// Get the month of the date in the stored offset.
     ///
-    /// The returned value will always be in the range `1..=12`.
-    ///
     /// ```rust
-    /// # use time::macros::{datetime, offset};
-    /// assert_eq!(datetime!("2019-01-01 0:00 UTC").month(), 1);
+    /// # use time::{macros::{datetime, offset}, Month};
+    /// assert_eq!(datetime!("2019-01-01 0:00 UTC").month(), Month::January);
     /// assert_eq!(
     ///     datetime!("2019-12-31 23:00 UTC")
     ///         .to_offset(offset!("+1"))
     ///         .month(),
-    ///     1,
+    ///     Month::January,
     /// );
     /// ```
-    pub const fn month(self) -> u8 {
+    pub const fn month(self) -> Month {
         self.date().month()
     }
 
@@ -354,6 +398,25 @@ impl OffsetDateTime {
         self.date().day()
     }
 
+    /// Get the quarter of the year in the stored offset, where the first quarter begins in
+    /// January.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time::macros::{datetime, offset};
+    /// assert_eq!(datetime!("2019-01-01 0:00 UTC").quarter(), 1);
+    /// assert_eq!(
+    ///     datetime!("2019-12-31 23:00 UTC")
+    ///         .to_offset(offset!("+1"))
+    ///         .quarter(),
+    ///     1,
+    /// );
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        self.date().quarter()
+    }
+
     /// Get the day of the year of the date in the stored offset.
     ///
     /// The returned value will always be in the range `1..=366`.
@@ -430,13 +493,13 @@ impl OffsetDateTime {
     /// Get the year, month, and day.
     ///
     /// ```rust
-    /// # use time::macros::datetime;
+    /// # use time::{macros::datetime, Month};
     /// assert_eq!(
     ///     datetime!("2019-01-01 0:00 UTC").to_calendar_date(),
-    ///     (2019, 1, 1)
+    ///     (2019, Month::January, 1)
     /// );
     /// ```
-    pub const fn to_calendar_date(self) -> (i32, u8, u8) {
+    pub const fn to_calendar_date(self) -> (i32, Month, u8) {
         self.date().to_calendar_date()
     }
 
@@ -696,7 +759,8 @@ impl OffsetDateTime {
 }
 
 // region: replacement
-/// Methods that replace part of the `OffsetDateTime`.
+/// Methods that replace part of the `OffsetDateTime`. Components are replaced as they appear in
+/// the stored offset, which is left unchanged unless explicitly replaced.
 impl OffsetDateTime {
     /// Replace the time, which is assumed to be in the stored offset. The date and offset
     /// components are unchanged.
@@ -778,9 +842,634 @@ impl OffsetDateTime {
     pub const fn replace_offset(self, offset: UtcOffset) -> Self {
         self.utc_datetime.assume_offset(offset)
     }
+
+    /// Replace the year. The month and day will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 12:00 +1").replace_year(2019),
+    ///     Ok(datetime!("2019-02-18 12:00 +1"))
+    /// );
+    /// // -1_000_000_000 isn't a valid year.
+    /// assert!(datetime!("2022-02-18 12:00 +1").replace_year(-1_000_000_000).is_err());
+    /// ```
+    pub const fn replace_year(self, year: i32) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_year(year)))
+    }
+
+    /// Replace the month of the year. The year and day will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Month};
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 12:00 +1").replace_month(Month::January),
+    ///     Ok(datetime!("2022-01-18 12:00 +1"))
+    /// );
+    /// // February 30 doesn't exist.
+    /// assert!(datetime!("2022-01-30 12:00 +1").replace_month(Month::February).is_err());
+    /// ```
+    pub const fn replace_month(self, month: Month) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_month(month)))
+    }
+
+    /// Replace the day of the month. The year and month will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 12:00 +1").replace_day(1),
+    ///     Ok(datetime!("2022-02-01 12:00 +1"))
+    /// );
+    /// // February 30 doesn't exist.
+    /// assert!(datetime!("2022-02-18 12:00 +1").replace_day(30).is_err());
+    /// ```
+    pub const fn replace_day(self, day: u8) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_day(day)))
+    }
+
+    /// Replace the day of the year. The year will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-049 12:00 +1").replace_ordinal(1),
+    ///     Ok(datetime!("2022-001 12:00 +1"))
+    /// );
+    /// // 2022 isn't a leap year.
+    /// assert!(datetime!("2022-049 12:00 +1").replace_ordinal(366).is_err());
+    /// ```
+    pub const fn replace_ordinal(self, ordinal: u16) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_ordinal(ordinal)))
+    }
+
+    /// Replace the clock hour.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004 +1").replace_hour(7),
+    ///     Ok(datetime!("2022-02-18 7:02:03.004 +1"))
+    /// );
+    /// // 24 isn't a valid hour.
+    /// assert!(datetime!("2022-02-18 1:02:03.004 +1").replace_hour(24).is_err());
+    /// ```
+    pub const fn replace_hour(self, hour: u8) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_hour(hour)))
+    }
+
+    /// Replace the minutes within the hour.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004 +1").replace_minute(7),
+    ///     Ok(datetime!("2022-02-18 1:07:03.004 +1"))
+    /// );
+    /// // 60 isn't a valid minute.
+    /// assert!(datetime!("2022-02-18 1:02:03.004 +1").replace_minute(60).is_err());
+    /// ```
+    pub const fn replace_minute(self, minute: u8) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_minute(minute)))
+    }
+
+    /// Replace the seconds within the minute.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004 +1").replace_second(7),
+    ///     Ok(datetime!("2022-02-18 1:02:07.004 +1"))
+    /// );
+    /// // 60 isn't a valid second.
+    /// assert!(datetime!("2022-02-18 1:02:03.004 +1").replace_second(60).is_err());
+    /// ```
+    pub const fn replace_second(self, second: u8) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_second(second)))
+    }
+
+    /// Replace the milliseconds within the second. Any sub-millisecond component is discarded.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004_005 +1").replace_millisecond(7),
+    ///     Ok(datetime!("2022-02-18 1:02:03.007 +1"))
+    /// );
+    /// // 1_000 isn't a valid millisecond.
+    /// assert!(datetime!("2022-02-18 1:02:03.004 +1").replace_millisecond(1_000).is_err());
+    /// ```
+    pub const fn replace_millisecond(
+        self,
+        millisecond: u16,
+    ) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_millisecond(millisecond)))
+    }
+
+    /// Replace the microseconds within the second. Any sub-microsecond component is discarded.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004_005_006 +1").replace_microsecond(7_008),
+    ///     Ok(datetime!("2022-02-18 1:02:03.007_008 +1"))
+    /// );
+    /// // 1_000_000 isn't a valid microsecond.
+    /// assert!(datetime!("2022-02-18 1:02:03.004 +1").replace_microsecond(1_000_000).is_err());
+    /// ```
+    pub const fn replace_microsecond(
+        self,
+        microsecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_microsecond(microsecond)))
+    }
+
+    /// Replace the nanoseconds within the second.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004_005_006 +1").replace_nanosecond(7_008_009),
+    ///     Ok(datetime!("2022-02-18 1:02:03.007_008_009 +1"))
+    /// );
+    /// // 1_000_000_000 isn't a valid nanosecond.
+    /// assert!(datetime!("2022-02-18 1:02:03.004 +1").replace_nanosecond(1_000_000_000).is_err());
+    /// ```
+    pub const fn replace_nanosecond(self, nanosecond: u32) -> Result<Self, error::ComponentRange> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.replace_local_datetime(const_try!(datetime.replace_nanosecond(nanosecond)))
+    }
 }
 // endregion replacement
 
+// region: calendar arithmetic
+/// Methods that move an `OffsetDateTime` by calendar months and years.
+///
+/// The arithmetic is performed on the date in the stored offset, and the offset is unchanged.
+impl OffsetDateTime {
+    /// Add the provided number of months to the date, preserving the day of the month, time, and
+    /// offset. See [`Date::checked_add_months`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, DayOverflow};
+    /// assert_eq!(
+    ///     datetime!("2021-01-31 23:00 -5").checked_add_months(1, DayOverflow::Clamp),
+    ///     Some(datetime!("2021-02-28 23:00 -5"))
+    /// );
+    /// ```
+    pub const fn checked_add_months(self, months: i32, overflow: DayOverflow) -> Option<Self> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.with_local_datetime(const_try_opt!(datetime.checked_add_months(months, overflow)))
+    }
+
+    /// Subtract the provided number of months from the date, preserving the day of the month,
+    /// time, and offset. See [`Date::checked_sub_months`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, DayOverflow};
+    /// assert_eq!(
+    ///     datetime!("2021-03-31 0:00 +1").checked_sub_months(1, DayOverflow::Clamp),
+    ///     Some(datetime!("2021-02-28 0:00 +1"))
+    /// );
+    /// ```
+    pub const fn checked_sub_months(self, months: i32, overflow: DayOverflow) -> Option<Self> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.with_local_datetime(const_try_opt!(datetime.checked_sub_months(months, overflow)))
+    }
+
+    /// Add the provided number of years to the date, preserving the month, day, time, and offset.
+    /// See [`Date::checked_add_years`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, DayOverflow};
+    /// assert_eq!(
+    ///     datetime!("2020-02-29 12:00 UTC").checked_add_years(1, DayOverflow::Rollover),
+    ///     Some(datetime!("2021-03-01 12:00 UTC"))
+    /// );
+    /// ```
+    pub const fn checked_add_years(self, years: i32, overflow: DayOverflow) -> Option<Self> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.with_local_datetime(const_try_opt!(datetime.checked_add_years(years, overflow)))
+    }
+
+    /// Subtract the provided number of years from the date, preserving the month, day, time, and
+    /// offset. See [`Date::checked_sub_years`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, DayOverflow};
+    /// assert_eq!(
+    ///     datetime!("2020-02-29 12:00 UTC").checked_sub_years(1, DayOverflow::Error),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_sub_years(self, years: i32, overflow: DayOverflow) -> Option<Self> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.with_local_datetime(const_try_opt!(datetime.checked_sub_years(years, overflow)))
+    }
+}
+// endregion calendar arithmetic
+
+// region: checked and saturating arithmetic
+/// Methods that add or subtract a duration without panicking.
+///
+/// The result is considered to have overflowed if either the UTC datetime or the datetime in the
+/// stored offset is out of range.
+impl OffsetDateTime {
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalDuration, macros::{datetime, offset, time}};
+    /// let datetime = Date::MAX.with_time(time!("23:00")).assume_offset(offset!("+1"));
+    /// assert_eq!(datetime.checked_add(1.hours()), None);
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").checked_add(27.hours()),
+    ///     Some(datetime!("2019-11-26 18:30 +10"))
+    /// );
+    /// ```
+    pub const fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.with_utc_datetime(const_try_opt!(self.utc_datetime.checked_add(duration)))
+    }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration, macros::{datetime, offset, time}};
+    /// let datetime = Date::MAX.with_time(time!("23:00")).assume_offset(offset!("+1"));
+    /// assert_eq!(datetime.checked_add_std(1.std_hours()), None);
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").checked_add_std(27.std_hours()),
+    ///     Some(datetime!("2019-11-26 18:30 +10"))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        self.with_utc_datetime(const_try_opt!(self.utc_datetime.checked_add_std(duration)))
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalDuration, macros::{datetime, offset}};
+    /// let datetime = Date::MIN.midnight().assume_offset(offset!("-1"));
+    /// assert_eq!(datetime.checked_sub(1.hours()), None);
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").checked_sub(27.hours()),
+    ///     Some(datetime!("2019-11-24 12:30 +10"))
+    /// );
+    /// ```
+    pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.with_utc_datetime(const_try_opt!(self.utc_datetime.checked_sub(duration)))
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration, macros::{datetime, offset}};
+    /// let datetime = Date::MIN.midnight().assume_offset(offset!("-1"));
+    /// assert_eq!(datetime.checked_sub_std(1.std_hours()), None);
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").checked_sub_std(27.std_hours()),
+    ///     Some(datetime!("2019-11-24 12:30 +10"))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        self.with_utc_datetime(const_try_opt!(self.utc_datetime.checked_sub_std(duration)))
+    }
+
+    /// Computes `self + duration`, saturating value on overflow. The offset is unchanged.
+    ///
+    /// ```rust
+    /// # use time::{Duration, PrimitiveDateTime, ext::NumericalDuration};
+    /// # use time::macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").saturating_add(Duration::MAX),
+    ///     PrimitiveDateTime::MAX.assume_offset(offset!("+10"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 -10").saturating_add(Duration::MAX),
+    ///     PrimitiveDateTime::MAX.assume_utc().to_offset(offset!("-10"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").saturating_add(27.hours()),
+    ///     datetime!("2019-11-26 18:30 +10")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn saturating_add(self, duration: Duration) -> Self {
+        match self.checked_add(duration) {
+            Some(datetime) => datetime,
+            None if duration.is_negative() => Self::min_in_offset(self.offset),
+            None => Self::max_in_offset(self.offset),
+        }
+    }
+
+    /// Computes `self + duration`, saturating value on overflow. The offset is unchanged.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalStdDuration, macros::{datetime, offset}};
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").saturating_add_std(std::time::Duration::MAX),
+    ///     PrimitiveDateTime::MAX.assume_offset(offset!("+10"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").saturating_add_std(27.std_hours()),
+    ///     datetime!("2019-11-26 18:30 +10")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn saturating_add_std(self, duration: StdDuration) -> Self {
+        match self.checked_add_std(duration) {
+            Some(datetime) => datetime,
+            None => Self::max_in_offset(self.offset),
+        }
+    }
+
+    /// Computes `self - duration`, saturating value on overflow. The offset is unchanged.
+    ///
+    /// ```rust
+    /// # use time::{Duration, PrimitiveDateTime, ext::NumericalDuration};
+    /// # use time::macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").saturating_sub(Duration::MAX),
+    ///     PrimitiveDateTime::MIN.assume_utc().to_offset(offset!("+10"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 -10").saturating_sub(Duration::MAX),
+    ///     PrimitiveDateTime::MIN.assume_offset(offset!("-10"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").saturating_sub(27.hours()),
+    ///     datetime!("2019-11-24 12:30 +10")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn saturating_sub(self, duration: Duration) -> Self {
+        match self.checked_sub(duration) {
+            Some(datetime) => datetime,
+            None if duration.is_negative() => Self::max_in_offset(self.offset),
+            None => Self::min_in_offset(self.offset),
+        }
+    }
+
+    /// Computes `self - duration`, saturating value on overflow. The offset is unchanged.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalStdDuration, macros::{datetime, offset}};
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 -10").saturating_sub_std(std::time::Duration::MAX),
+    ///     PrimitiveDateTime::MIN.assume_offset(offset!("-10"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30 +10").saturating_sub_std(27.std_hours()),
+    ///     datetime!("2019-11-24 12:30 +10")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `OffsetDateTime`."]
+    pub const fn saturating_sub_std(self, duration: StdDuration) -> Self {
+        match self.checked_sub_std(duration) {
+            Some(datetime) => datetime,
+            None => Self::min_in_offset(self.offset),
+        }
+    }
+
+    /// Replace the UTC datetime, returning `None` if either it or the datetime in the stored offset
+    /// is out of range.
+    const fn with_utc_datetime(self, utc_datetime: PrimitiveDateTime) -> Option<Self> {
+        let utc_year = utc_datetime.year();
+        let year = utc_datetime.utc_to_offset(self.offset).year();
+        if utc_year < MIN_YEAR || utc_year > MAX_YEAR || year < MIN_YEAR || year > MAX_YEAR {
+            None
+        } else {
+            Some(Self {
+                utc_datetime,
+                offset: self.offset,
+            })
+        }
+    }
+
+    /// Replace the datetime in the stored offset, returning `None` if the UTC datetime is out of
+    /// range.
+    const fn with_local_datetime(self, datetime: PrimitiveDateTime) -> Option<Self> {
+        self.with_utc_datetime(datetime.offset_to_utc(self.offset))
+    }
+
+    /// Replace the datetime in the stored offset, returning an error if the UTC datetime is out of
+    /// range.
+    const fn replace_local_datetime(
+        self,
+        datetime: PrimitiveDateTime,
+    ) -> Result<Self, error::ComponentRange> {
+        match self.with_local_datetime(datetime) {
+            Some(datetime) => Ok(datetime),
+            None => Err(error::ComponentRange {
+                name: "year",
+                minimum: MIN_YEAR as _,
+                maximum: MAX_YEAR as _,
+                value: datetime.offset_to_utc(self.offset).year() as _,
+                conditional_range: true,
+            }),
+        }
+    }
+
+    /// Convert to the provided offset, returning `None` if the datetime in that offset is out of
+    /// range.
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn checked_to_offset(self, offset: UtcOffset) -> Option<Self> {
+        self.to_offset(offset).with_utc_datetime(self.utc_datetime)
+    }
+
+    /// The minimum `OffsetDateTime` with the provided offset for which both the UTC datetime and
+    /// the datetime in the offset are in range.
+    pub(crate) const fn min_in_offset(offset: UtcOffset) -> Self {
+        if offset.is_negative() {
+            PrimitiveDateTime::MIN.assume_offset(offset)
+        } else {
+            PrimitiveDateTime::MIN.assume_utc().to_offset(offset)
+        }
+    }
+
+    /// The maximum `OffsetDateTime` with the provided offset for which both the UTC datetime and
+    /// the datetime in the offset are in range.
+    pub(crate) const fn max_in_offset(offset: UtcOffset) -> Self {
+        if offset.is_positive() {
+            PrimitiveDateTime::MAX.assume_offset(offset)
+        } else {
+            PrimitiveDateTime::MAX.assume_utc().to_offset(offset)
+        }
+    }
+}
+// endregion checked and saturating arithmetic
+
+// region: ranges
+/// Methods for iterating over ranges of `OffsetDateTime`s.
+impl OffsetDateTime {
+    /// Create an iterator from `start` up to but not including `end`, advancing by `step` each
+    /// time. If `end` is not after `start`, the iterator is empty.
+    ///
+    /// The yielded values have the same offset as `start`, regardless of the offset of `end`.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Duration, OffsetDateTime};
+    /// let mut datetimes = OffsetDateTime::range_step_by(
+    ///     datetime!("2021-03-14 0:00 UTC"),
+    ///     datetime!("2021-03-14 12:00 UTC"),
+    ///     Duration::hours(5),
+    /// );
+    /// assert_eq!(datetimes.next(), Some(datetime!("2021-03-14 0:00 UTC")));
+    /// assert_eq!(datetimes.next(), Some(datetime!("2021-03-14 5:00 UTC")));
+    /// assert_eq!(datetimes.next(), Some(datetime!("2021-03-14 10:00 UTC")));
+    /// assert_eq!(datetimes.next(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn range_step_by(start: Self, end: Self, step: Duration) -> DateTimeStep<Self> {
+        DateTimeStep::<Self>::new(start, end, step)
+    }
+}
+// endregion ranges
+
+// region: rounding
+/// Methods that truncate or round the `OffsetDateTime` to a [`Unit`] or a multiple of a
+/// [`Duration`].
+///
+/// Units are applied to the datetime in the stored offset, and the offset is unchanged.
+impl OffsetDateTime {
+    /// Truncate the `OffsetDateTime` to the start of the provided unit, returning `None` if the
+    /// result is out of range. See [`PrimitiveDateTime::truncate`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Unit};
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 1:45 +3").truncate(Unit::Day),
+    ///     Some(datetime!("2021-08-19 0:00 +3"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 1:45 +3").truncate(Unit::Month),
+    ///     Some(datetime!("2021-08-01 0:00 +3"))
+    /// );
+    /// ```
+    pub const fn truncate(self, unit: Unit) -> Option<Self> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.with_local_datetime(const_try_opt!(datetime.truncate(unit)))
+    }
+
+    /// Round the `OffsetDateTime` up to the start of the next unit, returning `None` if the
+    /// result is out of range. See [`PrimitiveDateTime::ceil`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Unit};
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 1:45 +3").ceil(Unit::Day),
+    ///     Some(datetime!("2021-08-20 0:00 +3"))
+    /// );
+    /// ```
+    pub fn ceil(self, unit: Unit) -> Option<Self> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.with_local_datetime(datetime.ceil(unit)?)
+    }
+
+    /// Round the `OffsetDateTime` to the nearest start of a unit, returning `None` if the result
+    /// is out of range. See [`PrimitiveDateTime::round`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Unit};
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 1:45 +3").round(Unit::Hour),
+    ///     Some(datetime!("2021-08-19 2:00 +3"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 1:45 +3").round(Unit::Day),
+    ///     Some(datetime!("2021-08-19 0:00 +3"))
+    /// );
+    /// ```
+    pub fn round(self, unit: Unit) -> Option<Self> {
+        let datetime = self.utc_datetime.utc_to_offset(self.offset);
+        self.with_local_datetime(datetime.round(unit)?)
+    }
+
+    /// Truncate the `OffsetDateTime` to a multiple of `step` after or before `origin`, returning
+    /// `None` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Duration};
+    /// let origin = datetime!("2021-01-01 0:00 UTC");
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:44 +0:30").truncate_to_multiple(Duration::hours(1), origin),
+    ///     Some(datetime!("2021-08-19 13:30 +0:30"))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn truncate_to_multiple(self, step: Duration, origin: Self) -> Option<Self> {
+        assert!(step.is_positive(), "step must be positive");
+        self.checked_sub((self - origin).rem_euclid(step))
+    }
+
+    /// Round the `OffsetDateTime` up to a multiple of `step` after or before `origin`, returning
+    /// `None` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Duration};
+    /// let origin = datetime!("2021-01-01 0:00 UTC");
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:31 UTC").ceil_to_multiple(Duration::minutes(15), origin),
+    ///     Some(datetime!("2021-08-19 13:45 UTC"))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn ceil_to_multiple(self, step: Duration, origin: Self) -> Option<Self> {
+        assert!(step.is_positive(), "step must be positive");
+        let remainder = (self - origin).rem_euclid(step);
+        if remainder.is_zero() {
+            Some(self)
+        } else {
+            self.checked_add(step - remainder)
+        }
+    }
+
+    /// Round the `OffsetDateTime` to the nearest multiple of `step` after or before `origin`,
+    /// returning `None` if the result is out of range. Values exactly halfway between two
+    /// multiples are rounded up.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Duration};
+    /// let origin = datetime!("2021-01-01 0:00 UTC");
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:37:30 UTC").round_to_multiple(Duration::minutes(15), origin),
+    ///     Some(datetime!("2021-08-19 13:45 UTC"))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn round_to_multiple(self, step: Duration, origin: Self) -> Option<Self> {
+        assert!(step.is_positive(), "step must be positive");
+        let remainder = (self - origin).rem_euclid(step);
+        if remainder >= step - remainder {
+            self.checked_add(step - remainder)
+        } else {
+            self.checked_sub(remainder)
+        }
+    }
+}
+// endregion rounding
+
 // region: formatting & parsing
 #[cfg(feature = "formatting")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
@@ -799,6 +1488,7 @@ impl OffsetDateTime {
             Some(local.date),
             Some(local.time),
             Some(self.offset),
+            None,
         )
     }
 
@@ -820,7 +1510,7 @@ impl OffsetDateTime {
     /// ```
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
         let local = self.utc_datetime.utc_to_offset(self.offset);
-        format.format(Some(local.date), Some(local.time), Some(self.offset))
+        format.format(Some(local.date), Some(local.time), Some(self.offset), None)
     }
 }
 
@@ -952,6 +1642,38 @@ impl SubAssign<StdDuration> for OffsetDateTime {
     }
 }
 
+/// The [`Period`] is applied to the date in the stored offset, and the offset is unchanged.
+impl Add<Period> for OffsetDateTime {
+    type Output = Self;
+
+    fn add(self, period: Period) -> Self::Output {
+        self.with_local_datetime(self.utc_datetime.utc_to_offset(self.offset) + period)
+            .expect("overflow adding period to datetime")
+    }
+}
+
+impl AddAssign<Period> for OffsetDateTime {
+    fn add_assign(&mut self, period: Period) {
+        *self = *self + period;
+    }
+}
+
+/// The [`Period`] is applied to the date in the stored offset, and the offset is unchanged.
+impl Sub<Period> for OffsetDateTime {
+    type Output = Self;
+
+    fn sub(self, period: Period) -> Self::Output {
+        self.with_local_datetime(self.utc_datetime.utc_to_offset(self.offset) - period)
+            .expect("overflow subtracting period from datetime")
+    }
+}
+
+impl SubAssign<Period> for OffsetDateTime {
+    fn sub_assign(&mut self, period: Period) {
+        *self = *self - period;
+    }
+}
+
 impl Sub<OffsetDateTime> for OffsetDateTime {
     type Output = Duration;
 
@@ -25,6 +25,22 @@ pub(crate) fn first_match<'a, 'b: 'a, T: Copy + 'a>(
     }
 }
 
+/// Consume the first matching string, ignoring ASCII case, returning its associated value.
+pub(crate) fn first_match_ignore_case<'a, 'b, T: Copy>(
+    options: &'a [(&'a str, T)],
+) -> impl Fn(&'b [u8]) -> Option<ParsedItem<'b, T>> + 'a {
+    move |input| {
+        options.iter().find_map(|&(expected, t)| {
+            let head = input.get(..expected.len())?;
+            if head.eq_ignore_ascii_case(expected.as_bytes()) {
+                Some(ParsedItem(&input[expected.len()..], t))
+            } else {
+                None
+            }
+        })
+    }
+}
+
 /// Consume between `n` and `m` instances of the provided parser.
 pub(crate) fn n_to_m<'a, T>(
     n: u8,
@@ -134,3 +150,34 @@ pub(crate) fn opt<'a, T>(
         None => ParsedItem(input, None),
     }
 }
+
+/// Consume any amount of whitespace and comments, as defined by `CFWS` in RFC 2822. Comments may be
+/// nested and may contain escaped characters. An unterminated comment is not consumed.
+pub(crate) fn cfws(mut input: &[u8]) -> ParsedItem<'_, ()> {
+    loop {
+        match input {
+            [c, remaining @ ..] if c.is_ascii_whitespace() => input = remaining,
+            [b'(', ..] => {
+                let mut depth = 0_usize;
+                let mut index = 0;
+                let end = loop {
+                    match input.get(index) {
+                        Some(b'(') => depth += 1,
+                        Some(b')') => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break index + 1;
+                            }
+                        }
+                        Some(b'\\') => index += 1,
+                        Some(_) => {}
+                        None => return ParsedItem(input, ()),
+                    }
+                    index += 1;
+                };
+                input = &input[end..];
+            }
+            _ => return ParsedItem(input, ()),
+        }
+    }
+}
@@ -1,15 +1,16 @@
 //! Parsing implementations for all [`Component`](crate::format_description::Component)s.
 
+use core::convert::TryFrom;
 use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::format_description::modifier;
 #[cfg(feature = "large-dates")]
 use crate::parsing::combinator::n_to_m_digits_padded;
 use crate::parsing::combinator::{
-    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, opt, sign,
+    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, n_to_m, opt, sign,
 };
 use crate::parsing::ParsedItem;
-use crate::Weekday;
+use crate::{Month, Weekday};
 
 // region: date components
 /// Parse the "year" component of a `Date`.
@@ -35,47 +36,78 @@ pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<Pars
     }
 }
 
+/// Parse the "quarter" component of a `Date`.
+pub(crate) fn parse_quarter(
+    input: &[u8],
+    modifier::Quarter { .. }: modifier::Quarter,
+) -> Option<ParsedItem<'_, NonZeroU8>> {
+    exactly_n_digits::<NonZeroU8>(1)(input)?.flat_map(|quarter| {
+        if quarter.get() <= 4 {
+            Some(quarter)
+        } else {
+            None
+        }
+    })
+}
+
+/// Parse the "fiscal year" component of a `Date`.
+pub(crate) fn parse_fiscal_year(
+    input: &[u8],
+    modifiers: modifier::FiscalYear,
+) -> Option<ParsedItem<'_, i32>> {
+    parse_year(
+        input,
+        modifier::Year {
+            padding: modifiers.padding,
+            repr: modifiers.repr,
+            iso_week_based: false,
+            sign_is_mandatory: modifiers.sign_is_mandatory,
+        },
+    )
+}
+
 /// Parse the "month" component of a `Date`.
 pub(crate) fn parse_month(
     input: &[u8],
     modifiers: modifier::Month,
-) -> Option<ParsedItem<'_, NonZeroU8>> {
-    let ParsedItem(remaining, value) = first_match(match modifiers.repr {
+) -> Option<ParsedItem<'_, Month>> {
+    use Month::*;
+    first_match(match modifiers.repr {
         modifier::MonthRepr::Numerical => {
-            return exactly_n_digits_padded(2, modifiers.padding)(input);
+            return exactly_n_digits_padded::<u8>(2, modifiers.padding)(input)?
+                .flat_map(|month| Month::try_from(month).ok());
         }
         modifier::MonthRepr::Long => [
-            ("January", 1),
-            ("February", 2),
-            ("March", 3),
-            ("April", 4),
-            ("May", 5),
-            ("June", 6),
-            ("July", 7),
-            ("August", 8),
-            ("September", 9),
-            ("October", 10),
-            ("November", 11),
-            ("December", 12),
+            ("January", January),
+            ("February", February),
+            ("March", March),
+            ("April", April),
+            ("May", May),
+            ("June", June),
+            ("July", July),
+            ("August", August),
+            ("September", September),
+            ("October", October),
+            ("November", November),
+            ("December", December),
         ]
         .iter(),
         modifier::MonthRepr::Short => [
-            ("Jan", 1),
-            ("Feb", 2),
-            ("Mar", 3),
-            ("Apr", 4),
-            ("May", 5),
-            ("Jun", 6),
-            ("Jul", 7),
-            ("Aug", 8),
-            ("Sep", 9),
-            ("Oct", 10),
-            ("Nov", 11),
-            ("Dec", 12),
+            ("Jan", January),
+            ("Feb", February),
+            ("Mar", March),
+            ("Apr", April),
+            ("May", May),
+            ("Jun", June),
+            ("Jul", July),
+            ("Aug", August),
+            ("Sep", September),
+            ("Oct", October),
+            ("Nov", November),
+            ("Dec", December),
         ]
         .iter(),
-    })(input)?;
-    Some(ParsedItem(remaining, NonZeroU8::new(value)?))
+    })(input)
 }
 
 /// Parse the "week number" component of a `Date`.
@@ -278,3 +310,32 @@ pub(crate) fn parse_offset_second(
     exactly_n_digits_padded(2, modifiers.padding)(input)
 }
 // endregion offset components
+
+// region: time zone components
+/// Parse the "abbreviation" component of a `TimeZone`.
+pub(crate) fn parse_tz_abbrev(
+    input: &[u8],
+    modifier::TzAbbrev: modifier::TzAbbrev,
+) -> Option<ParsedItem<'_, ()>> {
+    Some(
+        n_to_m(3, u8::MAX, |input| match input {
+            [c, remaining @ ..] if c.is_ascii_alphanumeric() || *c == b'+' || *c == b'-' => {
+                Some(ParsedItem(remaining, ()))
+            }
+            _ => None,
+        })(input)?
+        .map(|_| ()),
+    )
+}
+
+/// Parse the "name" component of a `TimeZone`. Only names in the embedded time zone database are
+/// recognized.
+#[cfg(feature = "tzdb-embedded")]
+pub(crate) fn parse_tz_name(
+    input: &[u8],
+    modifier::TzName: modifier::TzName,
+) -> Option<ParsedItem<'_, &'static str>> {
+    let name = crate::time_zone::tzdb::longest_name_prefix(input)?;
+    Some(ParsedItem(&input[name.len()..], name))
+}
+// endregion time zone components
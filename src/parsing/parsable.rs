@@ -2,13 +2,16 @@
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use core::convert::TryInto;
+use core::convert::{TryFrom, TryInto};
+use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::error::TryFromParsed;
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
 use crate::format_description::{well_known, FormatItem};
 use crate::parsing::shim::SliceStripPrefix;
 use crate::parsing::{Parsed, ParsedItem};
-use crate::{error, Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
 /// exist in generic bounds.
@@ -84,6 +87,10 @@ impl sealed::Parsable for FormatItem<'_> {
             }
             Self::Component(component) => input = parsed.parse_component(input, *component)?,
             Self::Compound(compound) => input = compound.parse_into(input, parsed)?,
+            Self::Optional(item) => input = parse_optional(*item, input, parsed),
+            Self::First(items) => input = parse_first(items, input, parsed)?,
+            #[cfg(feature = "alloc")]
+            Self::Owned(item) => input = item.parse_into(input, parsed)?,
         }
         Ok(input)
     }
@@ -113,6 +120,72 @@ impl sealed::Parsable for Vec<FormatItem<'_>> {
         self.as_slice().parse_into(input, parsed)
     }
 }
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+impl sealed::Parsable for OwnedFormatItem {
+    fn parse_into<'a>(
+        &self,
+        mut input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        match self {
+            Self::Literal(literal) => {
+                input = input
+                    .strip_prefix_(literal)
+                    .ok_or(error::ParseFromDescription::InvalidLiteral)?;
+            }
+            Self::Component(component) => input = parsed.parse_component(input, *component)?,
+            Self::Compound(compound) => {
+                for item in compound.iter() {
+                    input = item.parse_into(input, parsed)?;
+                }
+            }
+            Self::Optional(item) => input = parse_optional(&**item, input, parsed),
+            Self::First(items) => input = parse_first(items, input, parsed)?,
+        }
+        Ok(input)
+    }
+}
+
+/// Parse an item that may not be present. If the item fails to parse, neither the input nor the
+/// [`Parsed`] struct is modified.
+fn parse_optional<'a>(
+    item: &impl sealed::Parsable,
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> &'a [u8] {
+    let mut optional_parsed = *parsed;
+    item.parse_into(input, &mut optional_parsed)
+        .map_or(input, |remaining| {
+            *parsed = optional_parsed;
+            remaining
+        })
+}
+
+/// Parse the first of the items that parses successfully. If none do, the error from the first
+/// item is returned.
+fn parse_first<'a>(
+    items: &[impl sealed::Parsable],
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Parse> {
+    let mut first_err = None;
+
+    for item in items {
+        let mut first_parsed = *parsed;
+        match item.parse_into(input, &mut first_parsed) {
+            Ok(remaining) => {
+                *parsed = first_parsed;
+                return Ok(remaining);
+            }
+            Err(err) if first_err.is_none() => first_err = Some(err),
+            Err(_) => {}
+        }
+    }
+
+    first_err.map_or(Ok(input), Err)
+}
 // endregion custom formats
 
 // region: well-known formats
@@ -135,6 +208,7 @@ impl sealed::Parsable for well_known::Rfc3339 {
             .assign_value_to_with(&mut parsed.year, |year: u32| year as i32);
         let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
         let input = exactly_n_digits(2)(input)
+            .and_then(|item| item.flat_map(|month: u8| Month::try_from(month).ok()))
             .ok_or(InvalidComponent("month"))?
             .assign_value_to(&mut parsed.month);
         let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
@@ -213,7 +287,8 @@ impl sealed::Parsable for well_known::Rfc3339 {
         let ParsedItem(input, year) = exactly_n_digits(4)(input).ok_or(InvalidComponent("year"))?;
         let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
         let ParsedItem(input, month) =
-            exactly_n_digits(2)(input).ok_or(InvalidComponent("month"))?;
+            exactly_n_digits::<u8>(2)(input).ok_or(InvalidComponent("month"))?;
+        let month = Month::try_from(month).map_err(TryFromParsed::ComponentRange)?;
         let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
         let ParsedItem(input, day) = exactly_n_digits(2)(input).ok_or(InvalidComponent("day"))?;
         let input = ascii_char_ignore_case(b'T')(input)
@@ -282,4 +357,593 @@ impl sealed::Parsable for well_known::Rfc3339 {
             .assume_offset(offset))
     }
 }
+
+impl sealed::Parsable for well_known::Rfc2822 {
+    #[allow(clippy::too_many_lines)]
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::{
+            any_digit, ascii_char, cfws, exactly_n_digits, first_match_ignore_case, n_to_m,
+            n_to_m_digits, sign,
+        };
+
+        let colon = ascii_char(b':');
+        let comma = ascii_char(b',');
+
+        let input = cfws(input).unwrap();
+        let input = match first_match_ignore_case(&[
+            ("Mon", Weekday::Monday),
+            ("Tue", Weekday::Tuesday),
+            ("Wed", Weekday::Wednesday),
+            ("Thu", Weekday::Thursday),
+            ("Fri", Weekday::Friday),
+            ("Sat", Weekday::Saturday),
+            ("Sun", Weekday::Sunday),
+        ])(input)
+        {
+            Some(item) => {
+                let input = cfws(item.assign_value_to(&mut parsed.weekday)).unwrap();
+                let input = comma(input).ok_or(InvalidLiteral)?.unwrap();
+                cfws(input).unwrap()
+            }
+            None => input,
+        };
+        let input = n_to_m_digits(1, 2)(input)
+            .and_then(|item| item.flat_map(NonZeroU8::new))
+            .ok_or(InvalidComponent("day"))?
+            .assign_value_to(&mut parsed.day);
+        let input = cfws(input).unwrap();
+        let input = first_match_ignore_case(&[
+            ("Jan", Month::January),
+            ("Feb", Month::February),
+            ("Mar", Month::March),
+            ("Apr", Month::April),
+            ("May", Month::May),
+            ("Jun", Month::June),
+            ("Jul", Month::July),
+            ("Aug", Month::August),
+            ("Sep", Month::September),
+            ("Oct", Month::October),
+            ("Nov", Month::November),
+            ("Dec", Month::December),
+        ])(input)
+        .ok_or(InvalidComponent("month"))?
+        .assign_value_to(&mut parsed.month);
+        let input = cfws(input).unwrap();
+        let input = n_to_m(2, 6, any_digit)(input)
+            .and_then(|item| {
+                item.flat_map(|digits| {
+                    let year = digits
+                        .iter()
+                        .fold(0, |year, &digit| year * 10 + (digit - b'0') as i32);
+                    // Two and three-digit years are permitted by the obsolete syntax.
+                    match digits.len() {
+                        2 if year < 50 => Some(year + 2000),
+                        2 | 3 => Some(year + 1900),
+                        _ if year >= 1900 => Some(year),
+                        _ => None,
+                    }
+                })
+            })
+            .ok_or(InvalidComponent("year"))?
+            .assign_value_to(&mut parsed.year);
+        let input = cfws(input).unwrap();
+        let input = exactly_n_digits(2)(input)
+            .ok_or(InvalidComponent("hour"))?
+            .assign_value_to(&mut parsed.hour_24);
+        let input = cfws(input).unwrap();
+        let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+        let input = cfws(input).unwrap();
+        let input = exactly_n_digits(2)(input)
+            .ok_or(InvalidComponent("minute"))?
+            .assign_value_to(&mut parsed.minute);
+        let input = cfws(input).unwrap();
+        let input = if let Some(ParsedItem(input, ())) = colon(input) {
+            let input = cfws(input).unwrap();
+            exactly_n_digits(2)(input)
+                .ok_or(InvalidComponent("second"))?
+                .assign_value_to_with(&mut parsed.second, |second| {
+                    // Leap seconds are not supported, so treat it as the previous second.
+                    if second == 60 { 59 } else { second }
+                })
+        } else {
+            parsed.second = Some(0);
+            input
+        };
+        parsed.subsecond = Some(0);
+        let input = cfws(input).unwrap();
+
+        let input = if let Some(ParsedItem(input, offset_sign)) = sign(input) {
+            let ParsedItem(input, offset) =
+                exactly_n_digits::<u16>(4)(input).ok_or(InvalidComponent("offset_hour"))?;
+            let offset_hour = (offset / 100) as i8;
+            let offset_minute = (offset % 100) as u8;
+            if offset_minute >= 60 {
+                return Err(InvalidComponent("offset_minute").into());
+            }
+            parsed.offset_hour = Some(if offset_sign == b'-' {
+                -offset_hour
+            } else {
+                offset_hour
+            });
+            parsed.offset_minute = Some(offset_minute);
+            input
+        } else {
+            let len = input
+                .iter()
+                .take_while(|byte| byte.is_ascii_alphabetic())
+                .count();
+            let (zone, input) = input.split_at(len);
+            let named_zones: [(&[u8], i8); 10] = [
+                (b"UT", 0),
+                (b"GMT", 0),
+                (b"EDT", -4),
+                (b"EST", -5),
+                (b"CDT", -5),
+                (b"CST", -6),
+                (b"MDT", -6),
+                (b"MST", -7),
+                (b"PDT", -7),
+                (b"PST", -8),
+            ];
+            let named_zone = named_zones
+                .iter()
+                .find(|(name, _)| zone.eq_ignore_ascii_case(name));
+            parsed.offset_hour = Some(match (named_zone, zone) {
+                (Some(&(_, offset_hour)), _) => offset_hour,
+                // Military zones other than `Z` were incorrectly defined in RFC 822, so their
+                // meaning is unknown.
+                (None, [zone]) if !zone.eq_ignore_ascii_case(&b'J') => 0,
+                _ => return Err(InvalidComponent("offset_hour").into()),
+            });
+            parsed.offset_minute = Some(0);
+            input
+        };
+        parsed.offset_second = Some(0);
+
+        Ok(cfws(input).unwrap())
+    }
+
+    fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
+        let parsed = self.parse(input)?;
+        let date = parsed.try_into()?;
+        check_weekday(parsed, date)?;
+        Ok(date)
+    }
+
+    fn parse_date_time(&self, input: &[u8]) -> Result<PrimitiveDateTime, error::Parse> {
+        let parsed = self.parse(input)?;
+        let date_time: PrimitiveDateTime = parsed.try_into()?;
+        check_weekday(parsed, date_time.date())?;
+        Ok(date_time)
+    }
+
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        let parsed = self.parse(input)?;
+        let date_time: OffsetDateTime = parsed.try_into()?;
+        check_weekday(parsed, date_time.date())?;
+        Ok(date_time)
+    }
+}
+
+/// Ensure that the parsed weekday, if any, matches the date.
+fn check_weekday(parsed: Parsed, date: Date) -> Result<(), error::Parse> {
+    match parsed.weekday {
+        Some(weekday) if weekday != date.weekday() => {
+            Err(error::ParseFromDescription::InvalidComponent("weekday").into())
+        }
+        _ => Ok(()),
+    }
+}
+
+impl sealed::Parsable for well_known::HttpDate {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::format_description::modifier;
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits};
+        use crate::parsing::component::{parse_month, parse_weekday};
+
+        let space = ascii_char(b' ');
+        let dash = ascii_char(b'-');
+        let month = |input| {
+            parse_month(
+                input,
+                modifier::Month {
+                    padding: modifier::Padding::Zero,
+                    repr: modifier::MonthRepr::Short,
+                },
+            )
+            .ok_or(InvalidComponent("month"))
+        };
+        let weekday = |input, repr| {
+            parse_weekday(
+                input,
+                modifier::Weekday {
+                    repr,
+                    one_indexed: true,
+                },
+            )
+        };
+        let day = |input| {
+            exactly_n_digits(2)(input)
+                .and_then(|item| item.flat_map(NonZeroU8::new))
+                .ok_or(InvalidComponent("day"))
+        };
+        let gmt = |input: &'a [u8]| input.strip_prefix_(b" GMT").ok_or(InvalidLiteral);
+
+        // The RFC 850 format is the only one using the full weekday name.
+        if let Some(item) = weekday(input, modifier::WeekdayRepr::Long) {
+            let input = item.assign_value_to(&mut parsed.weekday);
+            let input = input.strip_prefix_(b", ").ok_or(InvalidLiteral)?;
+            let input = day(input)?.assign_value_to(&mut parsed.day);
+            let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
+            let input = month(input)?.assign_value_to(&mut parsed.month);
+            let input = dash(input).ok_or(InvalidLiteral)?.unwrap();
+            let input = exactly_n_digits(2)(input)
+                .ok_or(InvalidComponent("year"))?
+                .assign_value_to_with(&mut parsed.year, http_two_digit_year);
+            let input = space(input).ok_or(InvalidLiteral)?.unwrap();
+            let input = parse_http_time(input, parsed)?;
+            return Ok(gmt(input)?);
+        }
+
+        let input = weekday(input, modifier::WeekdayRepr::Short)
+            .ok_or(InvalidComponent("weekday"))?
+            .assign_value_to(&mut parsed.weekday);
+
+        // The asctime format has a space directly after the weekday, while IMF-fixdate has a comma.
+        let input = if let Some(ParsedItem(input, ())) = space(input) {
+            let input = month(input)?.assign_value_to(&mut parsed.month);
+            let input = space(input).ok_or(InvalidLiteral)?.unwrap();
+            let input = match space(input) {
+                Some(ParsedItem(input, ())) => exactly_n_digits(1)(input)
+                    .and_then(|item| item.flat_map(NonZeroU8::new))
+                    .ok_or(InvalidComponent("day"))?,
+                None => day(input)?,
+            }
+            .assign_value_to(&mut parsed.day);
+            let input = space(input).ok_or(InvalidLiteral)?.unwrap();
+            let input = parse_http_time(input, parsed)?;
+            let input = space(input).ok_or(InvalidLiteral)?.unwrap();
+            exactly_n_digits(4)(input)
+                .ok_or(InvalidComponent("year"))?
+                .assign_value_to_with(&mut parsed.year, |year: u32| year as i32)
+        } else {
+            let input = input.strip_prefix_(b", ").ok_or(InvalidLiteral)?;
+            let input = day(input)?.assign_value_to(&mut parsed.day);
+            let input = space(input).ok_or(InvalidLiteral)?.unwrap();
+            let input = month(input)?.assign_value_to(&mut parsed.month);
+            let input = space(input).ok_or(InvalidLiteral)?.unwrap();
+            let input = exactly_n_digits(4)(input)
+                .ok_or(InvalidComponent("year"))?
+                .assign_value_to_with(&mut parsed.year, |year: u32| year as i32);
+            let input = space(input).ok_or(InvalidLiteral)?.unwrap();
+            let input = parse_http_time(input, parsed)?;
+            gmt(input)?
+        };
+
+        Ok(input)
+    }
+
+    fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
+        let parsed = self.parse(input)?;
+        let date = parsed.try_into()?;
+        check_weekday(parsed, date)?;
+        Ok(date)
+    }
+
+    fn parse_date_time(&self, input: &[u8]) -> Result<PrimitiveDateTime, error::Parse> {
+        let parsed = self.parse(input)?;
+        let date_time: PrimitiveDateTime = parsed.try_into()?;
+        check_weekday(parsed, date_time.date())?;
+        Ok(date_time)
+    }
+
+    fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
+        let parsed = self.parse(input)?;
+        let date_time: OffsetDateTime = parsed.try_into()?;
+        check_weekday(parsed, date_time.date())?;
+        Ok(date_time)
+    }
+}
+
+/// Parse the `hh:mm:ss` time of an HTTP date. As all HTTP dates are in UTC, the offset is also
+/// set.
+fn parse_http_time<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::ParseFromDescription> {
+    use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+    use crate::parsing::combinator::{ascii_char, exactly_n_digits};
+
+    let colon = ascii_char(b':');
+
+    let input = exactly_n_digits(2)(input)
+        .ok_or(InvalidComponent("hour"))?
+        .assign_value_to(&mut parsed.hour_24);
+    let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+    let input = exactly_n_digits(2)(input)
+        .ok_or(InvalidComponent("minute"))?
+        .assign_value_to(&mut parsed.minute);
+    let input = colon(input).ok_or(InvalidLiteral)?.unwrap();
+    let input = exactly_n_digits(2)(input)
+        .ok_or(InvalidComponent("second"))?
+        .assign_value_to_with(&mut parsed.second, |second| {
+            // Leap seconds are not supported, so treat it as the previous second.
+            if second == 60 { 59 } else { second }
+        });
+    parsed.subsecond = Some(0);
+    parsed.offset_hour = Some(0);
+    parsed.offset_minute = Some(0);
+    parsed.offset_second = Some(0);
+
+    Ok(input)
+}
+
+/// Determine the full year of a two-digit year in the RFC 850 format. A year that appears to be
+/// more than 50 years in the future is placed in the previous century.
+#[cfg(feature = "std")]
+fn http_two_digit_year(year: u8) -> i32 {
+    let current_year = OffsetDateTime::now_utc().year();
+    let year = current_year - current_year.rem_euclid(100) + year as i32;
+    if year > current_year + 50 {
+        year - 100
+    } else {
+        year
+    }
+}
+
+/// Determine the full year of a two-digit year in the RFC 850 format. As the current year is not
+/// known, years 00 through 49 are placed in the 2000s and the rest in the 1900s.
+#[cfg(not(feature = "std"))]
+const fn http_two_digit_year(year: u8) -> i32 {
+    if year < 50 {
+        year as i32 + 2000
+    } else {
+        year as i32 + 1900
+    }
+}
+
+impl sealed::Parsable for well_known::Iso8601 {
+    fn parse_into<'a>(
+        &self,
+        mut input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::InvalidLiteral;
+        use crate::parsing::combinator::{ascii_char_ignore_case, opt};
+
+        let (parse_date, parse_time, parse_offset) = self.config.formatted_components.components();
+
+        if parse_date {
+            input = parse_iso8601_date(input, parsed)?;
+        }
+        if parse_time {
+            // The designator is only optional when the time is the first component.
+            input = if parse_date {
+                ascii_char_ignore_case(b'T')(input)
+                    .ok_or(InvalidLiteral)?
+                    .unwrap()
+            } else {
+                opt(ascii_char_ignore_case(b'T'))(input).0
+            };
+            input = parse_iso8601_time(input, parsed)?;
+        }
+        if parse_offset {
+            input = parse_iso8601_offset(input, parsed)?;
+        }
+
+        Ok(input)
+    }
+}
+
+/// Parse the date portion of an ISO 8601 value in any of the calendar, week, or ordinal forms.
+fn parse_iso8601_date<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::ParseFromDescription> {
+    use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+    use crate::parsing::combinator::{
+        ascii_char, ascii_char_ignore_case, exactly_n_digits, opt, sign,
+    };
+
+    let dash = ascii_char(b'-');
+
+    let ParsedItem(input, year) = match sign(input) {
+        Some(ParsedItem(input, year_sign)) => exactly_n_digits::<u32>(6)(input)
+            .ok_or(InvalidComponent("year"))?
+            .map(|year| {
+                if year_sign == b'-' {
+                    -(year as i32)
+                } else {
+                    year as i32
+                }
+            }),
+        None => exactly_n_digits::<u32>(4)(input)
+            .ok_or(InvalidComponent("year"))?
+            .map(|year| year as i32),
+    };
+    let ParsedItem(input, extended) = opt(&dash)(input).map(|dash| dash.is_some());
+
+    if let Some(ParsedItem(input, ())) = ascii_char_ignore_case(b'W')(input) {
+        parsed.iso_year = Some(year);
+        let input = exactly_n_digits(2)(input)
+            .and_then(|item| item.flat_map(NonZeroU8::new))
+            .ok_or(InvalidComponent("week"))?
+            .assign_value_to(&mut parsed.iso_week_number);
+        let input = if extended {
+            dash(input).ok_or(InvalidLiteral)?.unwrap()
+        } else {
+            input
+        };
+        return Ok(exactly_n_digits(1)(input)
+            .and_then(|item| {
+                item.flat_map(|weekday: u8| match weekday {
+                    1..=7 => Some(Weekday::Sunday.nth_next(weekday)),
+                    _ => None,
+                })
+            })
+            .ok_or(InvalidComponent("weekday"))?
+            .assign_value_to(&mut parsed.weekday));
+    }
+
+    parsed.year = Some(year);
+    // The calendar and ordinal forms can only be distinguished by the number of digits.
+    match (
+        extended,
+        input.iter().take_while(|c| c.is_ascii_digit()).count(),
+    ) {
+        (_, 3) => Ok(exactly_n_digits(3)(input)
+            .and_then(|item| item.flat_map(NonZeroU16::new))
+            .ok_or(InvalidComponent("ordinal"))?
+            .assign_value_to(&mut parsed.ordinal)),
+        (true, 2) | (false, 4) => {
+            let input = exactly_n_digits(2)(input)
+                .and_then(|item| item.flat_map(|month: u8| Month::try_from(month).ok()))
+                .ok_or(InvalidComponent("month"))?
+                .assign_value_to(&mut parsed.month);
+            let input = if extended {
+                dash(input).ok_or(InvalidLiteral)?.unwrap()
+            } else {
+                input
+            };
+            Ok(exactly_n_digits(2)(input)
+                .and_then(|item| item.flat_map(NonZeroU8::new))
+                .ok_or(InvalidComponent("day"))?
+                .assign_value_to(&mut parsed.day))
+        }
+        _ => Err(InvalidComponent("month")),
+    }
+}
+
+/// Parse the time portion of an ISO 8601 value, with any precision and an optional decimal
+/// fraction on the smallest unit.
+fn parse_iso8601_time<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::ParseFromDescription> {
+    use crate::error::ParseFromDescription::InvalidComponent;
+    use crate::parsing::combinator::{any_digit, ascii_char, exactly_n_digits, opt};
+
+    let ParsedItem(input, hour) =
+        exactly_n_digits::<u8>(2)(input).ok_or(InvalidComponent("hour"))?;
+    let ParsedItem(input, extended) = opt(ascii_char(b':'))(input).map(|colon| colon.is_some());
+    let separator = |input| {
+        if extended {
+            ascii_char(b':')(input)
+        } else {
+            Some(ParsedItem(input, ()))
+        }
+    };
+
+    let (input, minute) = match exactly_n_digits::<u8>(2)(input) {
+        Some(ParsedItem(input, minute)) => (input, Some(minute)),
+        None if extended => return Err(InvalidComponent("minute")),
+        None => (input, None),
+    };
+    let (input, second) = match minute.and_then(|_| separator(input)) {
+        Some(ParsedItem(after_separator, ())) => match exactly_n_digits::<u8>(2)(after_separator) {
+            // As in RFC 3339, a leap second is treated as the previous second.
+            Some(ParsedItem(input, second)) => {
+                (input, Some(if second == 60 { 59 } else { second }))
+            }
+            None if extended => return Err(InvalidComponent("second")),
+            None => (input, None),
+        },
+        None => (input, None),
+    };
+
+    // The fraction is stored as the number of nanoseconds in a second-long unit, regardless of
+    // the actual unit it applies to. Digits beyond the ninth are ignored.
+    let (input, fraction) = match input {
+        [b'.', remaining @ ..] | [b',', remaining @ ..] => {
+            let ParsedItem(mut input, mut value) = any_digit(remaining)
+                .ok_or(InvalidComponent("subsecond"))?
+                .map(|v| (v - b'0') as u64 * 100_000_000);
+
+            let mut multiplier = 10_000_000;
+            while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
+                value += (digit - b'0') as u64 * multiplier;
+                input = new_input;
+                multiplier /= 10;
+            }
+
+            (input, value)
+        }
+        _ => (input, 0),
+    };
+
+    let (minute, second, nanosecond) = match (minute, second) {
+        (None, _) => {
+            let nanoseconds = fraction * 3_600;
+            (
+                (nanoseconds / 60_000_000_000) as u8,
+                (nanoseconds / 1_000_000_000 % 60) as u8,
+                (nanoseconds % 1_000_000_000) as u32,
+            )
+        }
+        (Some(minute), None) => {
+            let nanoseconds = fraction * 60;
+            (
+                minute,
+                (nanoseconds / 1_000_000_000) as u8,
+                (nanoseconds % 1_000_000_000) as u32,
+            )
+        }
+        (Some(minute), Some(second)) => (minute, second, fraction as u32),
+    };
+
+    parsed.hour_24 = Some(hour);
+    parsed.minute = Some(minute);
+    parsed.second = Some(second);
+    parsed.subsecond = Some(nanosecond);
+
+    Ok(input)
+}
+
+/// Parse the offset portion of an ISO 8601 value, which may be `Z`, `±hh`, `±hhmm`, or `±hh:mm`.
+fn parse_iso8601_offset<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::ParseFromDescription> {
+    use crate::error::ParseFromDescription::InvalidComponent;
+    use crate::parsing::combinator::{
+        ascii_char, ascii_char_ignore_case, exactly_n_digits, opt, sign,
+    };
+
+    parsed.offset_second = Some(0);
+
+    if let Some(ParsedItem(input, ())) = ascii_char_ignore_case(b'Z')(input) {
+        parsed.offset_hour = Some(0);
+        parsed.offset_minute = Some(0);
+        return Ok(input);
+    }
+
+    let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset_hour"))?;
+    let input = exactly_n_digits(2)(input)
+        .ok_or(InvalidComponent("offset_hour"))?
+        .assign_value_to_with(&mut parsed.offset_hour, |offset_hour: u8| {
+            if offset_sign == b'-' {
+                -(offset_hour as i8)
+            } else {
+                offset_hour as _
+            }
+        });
+
+    match ascii_char(b':')(input) {
+        Some(ParsedItem(input, ())) => Ok(exactly_n_digits(2)(input)
+            .ok_or(InvalidComponent("offset_minute"))?
+            .assign_value_to(&mut parsed.offset_minute)),
+        None => Ok(opt(exactly_n_digits(2))(input)
+            .assign_value_to_with(&mut parsed.offset_minute, |minute| minute.unwrap_or(0))),
+    }
+}
 // endregion well-known formats
@@ -6,13 +6,20 @@ use core::num::{NonZeroU16, NonZeroU8};
 use crate::error::TryFromParsed::InsufficientInformation;
 use crate::format_description::modifier::{WeekNumberRepr, YearRepr};
 use crate::format_description::Component;
+#[cfg(feature = "tzdb-embedded")]
+use crate::parsing::component::parse_tz_name;
 use crate::parsing::component::{
-    parse_day, parse_hour, parse_minute, parse_month, parse_offset_hour, parse_offset_minute,
-    parse_offset_second, parse_ordinal, parse_period, parse_second, parse_subsecond,
-    parse_week_number, parse_weekday, parse_year, Period,
+    parse_day, parse_fiscal_year, parse_hour, parse_minute, parse_month, parse_offset_hour,
+    parse_offset_minute, parse_offset_second, parse_ordinal, parse_period, parse_quarter,
+    parse_second, parse_subsecond, parse_tz_abbrev, parse_week_number, parse_weekday, parse_year,
+    Period,
 };
 use crate::parsing::ParsedItem;
-use crate::{error, Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+#[cfg(feature = "tzdb-embedded")]
+use crate::TimeZone;
+use crate::{
+    error, Date, FiscalCalendar, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
 
 /// All information parsed.
 ///
@@ -31,8 +38,18 @@ pub struct Parsed {
     pub iso_year: Option<i32>,
     /// The last two digits of the ISO week year.
     pub iso_year_last_two: Option<u8>,
+    /// Fiscal year, as determined by [`fiscal_calendar`](Self::fiscal_calendar).
+    pub fiscal_year: Option<i32>,
+    /// The last two digits of the fiscal year.
+    pub fiscal_year_last_two: Option<u8>,
+    /// The calendar of the fiscal year. If not present, the calendar year is used.
+    pub fiscal_calendar: Option<FiscalCalendar>,
+    /// Quarter of the year, as determined by [`quarter_calendar`](Self::quarter_calendar).
+    pub quarter: Option<NonZeroU8>,
+    /// The calendar whose quarters are used. If not present, calendar quarters are used.
+    pub quarter_calendar: Option<FiscalCalendar>,
     /// Month of the year.
-    pub month: Option<NonZeroU8>,
+    pub month: Option<Month>,
     /// Week of the year, where week one begins on the first Sunday of the calendar year.
     pub sunday_week_number: Option<u8>,
     /// Week of the year, where week one begins on the first Monday of the calendar year.
@@ -64,6 +81,10 @@ pub struct Parsed {
     pub offset_minute: Option<u8>,
     /// Seconds within the minute of the UTC offset.
     pub offset_second: Option<u8>,
+    /// IANA name of the time zone, as found in the embedded time zone database.
+    #[cfg(feature = "tzdb-embedded")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "tzdb-embedded")))]
+    pub time_zone_name: Option<&'static str>,
 }
 
 impl Parsed {
@@ -74,6 +95,11 @@ impl Parsed {
             year_last_two: None,
             iso_year: None,
             iso_year_last_two: None,
+            fiscal_year: None,
+            fiscal_year_last_two: None,
+            fiscal_calendar: None,
+            quarter: None,
+            quarter_calendar: None,
             month: None,
             sunday_week_number: None,
             monday_week_number: None,
@@ -90,6 +116,8 @@ impl Parsed {
             offset_hour: None,
             offset_minute: None,
             offset_second: None,
+            #[cfg(feature = "tzdb-embedded")]
+            time_zone_name: None,
         }
     }
 
@@ -139,6 +167,23 @@ impl Parsed {
                 }
                 Ok(remaining)
             }
+            Component::Quarter(modifiers) => {
+                let remaining = parse_quarter(input, modifiers)
+                    .ok_or(InvalidComponent("quarter"))?
+                    .assign_value_to(&mut self.quarter);
+                self.quarter_calendar = Some(modifiers.calendar);
+                Ok(remaining)
+            }
+            Component::FiscalYear(modifiers) => {
+                let ParsedItem(remaining, value) =
+                    parse_fiscal_year(input, modifiers).ok_or(InvalidComponent("fiscal year"))?;
+                match modifiers.repr {
+                    YearRepr::Full => self.fiscal_year = Some(value),
+                    YearRepr::LastTwo => self.fiscal_year_last_two = Some(value as u8),
+                }
+                self.fiscal_calendar = Some(modifiers.calendar);
+                Ok(remaining)
+            }
             Component::Hour(modifiers) => {
                 let ParsedItem(remaining, value) =
                     parse_hour(input, modifiers).ok_or(InvalidComponent("hour"))?;
@@ -171,6 +216,15 @@ impl Parsed {
             Component::OffsetSecond(modifiers) => Ok(parse_offset_second(input, modifiers)
                 .ok_or(InvalidComponent("offset second"))?
                 .assign_value_to(&mut self.offset_second)),
+            Component::TzAbbrev(modifiers) => Ok(parse_tz_abbrev(input, modifiers)
+                .ok_or(InvalidComponent("time zone abbreviation"))?
+                .unwrap()),
+            #[cfg(feature = "tzdb-embedded")]
+            Component::TzName(modifiers) => Ok(parse_tz_name(input, modifiers)
+                .ok_or(InvalidComponent("time zone name"))?
+                .assign_value_to(&mut self.time_zone_name)),
+            #[cfg(not(feature = "tzdb-embedded"))]
+            Component::TzName(_) => Err(InvalidComponent("time zone name")),
         }
     }
 }
@@ -199,31 +253,65 @@ impl TryFrom<Parsed> for Date {
             }
         }
 
+        /// Ensure that the value parsed for a component, if any, is the one determined by the
+        /// date.
+        const fn check_component(
+            name: &'static str,
+            parsed: Option<i32>,
+            actual: i32,
+        ) -> Result<(), error::ComponentRange> {
+            match parsed {
+                Some(value) if value != actual => Err(error::ComponentRange {
+                    name,
+                    minimum: actual as _,
+                    maximum: actual as _,
+                    value: value as _,
+                    conditional_range: true,
+                }),
+                _ => Ok(()),
+            }
+        }
+
         // TODO Only the basics have been covered. There are many other valid values that are not
         // currently constructed from the information known.
 
-        match parsed {
-            items!(year, ordinal) => Ok(Self::from_ordinal_date(year, ordinal.get())?),
-            items!(year, month, day) => Ok(Self::from_calendar_date(year, month.get(), day.get())?),
-            items!(iso_year, iso_week_number, weekday) => Ok(Self::from_iso_week_date(
-                iso_year,
-                iso_week_number.get(),
-                weekday,
-            )?),
-            items!(year, sunday_week_number, weekday) => Ok(Self::from_ordinal_date(
+        let date = match parsed {
+            items!(year, ordinal) => Self::from_ordinal_date(year, ordinal.get())?,
+            items!(year, month, day) => Self::from_calendar_date(year, month, day.get())?,
+            items!(iso_year, iso_week_number, weekday) => {
+                Self::from_iso_week_date(iso_year, iso_week_number.get(), weekday)?
+            }
+            items!(year, sunday_week_number, weekday) => Self::from_ordinal_date(
                 year,
                 (sunday_week_number as i16 * 7 + weekday.number_days_from_sunday() as i16
                     - adjustment(year)
                     + 1) as u16,
-            )?),
-            items!(year, monday_week_number, weekday) => Ok(Self::from_ordinal_date(
+            )?,
+            items!(year, monday_week_number, weekday) => Self::from_ordinal_date(
                 year,
                 (monday_week_number as i16 * 7 + weekday.number_days_from_monday() as i16
                     - adjustment(year)
                     + 1) as u16,
-            )?),
-            _ => Err(InsufficientInformation),
-        }
+            )?,
+            _ => return Err(InsufficientInformation),
+        };
+
+        // The fiscal year and quarter do not determine the date, but must agree with it.
+        let fiscal_year = parsed.fiscal_calendar.unwrap_or_default().year(date);
+        check_component("fiscal_year", parsed.fiscal_year, fiscal_year)?;
+        check_component(
+            "fiscal_year_last_two",
+            parsed.fiscal_year_last_two.map(i32::from),
+            fiscal_year.rem_euclid(100),
+        )?;
+        let quarter = parsed.quarter_calendar.unwrap_or_default().quarter(date);
+        check_component(
+            "quarter",
+            parsed.quarter.map(|quarter| quarter.get().into()),
+            quarter.into(),
+        )?;
+
+        Ok(date)
     }
 }
 
@@ -272,3 +360,16 @@ impl TryFrom<Parsed> for OffsetDateTime {
         Ok(PrimitiveDateTime::try_from(parsed)?.assume_offset(parsed.try_into()?))
     }
 }
+
+#[cfg(feature = "tzdb-embedded")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "tzdb-embedded")))]
+impl TryFrom<Parsed> for TimeZone {
+    type Error = error::TryFromParsed;
+
+    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+        parsed
+            .time_zone_name
+            .and_then(Self::get)
+            .ok_or(InsufficientInformation)
+    }
+}
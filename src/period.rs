@@ -0,0 +1,192 @@
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A calendar-based span of time, composed of a number of years, months, and days.
+///
+/// Unlike a [`Duration`](crate::Duration), a `Period` does not have a fixed length. A month may be
+/// anywhere from 28 to 31 days and a year either 365 or 366 days, so the length is only known once
+/// the `Period` is applied to a date. When doing so, the years and months are added first, with the
+/// day of the month clamped to the end of the resulting month if necessary. The days are added
+/// afterwards.
+///
+/// The components are not normalized automatically, so `Period::new(0, 12, 0)` is not equal to
+/// `Period::new(1, 0, 0)`. Use [`Period::normalized`] to carry excess months into years.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Period {
+    /// Number of years.
+    years: i32,
+    /// Number of months.
+    months: i32,
+    /// Number of days.
+    days: i32,
+}
+
+impl Period {
+    /// A `Period` with all components zero.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// assert_eq!(Period::ZERO, Period::new(0, 0, 0));
+    /// ```
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    /// Create a new `Period` with the provided years, months, and days. The components may have
+    /// differing signs.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// let period = Period::new(1, 2, 3);
+    /// assert_eq!(period.years(), 1);
+    /// assert_eq!(period.months(), 2);
+    /// assert_eq!(period.days(), 3);
+    /// ```
+    pub const fn new(years: i32, months: i32, days: i32) -> Self {
+        Self {
+            years,
+            months,
+            days,
+        }
+    }
+
+    /// Get the number of years.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// assert_eq!(Period::new(1, 2, 3).years(), 1);
+    /// ```
+    pub const fn years(self) -> i32 {
+        self.years
+    }
+
+    /// Get the number of months. This is not necessarily less than twelve.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// assert_eq!(Period::new(1, 2, 3).months(), 2);
+    /// assert_eq!(Period::new(0, 14, 0).months(), 14);
+    /// ```
+    pub const fn months(self) -> i32 {
+        self.months
+    }
+
+    /// Get the number of days.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// assert_eq!(Period::new(1, 2, 3).days(), 3);
+    /// ```
+    pub const fn days(self) -> i32 {
+        self.days
+    }
+
+    /// Check if all components of the `Period` are zero.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// assert!(Period::ZERO.is_zero());
+    /// assert!(!Period::new(0, 0, 1).is_zero());
+    /// ```
+    pub const fn is_zero(self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+
+    /// Get the total number of months in the years and months components.
+    pub(crate) const fn total_months(self) -> i64 {
+        self.years as i64 * 12 + self.months as i64
+    }
+
+    /// Carry whole years out of the months component, so that the months are between -11 and 11
+    /// inclusive and have the same sign as the years. The days are left unchanged, as the number of
+    /// days in a month varies.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// assert_eq!(Period::new(1, 14, 40).normalized(), Period::new(2, 2, 40));
+    /// assert_eq!(Period::new(1, -3, 0).normalized(), Period::new(0, 9, 0));
+    /// assert_eq!(Period::new(-1, -14, 0).normalized(), Period::new(-2, -2, 0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This may panic if the number of years overflows.
+    #[must_use = "This method does not mutate the original `Period`."]
+    pub fn normalized(self) -> Self {
+        let total_months = self.total_months();
+        Self::new(
+            i32::try_from(total_months / 12).expect("overflow normalizing period"),
+            (total_months % 12) as _,
+            self.days,
+        )
+    }
+}
+
+// region: trait impls
+/// The `Period` is displayed using the ISO 8601 duration format, such as `P1Y2M3D`. Components
+/// that are zero are omitted, unless all of them are.
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("P0D");
+        }
+
+        f.write_str("P")?;
+        if self.years != 0 {
+            write!(f, "{}Y", self.years)?;
+        }
+        if self.months != 0 {
+            write!(f, "{}M", self.months)?;
+        }
+        if self.days != 0 {
+            write!(f, "{}D", self.days)?;
+        }
+        Ok(())
+    }
+}
+
+impl Add for Period {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let add = |a: i32, b: i32| a.checked_add(b).expect("overflow when adding periods");
+        Self::new(
+            add(self.years, rhs.years),
+            add(self.months, rhs.months),
+            add(self.days, rhs.days),
+        )
+    }
+}
+
+impl AddAssign for Period {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Neg for Period {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.years, -self.months, -self.days)
+    }
+}
+
+impl Sub for Period {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let sub = |a: i32, b: i32| a.checked_sub(b).expect("overflow when subtracting periods");
+        Self::new(
+            sub(self.years, rhs.years),
+            sub(self.months, rhs.months),
+            sub(self.days, rhs.days),
+        )
+    }
+}
+
+impl SubAssign for Period {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+// endregion trait impls
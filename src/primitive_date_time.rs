@@ -5,13 +5,19 @@ use core::time::Duration as StdDuration;
 #[cfg(feature = "formatting")]
 use std::io;
 
-#[cfg(feature = "parsing")]
-use crate::error;
 #[cfg(feature = "formatting")]
 use crate::formatting::formattable::sealed::Formattable;
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
-use crate::{util, Date, Duration, OffsetDateTime, Time, UtcOffset, Weekday};
+use crate::range::DateTimeStep;
+#[cfg(feature = "alloc")]
+use crate::time_zone::{LocalOffsets, LocalResult};
+use crate::{
+    error, util, Date, DayOverflow, Duration, Month, OffsetDateTime, Period, Time, Unit, UtcOffset,
+    Weekday,
+};
+#[cfg(feature = "alloc")]
+use crate::{TimeZone, ZonedDateTime};
 
 /// Combined date and time.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -23,6 +29,19 @@ pub struct PrimitiveDateTime {
 }
 
 impl PrimitiveDateTime {
+    /// The minimum valid `PrimitiveDateTime`.
+    ///
+    /// The value of this may vary depending on the feature flags enabled.
+    pub const MIN: Self = Self::new(Date::MIN, Time::MIDNIGHT);
+
+    /// The maximum valid `PrimitiveDateTime`.
+    ///
+    /// The value of this may vary depending on the feature flags enabled.
+    pub const MAX: Self = Self::new(
+        Date::MAX,
+        Time::__from_hms_nanos_unchecked(23, 59, 59, 999_999_999),
+    );
+
     /// Create a new `PrimitiveDateTime` from the provided [`Date`] and [`Time`].
     ///
     /// ```rust
@@ -72,14 +91,12 @@ impl PrimitiveDateTime {
 
     /// Get the month of the date.
     ///
-    /// The returned value will always be in the range `1..=12`.
-    ///
     /// ```rust
-    /// # use time::macros::datetime;
-    /// assert_eq!(datetime!("2019-01-01 0:00").month(), 1);
-    /// assert_eq!(datetime!("2019-12-31 0:00").month(), 12);
+    /// # use time::{macros::datetime, Month};
+    /// assert_eq!(datetime!("2019-01-01 0:00").month(), Month::January);
+    /// assert_eq!(datetime!("2019-12-31 0:00").month(), Month::December);
     /// ```
-    pub const fn month(self) -> u8 {
+    pub const fn month(self) -> Month {
         self.date.month()
     }
 
@@ -96,6 +113,19 @@ impl PrimitiveDateTime {
         self.date.day()
     }
 
+    /// Get the quarter of the year, where the first quarter begins in January.
+    ///
+    /// The returned value will always be in the range `1..=4`.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(datetime!("2019-01-01 0:00").quarter(), 1);
+    /// assert_eq!(datetime!("2019-12-31 0:00").quarter(), 4);
+    /// ```
+    pub const fn quarter(self) -> u8 {
+        self.date.quarter()
+    }
+
     /// Get the day of the year.
     ///
     /// The returned value will always be in the range `1..=366` (`1..=365` for common years).
@@ -158,13 +188,13 @@ impl PrimitiveDateTime {
     /// Get the year, month, and day.
     ///
     /// ```rust
-    /// # use time::macros::datetime;
+    /// # use time::{macros::datetime, Month};
     /// assert_eq!(
     ///     datetime!("2019-01-01 0:00").to_calendar_date(),
-    ///     (2019, 1, 1)
+    ///     (2019, Month::January, 1)
     /// );
     /// ```
-    pub const fn to_calendar_date(self) -> (i32, u8, u8) {
+    pub const fn to_calendar_date(self) -> (i32, Month, u8) {
         self.date.to_calendar_date()
     }
 
@@ -426,6 +456,51 @@ impl PrimitiveDateTime {
             offset: UtcOffset::UTC,
         }
     }
+
+    /// Assuming that the existing `PrimitiveDateTime` is a local datetime in the provided
+    /// [`TimeZone`], return the [`ZonedDateTime`] it refers to.
+    ///
+    /// Around a transition, a local datetime may occur twice or not at all. The returned
+    /// [`LocalResult`] indicates which is the case, so that the caller can decide how to resolve
+    /// it.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::{LocalResult, PosixTz}, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// assert_eq!(
+    ///     datetime!("2021-07-01 12:00").assume_timezone(&new_york),
+    ///     LocalResult::Single(datetime!("2021-07-01 12:00 -4").to_zoned(&new_york)),
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2021-03-14 2:30").assume_timezone(&new_york),
+    ///     LocalResult::Gap {
+    ///         before: datetime!("2021-03-14 1:30 -5").to_zoned(&new_york),
+    ///         after: datetime!("2021-03-14 3:30 -4").to_zoned(&new_york),
+    ///     },
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+    pub fn assume_timezone(self, time_zone: &TimeZone) -> LocalResult<ZonedDateTime<'_>> {
+        let zoned = |offset| ZonedDateTime {
+            datetime: self.assume_offset(offset),
+            time_zone,
+        };
+
+        match time_zone.local_offsets(self) {
+            LocalOffsets::Single(offset) => LocalResult::Single(zoned(offset)),
+            LocalOffsets::Ambiguous(earlier, later) => {
+                LocalResult::Ambiguous(zoned(earlier), zoned(later))
+            }
+            // Interpreting the datetime with the offset after the gap yields a moment before the
+            // gap, and vice versa.
+            LocalOffsets::Gap(before, after) => LocalResult::Gap {
+                before: self.assume_offset(after).to_zoned(time_zone),
+                after: self.assume_offset(before).to_zoned(time_zone),
+            },
+        }
+    }
     // endregion attach offset
 }
 
@@ -459,9 +534,674 @@ impl PrimitiveDateTime {
     pub const fn replace_date(self, date: Date) -> Self {
         date.with_time(self.time)
     }
+
+    /// Replace the year. The month and day will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 12:00").replace_year(2019),
+    ///     Ok(datetime!("2019-02-18 12:00"))
+    /// );
+    /// // -1_000_000_000 isn't a valid year.
+    /// assert!(datetime!("2022-02-18 12:00").replace_year(-1_000_000_000).is_err());
+    /// ```
+    pub const fn replace_year(self, year: i32) -> Result<Self, error::ComponentRange> {
+        Ok(const_try!(self.date.replace_year(year)).with_time(self.time))
+    }
+
+    /// Replace the month of the year. The year and day will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Month};
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 12:00").replace_month(Month::January),
+    ///     Ok(datetime!("2022-01-18 12:00"))
+    /// );
+    /// // February 30 doesn't exist.
+    /// assert!(datetime!("2022-01-30 12:00").replace_month(Month::February).is_err());
+    /// ```
+    pub const fn replace_month(self, month: Month) -> Result<Self, error::ComponentRange> {
+        Ok(const_try!(self.date.replace_month(month)).with_time(self.time))
+    }
+
+    /// Replace the day of the month. The year and month will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 12:00").replace_day(1),
+    ///     Ok(datetime!("2022-02-01 12:00"))
+    /// );
+    /// // February 30 doesn't exist.
+    /// assert!(datetime!("2022-02-18 12:00").replace_day(30).is_err());
+    /// ```
+    pub const fn replace_day(self, day: u8) -> Result<Self, error::ComponentRange> {
+        Ok(const_try!(self.date.replace_day(day)).with_time(self.time))
+    }
+
+    /// Replace the day of the year. The year will be unchanged.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-049 12:00").replace_ordinal(1),
+    ///     Ok(datetime!("2022-001 12:00"))
+    /// );
+    /// // 2022 isn't a leap year.
+    /// assert!(datetime!("2022-049 12:00").replace_ordinal(366).is_err());
+    /// ```
+    pub const fn replace_ordinal(self, ordinal: u16) -> Result<Self, error::ComponentRange> {
+        Ok(const_try!(self.date.replace_ordinal(ordinal)).with_time(self.time))
+    }
+
+    /// Replace the clock hour.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004").replace_hour(7),
+    ///     Ok(datetime!("2022-02-18 7:02:03.004"))
+    /// );
+    /// // 24 isn't a valid hour.
+    /// assert!(datetime!("2022-02-18 1:02:03.004").replace_hour(24).is_err());
+    /// ```
+    pub const fn replace_hour(self, hour: u8) -> Result<Self, error::ComponentRange> {
+        Ok(self
+            .date
+            .with_time(const_try!(self.time.replace_hour(hour))))
+    }
+
+    /// Replace the minutes within the hour.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004").replace_minute(7),
+    ///     Ok(datetime!("2022-02-18 1:07:03.004"))
+    /// );
+    /// // 60 isn't a valid minute.
+    /// assert!(datetime!("2022-02-18 1:02:03.004").replace_minute(60).is_err());
+    /// ```
+    pub const fn replace_minute(self, minute: u8) -> Result<Self, error::ComponentRange> {
+        Ok(self
+            .date
+            .with_time(const_try!(self.time.replace_minute(minute))))
+    }
+
+    /// Replace the seconds within the minute.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004").replace_second(7),
+    ///     Ok(datetime!("2022-02-18 1:02:07.004"))
+    /// );
+    /// // 60 isn't a valid second.
+    /// assert!(datetime!("2022-02-18 1:02:03.004").replace_second(60).is_err());
+    /// ```
+    pub const fn replace_second(self, second: u8) -> Result<Self, error::ComponentRange> {
+        Ok(self
+            .date
+            .with_time(const_try!(self.time.replace_second(second))))
+    }
+
+    /// Replace the milliseconds within the second. Any sub-millisecond component is discarded.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004_005").replace_millisecond(7),
+    ///     Ok(datetime!("2022-02-18 1:02:03.007"))
+    /// );
+    /// // 1_000 isn't a valid millisecond.
+    /// assert!(datetime!("2022-02-18 1:02:03.004").replace_millisecond(1_000).is_err());
+    /// ```
+    pub const fn replace_millisecond(
+        self,
+        millisecond: u16,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(self
+            .date
+            .with_time(const_try!(self.time.replace_millisecond(millisecond))))
+    }
+
+    /// Replace the microseconds within the second. Any sub-microsecond component is discarded.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004_005_006").replace_microsecond(7_008),
+    ///     Ok(datetime!("2022-02-18 1:02:03.007_008"))
+    /// );
+    /// // 1_000_000 isn't a valid microsecond.
+    /// assert!(datetime!("2022-02-18 1:02:03.004").replace_microsecond(1_000_000).is_err());
+    /// ```
+    pub const fn replace_microsecond(
+        self,
+        microsecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        Ok(self
+            .date
+            .with_time(const_try!(self.time.replace_microsecond(microsecond))))
+    }
+
+    /// Replace the nanoseconds within the second.
+    ///
+    /// ```rust
+    /// # use time::macros::datetime;
+    /// assert_eq!(
+    ///     datetime!("2022-02-18 1:02:03.004_005_006").replace_nanosecond(7_008_009),
+    ///     Ok(datetime!("2022-02-18 1:02:03.007_008_009"))
+    /// );
+    /// // 1_000_000_000 isn't a valid nanosecond.
+    /// assert!(datetime!("2022-02-18 1:02:03.004").replace_nanosecond(1_000_000_000).is_err());
+    /// ```
+    pub const fn replace_nanosecond(self, nanosecond: u32) -> Result<Self, error::ComponentRange> {
+        Ok(self
+            .date
+            .with_time(const_try!(self.time.replace_nanosecond(nanosecond))))
+    }
 }
 // endregion replacement
 
+// region: calendar arithmetic
+/// Methods that move a `PrimitiveDateTime` by calendar months and years.
+impl PrimitiveDateTime {
+    /// Add the provided number of months to the date, preserving the day of the month and the
+    /// time. See [`Date::checked_add_months`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, DayOverflow};
+    /// assert_eq!(
+    ///     datetime!("2021-01-31 12:00").checked_add_months(1, DayOverflow::Clamp),
+    ///     Some(datetime!("2021-02-28 12:00"))
+    /// );
+    /// ```
+    pub const fn checked_add_months(self, months: i32, overflow: DayOverflow) -> Option<Self> {
+        Some(Self::new(
+            const_try_opt!(self.date.checked_add_months(months, overflow)),
+            self.time,
+        ))
+    }
+
+    /// Subtract the provided number of months from the date, preserving the day of the month and
+    /// the time. See [`Date::checked_sub_months`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, DayOverflow};
+    /// assert_eq!(
+    ///     datetime!("2021-03-31 12:00").checked_sub_months(1, DayOverflow::Rollover),
+    ///     Some(datetime!("2021-03-03 12:00"))
+    /// );
+    /// ```
+    pub const fn checked_sub_months(self, months: i32, overflow: DayOverflow) -> Option<Self> {
+        Some(Self::new(
+            const_try_opt!(self.date.checked_sub_months(months, overflow)),
+            self.time,
+        ))
+    }
+
+    /// Add the provided number of years to the date, preserving the month, day, and time. See
+    /// [`Date::checked_add_years`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, DayOverflow};
+    /// assert_eq!(
+    ///     datetime!("2020-02-29 12:00").checked_add_years(1, DayOverflow::Clamp),
+    ///     Some(datetime!("2021-02-28 12:00"))
+    /// );
+    /// ```
+    pub const fn checked_add_years(self, years: i32, overflow: DayOverflow) -> Option<Self> {
+        Some(Self::new(
+            const_try_opt!(self.date.checked_add_years(years, overflow)),
+            self.time,
+        ))
+    }
+
+    /// Subtract the provided number of years from the date, preserving the month, day, and time.
+    /// See [`Date::checked_sub_years`] for details.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, DayOverflow};
+    /// assert_eq!(
+    ///     datetime!("2020-02-29 12:00").checked_sub_years(1, DayOverflow::Error),
+    ///     None
+    /// );
+    /// ```
+    pub const fn checked_sub_years(self, years: i32, overflow: DayOverflow) -> Option<Self> {
+        Some(Self::new(
+            const_try_opt!(self.date.checked_sub_years(years, overflow)),
+            self.time,
+        ))
+    }
+}
+// endregion calendar arithmetic
+
+// region: checked and saturating arithmetic
+/// Methods that add or subtract a duration without panicking.
+impl PrimitiveDateTime {
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalDuration, macros::datetime};
+    /// assert_eq!(PrimitiveDateTime::MAX.checked_add(1.nanoseconds()), None);
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30").checked_add(27.hours()),
+    ///     Some(datetime!("2019-11-26 18:30"))
+    /// );
+    /// ```
+    pub const fn checked_add(self, duration: Duration) -> Option<Self> {
+        let (date_adjustment, time) = self.time.adjusting_add(duration);
+        let date = const_try_opt!(self.date.checked_add(duration));
+
+        Some(Self {
+            date: match date_adjustment {
+                util::DateAdjustment::Previous => const_try_opt!(date.previous_day()),
+                util::DateAdjustment::Next => const_try_opt!(date.next_day()),
+                util::DateAdjustment::None => date,
+            },
+            time,
+        })
+    }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalStdDuration, macros::datetime};
+    /// assert_eq!(PrimitiveDateTime::MAX.checked_add_std(1.std_nanoseconds()), None);
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30").checked_add_std(27.std_hours()),
+    ///     Some(datetime!("2019-11-26 18:30"))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        let (is_next_day, time) = self.time.adjusting_add_std(duration);
+        let date = const_try_opt!(self.date.checked_add_std(duration));
+
+        Some(Self {
+            date: if is_next_day {
+                const_try_opt!(date.next_day())
+            } else {
+                date
+            },
+            time,
+        })
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalDuration, macros::datetime};
+    /// assert_eq!(PrimitiveDateTime::MIN.checked_sub(1.nanoseconds()), None);
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30").checked_sub(27.hours()),
+    ///     Some(datetime!("2019-11-24 12:30"))
+    /// );
+    /// ```
+    pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
+        // The minimum duration cannot be negated, but subtracting it overflows regardless.
+        if duration.whole_seconds() == i64::MIN {
+            return None;
+        }
+        self.checked_add(Duration::new_unchecked(
+            -duration.whole_seconds(),
+            -duration.subsec_nanoseconds(),
+        ))
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalStdDuration, macros::datetime};
+    /// assert_eq!(PrimitiveDateTime::MIN.checked_sub_std(1.std_nanoseconds()), None);
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30").checked_sub_std(27.std_hours()),
+    ///     Some(datetime!("2019-11-24 12:30"))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        let (is_previous_day, time) = self.time.adjusting_sub_std(duration);
+        let date = const_try_opt!(self.date.checked_sub_std(duration));
+
+        Some(Self {
+            date: if is_previous_day {
+                const_try_opt!(date.previous_day())
+            } else {
+                date
+            },
+            time,
+        })
+    }
+
+    /// Computes `self + duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalDuration, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::MAX.saturating_add(1.nanoseconds()),
+    ///     PrimitiveDateTime::MAX
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::MIN.saturating_add((-1).nanoseconds()),
+    ///     PrimitiveDateTime::MIN
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30").saturating_add(27.hours()),
+    ///     datetime!("2019-11-26 18:30")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `PrimitiveDateTime`."]
+    pub const fn saturating_add(self, duration: Duration) -> Self {
+        match self.checked_add(duration) {
+            Some(datetime) => datetime,
+            None if duration.is_negative() => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+
+    /// Computes `self + duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalStdDuration, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::MAX.saturating_add_std(1.std_nanoseconds()),
+    ///     PrimitiveDateTime::MAX
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30").saturating_add_std(27.std_hours()),
+    ///     datetime!("2019-11-26 18:30")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `PrimitiveDateTime`."]
+    pub const fn saturating_add_std(self, duration: StdDuration) -> Self {
+        match self.checked_add_std(duration) {
+            Some(datetime) => datetime,
+            None => Self::MAX,
+        }
+    }
+
+    /// Computes `self - duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalDuration, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::MIN.saturating_sub(1.nanoseconds()),
+    ///     PrimitiveDateTime::MIN
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::MAX.saturating_sub((-1).nanoseconds()),
+    ///     PrimitiveDateTime::MAX
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30").saturating_sub(27.hours()),
+    ///     datetime!("2019-11-24 12:30")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `PrimitiveDateTime`."]
+    pub const fn saturating_sub(self, duration: Duration) -> Self {
+        match self.checked_sub(duration) {
+            Some(datetime) => datetime,
+            None if duration.is_negative() => Self::MAX,
+            None => Self::MIN,
+        }
+    }
+
+    /// Computes `self - duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, ext::NumericalStdDuration, macros::datetime};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::MIN.saturating_sub_std(1.std_nanoseconds()),
+    ///     PrimitiveDateTime::MIN
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2019-11-25 15:30").saturating_sub_std(27.std_hours()),
+    ///     datetime!("2019-11-24 12:30")
+    /// );
+    /// ```
+    #[must_use = "This method does not mutate the original `PrimitiveDateTime`."]
+    pub const fn saturating_sub_std(self, duration: StdDuration) -> Self {
+        match self.checked_sub_std(duration) {
+            Some(datetime) => datetime,
+            None => Self::MIN,
+        }
+    }
+}
+// endregion checked and saturating arithmetic
+
+// region: ranges
+/// Methods for iterating over ranges of `PrimitiveDateTime`s.
+impl PrimitiveDateTime {
+    /// Create an iterator from `start` up to but not including `end`, advancing by `step` each
+    /// time. If `end` is not after `start`, the iterator is empty.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Duration, PrimitiveDateTime};
+    /// let mut datetimes = PrimitiveDateTime::range_step_by(
+    ///     datetime!("2021-03-14 0:00"),
+    ///     datetime!("2021-03-14 12:00"),
+    ///     Duration::hours(5),
+    /// );
+    /// assert_eq!(datetimes.next(), Some(datetime!("2021-03-14 0:00")));
+    /// assert_eq!(datetimes.next(), Some(datetime!("2021-03-14 5:00")));
+    /// assert_eq!(datetimes.next(), Some(datetime!("2021-03-14 10:00")));
+    /// assert_eq!(datetimes.next(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn range_step_by(start: Self, end: Self, step: Duration) -> DateTimeStep<Self> {
+        DateTimeStep::<Self>::new(start, end, step)
+    }
+}
+// endregion ranges
+
+// region: rounding
+/// Methods that truncate or round the `PrimitiveDateTime` to a [`Unit`] or a multiple of a
+/// [`Duration`].
+impl PrimitiveDateTime {
+    /// Truncate the `PrimitiveDateTime` to the start of the provided unit, returning `None` if the
+    /// result is out of range.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Unit};
+    /// let datetime = datetime!("2021-08-19 13:45:30.5"); // a Thursday
+    /// assert_eq!(datetime.truncate(Unit::Hour), Some(datetime!("2021-08-19 13:00")));
+    /// assert_eq!(datetime.truncate(Unit::IsoWeek), Some(datetime!("2021-08-16 0:00")));
+    /// assert_eq!(datetime.truncate(Unit::SundayWeek), Some(datetime!("2021-08-15 0:00")));
+    /// assert_eq!(datetime.truncate(Unit::Quarter), Some(datetime!("2021-07-01 0:00")));
+    /// assert_eq!(datetime.truncate(Unit::Year), Some(datetime!("2021-01-01 0:00")));
+    /// ```
+    pub const fn truncate(self, unit: Unit) -> Option<Self> {
+        if unit.is_time() {
+            return Some(self.date.with_time(self.time.truncate(unit)));
+        }
+
+        let year = self.date.year();
+        let date = match unit {
+            Unit::IsoWeek => const_try_opt!(Date::backward_checked(
+                self.date,
+                self.date.weekday().number_days_from_monday() as _
+            )),
+            Unit::SundayWeek => const_try_opt!(Date::backward_checked(
+                self.date,
+                self.date.weekday().number_days_from_sunday() as _
+            )),
+            Unit::Month => Date::__from_ordinal_date_unchecked(
+                year,
+                self.date.ordinal() - self.date.day() as u16 + 1,
+            ),
+            Unit::Quarter => self.date.quarter_start(),
+            Unit::Year => Date::__from_ordinal_date_unchecked(year, 1),
+            _ => self.date,
+        };
+        Some(date.midnight())
+    }
+
+    /// Round the `PrimitiveDateTime` up to the start of the next unit, returning `None` if the
+    /// result is out of range. Values already at the start of a unit are returned unchanged.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Unit};
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:45:30.5").ceil(Unit::Minute),
+    ///     Some(datetime!("2021-08-19 13:46"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:45:30.5").ceil(Unit::Month),
+    ///     Some(datetime!("2021-09-01 0:00"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2021-08-01 0:00").ceil(Unit::Month),
+    ///     Some(datetime!("2021-08-01 0:00"))
+    /// );
+    /// ```
+    pub fn ceil(self, unit: Unit) -> Option<Self> {
+        let floor = self.truncate(unit)?;
+        if floor == self {
+            Some(self)
+        } else {
+            floor.checked_add_unit(unit)
+        }
+    }
+
+    /// Round the `PrimitiveDateTime` to the nearest start of a unit, returning `None` if the
+    /// result is out of range. Values exactly halfway between two units are rounded up.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Unit};
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:45:30.5").round(Unit::Hour),
+    ///     Some(datetime!("2021-08-19 14:00"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:45:30.5").round(Unit::Month),
+    ///     Some(datetime!("2021-09-01 0:00"))
+    /// );
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:45:30.5").round(Unit::Year),
+    ///     Some(datetime!("2022-01-01 0:00"))
+    /// );
+    /// ```
+    pub fn round(self, unit: Unit) -> Option<Self> {
+        let floor = self.truncate(unit)?;
+        if (self - floor) * 2 >= floor.unit_length(unit) {
+            floor.checked_add_unit(unit)
+        } else {
+            Some(floor)
+        }
+    }
+
+    /// Truncate the `PrimitiveDateTime` to a multiple of `step` after or before `origin`,
+    /// returning `None` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Duration};
+    /// let origin = datetime!("2021-01-01 0:00");
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:44").truncate_to_multiple(Duration::minutes(15), origin),
+    ///     Some(datetime!("2021-08-19 13:30"))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn truncate_to_multiple(self, step: Duration, origin: Self) -> Option<Self> {
+        assert!(step.is_positive(), "step must be positive");
+        self.checked_sub((self - origin).rem_euclid(step))
+    }
+
+    /// Round the `PrimitiveDateTime` up to a multiple of `step` after or before `origin`,
+    /// returning `None` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Duration};
+    /// let origin = datetime!("2021-01-01 0:00");
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:31").ceil_to_multiple(Duration::minutes(15), origin),
+    ///     Some(datetime!("2021-08-19 13:45"))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn ceil_to_multiple(self, step: Duration, origin: Self) -> Option<Self> {
+        assert!(step.is_positive(), "step must be positive");
+        let remainder = (self - origin).rem_euclid(step);
+        if remainder.is_zero() {
+            Some(self)
+        } else {
+            self.checked_add(step - remainder)
+        }
+    }
+
+    /// Round the `PrimitiveDateTime` to the nearest multiple of `step` after or before `origin`,
+    /// returning `None` if the result is out of range. Values exactly halfway between two
+    /// multiples are rounded up.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, Duration};
+    /// let origin = datetime!("2021-01-01 0:00");
+    /// assert_eq!(
+    ///     datetime!("2021-08-19 13:37:30").round_to_multiple(Duration::minutes(15), origin),
+    ///     Some(datetime!("2021-08-19 13:45"))
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `step` is not positive.
+    pub fn round_to_multiple(self, step: Duration, origin: Self) -> Option<Self> {
+        assert!(step.is_positive(), "step must be positive");
+        let remainder = (self - origin).rem_euclid(step);
+        if remainder >= step - remainder {
+            self.checked_add(step - remainder)
+        } else {
+            self.checked_sub(remainder)
+        }
+    }
+
+    /// Get the length of the unit beginning at `self`, which must be the start of a unit.
+    const fn unit_length(self, unit: Unit) -> Duration {
+        if let Some(duration) = unit.fixed_duration() {
+            return duration;
+        }
+
+        let year = self.date.year();
+        let mut month = self.date.month();
+        let mut months = match unit {
+            Unit::Month => 1,
+            Unit::Quarter => 3,
+            _ => 12,
+        };
+        let mut days = 0;
+        while months > 0 {
+            days += month.length(year) as i64;
+            month = month.next();
+            months -= 1;
+        }
+        Duration::days(days)
+    }
+
+    /// Add one of the provided unit to `self`, which must be the start of a unit.
+    const fn checked_add_unit(self, unit: Unit) -> Option<Self> {
+        match unit {
+            Unit::Month => self.checked_add_months(1, DayOverflow::Clamp),
+            Unit::Quarter => self.checked_add_months(3, DayOverflow::Clamp),
+            Unit::Year => self.checked_add_months(12, DayOverflow::Clamp),
+            _ => match unit.fixed_duration() {
+                Some(duration) => self.checked_add(duration),
+                None => None,
+            },
+        }
+    }
+}
+// endregion rounding
+
 // region: offset conversion helpers
 /// Helper methods to adjust a [`PrimitiveDateTime`] to a given [`UtcOffset`].
 impl PrimitiveDateTime {
@@ -513,7 +1253,7 @@ impl PrimitiveDateTime {
         output: &mut impl io::Write,
         format: &F,
     ) -> Result<usize, F::Error> {
-        format.format_into(output, Some(self.date), Some(self.time), None)
+        format.format_into(output, Some(self.date), Some(self.time), None, None)
     }
 
     /// Format the `PrimitiveDateTime` using the provided format description. The format description
@@ -530,7 +1270,7 @@ impl PrimitiveDateTime {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
-        format.format(Some(self.date), Some(self.time), None)
+        format.format(Some(self.date), Some(self.time), None, None)
     }
 }
 
@@ -569,21 +1309,8 @@ impl Add<Duration> for PrimitiveDateTime {
     type Output = Self;
 
     fn add(self, duration: Duration) -> Self::Output {
-        let (date_adjustment, time) = self.time.adjusting_add(duration);
-        let date = self.date + duration;
-
-        Self {
-            date: match date_adjustment {
-                util::DateAdjustment::Previous => date
-                    .previous_day()
-                    .expect("resulting value is out of range"),
-                util::DateAdjustment::Next => {
-                    date.next_day().expect("resulting value is out of range")
-                }
-                util::DateAdjustment::None => date,
-            },
-            time,
-        }
+        self.checked_add(duration)
+            .expect("resulting value is out of range")
     }
 }
 
@@ -591,18 +1318,8 @@ impl Add<StdDuration> for PrimitiveDateTime {
     type Output = Self;
 
     fn add(self, duration: StdDuration) -> Self::Output {
-        let (is_next_day, time) = self.time.adjusting_add_std(duration);
-
-        Self {
-            date: if is_next_day {
-                (self.date + duration)
-                    .next_day()
-                    .expect("resulting value is out of range")
-            } else {
-                self.date + duration
-            },
-            time,
-        }
+        self.checked_add_std(duration)
+            .expect("resulting value is out of range")
     }
 }
 
@@ -622,7 +1339,8 @@ impl Sub<Duration> for PrimitiveDateTime {
     type Output = Self;
 
     fn sub(self, duration: Duration) -> Self::Output {
-        self + -duration
+        self.checked_sub(duration)
+            .expect("resulting value is out of range")
     }
 }
 
@@ -630,18 +1348,8 @@ impl Sub<StdDuration> for PrimitiveDateTime {
     type Output = Self;
 
     fn sub(self, duration: StdDuration) -> Self::Output {
-        let (is_previous_day, time) = self.time.adjusting_sub_std(duration);
-
-        Self {
-            date: if is_previous_day {
-                (self.date - duration)
-                    .previous_day()
-                    .expect("resulting value is out of range")
-            } else {
-                self.date - duration
-            },
-            time,
-        }
+        self.checked_sub_std(duration)
+            .expect("resulting value is out of range")
     }
 }
 
@@ -657,6 +1365,34 @@ impl SubAssign<StdDuration> for PrimitiveDateTime {
     }
 }
 
+impl Add<Period> for PrimitiveDateTime {
+    type Output = Self;
+
+    fn add(self, period: Period) -> Self::Output {
+        Self::new(self.date + period, self.time)
+    }
+}
+
+impl AddAssign<Period> for PrimitiveDateTime {
+    fn add_assign(&mut self, period: Period) {
+        *self = *self + period;
+    }
+}
+
+impl Sub<Period> for PrimitiveDateTime {
+    type Output = Self;
+
+    fn sub(self, period: Period) -> Self::Output {
+        Self::new(self.date - period, self.time)
+    }
+}
+
+impl SubAssign<Period> for PrimitiveDateTime {
+    fn sub_assign(&mut self, period: Period) {
+        *self = *self - period;
+    }
+}
+
 impl Sub<PrimitiveDateTime> for PrimitiveDateTime {
     type Output = Duration;
 
@@ -3,7 +3,9 @@
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 
-use crate::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use crate::{
+    Date, Duration, Month, OffsetDateTime, Period, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
 
 impl Distribution<Time> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Time {
@@ -77,3 +79,30 @@ impl Distribution<Weekday> for Standard {
         }
     }
 }
+
+impl Distribution<Month> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Month {
+        use Month::*;
+
+        match rng.gen_range(1..=12) {
+            1 => January,
+            2 => February,
+            3 => March,
+            4 => April,
+            5 => May,
+            6 => June,
+            7 => July,
+            8 => August,
+            9 => September,
+            10 => October,
+            11 => November,
+            _ => December,
+        }
+    }
+}
+
+impl Distribution<Period> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Period {
+        Period::new(Self.sample(rng), Self.sample(rng), Self.sample(rng))
+    }
+}
@@ -0,0 +1,427 @@
+//! Iterators over ranges of dates and datetimes.
+//!
+//! A [`DateRange`] is created with [`Date::range`] and yields every date in the range. It can be
+//! turned into a [`DateStep`] to yield only every nth day, week, or month. Ranges of
+//! [`PrimitiveDateTime`]s and [`OffsetDateTime`]s are stepped through by a fixed [`Duration`] using
+//! [`PrimitiveDateTime::range_step_by`] and [`OffsetDateTime::range_step_by`].
+//!
+//! All ranges are half-open: the start is included, while the end is not.
+
+use core::iter::FusedIterator;
+
+use crate::{Date, DayOverflow, Duration, OffsetDateTime, PrimitiveDateTime};
+
+// region: DateRange
+/// An iterator over every date in a half-open range, created by [`Date::range`].
+///
+/// ```rust
+/// # use time::{macros::date, Date};
+/// let dates: Vec<_> = Date::range(date!("2021-12-30"), date!("2022-01-02")).collect();
+/// assert_eq!(
+///     dates,
+///     [date!("2021-12-30"), date!("2021-12-31"), date!("2022-01-01")]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    /// The next date to be yielded from the front.
+    start: Date,
+    /// The date after the last one to be yielded from the back.
+    end: Date,
+}
+
+impl DateRange {
+    /// Create a new `DateRange`. If `end` is not after `start`, the range is empty.
+    pub(crate) const fn new(start: Date, end: Date) -> Self {
+        Self { start, end }
+    }
+
+    /// Get the first date remaining in the range, which is included.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// let range = Date::range(date!("2021-01-01"), date!("2021-02-01"));
+    /// assert_eq!(range.start(), date!("2021-01-01"));
+    /// ```
+    pub const fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Get the end of the range, which is excluded.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// let range = Date::range(date!("2021-01-01"), date!("2021-02-01"));
+    /// assert_eq!(range.end(), date!("2021-02-01"));
+    /// ```
+    pub const fn end(&self) -> Date {
+        self.end
+    }
+
+    /// Check if the provided date is remaining in the range.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// let range = Date::range(date!("2021-01-01"), date!("2021-02-01"));
+    /// assert!(range.contains(date!("2021-01-01")));
+    /// assert!(range.contains(date!("2021-01-31")));
+    /// assert!(!range.contains(date!("2021-02-01")));
+    /// ```
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date < self.end
+    }
+
+    /// Step through the range by the provided number of days. The first date of the range is
+    /// always yielded, if the range is not empty.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// let mut dates = Date::range(date!("2021-01-01"), date!("2021-01-10")).step_by_days(3);
+    /// assert_eq!(dates.next(), Some(date!("2021-01-01")));
+    /// assert_eq!(dates.next(), Some(date!("2021-01-04")));
+    /// assert_eq!(dates.next(), Some(date!("2021-01-07")));
+    /// assert_eq!(dates.next(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `days` is zero.
+    pub fn step_by_days(self, days: u32) -> DateStep {
+        assert!(days != 0, "cannot step by zero days");
+        let len = self.len() as u64;
+        DateStep {
+            start: self.start,
+            step: Step::Days(days),
+            front: 0,
+            back: len.div_ceil(days as u64) as u32,
+        }
+    }
+
+    /// Step through the range by the provided number of weeks. The first date of the range is
+    /// always yielded, if the range is not empty.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// let mut dates = Date::range(date!("2021-01-01"), date!("2021-01-16")).step_by_weeks(1);
+    /// assert_eq!(dates.next(), Some(date!("2021-01-01")));
+    /// assert_eq!(dates.next(), Some(date!("2021-01-08")));
+    /// assert_eq!(dates.next(), Some(date!("2021-01-15")));
+    /// assert_eq!(dates.next(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `weeks` is zero or the number of days in that many weeks overflows.
+    pub fn step_by_weeks(self, weeks: u32) -> DateStep {
+        self.step_by_days(
+            weeks
+                .checked_mul(7)
+                .expect("overflow converting weeks to days"),
+        )
+    }
+
+    /// Step through the range by the provided number of months. The first date of the range is
+    /// always yielded, if the range is not empty.
+    ///
+    /// Each date is computed from the start of the range, rather than from the previous date. If
+    /// the day of the month does not exist in a given month, the last day of that month is used
+    /// instead.
+    ///
+    /// ```rust
+    /// # use time::{macros::date, Date};
+    /// let mut dates = Date::range(date!("2021-01-31"), date!("2021-05-01")).step_by_months(1);
+    /// assert_eq!(dates.next(), Some(date!("2021-01-31")));
+    /// assert_eq!(dates.next(), Some(date!("2021-02-28")));
+    /// assert_eq!(dates.next(), Some(date!("2021-03-31")));
+    /// assert_eq!(dates.next(), Some(date!("2021-04-30")));
+    /// assert_eq!(dates.next(), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `months` is zero.
+    pub fn step_by_months(self, months: u32) -> DateStep {
+        assert!(months != 0, "cannot step by zero months");
+        let mut step = DateStep {
+            start: self.start,
+            step: Step::Months(months),
+            front: 0,
+            back: 0,
+        };
+
+        if self.start < self.end {
+            let (start_year, start_month, _) = self.start.to_calendar_date();
+            let (end_year, end_month, _) = self.end.to_calendar_date();
+            let total_months = (end_year as i64 - start_year as i64) * 12
+                + (end_month as i64 - start_month as i64);
+
+            // Every step before the month containing the end of the range is in range. A step
+            // landing in that month may or may not be.
+            let last = (total_months / months as i64) as u32;
+            step.back = last + 1;
+            if step.nth_date(last) >= self.end {
+                step.back = last;
+            }
+        }
+
+        step
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match Date::forward_checked(self.start, n) {
+            Some(date) if date < self.end => {
+                self.start = Date::forward_checked(date, 1).unwrap_or(self.end);
+                Some(date)
+            }
+            _ => {
+                self.start = self.end;
+                None
+            }
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn min(mut self) -> Option<Self::Item> {
+        self.next()
+    }
+
+    fn max(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for DateRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match Date::backward_checked(self.end, n.saturating_add(1)) {
+            Some(date) if date >= self.start => {
+                self.end = date;
+                Some(date)
+            }
+            _ => {
+                self.end = self.start;
+                None
+            }
+        }
+    }
+}
+
+impl ExactSizeIterator for DateRange {
+    fn len(&self) -> usize {
+        Date::steps_between(self.start, self.end).unwrap_or(0)
+    }
+}
+
+impl FusedIterator for DateRange {}
+// endregion DateRange
+
+// region: DateStep
+/// The unit a [`DateStep`] advances by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Step {
+    /// Advance by the provided number of days.
+    Days(u32),
+    /// Advance by the provided number of months, clamping the day of the month if necessary.
+    Months(u32),
+}
+
+/// An iterator over every nth day, week, or month in a half-open range of dates. This is created by
+/// [`DateRange::step_by_days`], [`DateRange::step_by_weeks`], and [`DateRange::step_by_months`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateStep {
+    /// The first date in the range, which all others are computed from.
+    start: Date,
+    /// The amount to advance by.
+    step: Step,
+    /// The index of the next date to be yielded from the front.
+    front: u32,
+    /// The index after the last date to be yielded from the back.
+    back: u32,
+}
+
+impl DateStep {
+    /// Get the date at the provided index, which must be in range.
+    fn nth_date(&self, index: u32) -> Date {
+        match self.step {
+            Step::Days(days) => Date::forward_checked(self.start, index as usize * days as usize),
+            Step::Months(months) => self
+                .start
+                .checked_add_months_wide(index as i64 * months as i64, DayOverflow::Clamp),
+        }
+        .expect("index is in range")
+    }
+}
+
+impl Iterator for DateStep {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.front = self.back;
+            return None;
+        }
+        let index = self.front + n as u32;
+        self.front = index + 1;
+        Some(self.nth_date(index))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for DateStep {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.back = self.front;
+            return None;
+        }
+        self.back -= n as u32 + 1;
+        Some(self.nth_date(self.back))
+    }
+}
+
+impl ExactSizeIterator for DateStep {
+    fn len(&self) -> usize {
+        (self.back - self.front) as usize
+    }
+}
+
+impl FusedIterator for DateStep {}
+// endregion DateStep
+
+// region: DateTimeStep
+/// An iterator over a half-open range of datetimes, advancing by a fixed [`Duration`]. This is
+/// created by [`PrimitiveDateTime::range_step_by`] and [`OffsetDateTime::range_step_by`].
+///
+/// As the number of steps may exceed `usize::MAX`, this does not implement `ExactSizeIterator`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateTimeStep<T> {
+    /// The next datetime to be yielded from the front.
+    front: T,
+    /// The next datetime to be yielded from the back.
+    back: T,
+    /// The amount to advance by.
+    step: Duration,
+    /// The number of datetimes remaining.
+    remaining: u128,
+}
+
+/// Implement the constructor and iterator traits for `DateTimeStep<$type>`.
+macro_rules! impl_date_time_step {
+    ($type:ty) => {
+        impl DateTimeStep<$type> {
+            /// Create a new `DateTimeStep`, panicking if `step` is not positive.
+            pub(crate) fn new(start: $type, end: $type, step: Duration) -> Self {
+                assert!(step.is_positive(), "step must be positive");
+                let span = (end - start).whole_nanoseconds();
+                let step_nanos = step.whole_nanoseconds();
+                let remaining = if span > 0 {
+                    ((span + step_nanos - 1) / step_nanos) as u128
+                } else {
+                    0
+                };
+                let back = if remaining == 0 {
+                    start
+                } else {
+                    start + Duration::nanoseconds_i128((remaining as i128 - 1) * step_nanos)
+                };
+
+                Self {
+                    front: start,
+                    back,
+                    step,
+                    remaining,
+                }
+            }
+        }
+
+        impl Iterator for DateTimeStep<$type> {
+            type Item = $type;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                match self.remaining {
+                    0 => None,
+                    1 => {
+                        self.remaining = 0;
+                        Some(self.front)
+                    }
+                    _ => {
+                        self.remaining -= 1;
+                        let value = self.front;
+                        self.front = value + self.step;
+                        Some(value)
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.remaining > usize::MAX as u128 {
+                    (usize::MAX, None)
+                } else {
+                    (self.remaining as usize, Some(self.remaining as usize))
+                }
+            }
+
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl DoubleEndedIterator for DateTimeStep<$type> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                match self.remaining {
+                    0 => None,
+                    1 => {
+                        self.remaining = 0;
+                        Some(self.back)
+                    }
+                    _ => {
+                        self.remaining -= 1;
+                        let value = self.back;
+                        self.back = value - self.step;
+                        Some(value)
+                    }
+                }
+            }
+        }
+
+        impl FusedIterator for DateTimeStep<$type> {}
+    };
+}
+
+impl_date_time_step!(PrimitiveDateTime);
+impl_date_time_step!(OffsetDateTime);
+// endregion DateTimeStep
@@ -0,0 +1,1354 @@
+//! Recurrence rules, as defined by [RFC 5545](https://tools.ietf.org/html/rfc5545).
+//!
+//! An [`RRule`] describes how an event repeats, such as "every other week on Tuesday and Thursday"
+//! or "the last weekday of every month". Given the start of the recurrence, it lazily yields each
+//! occurrence in ascending order. A [`RecurrenceSet`] combines the start of the recurrence with any
+//! number of rules, along with individual dates that are added (`RDATE`) or excluded (`EXDATE`).
+//!
+//! Occurrences are computed in local time. Times in UTC are treated as local times, other than the
+//! `UNTIL` part of a rule when occurrences are generated with an offset.
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::iter::{FusedIterator, Peekable};
+
+use crate::util::days_in_year;
+use crate::{
+    error, Date, DayOverflow, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
+    Weekday,
+};
+
+/// How often a recurrence rule repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Frequency {
+    #[allow(clippy::missing_docs_in_private_items)]
+    Secondly,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Minutely,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Hourly,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Daily,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Weekly,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Monthly,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Yearly,
+}
+
+impl Frequency {
+    /// The name of the frequency, as used in the `FREQ` part of a rule.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Secondly => "SECONDLY",
+            Self::Minutely => "MINUTELY",
+            Self::Hourly => "HOURLY",
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+}
+
+/// The last representable time of a day, used when `UNTIL` is a date.
+const END_OF_DAY: Time = Time::__from_hms_nanos_unchecked(23, 59, 59, 999_999_999);
+
+/// A `DATE` or `DATE-TIME` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum DateTimeValue {
+    /// A date without a time, such as `19970902`.
+    Date(Date),
+    /// A local date and time, such as `19970902T090000`.
+    Local(PrimitiveDateTime),
+    /// A date and time in UTC, such as `19970902T090000Z`.
+    Utc(PrimitiveDateTime),
+}
+
+impl DateTimeValue {
+    /// Parse a `DATE` or `DATE-TIME` value.
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.as_bytes();
+        let number = |range: core::ops::Range<usize>| {
+            let digits = s.get(range)?;
+            if digits.iter().all(u8::is_ascii_digit) {
+                Some(
+                    digits
+                        .iter()
+                        .fold(0, |value, &digit| value * 10 + (digit - b'0') as u32),
+                )
+            } else {
+                None
+            }
+        };
+
+        let date = Date::from_calendar_date(
+            number(0..4)? as _,
+            Month::try_from(number(4..6)? as u8).ok()?,
+            number(6..8)? as _,
+        )
+        .ok()?;
+        match s.len() {
+            8 => return Some(Self::Date(date)),
+            15 | 16 if s[8] == b'T' => {}
+            _ => return None,
+        }
+        let time = Time::from_hms(
+            number(9..11)? as _,
+            number(11..13)? as _,
+            number(13..15)? as _,
+        )
+        .ok()?;
+        match s.get(15) {
+            None => Some(Self::Local(PrimitiveDateTime::new(date, time))),
+            Some(b'Z') => Some(Self::Utc(PrimitiveDateTime::new(date, time))),
+            Some(_) => None,
+        }
+    }
+
+    /// The datetime of the value, treating a date as midnight.
+    const fn start(self) -> PrimitiveDateTime {
+        match self {
+            Self::Date(date) => date.midnight(),
+            Self::Local(datetime) | Self::Utc(datetime) => datetime,
+        }
+    }
+}
+
+impl fmt::Display for DateTimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let datetime = self.start();
+        write!(
+            f,
+            "{:04}{:02}{:02}",
+            datetime.year(),
+            datetime.month() as u8,
+            datetime.day()
+        )?;
+        if let Self::Local(_) | Self::Utc(_) = self {
+            write!(
+                f,
+                "T{:02}{:02}{:02}",
+                datetime.hour(),
+                datetime.minute(),
+                datetime.second()
+            )?;
+        }
+        if let Self::Utc(_) = self {
+            f.write_str("Z")?;
+        }
+        Ok(())
+    }
+}
+
+/// A recurrence rule, as defined by [RFC 5545](https://tools.ietf.org/html/rfc5545#section-3.3.10).
+///
+/// ```rust
+/// # use time::{macros::datetime, RRule};
+/// let rule = RRule::parse("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=3")?;
+/// let occurrences: Vec<_> = rule.occurrences(datetime!("2021-01-29 17:00")).collect();
+/// assert_eq!(
+///     occurrences,
+///     [
+///         datetime!("2021-01-29 17:00"),
+///         datetime!("2021-02-26 17:00"),
+///         datetime!("2021-03-31 17:00"),
+///     ]
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RRule {
+    /// How often the rule repeats.
+    frequency: Frequency,
+    /// The number of periods of the frequency between each repetition.
+    interval: u32,
+    /// The maximum number of occurrences.
+    count: Option<u32>,
+    /// The last moment at which an occurrence may occur.
+    until: Option<DateTimeValue>,
+    /// The seconds of the minute to repeat on.
+    by_second: Vec<u8>,
+    /// The minutes of the hour to repeat on.
+    by_minute: Vec<u8>,
+    /// The hours of the day to repeat on.
+    by_hour: Vec<u8>,
+    /// The weekdays to repeat on, with an optional ordinal within the month or year.
+    by_day: Vec<(Option<i8>, Weekday)>,
+    /// The days of the month to repeat on. Negative values count from the end of the month.
+    by_month_day: Vec<i8>,
+    /// The days of the year to repeat on. Negative values count from the end of the year.
+    by_year_day: Vec<i16>,
+    /// The weeks of the year to repeat on. Negative values count from the end of the year.
+    by_week_no: Vec<i8>,
+    /// The months of the year to repeat on.
+    by_month: Vec<Month>,
+    /// The occurrences within each period of the frequency to keep. Negative values count from the
+    /// end of the period.
+    by_set_pos: Vec<i16>,
+    /// The day on which the week starts.
+    week_start: Weekday,
+}
+
+impl RRule {
+    /// Parse a recurrence rule, such as `FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH`. A leading `RRULE:`
+    /// is permitted.
+    ///
+    /// ```rust
+    /// # use time::{rrule::Frequency, RRule};
+    /// let rule = RRule::parse("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH")?;
+    /// assert_eq!(rule.frequency(), Frequency::Weekly);
+    /// assert_eq!(rule.interval(), 2);
+    /// assert_eq!(rule.to_string(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::too_many_lines)]
+    pub fn parse(s: &str) -> Result<Self, error::Recurrence> {
+        let s = match s.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &s[6..],
+            _ => s,
+        };
+
+        let mut frequency = None;
+        let mut rule = Self {
+            frequency: Frequency::Yearly,
+            interval: 1,
+            count: None,
+            until: None,
+            by_second: Vec::new(),
+            by_minute: Vec::new(),
+            by_hour: Vec::new(),
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_year_day: Vec::new(),
+            by_week_no: Vec::new(),
+            by_month: Vec::new(),
+            by_set_pos: Vec::new(),
+            week_start: Weekday::Monday,
+        };
+        let mut seen_interval = false;
+        let mut seen_week_start = false;
+
+        for part in s.split(';') {
+            let mut split = part.splitn(2, '=');
+            let name = split.next().unwrap_or_default();
+            let value = split
+                .next()
+                .ok_or(error::Recurrence::InvalidRRule("missing value"))?;
+
+            match name.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    once(frequency.is_some())?;
+                    frequency = Some(parse_frequency(value)?);
+                }
+                "INTERVAL" => {
+                    once(seen_interval)?;
+                    seen_interval = true;
+                    rule.interval = match unsigned(value, u32::MAX) {
+                        Some(0) | None => {
+                            return Err(error::Recurrence::InvalidRRule("invalid INTERVAL"));
+                        }
+                        Some(interval) => interval,
+                    };
+                }
+                "COUNT" => {
+                    once(rule.count.is_some())?;
+                    rule.count = Some(
+                        unsigned(value, u32::MAX)
+                            .ok_or(error::Recurrence::InvalidRRule("invalid COUNT"))?,
+                    );
+                }
+                "UNTIL" => {
+                    once(rule.until.is_some())?;
+                    rule.until = Some(
+                        DateTimeValue::parse(value)
+                            .ok_or(error::Recurrence::InvalidRRule("invalid UNTIL"))?,
+                    );
+                }
+                "BYSECOND" => {
+                    once(!rule.by_second.is_empty())?;
+                    rule.by_second = list(value, "invalid BYSECOND", |v| {
+                        unsigned(v, 59).map(|v| v as _)
+                    })?;
+                }
+                "BYMINUTE" => {
+                    once(!rule.by_minute.is_empty())?;
+                    rule.by_minute = list(value, "invalid BYMINUTE", |v| {
+                        unsigned(v, 59).map(|v| v as _)
+                    })?;
+                }
+                "BYHOUR" => {
+                    once(!rule.by_hour.is_empty())?;
+                    rule.by_hour =
+                        list(value, "invalid BYHOUR", |v| unsigned(v, 23).map(|v| v as _))?;
+                }
+                "BYDAY" => {
+                    once(!rule.by_day.is_empty())?;
+                    rule.by_day = list(value, "invalid BYDAY", |v| {
+                        let split = v.len().checked_sub(2)?;
+                        let weekday = parse_weekday(v.get(split..)?)?;
+                        match v.get(..split)? {
+                            "" => Some((None, weekday)),
+                            n => Some((Some(signed(n, 53)? as _), weekday)),
+                        }
+                    })?;
+                }
+                "BYMONTHDAY" => {
+                    once(!rule.by_month_day.is_empty())?;
+                    rule.by_month_day = list(value, "invalid BYMONTHDAY", |v| {
+                        signed(v, 31).map(|v| v as _)
+                    })?;
+                }
+                "BYYEARDAY" => {
+                    once(!rule.by_year_day.is_empty())?;
+                    rule.by_year_day = list(value, "invalid BYYEARDAY", |v| signed(v, 366))?;
+                }
+                "BYWEEKNO" => {
+                    once(!rule.by_week_no.is_empty())?;
+                    rule.by_week_no =
+                        list(value, "invalid BYWEEKNO", |v| signed(v, 53).map(|v| v as _))?;
+                }
+                "BYMONTH" => {
+                    once(!rule.by_month.is_empty())?;
+                    rule.by_month = list(value, "invalid BYMONTH", |v| {
+                        Month::try_from(unsigned(v, 12)? as u8).ok()
+                    })?;
+                }
+                "BYSETPOS" => {
+                    once(!rule.by_set_pos.is_empty())?;
+                    rule.by_set_pos = list(value, "invalid BYSETPOS", |v| signed(v, 366))?;
+                }
+                "WKST" => {
+                    once(seen_week_start)?;
+                    seen_week_start = true;
+                    rule.week_start = parse_weekday(value)
+                        .ok_or(error::Recurrence::InvalidRRule("invalid WKST"))?;
+                }
+                _ => return Err(error::Recurrence::InvalidRRule("unknown part")),
+            }
+        }
+
+        rule.frequency = frequency.ok_or(error::Recurrence::InvalidRRule("missing FREQ"))?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(error::Recurrence::InvalidRRule(
+                "COUNT and UNTIL are mutually exclusive",
+            ));
+        }
+        Ok(rule)
+    }
+
+    /// Get how often the rule repeats.
+    pub const fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Get the number of periods of the frequency between each repetition.
+    pub const fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Get the maximum number of occurrences, if any.
+    pub const fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Get the day on which the week starts.
+    pub const fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// Get an iterator over the occurrences of the rule, beginning at `start`.
+    ///
+    /// Only datetimes that match the rule are yielded, so `start` itself is not yielded unless it
+    /// matches. Missing parts of the rule, such as the day of the month of a monthly rule, are
+    /// taken from `start`. The iterator ends when the rule is exhausted or the next occurrence
+    /// would be out of range.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, RRule};
+    /// let rule = RRule::parse("FREQ=WEEKLY;INTERVAL=2;BYDAY=TU,TH")?;
+    /// let mut occurrences = rule.occurrences(datetime!("2021-06-01 9:00"));
+    /// assert_eq!(occurrences.next(), Some(datetime!("2021-06-01 9:00")));
+    /// assert_eq!(occurrences.next(), Some(datetime!("2021-06-03 9:00")));
+    /// assert_eq!(occurrences.next(), Some(datetime!("2021-06-15 9:00")));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn occurrences(&self, start: PrimitiveDateTime) -> Occurrences {
+        let until = self.until.map(|until| match until {
+            DateTimeValue::Date(date) => PrimitiveDateTime::new(date, END_OF_DAY),
+            DateTimeValue::Local(datetime) | DateTimeValue::Utc(datetime) => datetime,
+        });
+        Occurrences::new(self, start, until)
+    }
+
+    /// Get an iterator over the occurrences of the rule, beginning at `start`. All occurrences
+    /// have the same offset as `start`.
+    ///
+    /// This is identical to [`RRule::occurrences`], other than `UNTIL` in UTC being converted to
+    /// the offset of `start`.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, RRule};
+    /// let rule = RRule::parse("FREQ=DAILY;UNTIL=20210603T120000Z")?;
+    /// let occurrences: Vec<_> = rule
+    ///     .offset_occurrences(datetime!("2021-06-01 9:00 -4"))
+    ///     .collect();
+    /// assert_eq!(
+    ///     occurrences,
+    ///     [
+    ///         datetime!("2021-06-01 9:00 -4"),
+    ///         datetime!("2021-06-02 9:00 -4"),
+    ///     ]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn offset_occurrences(&self, start: OffsetDateTime) -> OffsetOccurrences {
+        let offset = start.offset();
+        let until = self.until.map(|until| match until {
+            DateTimeValue::Date(date) => PrimitiveDateTime::new(date, END_OF_DAY),
+            DateTimeValue::Local(datetime) => datetime,
+            DateTimeValue::Utc(datetime) => datetime
+                .assume_utc()
+                .checked_to_offset(offset)
+                .map_or(datetime, |datetime| {
+                    PrimitiveDateTime::new(datetime.date(), datetime.time())
+                }),
+        });
+        OffsetOccurrences {
+            inner: Occurrences::new(
+                self,
+                PrimitiveDateTime::new(start.date(), start.time()),
+                until,
+            ),
+            offset,
+        }
+    }
+}
+
+impl fmt::Display for RRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Write a part containing a list of values, if it is not empty.
+        fn write_list<T>(
+            f: &mut fmt::Formatter<'_>,
+            name: &str,
+            values: &[T],
+            mut write_value: impl FnMut(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+        ) -> fmt::Result {
+            for (i, value) in values.iter().enumerate() {
+                f.write_str(if i == 0 { name } else { "," })?;
+                write_value(f, value)?;
+            }
+            Ok(())
+        }
+
+        write!(f, "FREQ={}", self.frequency.name())?;
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        write_list(f, ";BYSECOND=", &self.by_second, |f, v| v.fmt(f))?;
+        write_list(f, ";BYMINUTE=", &self.by_minute, |f, v| v.fmt(f))?;
+        write_list(f, ";BYHOUR=", &self.by_hour, |f, v| v.fmt(f))?;
+        write_list(f, ";BYDAY=", &self.by_day, |f, (n, weekday)| {
+            if let Some(n) = n {
+                n.fmt(f)?;
+            }
+            f.write_str(weekday_name(*weekday))
+        })?;
+        write_list(f, ";BYMONTHDAY=", &self.by_month_day, |f, v| v.fmt(f))?;
+        write_list(f, ";BYYEARDAY=", &self.by_year_day, |f, v| v.fmt(f))?;
+        write_list(f, ";BYWEEKNO=", &self.by_week_no, |f, v| v.fmt(f))?;
+        write_list(f, ";BYMONTH=", &self.by_month, |f, v| (*v as u8).fmt(f))?;
+        write_list(f, ";BYSETPOS=", &self.by_set_pos, |f, v| v.fmt(f))?;
+        if self.week_start != Weekday::Monday {
+            write!(f, ";WKST={}", weekday_name(self.week_start))?;
+        }
+        Ok(())
+    }
+}
+
+// region: parsing helpers
+/// Ensure that a part has not been seen before.
+const fn once(seen: bool) -> Result<(), error::Recurrence> {
+    if seen {
+        Err(error::Recurrence::InvalidRRule("duplicate part"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parse the value of the `FREQ` part.
+fn parse_frequency(s: &str) -> Result<Frequency, error::Recurrence> {
+    [
+        Frequency::Secondly,
+        Frequency::Minutely,
+        Frequency::Hourly,
+        Frequency::Daily,
+        Frequency::Weekly,
+        Frequency::Monthly,
+        Frequency::Yearly,
+    ]
+    .iter()
+    .copied()
+    .find(|frequency| frequency.name().eq_ignore_ascii_case(s))
+    .ok_or(error::Recurrence::InvalidRRule("invalid FREQ"))
+}
+
+/// The two-letter name of a weekday, as used in the `BYDAY` and `WKST` parts.
+const fn weekday_name(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+/// Parse the two-letter name of a weekday.
+fn parse_weekday(s: &str) -> Option<Weekday> {
+    let mut weekday = Weekday::Monday;
+    for _ in 0..7 {
+        if weekday_name(weekday).eq_ignore_ascii_case(s) {
+            return Some(weekday);
+        }
+        weekday = weekday.next();
+    }
+    None
+}
+
+/// Parse an unsigned integer no greater than `max`.
+fn unsigned(s: &str, max: u32) -> Option<u32> {
+    if s.is_empty() || !s.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok().filter(|&value| value <= max)
+}
+
+/// Parse a nonzero integer, optionally preceded by a sign, whose magnitude is no greater than
+/// `max`.
+fn signed(s: &str, max: u16) -> Option<i16> {
+    let (negative, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let value = unsigned(digits, max as _)? as i16;
+    match (value, negative) {
+        (0, _) => None,
+        (value, true) => Some(-value),
+        (value, false) => Some(value),
+    }
+}
+
+/// Parse a comma-separated list of values, failing with the provided reason if any is invalid.
+fn list<T>(
+    s: &str,
+    reason: &'static str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<T>, error::Recurrence> {
+    s.split(',')
+        .map(|value| parse(value).ok_or(error::Recurrence::InvalidRRule(reason)))
+        .collect()
+}
+// endregion parsing helpers
+
+// region: Occurrences
+/// The number of days after which the Gregorian calendar repeats.
+const DAYS_PER_CYCLE: i64 = 146_097;
+
+/// The number of days since Monday of the day with the provided Julian day number.
+const fn weekday_index(julian_day: i32) -> i32 {
+    rem_euclid!(julian_day, 7)
+}
+
+/// The greatest common divisor of two integers.
+const fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// The length of time after which the periods of the rule, and therefore its occurrences, repeat,
+/// if representable.
+fn cycle(rule: &RRule) -> Option<Duration> {
+    // The calendar repeats every 400 years, and the periods repeat once a whole number of
+    // intervals fits in a whole number of those cycles.
+    let interval = rule.interval as i64;
+    let (step, steps_per_cycle) = match rule.frequency {
+        Frequency::Yearly => (interval, 400),
+        Frequency::Monthly => (interval, 4_800),
+        Frequency::Weekly => (interval, DAYS_PER_CYCLE / 7),
+        Frequency::Daily => (interval, DAYS_PER_CYCLE),
+        Frequency::Hourly => (interval * 3_600, DAYS_PER_CYCLE * 86_400),
+        Frequency::Minutely => (interval * 60, DAYS_PER_CYCLE * 86_400),
+        Frequency::Secondly => (interval, DAYS_PER_CYCLE * 86_400),
+    };
+    (step / gcd(step, steps_per_cycle))
+        .checked_mul(DAYS_PER_CYCLE * 86_400)
+        .map(Duration::seconds)
+}
+
+/// Whether any period of a rule with a frequency below daily begins at a time of day allowed by
+/// the rule, given the first period.
+fn any_time_of_day_allowed(rule: &RRule, first_period: PrimitiveDateTime) -> bool {
+    // The periods begin at every time of day that differs from that of the first period by a
+    // multiple of the greatest common divisor of the step and the length of a day, and at no
+    // other.
+    let frequency = rule.frequency;
+    let step = rule.interval as i64
+        * match frequency {
+            Frequency::Hourly => 3_600,
+            Frequency::Minutely => 60,
+            _ => 1,
+        };
+    let divisor = gcd(step, 86_400);
+    let first = first_period.hour() as i64 * 3_600
+        + first_period.minute() as i64 * 60
+        + first_period.second() as i64;
+    let allowed = |values: &[u8], value: i64, is_fixed: bool| {
+        !is_fixed || values.is_empty() || values.contains(&(value as u8))
+    };
+    (0..86_400 / divisor).any(|n| {
+        let seconds = (first + n * divisor) % 86_400;
+        allowed(&rule.by_hour, seconds / 3_600, true)
+            && allowed(
+                &rule.by_minute,
+                seconds / 60 % 60,
+                frequency < Frequency::Hourly,
+            )
+            && allowed(
+                &rule.by_second,
+                seconds % 60,
+                frequency < Frequency::Minutely,
+            )
+    })
+}
+
+/// An iterator over the occurrences of an [`RRule`], created by [`RRule::occurrences`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    /// The rule being expanded, with missing parts taken from the start.
+    rule: RRule,
+    /// The start of the recurrence. No earlier occurrence is yielded.
+    start: PrimitiveDateTime,
+    /// The last moment at which an occurrence may occur.
+    until: Option<PrimitiveDateTime>,
+    /// The number of occurrences that may still be yielded, if limited.
+    remaining: Option<u32>,
+    /// The beginning of the next period to be expanded, or `None` if there are no more.
+    period: Option<PrimitiveDateTime>,
+    /// The occurrences of the current period that have not been yielded, in descending order.
+    buffer: Vec<PrimitiveDateTime>,
+    /// The length of time after which the periods and their occurrences repeat, if representable.
+    cycle: Option<Duration>,
+    /// The beginning of the first of the consecutive periods, up to the current one, without any
+    /// occurrences.
+    empty_since: Option<PrimitiveDateTime>,
+}
+
+impl Occurrences {
+    /// Create an iterator over the occurrences of the rule, filling in the parts of the rule that
+    /// are missing.
+    fn new(rule: &RRule, start: PrimitiveDateTime, until: Option<PrimitiveDateTime>) -> Self {
+        let mut rule = rule.clone();
+        let frequency = rule.frequency;
+
+        if rule.by_week_no.is_empty()
+            && rule.by_year_day.is_empty()
+            && rule.by_month_day.is_empty()
+            && rule.by_day.is_empty()
+        {
+            match frequency {
+                Frequency::Yearly => {
+                    if rule.by_month.is_empty() {
+                        rule.by_month.push(start.month());
+                    }
+                    rule.by_month_day.push(start.day() as _);
+                }
+                Frequency::Monthly => rule.by_month_day.push(start.day() as _),
+                Frequency::Weekly => rule.by_day.push((None, start.weekday())),
+                _ => {}
+            }
+        }
+        if rule.by_hour.is_empty() && frequency >= Frequency::Daily {
+            rule.by_hour.push(start.hour());
+        }
+        if rule.by_minute.is_empty() && frequency >= Frequency::Hourly {
+            rule.by_minute.push(start.minute());
+        }
+        if rule.by_second.is_empty() && frequency >= Frequency::Minutely {
+            rule.by_second.push(start.second());
+        }
+        rule.by_hour.sort_unstable();
+        rule.by_hour.dedup();
+        rule.by_minute.sort_unstable();
+        rule.by_minute.dedup();
+        rule.by_second.sort_unstable();
+        rule.by_second.dedup();
+
+        let date = start.date();
+        let period = match frequency {
+            Frequency::Yearly => Date::from_calendar_date(date.year(), Month::January, 1)
+                .ok()
+                .map(Date::midnight),
+            Frequency::Monthly => Date::from_calendar_date(date.year(), date.month(), 1)
+                .ok()
+                .map(Date::midnight),
+            Frequency::Weekly => {
+                let days_into_week = rem_euclid!(
+                    weekday_index(date.to_julian_day())
+                        - rule.week_start.number_days_from_monday() as i32,
+                    7
+                );
+                // If the week begins before the earliest date, begin with the earliest date
+                // instead. The length of the period accounts for this.
+                Some(
+                    Date::from_julian_day(date.to_julian_day() - days_into_week)
+                        .unwrap_or(Date::MIN)
+                        .midnight(),
+                )
+            }
+            Frequency::Daily => Some(date.midnight()),
+            Frequency::Hourly => {
+                Some(start.replace_time(Time::__from_hms_nanos_unchecked(start.hour(), 0, 0, 0)))
+            }
+            Frequency::Minutely => Some(start.replace_time(Time::__from_hms_nanos_unchecked(
+                start.hour(),
+                start.minute(),
+                0,
+                0,
+            ))),
+            Frequency::Secondly => Some(start.replace_time(Time::__from_hms_nanos_unchecked(
+                start.hour(),
+                start.minute(),
+                start.second(),
+                0,
+            ))),
+        };
+
+        let cycle = cycle(&rule);
+        let period = period.filter(|&period| {
+            frequency >= Frequency::Daily || any_time_of_day_allowed(&rule, period)
+        });
+
+        Self {
+            remaining: rule.count,
+            rule,
+            start,
+            until,
+            period,
+            buffer: Vec::new(),
+            cycle,
+            empty_since: None,
+        }
+    }
+
+    /// Whether the date matches the parts of the rule that concern dates.
+    fn date_matches(&self, date: Date) -> bool {
+        let rule = &self.rule;
+        let (year, month, day) = date.to_calendar_date();
+        let days_in_month = month.length(year) as i16;
+        let days_in_year = days_in_year(year) as i16;
+
+        if !rule.by_month.is_empty() && !rule.by_month.contains(&month) {
+            return false;
+        }
+        if !rule.by_week_no.is_empty() {
+            let (week, weeks_in_year) = self.week_number(date);
+            if !rule
+                .by_week_no
+                .iter()
+                .any(|&n| n as i16 == week || n as i16 + weeks_in_year + 1 == week)
+            {
+                return false;
+            }
+        }
+        if !rule.by_year_day.is_empty()
+            && !rule.by_year_day.iter().any(|&n| {
+                n == date.ordinal() as i16 || n + days_in_year + 1 == date.ordinal() as i16
+            })
+        {
+            return false;
+        }
+        if !rule.by_month_day.is_empty()
+            && !rule
+                .by_month_day
+                .iter()
+                .any(|&n| n as i16 == day as i16 || n as i16 + days_in_month + 1 == day as i16)
+        {
+            return false;
+        }
+        if !rule.by_day.is_empty() {
+            // Ordinals refer to occurrences within the month or year, depending on the rule.
+            let (index, length) = match rule.frequency {
+                Frequency::Monthly => (day as i16, days_in_month),
+                Frequency::Yearly if !rule.by_month.is_empty() => (day as i16, days_in_month),
+                Frequency::Yearly => (date.ordinal() as i16, days_in_year),
+                _ => (0, 0),
+            };
+            if !rule.by_day.iter().any(|&(n, weekday)| {
+                weekday == date.weekday()
+                    && match n {
+                        None => true,
+                        Some(_) if length == 0 => true,
+                        Some(n) if n > 0 => (index - 1) / 7 + 1 == n as i16,
+                        Some(n) => (length - index) / 7 + 1 == -n as i16,
+                    }
+            }) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Get the number of the week containing the date, along with the number of weeks in the year
+    /// containing that week. Week one is the first week with at least four days in the year.
+    fn week_number(&self, date: Date) -> (i16, i16) {
+        let week_start = self.rule.week_start.number_days_from_monday() as i32;
+        // The first day of week one, given the Julian day of January 1.
+        let week_one = |january_1: i32| {
+            let january_4 = january_1 + 3;
+            january_4 - rem_euclid!(weekday_index(january_4) - week_start, 7)
+        };
+
+        let julian_day = date.to_julian_day();
+        let year = date.year();
+        let january_1 = julian_day - date.ordinal() as i32 + 1;
+        let this_year = week_one(january_1);
+        let next_year = week_one(january_1 + days_in_year(year) as i32);
+
+        let (week_one_start, next_week_one_start) = if julian_day < this_year {
+            (
+                week_one(january_1 - days_in_year(year - 1) as i32),
+                this_year,
+            )
+        } else if julian_day >= next_year {
+            let year_after = january_1 + days_in_year(year) as i32 + days_in_year(year + 1) as i32;
+            (next_year, week_one(year_after))
+        } else {
+            (this_year, next_year)
+        };
+        (
+            ((julian_day - week_one_start) / 7 + 1) as _,
+            ((next_week_one_start - week_one_start) / 7) as _,
+        )
+    }
+
+    /// Get the values of a time component for a period. Components shorter than the frequency are
+    /// expanded to every listed value, while others are fixed to that of the period, provided it is
+    /// listed.
+    fn time_values(values: &[u8], expand: bool, current: u8) -> Vec<u8> {
+        if expand {
+            values.to_vec()
+        } else if values.is_empty() || values.contains(&current) {
+            alloc::vec![current]
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Fill the buffer with the occurrences of the period.
+    fn fill(&mut self, period: PrimitiveDateTime) {
+        let rule = &self.rule;
+        let frequency = rule.frequency;
+
+        let hours = Self::time_values(&rule.by_hour, frequency >= Frequency::Daily, period.hour());
+        let minutes = Self::time_values(
+            &rule.by_minute,
+            frequency >= Frequency::Hourly,
+            period.minute(),
+        );
+        let seconds = Self::time_values(
+            &rule.by_second,
+            frequency >= Frequency::Minutely,
+            period.second(),
+        );
+        let mut times = Vec::with_capacity(hours.len() * minutes.len() * seconds.len());
+        for &hour in &hours {
+            for &minute in &minutes {
+                for &second in &seconds {
+                    times.push(Time::__from_hms_nanos_unchecked(
+                        hour,
+                        minute,
+                        second,
+                        self.start.nanosecond(),
+                    ));
+                }
+            }
+        }
+
+        // The ranges of dates in the period that may match, as the first date and the number of
+        // days. Only the listed months of a yearly rule are considered.
+        let first = period.date();
+        let mut ranges = Vec::new();
+        match frequency {
+            Frequency::Yearly if !rule.by_month.is_empty() => {
+                let mut month = Month::January;
+                for _ in 0..12 {
+                    if rule.by_month.contains(&month) {
+                        if let Ok(date) = Date::from_calendar_date(first.year(), month, 1) {
+                            ranges.push((date, month.length(first.year()) as i32));
+                        }
+                    }
+                    month = month.next();
+                }
+            }
+            Frequency::Yearly => ranges.push((first, days_in_year(first.year()) as i32)),
+            Frequency::Monthly => ranges.push((first, first.month().length(first.year()) as i32)),
+            Frequency::Weekly => ranges.push((
+                first,
+                7 - rem_euclid!(
+                    weekday_index(first.to_julian_day())
+                        - rule.week_start.number_days_from_monday() as i32,
+                    7
+                ),
+            )),
+            _ => ranges.push((first, 1)),
+        }
+
+        let mut candidates = Vec::new();
+        for &(first, days) in if times.is_empty() {
+            &[][..]
+        } else {
+            &ranges[..]
+        } {
+            let mut date = Some(first);
+            for _ in 0..days {
+                let current = match date {
+                    Some(date) => date,
+                    None => break,
+                };
+                if self.date_matches(current) {
+                    candidates.extend(
+                        times
+                            .iter()
+                            .map(|&time| PrimitiveDateTime::new(current, time)),
+                    );
+                }
+                date = current.next_day();
+            }
+        }
+
+        if !rule.by_set_pos.is_empty() {
+            let len = candidates.len() as i32;
+            let mut selected: Vec<_> = rule
+                .by_set_pos
+                .iter()
+                .filter_map(|&position| {
+                    let index = if position > 0 {
+                        position as i32 - 1
+                    } else {
+                        len + position as i32
+                    };
+                    candidates.get(usize::try_from(index).ok()?).copied()
+                })
+                .collect();
+            selected.sort_unstable();
+            selected.dedup();
+            candidates = selected;
+        }
+
+        let start = self.start;
+        self.buffer = candidates
+            .into_iter()
+            .rev()
+            .filter(|&candidate| candidate >= start)
+            .collect();
+    }
+
+    /// Get the beginning of the period following the provided one.
+    fn next_period(&self, period: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        let rule = &self.rule;
+        let interval = rule.interval;
+        let date = period.date();
+
+        let unit_seconds = match rule.frequency {
+            Frequency::Yearly => {
+                let year = date.year().checked_add(i32::try_from(interval).ok()?)?;
+                return Date::from_calendar_date(year, Month::January, 1)
+                    .ok()
+                    .map(Date::midnight);
+            }
+            Frequency::Monthly => {
+                return date
+                    .checked_add_months(i32::try_from(interval).ok()?, DayOverflow::Clamp)
+                    .map(Date::midnight);
+            }
+            Frequency::Weekly => {
+                // The period may have been shortened if it began at the earliest date.
+                let days_into_week = rem_euclid!(
+                    weekday_index(date.to_julian_day())
+                        - rule.week_start.number_days_from_monday() as i32,
+                    7
+                ) as i64;
+                let julian_day = date.to_julian_day() as i64 - days_into_week + 7 * interval as i64;
+                return Date::from_julian_day(i32::try_from(julian_day).ok()?)
+                    .ok()
+                    .map(Date::midnight);
+            }
+            Frequency::Daily => {
+                return Date::from_julian_day(
+                    i32::try_from(date.to_julian_day() as i64 + interval as i64).ok()?,
+                )
+                .ok()
+                .map(Date::midnight);
+            }
+            Frequency::Hourly => 3_600,
+            Frequency::Minutely => 60,
+            Frequency::Secondly => 1,
+        };
+        let step = interval as i64 * unit_seconds;
+
+        // When the day, hour, or minute of the period cannot match, skip the periods through its
+        // end rather than checking each.
+        let time = period.time();
+        let skip_to = if !self.date_matches(date) {
+            date.next_day().map(Date::midnight)
+        } else if rule.frequency < Frequency::Hourly
+            && !rule.by_hour.is_empty()
+            && !rule.by_hour.contains(&time.hour())
+        {
+            Some(period.replace_time(Time::__from_hms_nanos_unchecked(time.hour(), 0, 0, 0)))
+                .and_then(|hour| hour.checked_add(Duration::HOUR))
+        } else if rule.frequency < Frequency::Minutely
+            && !rule.by_minute.is_empty()
+            && !rule.by_minute.contains(&time.minute())
+        {
+            Some(period.replace_time(Time::__from_hms_nanos_unchecked(
+                time.hour(),
+                time.minute(),
+                0,
+                0,
+            )))
+            .and_then(|minute| minute.checked_add(Duration::MINUTE))
+        } else {
+            None
+        };
+        let steps = match skip_to {
+            Some(skip_to) => {
+                let seconds = (skip_to - period).whole_seconds();
+                (seconds + step - 1) / step
+            }
+            None if self.date_matches(date) => 1,
+            // The period cannot match and there is no later date.
+            None => return None,
+        };
+        period.checked_add(Duration::seconds(steps.checked_mul(step)?))
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = PrimitiveDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining == Some(0) {
+                return None;
+            }
+
+            if let Some(occurrence) = self.buffer.pop() {
+                if matches!(self.until, Some(until) if occurrence > until) {
+                    self.buffer.clear();
+                    self.period = None;
+                    return None;
+                }
+                if let Some(remaining) = &mut self.remaining {
+                    *remaining -= 1;
+                }
+                return Some(occurrence);
+            }
+
+            let period = self.period?;
+            if matches!(self.until, Some(until) if period > until) {
+                self.period = None;
+                return None;
+            }
+            self.fill(period);
+
+            // If no period has an occurrence for an entire cycle, none ever will. The first period
+            // is not considered, as its occurrences may precede the start.
+            if !self.buffer.is_empty() || period < self.start {
+                self.empty_since = None;
+            } else {
+                let empty_since = *self.empty_since.get_or_insert(period);
+                if matches!(self.cycle, Some(cycle) if period - empty_since >= cycle) {
+                    self.period = None;
+                    return None;
+                }
+            }
+
+            self.period = self.next_period(period);
+        }
+    }
+}
+
+impl FusedIterator for Occurrences {}
+
+/// An iterator over the occurrences of an [`RRule`] with a fixed offset, created by
+/// [`RRule::offset_occurrences`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct OffsetOccurrences {
+    /// The occurrences in local time.
+    inner: Occurrences,
+    /// The offset of every occurrence.
+    offset: UtcOffset,
+}
+
+impl Iterator for OffsetOccurrences {
+    type Item = OffsetDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
+        self.inner
+            .next()
+            .map(|datetime| datetime.assume_offset(offset))
+    }
+}
+
+impl FusedIterator for OffsetOccurrences {}
+// endregion Occurrences
+
+// region: RecurrenceSet
+/// The start of a recurrence, together with the rules and individual dates that determine its
+/// occurrences, as described in
+/// [RFC 5545](https://tools.ietf.org/html/rfc5545#section-3.8.5).
+///
+/// The start is always the first occurrence. Occurrences of every rule and every added date
+/// (`RDATE`) are included, with duplicates removed, unless they are excluded (`EXDATE`).
+///
+/// ```rust
+/// # use time::{macros::datetime, rrule::RecurrenceSet, RRule};
+/// let set = RecurrenceSet::new(datetime!("2021-06-01 9:00"))
+///     .with_rrule(RRule::parse("FREQ=DAILY;COUNT=4")?)
+///     .with_rdate(datetime!("2021-06-10 9:00"))
+///     .with_exdate(datetime!("2021-06-02 9:00"));
+/// let occurrences: Vec<_> = set.occurrences().collect();
+/// assert_eq!(
+///     occurrences,
+///     [
+///         datetime!("2021-06-01 9:00"),
+///         datetime!("2021-06-03 9:00"),
+///         datetime!("2021-06-04 9:00"),
+///         datetime!("2021-06-10 9:00"),
+///     ]
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RecurrenceSet {
+    /// The start of the recurrence.
+    start: PrimitiveDateTime,
+    /// The rules determining the occurrences.
+    rrules: Vec<RRule>,
+    /// Additional occurrences.
+    rdates: Vec<PrimitiveDateTime>,
+    /// Datetimes that are never occurrences.
+    exdates: Vec<PrimitiveDateTime>,
+}
+
+impl RecurrenceSet {
+    /// Create a `RecurrenceSet` beginning at the provided datetime, with no other occurrences.
+    pub const fn new(start: PrimitiveDateTime) -> Self {
+        Self {
+            start,
+            rrules: Vec::new(),
+            rdates: Vec::new(),
+            exdates: Vec::new(),
+        }
+    }
+
+    /// Parse the `DTSTART`, `RRULE`, `RDATE`, and `EXDATE` properties of an iCalendar component,
+    /// one per line. `DTSTART` is required, while the others may occur any number of times.
+    ///
+    /// Property parameters other than `VALUE` are ignored, so times with a `TZID` are treated as
+    /// local times. `RDATE` values of type `PERIOD` are not supported.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, rrule::RecurrenceSet};
+    /// let set = RecurrenceSet::parse(
+    ///     "DTSTART;TZID=America/New_York:19970902T090000\n\
+    ///      RRULE:FREQ=WEEKLY;COUNT=3\n\
+    ///      EXDATE:19970909T090000",
+    /// )?;
+    /// let occurrences: Vec<_> = set.occurrences().collect();
+    /// assert_eq!(
+    ///     occurrences,
+    ///     [datetime!("1997-09-02 9:00"), datetime!("1997-09-16 9:00")]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, error::Recurrence> {
+        let mut start = None;
+        let mut rrules = Vec::new();
+        let mut rdates = Vec::new();
+        let mut exdates = Vec::new();
+
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let colon = line
+                .find(':')
+                .ok_or(error::Recurrence::InvalidRecurrenceSet("missing value"))?;
+            let (name, value) = (&line[..colon], &line[colon + 1..]);
+            let mut parameters = name.split(';');
+            let name = parameters.next().unwrap_or_default().to_ascii_uppercase();
+            if parameters.any(|parameter| parameter.eq_ignore_ascii_case("VALUE=PERIOD")) {
+                return Err(error::Recurrence::InvalidRecurrenceSet(
+                    "PERIOD values are not supported",
+                ));
+            }
+
+            let values = || {
+                value
+                    .split(',')
+                    .map(|value| {
+                        DateTimeValue::parse(value).map(DateTimeValue::start).ok_or(
+                            error::Recurrence::InvalidRecurrenceSet("invalid date or datetime"),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            match name.as_str() {
+                "DTSTART" => {
+                    if start.is_some() {
+                        return Err(error::Recurrence::InvalidRecurrenceSet("duplicate DTSTART"));
+                    }
+                    start = Some(
+                        DateTimeValue::parse(value)
+                            .map(DateTimeValue::start)
+                            .ok_or(error::Recurrence::InvalidRecurrenceSet("invalid DTSTART"))?,
+                    );
+                }
+                "RRULE" => rrules.push(RRule::parse(value)?),
+                "RDATE" => rdates.extend(values()?),
+                "EXDATE" => exdates.extend(values()?),
+                _ => {
+                    return Err(error::Recurrence::InvalidRecurrenceSet("unknown property"));
+                }
+            }
+        }
+
+        Ok(Self {
+            start: start.ok_or(error::Recurrence::InvalidRecurrenceSet("missing DTSTART"))?,
+            rrules,
+            rdates,
+            exdates,
+        })
+    }
+
+    /// Add a rule determining occurrences.
+    #[must_use = "This method does not mutate the original `RecurrenceSet`."]
+    pub fn with_rrule(mut self, rrule: RRule) -> Self {
+        self.rrules.push(rrule);
+        self
+    }
+
+    /// Add an individual occurrence.
+    #[must_use = "This method does not mutate the original `RecurrenceSet`."]
+    pub fn with_rdate(mut self, rdate: PrimitiveDateTime) -> Self {
+        self.rdates.push(rdate);
+        self
+    }
+
+    /// Exclude a datetime from the occurrences.
+    #[must_use = "This method does not mutate the original `RecurrenceSet`."]
+    pub fn with_exdate(mut self, exdate: PrimitiveDateTime) -> Self {
+        self.exdates.push(exdate);
+        self
+    }
+
+    /// Get the start of the recurrence.
+    pub const fn start(&self) -> PrimitiveDateTime {
+        self.start
+    }
+
+    /// Get the rules determining the occurrences.
+    pub fn rrules(&self) -> &[RRule] {
+        &self.rrules
+    }
+
+    /// Get an iterator over the occurrences in ascending order.
+    pub fn occurrences(&self) -> SetOccurrences {
+        let mut rdates = self.rdates.clone();
+        rdates.push(self.start);
+        rdates.retain(|&rdate| rdate >= self.start);
+        rdates.sort_unstable_by(|a, b| b.cmp(a));
+        rdates.dedup();
+        let mut exdates = self.exdates.clone();
+        exdates.sort_unstable();
+
+        SetOccurrences {
+            rrules: self
+                .rrules
+                .iter()
+                .map(|rrule| rrule.occurrences(self.start).peekable())
+                .collect(),
+            rdates,
+            exdates,
+        }
+    }
+}
+
+/// An iterator over the occurrences of a [`RecurrenceSet`], created by
+/// [`RecurrenceSet::occurrences`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct SetOccurrences {
+    /// The occurrences of each rule.
+    rrules: Vec<Peekable<Occurrences>>,
+    /// The additional occurrences that have not been yielded, in descending order.
+    rdates: Vec<PrimitiveDateTime>,
+    /// The excluded datetimes, in ascending order.
+    exdates: Vec<PrimitiveDateTime>,
+}
+
+impl Iterator for SetOccurrences {
+    type Item = PrimitiveDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut next = self.rdates.last().copied();
+            for rrule in &mut self.rrules {
+                if let Some(&occurrence) = rrule.peek() {
+                    if !matches!(next, Some(next) if next <= occurrence) {
+                        next = Some(occurrence);
+                    }
+                }
+            }
+            let next = next?;
+
+            // Consume the occurrence from every source it appears in.
+            if self.rdates.last() == Some(&next) {
+                self.rdates.pop();
+            }
+            for rrule in &mut self.rrules {
+                if rrule.peek() == Some(&next) {
+                    rrule.next();
+                }
+            }
+
+            if self.exdates.binary_search(&next).is_err() {
+                return Some(next);
+            }
+        }
+    }
+}
+
+impl FusedIterator for SetOccurrences {}
+// endregion RecurrenceSet
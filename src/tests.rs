@@ -3,8 +3,14 @@
 //! This module should only be used when it is not possible to test the implementation in a
 //! reasonable manner externally.
 
+#[cfg(all(target_family = "unix", feature = "local-offset"))]
+use std::path::PathBuf;
+
 use crate::formatting::DigitCount;
 use crate::util::days_in_year_month;
+use crate::Month;
+#[cfg(all(target_family = "unix", feature = "local-offset"))]
+use crate::{OffsetDateTime, TimeZone, UtcOffset};
 
 #[test]
 fn digit_count() {
@@ -33,30 +39,111 @@ fn digit_count() {
 #[test]
 fn test_days_in_year_month() {
     // Common year
-    assert_eq!(days_in_year_month(2019, 1), 31);
-    assert_eq!(days_in_year_month(2019, 2), 28);
-    assert_eq!(days_in_year_month(2019, 3), 31);
-    assert_eq!(days_in_year_month(2019, 4), 30);
-    assert_eq!(days_in_year_month(2019, 5), 31);
-    assert_eq!(days_in_year_month(2019, 6), 30);
-    assert_eq!(days_in_year_month(2019, 7), 31);
-    assert_eq!(days_in_year_month(2019, 8), 31);
-    assert_eq!(days_in_year_month(2019, 9), 30);
-    assert_eq!(days_in_year_month(2019, 10), 31);
-    assert_eq!(days_in_year_month(2019, 11), 30);
-    assert_eq!(days_in_year_month(2019, 12), 31);
+    assert_eq!(days_in_year_month(2019, Month::January), 31);
+    assert_eq!(days_in_year_month(2019, Month::February), 28);
+    assert_eq!(days_in_year_month(2019, Month::March), 31);
+    assert_eq!(days_in_year_month(2019, Month::April), 30);
+    assert_eq!(days_in_year_month(2019, Month::May), 31);
+    assert_eq!(days_in_year_month(2019, Month::June), 30);
+    assert_eq!(days_in_year_month(2019, Month::July), 31);
+    assert_eq!(days_in_year_month(2019, Month::August), 31);
+    assert_eq!(days_in_year_month(2019, Month::September), 30);
+    assert_eq!(days_in_year_month(2019, Month::October), 31);
+    assert_eq!(days_in_year_month(2019, Month::November), 30);
+    assert_eq!(days_in_year_month(2019, Month::December), 31);
 
     // Leap year
-    assert_eq!(days_in_year_month(2020, 1), 31);
-    assert_eq!(days_in_year_month(2020, 2), 29);
-    assert_eq!(days_in_year_month(2020, 3), 31);
-    assert_eq!(days_in_year_month(2020, 4), 30);
-    assert_eq!(days_in_year_month(2020, 5), 31);
-    assert_eq!(days_in_year_month(2020, 6), 30);
-    assert_eq!(days_in_year_month(2020, 7), 31);
-    assert_eq!(days_in_year_month(2020, 8), 31);
-    assert_eq!(days_in_year_month(2020, 9), 30);
-    assert_eq!(days_in_year_month(2020, 10), 31);
-    assert_eq!(days_in_year_month(2020, 11), 30);
-    assert_eq!(days_in_year_month(2020, 12), 31);
+    assert_eq!(days_in_year_month(2020, Month::January), 31);
+    assert_eq!(days_in_year_month(2020, Month::February), 29);
+    assert_eq!(days_in_year_month(2020, Month::March), 31);
+    assert_eq!(days_in_year_month(2020, Month::April), 30);
+    assert_eq!(days_in_year_month(2020, Month::May), 31);
+    assert_eq!(days_in_year_month(2020, Month::June), 30);
+    assert_eq!(days_in_year_month(2020, Month::July), 31);
+    assert_eq!(days_in_year_month(2020, Month::August), 31);
+    assert_eq!(days_in_year_month(2020, Month::September), 30);
+    assert_eq!(days_in_year_month(2020, Month::October), 31);
+    assert_eq!(days_in_year_month(2020, Month::November), 30);
+    assert_eq!(days_in_year_month(2020, Month::December), 31);
+}
+
+/// Create an empty temporary directory that is not shared with other tests or other runs of the
+/// test suite.
+#[cfg(all(target_family = "unix", feature = "local-offset"))]
+fn unique_temp_dir(name: &str) -> std::io::Result<PathBuf> {
+    let directory = std::env::temp_dir().join(format!("time-rs-{}-{}", name, std::process::id()));
+    if directory.exists() {
+        std::fs::remove_dir_all(&directory)?;
+    }
+    std::fs::create_dir_all(&directory)?;
+    Ok(directory)
+}
+
+#[cfg(all(target_family = "unix", feature = "local-offset"))]
+#[test]
+fn local_time_zone() -> Result<(), Box<dyn std::error::Error>> {
+    // A version 1 TZif file with a single local time type of EST (-5:00) and no transitions.
+    let mut tzif = b"TZif".to_vec();
+    tzif.extend_from_slice(&[0; 16]);
+    for &count in &[0_u32, 0, 0, 0, 1, 4] {
+        tzif.extend_from_slice(&count.to_be_bytes());
+    }
+    tzif.extend_from_slice(&(-18_000_i32).to_be_bytes());
+    tzif.extend_from_slice(&[0, 0]);
+    tzif.extend_from_slice(b"EST\0");
+
+    let directory = unique_temp_dir("local-time-zone")?;
+    let tzdir = directory.join("zoneinfo");
+    std::fs::create_dir_all(tzdir.join("America"))?;
+    let path = tzdir.join("America/New_York");
+    std::fs::write(&path, tzif)?;
+
+    // 2021-07-01 0:00 UTC
+    let datetime = OffsetDateTime::from_unix_timestamp(1_625_097_600)?;
+    let est = UtcOffset::from_hms(-5, 0, 0)?;
+    let local_from = |tz: &str| TimeZone::local_from(Some(tz.as_ref()), Some(tzdir.as_ref()));
+
+    let path = path.to_str().unwrap_or_default();
+    let time_zone = local_from(&format!(":{}", path))?;
+    assert_eq!(time_zone.offset_at(datetime), est);
+    assert_eq!(time_zone.name(), Some("America/New_York"));
+    assert_eq!(local_from(path)?.offset_at(datetime), est);
+    let time_zone = local_from("America/New_York")?;
+    assert_eq!(time_zone.offset_at(datetime), est);
+    assert_eq!(time_zone.name(), Some("America/New_York"));
+    assert_eq!(local_from(":America/New_York")?.offset_at(datetime), est);
+    assert_eq!(
+        local_from("EST5EDT,M3.2.0,M11.1.0")?.offset_at(datetime),
+        UtcOffset::from_hms(-4, 0, 0)?
+    );
+    assert_eq!(local_from("")?.offset_at(datetime), UtcOffset::UTC);
+    assert_eq!(local_from(":")?.offset_at(datetime), UtcOffset::UTC);
+    assert!(local_from(&format!("{}/Nowhere", tzdir.display())).is_err());
+    assert!(local_from("America/Nowhere").is_err());
+
+    std::fs::remove_dir_all(directory)?;
+    Ok(())
+}
+
+#[cfg(all(
+    target_family = "unix",
+    feature = "local-offset",
+    feature = "tzdb-embedded"
+))]
+#[test]
+fn local_time_zone_embedded() -> Result<(), Box<dyn std::error::Error>> {
+    // The directory is empty, so the embedded database is used.
+    let directory = unique_temp_dir("local-time-zone-embedded")?;
+    let time_zone =
+        TimeZone::local_from(Some("America/Sao_Paulo".as_ref()), Some(directory.as_ref()))?;
+    std::fs::remove_dir_all(directory)?;
+
+    // 2021-07-01 0:00 UTC
+    let datetime = OffsetDateTime::from_unix_timestamp(1_625_097_600)?;
+    assert_eq!(
+        time_zone.offset_at(datetime),
+        UtcOffset::from_hms(-3, 0, 0)?
+    );
+    assert_eq!(time_zone.name(), Some("America/Sao_Paulo"));
+    Ok(())
 }
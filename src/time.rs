@@ -11,7 +11,7 @@ use crate::formatting::formattable::sealed::Formattable;
 #[cfg(feature = "parsing")]
 use crate::parsing::parsable::sealed::Parsable;
 use crate::util::DateAdjustment;
-use crate::{error, Duration};
+use crate::{error, Duration, Unit};
 
 /// By explicitly inserting this enum where padding is expected, the compiler is able to better
 /// perform niche value optimization.
@@ -427,6 +427,207 @@ impl Time {
     // endregion arithmetic helpers
 }
 
+// region: replacement
+/// Methods that replace part of the `Time`.
+impl Time {
+    /// Replace the clock hour.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(time!("1:02:03.004").replace_hour(7), Ok(time!("7:02:03.004")));
+    /// assert!(time!("1:02:03.004").replace_hour(24).is_err()); // 24 isn't a valid hour.
+    /// ```
+    pub const fn replace_hour(self, hour: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(hour in 0 => 23);
+        Ok(Self::__from_hms_nanos_unchecked(
+            hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+        ))
+    }
+
+    /// Replace the minutes within the hour.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(time!("1:02:03.004").replace_minute(7), Ok(time!("1:07:03.004")));
+    /// assert!(time!("1:02:03.004").replace_minute(60).is_err()); // 60 isn't a valid minute.
+    /// ```
+    pub const fn replace_minute(self, minute: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(minute in 0 => 59);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            minute,
+            self.second,
+            self.nanosecond,
+        ))
+    }
+
+    /// Replace the seconds within the minute.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(time!("1:02:03.004").replace_second(7), Ok(time!("1:02:07.004")));
+    /// assert!(time!("1:02:03.004").replace_second(60).is_err()); // 60 isn't a valid second.
+    /// ```
+    pub const fn replace_second(self, second: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(second in 0 => 59);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            second,
+            self.nanosecond,
+        ))
+    }
+
+    /// Replace the milliseconds within the second. Any sub-millisecond component is discarded.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!("1:02:03.004_005_006").replace_millisecond(7),
+    ///     Ok(time!("1:02:03.007"))
+    /// );
+    /// assert!(time!("1:02:03.004_005_006").replace_millisecond(1_000).is_err()); // Not valid.
+    /// ```
+    pub const fn replace_millisecond(
+        self,
+        millisecond: u16,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(millisecond in 0 => 999);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            self.second,
+            millisecond as u32 * 1_000_000,
+        ))
+    }
+
+    /// Replace the microseconds within the second. Any sub-microsecond component is discarded.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!("1:02:03.004_005_006").replace_microsecond(7_008),
+    ///     Ok(time!("1:02:03.007_008"))
+    /// );
+    /// assert!(time!("1:02:03.004_005_006").replace_microsecond(1_000_000).is_err()); // Not valid.
+    /// ```
+    pub const fn replace_microsecond(
+        self,
+        microsecond: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(microsecond in 0 => 999_999);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            self.second,
+            microsecond * 1_000,
+        ))
+    }
+
+    /// Replace the nanoseconds within the second.
+    ///
+    /// ```rust
+    /// # use time::macros::time;
+    /// assert_eq!(
+    ///     time!("1:02:03.004_005_006").replace_nanosecond(7_008_009),
+    ///     Ok(time!("1:02:03.007_008_009"))
+    /// );
+    /// assert!(time!("1:02:03.004_005_006").replace_nanosecond(1_000_000_000).is_err());
+    /// ```
+    pub const fn replace_nanosecond(self, nanosecond: u32) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(nanosecond in 0 => 999_999_999);
+        Ok(Self::__from_hms_nanos_unchecked(
+            self.hour,
+            self.minute,
+            self.second,
+            nanosecond,
+        ))
+    }
+}
+// endregion replacement
+
+// region: rounding
+/// Methods that truncate or round the `Time` to a [`Unit`].
+///
+/// Units of a day or longer always result in midnight.
+impl Time {
+    /// Truncate the `Time` to the start of the provided unit, discarding all smaller components.
+    ///
+    /// ```rust
+    /// # use time::{Unit, macros::time};
+    /// assert_eq!(time!("1:02:03.004").truncate(Unit::Second), time!("1:02:03"));
+    /// assert_eq!(time!("1:02:03.004").truncate(Unit::Hour), time!("1:00"));
+    /// assert_eq!(time!("1:02:03.004").truncate(Unit::Day), time!("0:00"));
+    /// ```
+    #[must_use = "This method does not mutate the original `Time`."]
+    pub const fn truncate(self, unit: Unit) -> Self {
+        match unit {
+            Unit::Microsecond => Self::__from_hms_nanos_unchecked(
+                self.hour,
+                self.minute,
+                self.second,
+                self.nanosecond - self.nanosecond % 1_000,
+            ),
+            Unit::Millisecond => Self::__from_hms_nanos_unchecked(
+                self.hour,
+                self.minute,
+                self.second,
+                self.nanosecond - self.nanosecond % 1_000_000,
+            ),
+            Unit::Second => {
+                Self::__from_hms_nanos_unchecked(self.hour, self.minute, self.second, 0)
+            }
+            Unit::Minute => Self::__from_hms_nanos_unchecked(self.hour, self.minute, 0, 0),
+            Unit::Hour => Self::__from_hms_nanos_unchecked(self.hour, 0, 0, 0),
+            _ => Self::MIDNIGHT,
+        }
+    }
+
+    /// Round the `Time` up to the next multiple of the provided unit. Values that are already a
+    /// multiple of the unit are returned unchanged. Like other arithmetic on `Time`, the result
+    /// wraps around to midnight.
+    ///
+    /// ```rust
+    /// # use time::{Unit, macros::time};
+    /// assert_eq!(time!("1:02:03.004").ceil(Unit::Second), time!("1:02:04"));
+    /// assert_eq!(time!("1:02:00").ceil(Unit::Minute), time!("1:02"));
+    /// assert_eq!(time!("23:30").ceil(Unit::Hour), time!("0:00"));
+    /// ```
+    #[must_use = "This method does not mutate the original `Time`."]
+    pub fn ceil(self, unit: Unit) -> Self {
+        let truncated = self.truncate(unit);
+        match unit.fixed_duration() {
+            Some(duration) if truncated != self && unit.is_time() => truncated + duration,
+            _ => truncated,
+        }
+    }
+
+    /// Round the `Time` to the nearest multiple of the provided unit. Values exactly halfway
+    /// between two multiples are rounded up. Like other arithmetic on `Time`, the result wraps
+    /// around to midnight.
+    ///
+    /// ```rust
+    /// # use time::{Unit, macros::time};
+    /// assert_eq!(time!("1:02:29.999").round(Unit::Minute), time!("1:02"));
+    /// assert_eq!(time!("1:02:30").round(Unit::Minute), time!("1:03"));
+    /// assert_eq!(time!("23:30").round(Unit::Hour), time!("0:00"));
+    /// ```
+    #[must_use = "This method does not mutate the original `Time`."]
+    pub fn round(self, unit: Unit) -> Self {
+        let truncated = self.truncate(unit);
+        match unit.fixed_duration() {
+            Some(duration) if unit.is_time() && (self - truncated) * 2 >= duration => {
+                truncated + duration
+            }
+            _ => truncated,
+        }
+    }
+}
+// endregion rounding
+
 // region: formatting & parsing
 #[cfg(feature = "formatting")]
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
@@ -439,7 +640,7 @@ impl Time {
         output: &mut impl io::Write,
         format: &F,
     ) -> Result<usize, F::Error> {
-        format.format_into(output, None, Some(self), None)
+        format.format_into(output, None, Some(self), None, None)
     }
 
     /// Format the `Time` using the provided format description. The format description will
@@ -453,7 +654,7 @@ impl Time {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
-        format.format(None, Some(self), None)
+        format.format(None, Some(self), None, None)
     }
 }
 
@@ -500,7 +701,9 @@ impl fmt::Display for Time {
         ];
         match self.format(&FORMAT) {
             Ok(ref s) => f.write_str(s),
-            Err(error::Format::InvalidComponent(_)) => {
+            Err(error::Format::InvalidComponent(_))
+            | Err(error::Format::Rfc2822YearBefore1900)
+            | Err(error::Format::Rfc2822OffsetSecond) => {
                 unreachable!("A well-known format is not used")
             }
             Err(error::Format::InsufficientTypeInformation) => {
@@ -0,0 +1,814 @@
+//! IANA time zones.
+//!
+//! A [`TimeZone`] is a set of rules describing the [`UtcOffset`] in use in a region at any given
+//! moment. Rules are loaded from compiled `TZif` files, such as those found in
+//! `/usr/share/zoneinfo` on most Unix-like systems, or from [POSIX TZ strings](PosixTz). With the
+//! `tzdb-embedded` feature, the IANA time zone database is also compiled into the binary.
+
+mod posix;
+#[cfg(feature = "tzdb-embedded")]
+pub(crate) mod tzdb;
+mod tzif;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+#[cfg(all(target_family = "unix", feature = "local-offset"))]
+use std::ffi::OsStr;
+#[cfg(feature = "std")]
+use std::path::{Component, Path};
+
+pub use self::posix::PosixTz;
+#[cfg(all(feature = "parsing", feature = "tzdb-embedded"))]
+use crate::parsing::parsable::sealed::Parsable;
+use crate::{error, OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// The directory time zones are loaded from when `TZDIR` is not set.
+#[cfg(feature = "std")]
+const DEFAULT_TZDIR: &str = "/usr/share/zoneinfo";
+
+/// A set of local time types that are in effect during a given period, as defined by the IANA time
+/// zone database.
+///
+/// ```rust,no_run
+/// # use time::{TimeZone, macros::{datetime, offset}};
+/// let new_york = TimeZone::load("America/New_York")?;
+/// assert_eq!(new_york.offset_at(datetime!("2021-01-01 12:00 UTC")), offset!("-5"));
+/// assert_eq!(new_york.offset_at(datetime!("2021-07-01 12:00 UTC")), offset!("-4"));
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZone {
+    /// The IANA name of the time zone, if known.
+    name: Option<String>,
+    /// All transitions between local time types, sorted in ascending order.
+    transitions: Vec<Transition>,
+    /// The local time types referred to by the transitions. There is always at least one.
+    local_time_types: Vec<LocalTimeType>,
+    /// The rules in effect after the last transition, if any.
+    posix_tz: Option<PosixTz>,
+}
+
+/// The result of interpreting a local datetime in a [`TimeZone`], as returned by
+/// [`PrimitiveDateTime::assume_timezone`].
+///
+/// Around a transition, a local datetime may occur twice, such as when clocks are set back at the
+/// end of daylight saving time, or not at all, such as when clocks are set forward at its start.
+///
+/// ```rust
+/// # use time::{macros::{datetime, offset}, time_zone::{LocalResult, PosixTz}, TimeZone};
+/// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+/// match datetime!("2021-11-07 1:30").assume_timezone(&new_york) {
+///     LocalResult::Ambiguous(earlier, later) => {
+///         assert_eq!(earlier.offset(), offset!("-4"));
+///         assert_eq!(later.offset(), offset!("-5"));
+///     }
+///     _ => panic!("1:30 occurs twice"),
+/// }
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalResult<T> {
+    /// The local datetime occurs exactly once.
+    Single(T),
+    /// The local datetime occurs twice. The earlier occurrence is first.
+    Ambiguous(T, T),
+    /// The local datetime does not occur, as it falls in a gap.
+    Gap {
+        /// The local datetime shifted backward by the length of the gap.
+        before: T,
+        /// The local datetime shifted forward by the length of the gap.
+        after: T,
+    },
+}
+
+/// The offsets with which a local datetime occurs in a [`TimeZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LocalOffsets {
+    /// The datetime occurs exactly once.
+    Single(UtcOffset),
+    /// The datetime occurs twice: first with the earlier offset, then with the later one.
+    Ambiguous(UtcOffset, UtcOffset),
+    /// The datetime is skipped. The offsets in effect before and after the gap are included.
+    Gap(UtcOffset, UtcOffset),
+}
+
+/// A moment at which the local time type of a [`TimeZone`] changes.
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Transition {
+    /// The moment of the transition, as a Unix timestamp.
+    pub(crate) unix_timestamp: i64,
+    /// The index of the local time type in effect from this transition onwards.
+    pub(crate) local_time_type: usize,
+}
+
+/// A moment at which the local time type of a [`TimeZone`] changes, along with the local time types
+/// in effect before and after it.
+///
+/// Unlike a [`Transition`], this may also be derived from the [POSIX TZ string](TimeZone::posix_tz)
+/// of the time zone, and is never a transition between identical local time types.
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZoneTransition<'a> {
+    /// The moment of the transition, in the offset in effect after it.
+    datetime: OffsetDateTime,
+    /// The local time type in effect before the transition.
+    before: &'a LocalTimeType,
+    /// The local time type in effect from the transition onwards.
+    after: &'a LocalTimeType,
+}
+
+/// An iterator over the transitions of a [`TimeZone`] in a given range, as returned by
+/// [`TimeZone::transitions`].
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct Transitions<'a> {
+    /// The time zone whose transitions are returned.
+    time_zone: &'a TimeZone,
+    /// The Unix timestamp that all remaining transitions are after.
+    after: i64,
+    /// The Unix timestamp that all remaining transitions are before.
+    before: i64,
+}
+
+/// A UTC offset, along with whether it is daylight saving time and its abbreviation.
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocalTimeType {
+    /// The offset from UTC.
+    pub(crate) offset: UtcOffset,
+    /// Whether the local time type is considered daylight saving time.
+    pub(crate) is_dst: bool,
+    /// The abbreviation of the local time type, such as `CEST`.
+    pub(crate) abbreviation: String,
+}
+
+impl TimeZone {
+    /// The release of the IANA time zone database that is embedded, such as `2021a`.
+    #[cfg(feature = "tzdb-embedded")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "tzdb-embedded")))]
+    pub const TZDB_VERSION: &'static str = tzdb::VERSION;
+
+    // region: constructors
+    /// Create a `TimeZone` from the contents of a `TZif` file. Versions 1 through 3 of the format
+    /// are supported, including the POSIX TZ string in the footer of versions 2 and 3.
+    ///
+    /// The returned value has no [name](TimeZone::name).
+    pub fn from_tzif(bytes: &[u8]) -> Result<Self, error::TimeZone> {
+        let tzif = tzif::parse(bytes)?;
+        Ok(Self {
+            name: None,
+            transitions: tzif.transitions,
+            local_time_types: tzif.local_time_types,
+            posix_tz: tzif.posix_tz,
+        })
+    }
+
+    /// Load the time zone with the provided IANA name, such as `America/New_York`.
+    ///
+    /// The `TZif` file is read from the directory named by the `TZDIR` environment variable, falling
+    /// back to `/usr/share/zoneinfo` if it is not set.
+    ///
+    /// ```rust,no_run
+    /// # use time::TimeZone;
+    /// let berlin = TimeZone::load("Europe/Berlin")?;
+    /// assert_eq!(berlin.name(), Some("Europe/Berlin"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn load(name: &str) -> Result<Self, error::TimeZone> {
+        let directory = std::env::var_os("TZDIR").unwrap_or_else(|| DEFAULT_TZDIR.into());
+        Self::load_from(directory, name)
+    }
+
+    /// Load the time zone with the provided IANA name, such as `America/New_York`, from the
+    /// provided directory.
+    ///
+    /// The name must be a relative path that does not contain `.` or `..` components.
+    ///
+    /// ```rust,no_run
+    /// # use time::TimeZone;
+    /// let berlin = TimeZone::load_from("/usr/share/zoneinfo", "Europe/Berlin")?;
+    /// assert_eq!(berlin.name(), Some("Europe/Berlin"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn load_from(directory: impl AsRef<Path>, name: &str) -> Result<Self, error::TimeZone> {
+        let relative_path = Path::new(name);
+        if name.is_empty()
+            || !relative_path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return Err(error::TimeZone::InvalidName);
+        }
+
+        let mut time_zone = Self::from_file(directory.as_ref().join(relative_path))?;
+        time_zone.name = Some(String::from(name));
+        Ok(time_zone)
+    }
+
+    /// Get the time zone with the provided IANA name, such as `Europe/Berlin`, from the embedded
+    /// time zone database. Links, such as `US/Eastern`, are also supported. If there is no time
+    /// zone with the name, `None` is returned.
+    ///
+    /// Unlike [`TimeZone::load`], this does not require access to the file system.
+    ///
+    /// ```rust
+    /// # use time::{TimeZone, macros::{datetime, offset}};
+    /// let berlin = TimeZone::get("Europe/Berlin").unwrap();
+    /// assert_eq!(berlin.name(), Some("Europe/Berlin"));
+    /// assert_eq!(berlin.offset_at(datetime!("2021-07-01 12:00 UTC")), offset!("+2"));
+    /// assert!(TimeZone::get("Europe/Atlantis").is_none());
+    /// ```
+    #[cfg(feature = "tzdb-embedded")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "tzdb-embedded")))]
+    pub fn get(name: &str) -> Option<Self> {
+        let mut time_zone = Self::from_tzif(tzdb::get(name)?).ok()?;
+        time_zone.name = Some(String::from(name));
+        Some(time_zone)
+    }
+
+    /// Get the names of all time zones in the embedded time zone database, including links, in
+    /// ascending order. Each can be passed to [`TimeZone::get`].
+    ///
+    /// ```rust
+    /// # use time::TimeZone;
+    /// assert!(TimeZone::names().any(|name| name == "America/New_York"));
+    /// ```
+    #[cfg(feature = "tzdb-embedded")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "tzdb-embedded")))]
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        tzdb::names()
+    }
+
+    /// Parse the time zone named in the input using the provided format description. The format
+    /// description must contain a `[tz_name]` component, whose value is looked up in the embedded
+    /// time zone database.
+    ///
+    /// ```rust
+    /// # use time::{format_description, TimeZone, ZonedDateTime};
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute] [tz_name]")?;
+    /// let input = "2021-03-01 12:00 Europe/Paris";
+    /// let paris = TimeZone::parse(input, &format)?;
+    /// assert_eq!(paris.name(), Some("Europe/Paris"));
+    /// let datetime = ZonedDateTime::parse(input, &format, &paris)?;
+    /// assert_eq!(datetime.format(&format)?, input);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(all(feature = "parsing", feature = "tzdb-embedded"))]
+    #[cfg_attr(
+        __time_03_docs,
+        doc(cfg(all(feature = "parsing", feature = "tzdb-embedded")))
+    )]
+    pub fn parse(input: &str, description: &impl Parsable) -> Result<Self, error::Parse> {
+        use core::convert::TryFrom;
+
+        Ok(Self::try_from(description.parse(input.as_bytes())?)?)
+    }
+
+    /// Load a time zone from the `TZif` file at the provided path.
+    ///
+    /// The returned value has no [name](TimeZone::name).
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, error::TimeZone> {
+        Self::from_tzif(&std::fs::read(path)?)
+    }
+
+    /// Create a `TimeZone` that is always UTC.
+    #[cfg(all(target_family = "unix", feature = "local-offset"))]
+    fn utc() -> Self {
+        Self {
+            name: Some(String::from("UTC")),
+            transitions: Vec::new(),
+            local_time_types: alloc::vec![LocalTimeType {
+                offset: UtcOffset::UTC,
+                is_dst: false,
+                abbreviation: String::from("UTC"),
+            }],
+            posix_tz: None,
+        }
+    }
+
+    /// Load the system's time zone in the same manner as the C library, without calling into it.
+    ///
+    /// If the `TZ` environment variable is set, its value (less any leading `:`) is used. An empty
+    /// value is UTC, an absolute path is read directly, and any other value is loaded as an IANA
+    /// name (from the embedded database if it is not found on disk) or, failing that, parsed as a
+    /// POSIX TZ string. Otherwise, `/etc/localtime` is read.
+    /// When a file inside a `zoneinfo` directory is read, either directly or via a symbolic link,
+    /// the name of the time zone is taken from its path.
+    #[cfg(all(target_family = "unix", feature = "local-offset"))]
+    pub(crate) fn local() -> Result<Self, error::TimeZone> {
+        Self::local_from(
+            std::env::var_os("TZ").as_deref(),
+            std::env::var_os("TZDIR").as_deref(),
+        )
+    }
+
+    /// Load the system's time zone as [`TimeZone::local`] does, using the provided values of the
+    /// `TZ` and `TZDIR` environment variables rather than reading them.
+    #[cfg(all(target_family = "unix", feature = "local-offset"))]
+    pub(crate) fn local_from(
+        tz: Option<&OsStr>,
+        tzdir: Option<&OsStr>,
+    ) -> Result<Self, error::TimeZone> {
+        /// The file describing the system's time zone.
+        const LOCALTIME: &str = "/etc/localtime";
+
+        /// Obtain the name of the time zone stored at the provided path, if it is in a `zoneinfo`
+        /// directory.
+        fn name_from_path(path: &Path) -> Option<String> {
+            /// The component preceding the name of the time zone.
+            const ZONEINFO: &str = "zoneinfo/";
+
+            let path = path.to_str()?;
+            let index = path.rfind(ZONEINFO)?;
+            Some(String::from(&path[index + ZONEINFO.len()..]))
+        }
+
+        let path = match tz {
+            Some(tz) => {
+                let tz = tz.to_str().ok_or(error::TimeZone::InvalidName)?;
+                let tz = tz.strip_prefix(':').unwrap_or(tz);
+                if tz.is_empty() {
+                    return Ok(Self::utc());
+                } else if !tz.starts_with('/') {
+                    let directory = tzdir.map_or_else(|| Path::new(DEFAULT_TZDIR), Path::new);
+                    return Self::load_from(directory, tz).or_else(|err| {
+                        #[cfg(feature = "tzdb-embedded")]
+                        if let Some(time_zone) = Self::get(tz) {
+                            return Ok(time_zone);
+                        }
+                        PosixTz::parse(tz).map_or(Err(err), |posix_tz| Ok(posix_tz.into()))
+                    });
+                }
+                Path::new(tz).to_path_buf()
+            }
+            None => Path::new(LOCALTIME).to_path_buf(),
+        };
+
+        let mut time_zone = Self::from_file(&path)?;
+        time_zone.name = std::fs::read_link(&path)
+            .ok()
+            .and_then(|target| name_from_path(&target))
+            .or_else(|| name_from_path(&path));
+        Ok(time_zone)
+    }
+    // endregion constructors
+
+    // region: getters
+    /// Get the IANA name of the time zone, if it is known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Get all transitions between local time types, in ascending order.
+    pub fn transition_table(&self) -> &[Transition] {
+        &self.transitions
+    }
+
+    /// Get all local time types used by the time zone. The local time type of a transition is an
+    /// index into this slice.
+    ///
+    /// This is guaranteed to be non-empty.
+    pub fn local_time_types(&self) -> &[LocalTimeType] {
+        &self.local_time_types
+    }
+
+    /// Get the rules in effect after the last transition, if any. For a `TZif` file, this is the
+    /// POSIX TZ string in its footer.
+    pub const fn posix_tz(&self) -> Option<&PosixTz> {
+        self.posix_tz.as_ref()
+    }
+    // endregion getters
+
+    // region: evaluation
+    /// Get the local time type in effect at the provided moment.
+    ///
+    /// Before the first transition, the first local time type is in effect. After the last
+    /// transition, the [POSIX TZ string](TimeZone::posix_tz) is used if present. Otherwise, the
+    /// local time type of the last transition remains in effect.
+    pub fn local_time_type_at(&self, datetime: OffsetDateTime) -> &LocalTimeType {
+        self.local_time_type_at_timestamp(datetime.unix_timestamp())
+    }
+
+    /// Get the [`UtcOffset`] in effect at the provided moment.
+    pub fn offset_at(&self, datetime: OffsetDateTime) -> UtcOffset {
+        self.local_time_type_at(datetime).offset
+    }
+
+    /// Get the local time type in effect at the provided Unix timestamp.
+    pub(crate) fn local_time_type_at_timestamp(&self, unix_timestamp: i64) -> &LocalTimeType {
+        let index = match self
+            .transitions
+            .binary_search_by_key(&unix_timestamp, |transition| transition.unix_timestamp)
+        {
+            Ok(index) => self.transitions[index].local_time_type,
+            Err(index) => {
+                if index == self.transitions.len() {
+                    if let Some(posix_tz) = &self.posix_tz {
+                        return posix_tz.local_time_type_at_timestamp(unix_timestamp);
+                    }
+                }
+                index
+                    .checked_sub(1)
+                    .map_or(0, |index| self.transitions[index].local_time_type)
+            }
+        };
+        &self.local_time_types[index]
+    }
+
+    /// Determine the offsets with which the provided local datetime occurs.
+    ///
+    /// Only the offsets in effect a day either side of the datetime are considered, so multiple
+    /// transitions in quick succession are not detected.
+    pub(crate) fn local_offsets(&self, datetime: PrimitiveDateTime) -> LocalOffsets {
+        let local_timestamp = datetime.assume_utc().unix_timestamp();
+        let offset_at =
+            |unix_timestamp: i64| self.local_time_type_at_timestamp(unix_timestamp).offset;
+        let is_valid = |offset: UtcOffset| {
+            offset_at(local_timestamp - offset.whole_seconds() as i64) == offset
+        };
+
+        let earlier = offset_at(local_timestamp - 86_400);
+        let later = offset_at(local_timestamp + 86_400);
+        match (is_valid(earlier), is_valid(later)) {
+            (true, true) if earlier != later => LocalOffsets::Ambiguous(earlier, later),
+            (true, _) => LocalOffsets::Single(earlier),
+            (false, true) => LocalOffsets::Single(later),
+            (false, false) => LocalOffsets::Gap(earlier, later),
+        }
+    }
+    // endregion evaluation
+
+    // region: transitions
+    /// Get the first transition strictly after the provided moment, if any.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let transition = new_york
+    ///     .next_transition(datetime!("2021-07-01 0:00 UTC"))
+    ///     .unwrap();
+    /// assert_eq!(transition.datetime(), datetime!("2021-11-07 1:00 -5"));
+    /// assert_eq!(transition.offset_before(), offset!("-4"));
+    /// assert_eq!(transition.offset_after(), offset!("-5"));
+    /// assert_eq!(transition.abbreviation(), "EST");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn next_transition(&self, datetime: OffsetDateTime) -> Option<ZoneTransition<'_>> {
+        self.transition_after(datetime.unix_timestamp())
+    }
+
+    /// Get the last transition strictly before the provided moment, if any.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let transition = new_york
+    ///     .previous_transition(datetime!("2021-07-01 0:00 UTC"))
+    ///     .unwrap();
+    /// assert_eq!(transition.datetime(), datetime!("2021-03-14 3:00 -4"));
+    /// assert_eq!(transition.offset_before(), offset!("-5"));
+    /// assert_eq!(transition.offset_after(), offset!("-4"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn previous_transition(&self, datetime: OffsetDateTime) -> Option<ZoneTransition<'_>> {
+        self.transition_before(ceil_unix_timestamp(datetime))
+    }
+
+    /// Get an iterator over all transitions at or after `start` and strictly before `end`, in
+    /// ascending order. The iterator can also be consumed from the end.
+    ///
+    /// Transitions from both the [transition table](TimeZone::transition_table) and the
+    /// [POSIX TZ string](TimeZone::posix_tz) are included. Transitions that do not change the
+    /// local time type are not.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let transitions = new_york
+    ///     .transitions(datetime!("2021-01-01 0:00 UTC"), datetime!("2023-01-01 0:00 UTC"))
+    ///     .map(|transition| transition.datetime())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     transitions,
+    ///     [
+    ///         datetime!("2021-03-14 3:00 -4"),
+    ///         datetime!("2021-11-07 1:00 -5"),
+    ///         datetime!("2022-03-13 3:00 -4"),
+    ///         datetime!("2022-11-06 1:00 -5"),
+    ///     ]
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn transitions(&self, start: OffsetDateTime, end: OffsetDateTime) -> Transitions<'_> {
+        Transitions {
+            time_zone: self,
+            after: ceil_unix_timestamp(start) - 1,
+            before: ceil_unix_timestamp(end),
+        }
+    }
+
+    /// Get the transition at the provided Unix timestamp, if the local time type changes then.
+    fn transition_at(&self, unix_timestamp: i64) -> Option<ZoneTransition<'_>> {
+        let before = self.local_time_type_at_timestamp(unix_timestamp.saturating_sub(1));
+        let after = self.local_time_type_at_timestamp(unix_timestamp);
+        if before == after {
+            return None;
+        }
+
+        Some(ZoneTransition {
+            datetime: OffsetDateTime::from_unix_timestamp(unix_timestamp)
+                .ok()?
+                .to_offset(after.offset),
+            before,
+            after,
+        })
+    }
+
+    /// Get the first transition strictly after the provided Unix timestamp.
+    fn transition_after(&self, unix_timestamp: i64) -> Option<ZoneTransition<'_>> {
+        let index = match self
+            .transitions
+            .binary_search_by_key(&unix_timestamp, |transition| transition.unix_timestamp)
+        {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        };
+        if let Some(transition) = self.transitions[index..]
+            .iter()
+            .find_map(|transition| self.transition_at(transition.unix_timestamp))
+        {
+            return Some(transition);
+        }
+
+        // The POSIX TZ string only applies after the last transition in the table.
+        let posix_tz = self.posix_tz.as_ref()?;
+        let after = self.transitions.last().map_or(unix_timestamp, |last| {
+            last.unix_timestamp.max(unix_timestamp)
+        });
+        let mut year = OffsetDateTime::from_unix_timestamp(after).ok()?.year() - 1;
+        loop {
+            let candidates = posix_tz.transitions_in_year(year)?;
+            let mut unchanged = 0;
+            for &candidate in &candidates {
+                if candidate <= after {
+                    continue;
+                }
+                match self.transition_at(candidate) {
+                    Some(transition) => return Some(transition),
+                    None => unchanged += 1,
+                }
+            }
+            // The rules are the same every year, so they will never change the local time type.
+            if unchanged == 2 {
+                return None;
+            }
+            year += 1;
+        }
+    }
+
+    /// Get the last transition strictly before the provided Unix timestamp.
+    fn transition_before(&self, unix_timestamp: i64) -> Option<ZoneTransition<'_>> {
+        let last = self.transitions.last().map(|last| last.unix_timestamp);
+
+        // The POSIX TZ string only applies after the last transition in the table.
+        if let (Some(posix_tz), Ok(datetime)) = (
+            &self.posix_tz,
+            OffsetDateTime::from_unix_timestamp(unix_timestamp.saturating_sub(1)),
+        ) {
+            let mut year = datetime.year() + 1;
+            'years: while let Some(candidates) = posix_tz.transitions_in_year(year) {
+                let mut unchanged = 0;
+                for &candidate in candidates.iter().rev() {
+                    if candidate >= unix_timestamp {
+                        continue;
+                    }
+                    if matches!(last, Some(last) if candidate <= last) {
+                        break 'years;
+                    }
+                    match self.transition_at(candidate) {
+                        Some(transition) => return Some(transition),
+                        None => unchanged += 1,
+                    }
+                }
+                // The rules are the same every year, so they will never change the local time
+                // type.
+                if unchanged == 2 {
+                    break;
+                }
+                year -= 1;
+            }
+        }
+
+        let index = match self
+            .transitions
+            .binary_search_by_key(&unix_timestamp, |transition| transition.unix_timestamp)
+        {
+            Ok(index) | Err(index) => index,
+        };
+        self.transitions[..index]
+            .iter()
+            .rev()
+            .find_map(|transition| self.transition_at(transition.unix_timestamp))
+    }
+    // endregion transitions
+}
+
+impl From<PosixTz> for TimeZone {
+    fn from(posix_tz: PosixTz) -> Self {
+        let mut local_time_types = alloc::vec![posix_tz.standard_time().clone()];
+        local_time_types.extend(posix_tz.daylight_saving_time().cloned());
+        Self {
+            name: None,
+            transitions: Vec::new(),
+            local_time_types,
+            posix_tz: Some(posix_tz),
+        }
+    }
+}
+
+impl<T> LocalResult<T> {
+    /// Get the value if the local datetime occurs exactly once, rejecting ambiguous and
+    /// nonexistent datetimes.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// assert!(datetime!("2021-07-01 12:00").assume_timezone(&new_york).single().is_some());
+    /// assert!(datetime!("2021-11-07 1:30").assume_timezone(&new_york).single().is_none());
+    /// assert!(datetime!("2021-03-14 2:30").assume_timezone(&new_york).single().is_none());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // `T` may need to be dropped
+    pub fn single(self) -> Option<T> {
+        match self {
+            Self::Single(value) => Some(value),
+            Self::Ambiguous(..) | Self::Gap { .. } => None,
+        }
+    }
+
+    /// Get the earliest value. An ambiguous datetime resolves to its earlier occurrence, and a
+    /// nonexistent datetime is shifted backward by the length of the gap.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-11-07 1:30").assume_timezone(&new_york).earliest();
+    /// assert_eq!(datetime.offset(), offset!("-4"));
+    /// let datetime = datetime!("2021-03-14 2:30").assume_timezone(&new_york).earliest();
+    /// assert_eq!(datetime.datetime(), datetime!("2021-03-14 1:30"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // `T` may need to be dropped
+    pub fn earliest(self) -> T {
+        match self {
+            Self::Single(value) | Self::Ambiguous(value, _) | Self::Gap { before: value, .. } => {
+                value
+            }
+        }
+    }
+
+    /// Get the latest value. An ambiguous datetime resolves to its later occurrence, and a
+    /// nonexistent datetime is shifted forward by the length of the gap.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-11-07 1:30").assume_timezone(&new_york).latest();
+    /// assert_eq!(datetime.offset(), offset!("-5"));
+    /// let datetime = datetime!("2021-03-14 2:30").assume_timezone(&new_york).latest();
+    /// assert_eq!(datetime.datetime(), datetime!("2021-03-14 3:30"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // `T` may need to be dropped
+    pub fn latest(self) -> T {
+        match self {
+            Self::Single(value) | Self::Ambiguous(_, value) | Self::Gap { after: value, .. } => {
+                value
+            }
+        }
+    }
+
+    /// Get the value a wall clock would show. An ambiguous datetime resolves to its earlier
+    /// occurrence, and a nonexistent datetime is shifted forward by the length of the gap.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-11-07 1:30").assume_timezone(&new_york).shift_forward();
+    /// assert_eq!(datetime.offset(), offset!("-4"));
+    /// let datetime = datetime!("2021-03-14 2:30").assume_timezone(&new_york).shift_forward();
+    /// assert_eq!(datetime.datetime(), datetime!("2021-03-14 3:30"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[allow(clippy::missing_const_for_fn)] // `T` may need to be dropped
+    pub fn shift_forward(self) -> T {
+        match self {
+            Self::Single(value) | Self::Ambiguous(value, _) | Self::Gap { after: value, .. } => {
+                value
+            }
+        }
+    }
+}
+
+impl<'a> ZoneTransition<'a> {
+    /// Get the moment of the transition, in the offset in effect after it.
+    pub const fn datetime(self) -> OffsetDateTime {
+        self.datetime
+    }
+
+    /// Get the offset in effect before the transition.
+    pub const fn offset_before(self) -> UtcOffset {
+        self.before.offset
+    }
+
+    /// Get the offset in effect from the transition onwards.
+    pub const fn offset_after(self) -> UtcOffset {
+        self.after.offset
+    }
+
+    /// Get the abbreviation in effect from the transition onwards, such as `CEST`.
+    pub fn abbreviation(self) -> &'a str {
+        &self.after.abbreviation
+    }
+
+    /// Get the local time type in effect before the transition.
+    pub const fn local_time_type_before(self) -> &'a LocalTimeType {
+        self.before
+    }
+
+    /// Get the local time type in effect from the transition onwards.
+    pub const fn local_time_type_after(self) -> &'a LocalTimeType {
+        self.after
+    }
+}
+
+impl<'a> Iterator for Transitions<'a> {
+    type Item = ZoneTransition<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let transition = self
+            .time_zone
+            .transition_after(self.after)
+            .filter(|transition| transition.datetime.unix_timestamp() < self.before)?;
+        self.after = transition.datetime.unix_timestamp();
+        Some(transition)
+    }
+}
+
+impl DoubleEndedIterator for Transitions<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let transition = self
+            .time_zone
+            .transition_before(self.before)
+            .filter(|transition| transition.datetime.unix_timestamp() > self.after)?;
+        self.before = transition.datetime.unix_timestamp();
+        Some(transition)
+    }
+}
+
+impl FusedIterator for Transitions<'_> {}
+
+/// Get the Unix timestamp of the provided moment, rounded up to the next whole second.
+const fn ceil_unix_timestamp(datetime: OffsetDateTime) -> i64 {
+    datetime.unix_timestamp() + (datetime.nanosecond() != 0) as i64
+}
+
+impl Transition {
+    /// Get the moment of the transition as a Unix timestamp.
+    pub const fn unix_timestamp(self) -> i64 {
+        self.unix_timestamp
+    }
+
+    /// Get the index into [`TimeZone::local_time_types`] of the local time type in effect from
+    /// this transition onwards.
+    pub const fn local_time_type(self) -> usize {
+        self.local_time_type
+    }
+}
+
+impl LocalTimeType {
+    /// Get the offset from UTC.
+    pub const fn offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// Whether the local time type is considered daylight saving time.
+    pub const fn is_dst(&self) -> bool {
+        self.is_dst
+    }
+
+    /// Get the abbreviation of the local time type, such as `CEST`.
+    pub fn abbreviation(&self) -> &str {
+        &self.abbreviation
+    }
+}
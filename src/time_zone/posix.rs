@@ -0,0 +1,421 @@
+//! POSIX TZ strings, as found in the `TZ` environment variable and the footer of `TZif` files.
+
+use alloc::string::String;
+use core::convert::TryFrom;
+
+use crate::time_zone::LocalTimeType;
+use crate::util::{days_in_year_month, is_leap_year};
+use crate::{error, Date, Month, OffsetDateTime, UtcOffset, Weekday};
+
+/// The Julian day of the Unix epoch.
+const UNIX_EPOCH_JULIAN_DAY: i64 =
+    Date::__from_ordinal_date_unchecked(1970, 1).to_julian_day() as i64;
+
+/// A time zone described by a POSIX TZ string, such as `EST5EDT,M3.2.0,M11.1.0`.
+///
+/// This consists of a standard time and optionally a daylight saving time, along with the rules
+/// describing when the latter is in effect each year. The extensions to the format described in
+/// [RFC 8536](https://tools.ietf.org/html/rfc8536#section-3.3.1) are supported.
+///
+/// ```rust
+/// # use time::{time_zone::PosixTz, macros::{datetime, offset}};
+/// let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+/// let winter = tz.local_time_type_at(datetime!("2021-01-01 12:00 UTC"));
+/// assert_eq!(winter.offset(), offset!("-5"));
+/// assert_eq!(winter.abbreviation(), "EST");
+/// let summer = tz.local_time_type_at(datetime!("2021-07-01 12:00 UTC"));
+/// assert_eq!(summer.offset(), offset!("-4"));
+/// assert_eq!(summer.abbreviation(), "EDT");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosixTz {
+    /// The local time type of standard time.
+    std: LocalTimeType,
+    /// Daylight saving time, if it is ever in effect.
+    dst: Option<Dst>,
+}
+
+/// Daylight saving time, along with the rules for when it is in effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Dst {
+    /// The local time type of daylight saving time.
+    local_time_type: LocalTimeType,
+    /// When daylight saving time starts each year, in standard time.
+    start: Rule,
+    /// When daylight saving time ends each year, in daylight saving time.
+    end: Rule,
+}
+
+/// The moment a transition occurs each year, in the local time preceding the transition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rule {
+    /// The day of the year the transition occurs.
+    day: RuleDay,
+    /// The number of seconds after midnight the transition occurs. This may be negative or exceed
+    /// a day.
+    time: i32,
+}
+
+/// A day of the year on which a transition occurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDay {
+    /// `Jn`: A one-based day of the year, where February 29 is never counted.
+    JulianIgnoringLeapDay(u16),
+    /// `n`: A zero-based day of the year, where February 29 is counted in leap years.
+    ZeroBased(u16),
+    /// `Mm.w.d`: The `week`th `weekday` of `month`. A week of `5` indicates the last such day.
+    MonthWeekDay {
+        /// The month.
+        month: Month,
+        /// The week, from 1 to 5.
+        week: u8,
+        /// The day of the week.
+        weekday: Weekday,
+    },
+}
+
+impl RuleDay {
+    /// Get the date on which the transition occurs in the provided year.
+    fn date(self, year: i32) -> Option<Date> {
+        match self {
+            Self::JulianIgnoringLeapDay(day) if is_leap_year(year) && day >= 60 => {
+                Date::from_ordinal_date(year, day + 1).ok()
+            }
+            Self::JulianIgnoringLeapDay(day) => Date::from_ordinal_date(year, day).ok(),
+            Self::ZeroBased(day) => Date::from_julian_day(
+                Date::from_ordinal_date(year, 1).ok()?.to_julian_day() + day as i32,
+            )
+            .ok(),
+            Self::MonthWeekDay {
+                month,
+                week,
+                weekday,
+            } => {
+                let first = Date::from_calendar_date(year, month, 1).ok()?;
+                let mut day = 1
+                    + (weekday.number_days_from_sunday() + 7
+                        - first.weekday().number_days_from_sunday())
+                        % 7
+                    + (week - 1) * 7;
+                if day > days_in_year_month(year, month) {
+                    day -= 7;
+                }
+                Date::from_calendar_date(year, month, day).ok()
+            }
+        }
+    }
+}
+
+impl Rule {
+    /// Get the Unix timestamp at which the transition occurs in the provided year, given the
+    /// offset in effect before the transition.
+    fn unix_timestamp(self, year: i32, offset: UtcOffset) -> Option<i64> {
+        let date = self.day.date(year)?;
+        Some(
+            (date.to_julian_day() as i64 - UNIX_EPOCH_JULIAN_DAY) * 86_400 + self.time as i64
+                - offset.whole_seconds() as i64,
+        )
+    }
+}
+
+impl Dst {
+    /// Get the Unix timestamps at which daylight saving time starts and ends in the provided year.
+    fn transitions_in_year(&self, year: i32, std_offset: UtcOffset) -> Option<(i64, i64)> {
+        Some((
+            self.start.unix_timestamp(year, std_offset)?,
+            self.end.unix_timestamp(year, self.local_time_type.offset)?,
+        ))
+    }
+
+    /// Whether daylight saving time is in effect at the provided Unix timestamp.
+    fn is_in_effect(&self, unix_timestamp: i64, std_offset: UtcOffset) -> Option<bool> {
+        let local_day = div_floor!(unix_timestamp + std_offset.whole_seconds() as i64, 86_400);
+        let year = Date::from_julian_day((local_day + UNIX_EPOCH_JULIAN_DAY) as _)
+            .ok()?
+            .year();
+        let (start, end) = self.transitions_in_year(year, std_offset)?;
+
+        if start < end {
+            Some(start <= unix_timestamp && unix_timestamp < end)
+        } else {
+            // Daylight saving time is in effect over the new year, as in the southern hemisphere.
+            Some(!(end <= unix_timestamp && unix_timestamp < start))
+        }
+    }
+}
+
+impl PosixTz {
+    /// Parse a POSIX TZ string, such as `EST5EDT,M3.2.0,M11.1.0` or `<+0330>-3:30`.
+    ///
+    /// If a daylight saving time is present without any rules, the rules of the United States
+    /// (`M3.2.0,M11.1.0`) are used.
+    ///
+    /// ```rust
+    /// # use time::{time_zone::PosixTz, macros::offset};
+    /// let tz = PosixTz::parse("<+0330>-3:30")?;
+    /// assert_eq!(tz.standard_time().offset(), offset!("+3:30"));
+    /// assert_eq!(tz.standard_time().abbreviation(), "+0330");
+    /// assert!(tz.daylight_saving_time().is_none());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, error::TimeZone> {
+        let mut parser = Parser(s.as_bytes());
+
+        let std = LocalTimeType {
+            abbreviation: parser.name()?,
+            offset: parser.offset()?,
+            is_dst: false,
+        };
+        if parser.0.is_empty() {
+            return Ok(Self { std, dst: None });
+        }
+
+        let abbreviation = parser.name()?;
+        let offset = match parser.0.first() {
+            Some(b',') | None => UtcOffset::from_whole_seconds(std.offset.whole_seconds() + 3_600)
+                .map_err(|_| error::TimeZone::InvalidPosixTz("offset out of range"))?,
+            Some(_) => parser.offset()?,
+        };
+        let (start, end) = if parser.0.is_empty() {
+            (
+                Rule {
+                    day: RuleDay::MonthWeekDay {
+                        month: Month::March,
+                        week: 2,
+                        weekday: Weekday::Sunday,
+                    },
+                    time: 7_200,
+                },
+                Rule {
+                    day: RuleDay::MonthWeekDay {
+                        month: Month::November,
+                        week: 1,
+                        weekday: Weekday::Sunday,
+                    },
+                    time: 7_200,
+                },
+            )
+        } else {
+            parser.expect(b',')?;
+            let start = parser.rule()?;
+            parser.expect(b',')?;
+            (start, parser.rule()?)
+        };
+
+        if !parser.0.is_empty() {
+            return Err(error::TimeZone::InvalidPosixTz(
+                "unexpected trailing characters",
+            ));
+        }
+
+        Ok(Self {
+            std,
+            dst: Some(Dst {
+                local_time_type: LocalTimeType {
+                    offset,
+                    is_dst: true,
+                    abbreviation,
+                },
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// Get the local time type of standard time.
+    pub const fn standard_time(&self) -> &LocalTimeType {
+        &self.std
+    }
+
+    /// Get the local time type of daylight saving time, if it is ever in effect.
+    pub fn daylight_saving_time(&self) -> Option<&LocalTimeType> {
+        self.dst.as_ref().map(|dst| &dst.local_time_type)
+    }
+
+    /// Get the local time type in effect at the provided moment.
+    pub fn local_time_type_at(&self, datetime: OffsetDateTime) -> &LocalTimeType {
+        self.local_time_type_at_timestamp(datetime.unix_timestamp())
+    }
+
+    /// Get the [`UtcOffset`] in effect at the provided moment.
+    ///
+    /// ```rust
+    /// # use time::{time_zone::PosixTz, macros::{datetime, offset}};
+    /// let tz = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3")?;
+    /// assert_eq!(tz.offset_at(datetime!("2021-01-01 0:00 UTC")), offset!("+11"));
+    /// assert_eq!(tz.offset_at(datetime!("2021-07-01 0:00 UTC")), offset!("+10"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn offset_at(&self, datetime: OffsetDateTime) -> UtcOffset {
+        self.local_time_type_at(datetime).offset
+    }
+
+    /// Get the Unix timestamps at which daylight saving time starts and ends in the provided year,
+    /// in ascending order. If daylight saving time is never in effect, `None` is returned.
+    pub(crate) fn transitions_in_year(&self, year: i32) -> Option<[i64; 2]> {
+        let (start, end) = self
+            .dst
+            .as_ref()?
+            .transitions_in_year(year, self.std.offset)?;
+        Some([start.min(end), start.max(end)])
+    }
+
+    /// Get the local time type in effect at the provided Unix timestamp.
+    ///
+    /// Should the rules not be able to be evaluated, which can only occur near the limits of
+    /// [`Date`], standard time is used.
+    pub(crate) fn local_time_type_at_timestamp(&self, unix_timestamp: i64) -> &LocalTimeType {
+        match &self.dst {
+            Some(dst) if dst.is_in_effect(unix_timestamp, self.std.offset) == Some(true) => {
+                &dst.local_time_type
+            }
+            _ => &self.std,
+        }
+    }
+}
+
+/// A parser over the remaining bytes of a POSIX TZ string.
+#[derive(Debug)]
+struct Parser<'a>(&'a [u8]);
+
+impl<'a> Parser<'a> {
+    /// Consume the provided byte.
+    fn expect(&mut self, byte: u8) -> Result<(), error::TimeZone> {
+        match self.0.split_first() {
+            Some((&first, remaining)) if first == byte => {
+                self.0 = remaining;
+                Ok(())
+            }
+            _ => Err(error::TimeZone::InvalidPosixTz("unexpected character")),
+        }
+    }
+
+    /// Consume the longest prefix for which the predicate holds.
+    fn take_while(&mut self, predicate: impl Fn(u8) -> bool) -> &'a [u8] {
+        let len = self
+            .0
+            .iter()
+            .position(|&byte| !predicate(byte))
+            .unwrap_or(self.0.len());
+        let (taken, remaining) = self.0.split_at(len);
+        self.0 = remaining;
+        taken
+    }
+
+    /// Consume a number of at most `max_digits` digits that is no greater than `max`.
+    fn number(&mut self, max_digits: usize, max: u16) -> Result<u16, error::TimeZone> {
+        let digits = self.take_while(|byte| byte.is_ascii_digit());
+        if digits.is_empty() || digits.len() > max_digits {
+            return Err(error::TimeZone::InvalidPosixTz("invalid number"));
+        }
+        let value = digits
+            .iter()
+            .fold(0, |value, &digit| value * 10 + (digit - b'0') as u16);
+        if value > max {
+            return Err(error::TimeZone::InvalidPosixTz("number out of range"));
+        }
+        Ok(value)
+    }
+
+    /// Consume an abbreviation, which is either at least three alphabetic characters or at least
+    /// three alphanumeric characters, `+`, or `-` enclosed in angle brackets.
+    fn name(&mut self) -> Result<String, error::TimeZone> {
+        let name = if self.0.first() == Some(&b'<') {
+            self.expect(b'<')?;
+            let name = self
+                .take_while(|byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'-');
+            self.expect(b'>')?;
+            name
+        } else {
+            self.take_while(|byte| byte.is_ascii_alphabetic())
+        };
+
+        if name.len() < 3 {
+            return Err(error::TimeZone::InvalidPosixTz("invalid abbreviation"));
+        }
+        Ok(name.iter().map(|&byte| byte as char).collect())
+    }
+
+    /// Consume a signed duration of the form `[+-]hh[:mm[:ss]]`, returning the number of seconds.
+    fn duration(&mut self, max_hours: u16) -> Result<i32, error::TimeZone> {
+        let is_negative = match self.0.first() {
+            Some(b'-') => true,
+            Some(b'+') => false,
+            _ => return self.unsigned_duration(max_hours),
+        };
+        self.0 = &self.0[1..];
+        let seconds = self.unsigned_duration(max_hours)?;
+        Ok(if is_negative { -seconds } else { seconds })
+    }
+
+    /// Consume an unsigned duration of the form `hh[:mm[:ss]]`, returning the number of seconds.
+    fn unsigned_duration(&mut self, max_hours: u16) -> Result<i32, error::TimeZone> {
+        let mut seconds = self.number(3, max_hours)? as i32 * 3_600;
+        if self.0.first() == Some(&b':') {
+            self.expect(b':')?;
+            seconds += self.number(2, 59)? as i32 * 60;
+            if self.0.first() == Some(&b':') {
+                self.expect(b':')?;
+                seconds += self.number(2, 59)? as i32;
+            }
+        }
+        Ok(seconds)
+    }
+
+    /// Consume an offset. The sign is inverted relative to [`UtcOffset`], so that `5` is five
+    /// hours _west_ of UTC.
+    fn offset(&mut self) -> Result<UtcOffset, error::TimeZone> {
+        UtcOffset::from_whole_seconds(-self.duration(24)?)
+            .map_err(|_| error::TimeZone::InvalidPosixTz("offset out of range"))
+    }
+
+    /// Consume a rule of the form `date[/time]`.
+    fn rule(&mut self) -> Result<Rule, error::TimeZone> {
+        let day = match self.0.first() {
+            Some(b'J') => {
+                self.expect(b'J')?;
+                match self.number(3, 365)? {
+                    0 => return Err(error::TimeZone::InvalidPosixTz("number out of range")),
+                    day => RuleDay::JulianIgnoringLeapDay(day),
+                }
+            }
+            Some(b'M') => {
+                self.expect(b'M')?;
+                let month = Month::try_from(self.number(2, 12)? as u8)
+                    .map_err(|_| error::TimeZone::InvalidPosixTz("number out of range"))?;
+                self.expect(b'.')?;
+                let week = self.number(1, 5)? as u8;
+                self.expect(b'.')?;
+                let weekday = match self.number(1, 6)? {
+                    0 => Weekday::Sunday,
+                    1 => Weekday::Monday,
+                    2 => Weekday::Tuesday,
+                    3 => Weekday::Wednesday,
+                    4 => Weekday::Thursday,
+                    5 => Weekday::Friday,
+                    _ => Weekday::Saturday,
+                };
+                if week == 0 {
+                    return Err(error::TimeZone::InvalidPosixTz("number out of range"));
+                }
+                RuleDay::MonthWeekDay {
+                    month,
+                    week,
+                    weekday,
+                }
+            }
+            _ => RuleDay::ZeroBased(self.number(3, 365)?),
+        };
+
+        let time = if self.0.first() == Some(&b'/') {
+            self.expect(b'/')?;
+            self.duration(167)?
+        } else {
+            7_200
+        };
+
+        Ok(Rule { day, time })
+    }
+}
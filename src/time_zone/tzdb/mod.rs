@@ -0,0 +1,26 @@
+//! The IANA time zone database, embedded in the binary.
+
+mod zones;
+
+pub(super) use self::zones::VERSION;
+
+/// Get the `TZif` data of the zone or link with the provided name.
+pub(super) fn get(name: &str) -> Option<&'static [u8]> {
+    let index = zones::NAMES
+        .binary_search_by_key(&name, |&(name, _)| name)
+        .ok()?;
+    Some(zones::DATA[zones::NAMES[index].1])
+}
+
+/// Get the names of all zones and links, in ascending order.
+pub(super) fn names() -> impl Iterator<Item = &'static str> {
+    zones::NAMES.iter().map(|&(name, _)| name)
+}
+
+/// Get the longest name of a zone or link that the input begins with.
+#[cfg(feature = "parsing")]
+pub(crate) fn longest_name_prefix(input: &[u8]) -> Option<&'static str> {
+    names()
+        .filter(|name| input.starts_with(name.as_bytes()))
+        .max_by_key(|name| name.len())
+}
@@ -0,0 +1,1057 @@
+//! The IANA time zone database. This file is generated by `scripts/generate-tzdb.sh`.
+
+/// The release of the time zone database.
+pub(crate) const VERSION: &str = "2025b";
+
+/// The `TZif` data of each zone.
+pub(crate) static DATA: &[&[u8]] = &[
+    include_bytes!("data/Africa/Abidjan"),
+    include_bytes!("data/Africa/Accra"),
+    include_bytes!("data/Africa/Addis_Ababa"),
+    include_bytes!("data/Africa/Algiers"),
+    include_bytes!("data/Africa/Asmara"),
+    include_bytes!("data/Africa/Bamako"),
+    include_bytes!("data/Africa/Bangui"),
+    include_bytes!("data/Africa/Banjul"),
+    include_bytes!("data/Africa/Bissau"),
+    include_bytes!("data/Africa/Blantyre"),
+    include_bytes!("data/Africa/Brazzaville"),
+    include_bytes!("data/Africa/Bujumbura"),
+    include_bytes!("data/Africa/Cairo"),
+    include_bytes!("data/Africa/Casablanca"),
+    include_bytes!("data/Africa/Ceuta"),
+    include_bytes!("data/Africa/Conakry"),
+    include_bytes!("data/Africa/Dakar"),
+    include_bytes!("data/Africa/Dar_es_Salaam"),
+    include_bytes!("data/Africa/Djibouti"),
+    include_bytes!("data/Africa/Douala"),
+    include_bytes!("data/Africa/El_Aaiun"),
+    include_bytes!("data/Africa/Freetown"),
+    include_bytes!("data/Africa/Gaborone"),
+    include_bytes!("data/Africa/Harare"),
+    include_bytes!("data/Africa/Johannesburg"),
+    include_bytes!("data/Africa/Juba"),
+    include_bytes!("data/Africa/Kampala"),
+    include_bytes!("data/Africa/Khartoum"),
+    include_bytes!("data/Africa/Kigali"),
+    include_bytes!("data/Africa/Kinshasa"),
+    include_bytes!("data/Africa/Lagos"),
+    include_bytes!("data/Africa/Libreville"),
+    include_bytes!("data/Africa/Lome"),
+    include_bytes!("data/Africa/Luanda"),
+    include_bytes!("data/Africa/Lubumbashi"),
+    include_bytes!("data/Africa/Lusaka"),
+    include_bytes!("data/Africa/Malabo"),
+    include_bytes!("data/Africa/Maputo"),
+    include_bytes!("data/Africa/Maseru"),
+    include_bytes!("data/Africa/Mbabane"),
+    include_bytes!("data/Africa/Mogadishu"),
+    include_bytes!("data/Africa/Monrovia"),
+    include_bytes!("data/Africa/Nairobi"),
+    include_bytes!("data/Africa/Ndjamena"),
+    include_bytes!("data/Africa/Niamey"),
+    include_bytes!("data/Africa/Nouakchott"),
+    include_bytes!("data/Africa/Ouagadougou"),
+    include_bytes!("data/Africa/Porto-Novo"),
+    include_bytes!("data/Africa/Sao_Tome"),
+    include_bytes!("data/Africa/Tripoli"),
+    include_bytes!("data/Africa/Tunis"),
+    include_bytes!("data/Africa/Windhoek"),
+    include_bytes!("data/America/Adak"),
+    include_bytes!("data/America/Anchorage"),
+    include_bytes!("data/America/Anguilla"),
+    include_bytes!("data/America/Antigua"),
+    include_bytes!("data/America/Araguaina"),
+    include_bytes!("data/America/Argentina/Buenos_Aires"),
+    include_bytes!("data/America/Argentina/Catamarca"),
+    include_bytes!("data/America/Argentina/Cordoba"),
+    include_bytes!("data/America/Argentina/Jujuy"),
+    include_bytes!("data/America/Argentina/La_Rioja"),
+    include_bytes!("data/America/Argentina/Mendoza"),
+    include_bytes!("data/America/Argentina/Rio_Gallegos"),
+    include_bytes!("data/America/Argentina/Salta"),
+    include_bytes!("data/America/Argentina/San_Juan"),
+    include_bytes!("data/America/Argentina/San_Luis"),
+    include_bytes!("data/America/Argentina/Tucuman"),
+    include_bytes!("data/America/Argentina/Ushuaia"),
+    include_bytes!("data/America/Aruba"),
+    include_bytes!("data/America/Asuncion"),
+    include_bytes!("data/America/Atikokan"),
+    include_bytes!("data/America/Bahia"),
+    include_bytes!("data/America/Bahia_Banderas"),
+    include_bytes!("data/America/Barbados"),
+    include_bytes!("data/America/Belem"),
+    include_bytes!("data/America/Belize"),
+    include_bytes!("data/America/Blanc-Sablon"),
+    include_bytes!("data/America/Boa_Vista"),
+    include_bytes!("data/America/Bogota"),
+    include_bytes!("data/America/Boise"),
+    include_bytes!("data/America/Cambridge_Bay"),
+    include_bytes!("data/America/Campo_Grande"),
+    include_bytes!("data/America/Cancun"),
+    include_bytes!("data/America/Caracas"),
+    include_bytes!("data/America/Cayenne"),
+    include_bytes!("data/America/Cayman"),
+    include_bytes!("data/America/Chicago"),
+    include_bytes!("data/America/Chihuahua"),
+    include_bytes!("data/America/Ciudad_Juarez"),
+    include_bytes!("data/America/Costa_Rica"),
+    include_bytes!("data/America/Coyhaique"),
+    include_bytes!("data/America/Creston"),
+    include_bytes!("data/America/Cuiaba"),
+    include_bytes!("data/America/Curacao"),
+    include_bytes!("data/America/Danmarkshavn"),
+    include_bytes!("data/America/Dawson"),
+    include_bytes!("data/America/Dawson_Creek"),
+    include_bytes!("data/America/Denver"),
+    include_bytes!("data/America/Detroit"),
+    include_bytes!("data/America/Dominica"),
+    include_bytes!("data/America/Edmonton"),
+    include_bytes!("data/America/Eirunepe"),
+    include_bytes!("data/America/El_Salvador"),
+    include_bytes!("data/America/Fort_Nelson"),
+    include_bytes!("data/America/Fortaleza"),
+    include_bytes!("data/America/Glace_Bay"),
+    include_bytes!("data/America/Goose_Bay"),
+    include_bytes!("data/America/Grand_Turk"),
+    include_bytes!("data/America/Grenada"),
+    include_bytes!("data/America/Guadeloupe"),
+    include_bytes!("data/America/Guatemala"),
+    include_bytes!("data/America/Guayaquil"),
+    include_bytes!("data/America/Guyana"),
+    include_bytes!("data/America/Halifax"),
+    include_bytes!("data/America/Havana"),
+    include_bytes!("data/America/Hermosillo"),
+    include_bytes!("data/America/Indiana/Indianapolis"),
+    include_bytes!("data/America/Indiana/Knox"),
+    include_bytes!("data/America/Indiana/Marengo"),
+    include_bytes!("data/America/Indiana/Petersburg"),
+    include_bytes!("data/America/Indiana/Tell_City"),
+    include_bytes!("data/America/Indiana/Vevay"),
+    include_bytes!("data/America/Indiana/Vincennes"),
+    include_bytes!("data/America/Indiana/Winamac"),
+    include_bytes!("data/America/Inuvik"),
+    include_bytes!("data/America/Iqaluit"),
+    include_bytes!("data/America/Jamaica"),
+    include_bytes!("data/America/Juneau"),
+    include_bytes!("data/America/Kentucky/Louisville"),
+    include_bytes!("data/America/Kentucky/Monticello"),
+    include_bytes!("data/America/La_Paz"),
+    include_bytes!("data/America/Lima"),
+    include_bytes!("data/America/Los_Angeles"),
+    include_bytes!("data/America/Maceio"),
+    include_bytes!("data/America/Managua"),
+    include_bytes!("data/America/Manaus"),
+    include_bytes!("data/America/Martinique"),
+    include_bytes!("data/America/Matamoros"),
+    include_bytes!("data/America/Mazatlan"),
+    include_bytes!("data/America/Menominee"),
+    include_bytes!("data/America/Merida"),
+    include_bytes!("data/America/Metlakatla"),
+    include_bytes!("data/America/Mexico_City"),
+    include_bytes!("data/America/Miquelon"),
+    include_bytes!("data/America/Moncton"),
+    include_bytes!("data/America/Monterrey"),
+    include_bytes!("data/America/Montevideo"),
+    include_bytes!("data/America/Montserrat"),
+    include_bytes!("data/America/Nassau"),
+    include_bytes!("data/America/New_York"),
+    include_bytes!("data/America/Nome"),
+    include_bytes!("data/America/Noronha"),
+    include_bytes!("data/America/North_Dakota/Beulah"),
+    include_bytes!("data/America/North_Dakota/Center"),
+    include_bytes!("data/America/North_Dakota/New_Salem"),
+    include_bytes!("data/America/Nuuk"),
+    include_bytes!("data/America/Ojinaga"),
+    include_bytes!("data/America/Panama"),
+    include_bytes!("data/America/Paramaribo"),
+    include_bytes!("data/America/Phoenix"),
+    include_bytes!("data/America/Port-au-Prince"),
+    include_bytes!("data/America/Port_of_Spain"),
+    include_bytes!("data/America/Porto_Velho"),
+    include_bytes!("data/America/Puerto_Rico"),
+    include_bytes!("data/America/Punta_Arenas"),
+    include_bytes!("data/America/Rankin_Inlet"),
+    include_bytes!("data/America/Recife"),
+    include_bytes!("data/America/Regina"),
+    include_bytes!("data/America/Resolute"),
+    include_bytes!("data/America/Rio_Branco"),
+    include_bytes!("data/America/Santarem"),
+    include_bytes!("data/America/Santiago"),
+    include_bytes!("data/America/Santo_Domingo"),
+    include_bytes!("data/America/Sao_Paulo"),
+    include_bytes!("data/America/Scoresbysund"),
+    include_bytes!("data/America/Sitka"),
+    include_bytes!("data/America/St_Johns"),
+    include_bytes!("data/America/St_Kitts"),
+    include_bytes!("data/America/St_Lucia"),
+    include_bytes!("data/America/St_Thomas"),
+    include_bytes!("data/America/St_Vincent"),
+    include_bytes!("data/America/Swift_Current"),
+    include_bytes!("data/America/Tegucigalpa"),
+    include_bytes!("data/America/Thule"),
+    include_bytes!("data/America/Tijuana"),
+    include_bytes!("data/America/Toronto"),
+    include_bytes!("data/America/Tortola"),
+    include_bytes!("data/America/Vancouver"),
+    include_bytes!("data/America/Whitehorse"),
+    include_bytes!("data/America/Winnipeg"),
+    include_bytes!("data/America/Yakutat"),
+    include_bytes!("data/Antarctica/Casey"),
+    include_bytes!("data/Antarctica/Davis"),
+    include_bytes!("data/Antarctica/DumontDUrville"),
+    include_bytes!("data/Antarctica/Macquarie"),
+    include_bytes!("data/Antarctica/Mawson"),
+    include_bytes!("data/Antarctica/McMurdo"),
+    include_bytes!("data/Antarctica/Palmer"),
+    include_bytes!("data/Antarctica/Rothera"),
+    include_bytes!("data/Antarctica/Syowa"),
+    include_bytes!("data/Antarctica/Troll"),
+    include_bytes!("data/Antarctica/Vostok"),
+    include_bytes!("data/Asia/Aden"),
+    include_bytes!("data/Asia/Almaty"),
+    include_bytes!("data/Asia/Amman"),
+    include_bytes!("data/Asia/Anadyr"),
+    include_bytes!("data/Asia/Aqtau"),
+    include_bytes!("data/Asia/Aqtobe"),
+    include_bytes!("data/Asia/Ashgabat"),
+    include_bytes!("data/Asia/Atyrau"),
+    include_bytes!("data/Asia/Baghdad"),
+    include_bytes!("data/Asia/Bahrain"),
+    include_bytes!("data/Asia/Baku"),
+    include_bytes!("data/Asia/Bangkok"),
+    include_bytes!("data/Asia/Barnaul"),
+    include_bytes!("data/Asia/Beirut"),
+    include_bytes!("data/Asia/Bishkek"),
+    include_bytes!("data/Asia/Brunei"),
+    include_bytes!("data/Asia/Chita"),
+    include_bytes!("data/Asia/Colombo"),
+    include_bytes!("data/Asia/Damascus"),
+    include_bytes!("data/Asia/Dhaka"),
+    include_bytes!("data/Asia/Dili"),
+    include_bytes!("data/Asia/Dubai"),
+    include_bytes!("data/Asia/Dushanbe"),
+    include_bytes!("data/Asia/Famagusta"),
+    include_bytes!("data/Asia/Gaza"),
+    include_bytes!("data/Asia/Hebron"),
+    include_bytes!("data/Asia/Ho_Chi_Minh"),
+    include_bytes!("data/Asia/Hong_Kong"),
+    include_bytes!("data/Asia/Hovd"),
+    include_bytes!("data/Asia/Irkutsk"),
+    include_bytes!("data/Asia/Jakarta"),
+    include_bytes!("data/Asia/Jayapura"),
+    include_bytes!("data/Asia/Jerusalem"),
+    include_bytes!("data/Asia/Kabul"),
+    include_bytes!("data/Asia/Kamchatka"),
+    include_bytes!("data/Asia/Karachi"),
+    include_bytes!("data/Asia/Kathmandu"),
+    include_bytes!("data/Asia/Khandyga"),
+    include_bytes!("data/Asia/Kolkata"),
+    include_bytes!("data/Asia/Krasnoyarsk"),
+    include_bytes!("data/Asia/Kuala_Lumpur"),
+    include_bytes!("data/Asia/Kuching"),
+    include_bytes!("data/Asia/Kuwait"),
+    include_bytes!("data/Asia/Macau"),
+    include_bytes!("data/Asia/Magadan"),
+    include_bytes!("data/Asia/Makassar"),
+    include_bytes!("data/Asia/Manila"),
+    include_bytes!("data/Asia/Muscat"),
+    include_bytes!("data/Asia/Nicosia"),
+    include_bytes!("data/Asia/Novokuznetsk"),
+    include_bytes!("data/Asia/Novosibirsk"),
+    include_bytes!("data/Asia/Omsk"),
+    include_bytes!("data/Asia/Oral"),
+    include_bytes!("data/Asia/Phnom_Penh"),
+    include_bytes!("data/Asia/Pontianak"),
+    include_bytes!("data/Asia/Pyongyang"),
+    include_bytes!("data/Asia/Qatar"),
+    include_bytes!("data/Asia/Qostanay"),
+    include_bytes!("data/Asia/Qyzylorda"),
+    include_bytes!("data/Asia/Riyadh"),
+    include_bytes!("data/Asia/Sakhalin"),
+    include_bytes!("data/Asia/Samarkand"),
+    include_bytes!("data/Asia/Seoul"),
+    include_bytes!("data/Asia/Shanghai"),
+    include_bytes!("data/Asia/Singapore"),
+    include_bytes!("data/Asia/Srednekolymsk"),
+    include_bytes!("data/Asia/Taipei"),
+    include_bytes!("data/Asia/Tashkent"),
+    include_bytes!("data/Asia/Tbilisi"),
+    include_bytes!("data/Asia/Tehran"),
+    include_bytes!("data/Asia/Thimphu"),
+    include_bytes!("data/Asia/Tokyo"),
+    include_bytes!("data/Asia/Tomsk"),
+    include_bytes!("data/Asia/Ulaanbaatar"),
+    include_bytes!("data/Asia/Urumqi"),
+    include_bytes!("data/Asia/Ust-Nera"),
+    include_bytes!("data/Asia/Vientiane"),
+    include_bytes!("data/Asia/Vladivostok"),
+    include_bytes!("data/Asia/Yakutsk"),
+    include_bytes!("data/Asia/Yangon"),
+    include_bytes!("data/Asia/Yekaterinburg"),
+    include_bytes!("data/Asia/Yerevan"),
+    include_bytes!("data/Atlantic/Azores"),
+    include_bytes!("data/Atlantic/Bermuda"),
+    include_bytes!("data/Atlantic/Canary"),
+    include_bytes!("data/Atlantic/Cape_Verde"),
+    include_bytes!("data/Atlantic/Faroe"),
+    include_bytes!("data/Atlantic/Madeira"),
+    include_bytes!("data/Atlantic/Reykjavik"),
+    include_bytes!("data/Atlantic/South_Georgia"),
+    include_bytes!("data/Atlantic/St_Helena"),
+    include_bytes!("data/Atlantic/Stanley"),
+    include_bytes!("data/Australia/Adelaide"),
+    include_bytes!("data/Australia/Brisbane"),
+    include_bytes!("data/Australia/Broken_Hill"),
+    include_bytes!("data/Australia/Darwin"),
+    include_bytes!("data/Australia/Eucla"),
+    include_bytes!("data/Australia/Hobart"),
+    include_bytes!("data/Australia/Lindeman"),
+    include_bytes!("data/Australia/Lord_Howe"),
+    include_bytes!("data/Australia/Melbourne"),
+    include_bytes!("data/Australia/Perth"),
+    include_bytes!("data/Australia/Sydney"),
+    include_bytes!("data/CET"),
+    include_bytes!("data/CST6CDT"),
+    include_bytes!("data/EET"),
+    include_bytes!("data/EST"),
+    include_bytes!("data/EST5EDT"),
+    include_bytes!("data/Etc/GMT"),
+    include_bytes!("data/Etc/GMT+1"),
+    include_bytes!("data/Etc/GMT+10"),
+    include_bytes!("data/Etc/GMT+11"),
+    include_bytes!("data/Etc/GMT+12"),
+    include_bytes!("data/Etc/GMT+2"),
+    include_bytes!("data/Etc/GMT+3"),
+    include_bytes!("data/Etc/GMT+4"),
+    include_bytes!("data/Etc/GMT+5"),
+    include_bytes!("data/Etc/GMT+6"),
+    include_bytes!("data/Etc/GMT+7"),
+    include_bytes!("data/Etc/GMT+8"),
+    include_bytes!("data/Etc/GMT+9"),
+    include_bytes!("data/Etc/GMT-1"),
+    include_bytes!("data/Etc/GMT-10"),
+    include_bytes!("data/Etc/GMT-11"),
+    include_bytes!("data/Etc/GMT-12"),
+    include_bytes!("data/Etc/GMT-13"),
+    include_bytes!("data/Etc/GMT-14"),
+    include_bytes!("data/Etc/GMT-2"),
+    include_bytes!("data/Etc/GMT-3"),
+    include_bytes!("data/Etc/GMT-4"),
+    include_bytes!("data/Etc/GMT-5"),
+    include_bytes!("data/Etc/GMT-6"),
+    include_bytes!("data/Etc/GMT-7"),
+    include_bytes!("data/Etc/GMT-8"),
+    include_bytes!("data/Etc/GMT-9"),
+    include_bytes!("data/Etc/UTC"),
+    include_bytes!("data/Europe/Amsterdam"),
+    include_bytes!("data/Europe/Andorra"),
+    include_bytes!("data/Europe/Astrakhan"),
+    include_bytes!("data/Europe/Athens"),
+    include_bytes!("data/Europe/Belgrade"),
+    include_bytes!("data/Europe/Berlin"),
+    include_bytes!("data/Europe/Brussels"),
+    include_bytes!("data/Europe/Bucharest"),
+    include_bytes!("data/Europe/Budapest"),
+    include_bytes!("data/Europe/Chisinau"),
+    include_bytes!("data/Europe/Copenhagen"),
+    include_bytes!("data/Europe/Dublin"),
+    include_bytes!("data/Europe/Gibraltar"),
+    include_bytes!("data/Europe/Guernsey"),
+    include_bytes!("data/Europe/Helsinki"),
+    include_bytes!("data/Europe/Isle_of_Man"),
+    include_bytes!("data/Europe/Istanbul"),
+    include_bytes!("data/Europe/Jersey"),
+    include_bytes!("data/Europe/Kaliningrad"),
+    include_bytes!("data/Europe/Kirov"),
+    include_bytes!("data/Europe/Kyiv"),
+    include_bytes!("data/Europe/Lisbon"),
+    include_bytes!("data/Europe/Ljubljana"),
+    include_bytes!("data/Europe/London"),
+    include_bytes!("data/Europe/Luxembourg"),
+    include_bytes!("data/Europe/Madrid"),
+    include_bytes!("data/Europe/Malta"),
+    include_bytes!("data/Europe/Minsk"),
+    include_bytes!("data/Europe/Monaco"),
+    include_bytes!("data/Europe/Moscow"),
+    include_bytes!("data/Europe/Oslo"),
+    include_bytes!("data/Europe/Paris"),
+    include_bytes!("data/Europe/Prague"),
+    include_bytes!("data/Europe/Riga"),
+    include_bytes!("data/Europe/Rome"),
+    include_bytes!("data/Europe/Samara"),
+    include_bytes!("data/Europe/Sarajevo"),
+    include_bytes!("data/Europe/Saratov"),
+    include_bytes!("data/Europe/Simferopol"),
+    include_bytes!("data/Europe/Skopje"),
+    include_bytes!("data/Europe/Sofia"),
+    include_bytes!("data/Europe/Stockholm"),
+    include_bytes!("data/Europe/Tallinn"),
+    include_bytes!("data/Europe/Tirane"),
+    include_bytes!("data/Europe/Ulyanovsk"),
+    include_bytes!("data/Europe/Vaduz"),
+    include_bytes!("data/Europe/Vienna"),
+    include_bytes!("data/Europe/Vilnius"),
+    include_bytes!("data/Europe/Volgograd"),
+    include_bytes!("data/Europe/Warsaw"),
+    include_bytes!("data/Europe/Zagreb"),
+    include_bytes!("data/Europe/Zurich"),
+    include_bytes!("data/Factory"),
+    include_bytes!("data/HST"),
+    include_bytes!("data/Indian/Antananarivo"),
+    include_bytes!("data/Indian/Chagos"),
+    include_bytes!("data/Indian/Christmas"),
+    include_bytes!("data/Indian/Cocos"),
+    include_bytes!("data/Indian/Comoro"),
+    include_bytes!("data/Indian/Kerguelen"),
+    include_bytes!("data/Indian/Mahe"),
+    include_bytes!("data/Indian/Maldives"),
+    include_bytes!("data/Indian/Mauritius"),
+    include_bytes!("data/Indian/Mayotte"),
+    include_bytes!("data/Indian/Reunion"),
+    include_bytes!("data/MET"),
+    include_bytes!("data/MST"),
+    include_bytes!("data/MST7MDT"),
+    include_bytes!("data/PST8PDT"),
+    include_bytes!("data/Pacific/Apia"),
+    include_bytes!("data/Pacific/Auckland"),
+    include_bytes!("data/Pacific/Bougainville"),
+    include_bytes!("data/Pacific/Chatham"),
+    include_bytes!("data/Pacific/Chuuk"),
+    include_bytes!("data/Pacific/Easter"),
+    include_bytes!("data/Pacific/Efate"),
+    include_bytes!("data/Pacific/Fakaofo"),
+    include_bytes!("data/Pacific/Fiji"),
+    include_bytes!("data/Pacific/Funafuti"),
+    include_bytes!("data/Pacific/Galapagos"),
+    include_bytes!("data/Pacific/Gambier"),
+    include_bytes!("data/Pacific/Guadalcanal"),
+    include_bytes!("data/Pacific/Guam"),
+    include_bytes!("data/Pacific/Honolulu"),
+    include_bytes!("data/Pacific/Kanton"),
+    include_bytes!("data/Pacific/Kiritimati"),
+    include_bytes!("data/Pacific/Kosrae"),
+    include_bytes!("data/Pacific/Kwajalein"),
+    include_bytes!("data/Pacific/Majuro"),
+    include_bytes!("data/Pacific/Marquesas"),
+    include_bytes!("data/Pacific/Midway"),
+    include_bytes!("data/Pacific/Nauru"),
+    include_bytes!("data/Pacific/Niue"),
+    include_bytes!("data/Pacific/Norfolk"),
+    include_bytes!("data/Pacific/Noumea"),
+    include_bytes!("data/Pacific/Pago_Pago"),
+    include_bytes!("data/Pacific/Palau"),
+    include_bytes!("data/Pacific/Pitcairn"),
+    include_bytes!("data/Pacific/Pohnpei"),
+    include_bytes!("data/Pacific/Port_Moresby"),
+    include_bytes!("data/Pacific/Rarotonga"),
+    include_bytes!("data/Pacific/Saipan"),
+    include_bytes!("data/Pacific/Tahiti"),
+    include_bytes!("data/Pacific/Tarawa"),
+    include_bytes!("data/Pacific/Tongatapu"),
+    include_bytes!("data/Pacific/Wake"),
+    include_bytes!("data/Pacific/Wallis"),
+    include_bytes!("data/WET"),
+];
+
+/// The name of every zone and link in ascending order, along with the index of its data.
+pub(crate) static NAMES: &[(&str, usize)] = &[
+    ("Africa/Abidjan", 0),
+    ("Africa/Accra", 1),
+    ("Africa/Addis_Ababa", 2),
+    ("Africa/Algiers", 3),
+    ("Africa/Asmara", 4),
+    ("Africa/Asmera", 42),
+    ("Africa/Bamako", 5),
+    ("Africa/Bangui", 6),
+    ("Africa/Banjul", 7),
+    ("Africa/Bissau", 8),
+    ("Africa/Blantyre", 9),
+    ("Africa/Brazzaville", 10),
+    ("Africa/Bujumbura", 11),
+    ("Africa/Cairo", 12),
+    ("Africa/Casablanca", 13),
+    ("Africa/Ceuta", 14),
+    ("Africa/Conakry", 15),
+    ("Africa/Dakar", 16),
+    ("Africa/Dar_es_Salaam", 17),
+    ("Africa/Djibouti", 18),
+    ("Africa/Douala", 19),
+    ("Africa/El_Aaiun", 20),
+    ("Africa/Freetown", 21),
+    ("Africa/Gaborone", 22),
+    ("Africa/Harare", 23),
+    ("Africa/Johannesburg", 24),
+    ("Africa/Juba", 25),
+    ("Africa/Kampala", 26),
+    ("Africa/Khartoum", 27),
+    ("Africa/Kigali", 28),
+    ("Africa/Kinshasa", 29),
+    ("Africa/Lagos", 30),
+    ("Africa/Libreville", 31),
+    ("Africa/Lome", 32),
+    ("Africa/Luanda", 33),
+    ("Africa/Lubumbashi", 34),
+    ("Africa/Lusaka", 35),
+    ("Africa/Malabo", 36),
+    ("Africa/Maputo", 37),
+    ("Africa/Maseru", 38),
+    ("Africa/Mbabane", 39),
+    ("Africa/Mogadishu", 40),
+    ("Africa/Monrovia", 41),
+    ("Africa/Nairobi", 42),
+    ("Africa/Ndjamena", 43),
+    ("Africa/Niamey", 44),
+    ("Africa/Nouakchott", 45),
+    ("Africa/Ouagadougou", 46),
+    ("Africa/Porto-Novo", 47),
+    ("Africa/Sao_Tome", 48),
+    ("Africa/Timbuktu", 0),
+    ("Africa/Tripoli", 49),
+    ("Africa/Tunis", 50),
+    ("Africa/Windhoek", 51),
+    ("America/Adak", 52),
+    ("America/Anchorage", 53),
+    ("America/Anguilla", 54),
+    ("America/Antigua", 55),
+    ("America/Araguaina", 56),
+    ("America/Argentina/Buenos_Aires", 57),
+    ("America/Argentina/Catamarca", 58),
+    ("America/Argentina/ComodRivadavia", 58),
+    ("America/Argentina/Cordoba", 59),
+    ("America/Argentina/Jujuy", 60),
+    ("America/Argentina/La_Rioja", 61),
+    ("America/Argentina/Mendoza", 62),
+    ("America/Argentina/Rio_Gallegos", 63),
+    ("America/Argentina/Salta", 64),
+    ("America/Argentina/San_Juan", 65),
+    ("America/Argentina/San_Luis", 66),
+    ("America/Argentina/Tucuman", 67),
+    ("America/Argentina/Ushuaia", 68),
+    ("America/Aruba", 69),
+    ("America/Asuncion", 70),
+    ("America/Atikokan", 71),
+    ("America/Atka", 52),
+    ("America/Bahia", 72),
+    ("America/Bahia_Banderas", 73),
+    ("America/Barbados", 74),
+    ("America/Belem", 75),
+    ("America/Belize", 76),
+    ("America/Blanc-Sablon", 77),
+    ("America/Boa_Vista", 78),
+    ("America/Bogota", 79),
+    ("America/Boise", 80),
+    ("America/Buenos_Aires", 57),
+    ("America/Cambridge_Bay", 81),
+    ("America/Campo_Grande", 82),
+    ("America/Cancun", 83),
+    ("America/Caracas", 84),
+    ("America/Catamarca", 58),
+    ("America/Cayenne", 85),
+    ("America/Cayman", 86),
+    ("America/Chicago", 87),
+    ("America/Chihuahua", 88),
+    ("America/Ciudad_Juarez", 89),
+    ("America/Coral_Harbour", 158),
+    ("America/Cordoba", 59),
+    ("America/Costa_Rica", 90),
+    ("America/Coyhaique", 91),
+    ("America/Creston", 92),
+    ("America/Cuiaba", 93),
+    ("America/Curacao", 94),
+    ("America/Danmarkshavn", 95),
+    ("America/Dawson", 96),
+    ("America/Dawson_Creek", 97),
+    ("America/Denver", 98),
+    ("America/Detroit", 99),
+    ("America/Dominica", 100),
+    ("America/Edmonton", 101),
+    ("America/Eirunepe", 102),
+    ("America/El_Salvador", 103),
+    ("America/Ensenada", 185),
+    ("America/Fort_Nelson", 104),
+    ("America/Fort_Wayne", 117),
+    ("America/Fortaleza", 105),
+    ("America/Glace_Bay", 106),
+    ("America/Godthab", 156),
+    ("America/Goose_Bay", 107),
+    ("America/Grand_Turk", 108),
+    ("America/Grenada", 109),
+    ("America/Guadeloupe", 110),
+    ("America/Guatemala", 111),
+    ("America/Guayaquil", 112),
+    ("America/Guyana", 113),
+    ("America/Halifax", 114),
+    ("America/Havana", 115),
+    ("America/Hermosillo", 116),
+    ("America/Indiana/Indianapolis", 117),
+    ("America/Indiana/Knox", 118),
+    ("America/Indiana/Marengo", 119),
+    ("America/Indiana/Petersburg", 120),
+    ("America/Indiana/Tell_City", 121),
+    ("America/Indiana/Vevay", 122),
+    ("America/Indiana/Vincennes", 123),
+    ("America/Indiana/Winamac", 124),
+    ("America/Indianapolis", 117),
+    ("America/Inuvik", 125),
+    ("America/Iqaluit", 126),
+    ("America/Jamaica", 127),
+    ("America/Jujuy", 60),
+    ("America/Juneau", 128),
+    ("America/Kentucky/Louisville", 129),
+    ("America/Kentucky/Monticello", 130),
+    ("America/Knox_IN", 118),
+    ("America/Kralendijk", 164),
+    ("America/La_Paz", 131),
+    ("America/Lima", 132),
+    ("America/Los_Angeles", 133),
+    ("America/Louisville", 129),
+    ("America/Lower_Princes", 164),
+    ("America/Maceio", 134),
+    ("America/Managua", 135),
+    ("America/Manaus", 136),
+    ("America/Marigot", 164),
+    ("America/Martinique", 137),
+    ("America/Matamoros", 138),
+    ("America/Mazatlan", 139),
+    ("America/Mendoza", 62),
+    ("America/Menominee", 140),
+    ("America/Merida", 141),
+    ("America/Metlakatla", 142),
+    ("America/Mexico_City", 143),
+    ("America/Miquelon", 144),
+    ("America/Moncton", 145),
+    ("America/Monterrey", 146),
+    ("America/Montevideo", 147),
+    ("America/Montreal", 186),
+    ("America/Montserrat", 148),
+    ("America/Nassau", 149),
+    ("America/New_York", 150),
+    ("America/Nipigon", 186),
+    ("America/Nome", 151),
+    ("America/Noronha", 152),
+    ("America/North_Dakota/Beulah", 153),
+    ("America/North_Dakota/Center", 154),
+    ("America/North_Dakota/New_Salem", 155),
+    ("America/Nuuk", 156),
+    ("America/Ojinaga", 157),
+    ("America/Panama", 158),
+    ("America/Pangnirtung", 126),
+    ("America/Paramaribo", 159),
+    ("America/Phoenix", 160),
+    ("America/Port-au-Prince", 161),
+    ("America/Port_of_Spain", 162),
+    ("America/Porto_Acre", 170),
+    ("America/Porto_Velho", 163),
+    ("America/Puerto_Rico", 164),
+    ("America/Punta_Arenas", 165),
+    ("America/Rainy_River", 190),
+    ("America/Rankin_Inlet", 166),
+    ("America/Recife", 167),
+    ("America/Regina", 168),
+    ("America/Resolute", 169),
+    ("America/Rio_Branco", 170),
+    ("America/Rosario", 59),
+    ("America/Santa_Isabel", 185),
+    ("America/Santarem", 171),
+    ("America/Santiago", 172),
+    ("America/Santo_Domingo", 173),
+    ("America/Sao_Paulo", 174),
+    ("America/Scoresbysund", 175),
+    ("America/Shiprock", 98),
+    ("America/Sitka", 176),
+    ("America/St_Barthelemy", 164),
+    ("America/St_Johns", 177),
+    ("America/St_Kitts", 178),
+    ("America/St_Lucia", 179),
+    ("America/St_Thomas", 180),
+    ("America/St_Vincent", 181),
+    ("America/Swift_Current", 182),
+    ("America/Tegucigalpa", 183),
+    ("America/Thule", 184),
+    ("America/Thunder_Bay", 186),
+    ("America/Tijuana", 185),
+    ("America/Toronto", 186),
+    ("America/Tortola", 187),
+    ("America/Vancouver", 188),
+    ("America/Virgin", 164),
+    ("America/Whitehorse", 189),
+    ("America/Winnipeg", 190),
+    ("America/Yakutat", 191),
+    ("America/Yellowknife", 101),
+    ("Antarctica/Casey", 192),
+    ("Antarctica/Davis", 193),
+    ("Antarctica/DumontDUrville", 194),
+    ("Antarctica/Macquarie", 195),
+    ("Antarctica/Mawson", 196),
+    ("Antarctica/McMurdo", 197),
+    ("Antarctica/Palmer", 198),
+    ("Antarctica/Rothera", 199),
+    ("Antarctica/South_Pole", 409),
+    ("Antarctica/Syowa", 200),
+    ("Antarctica/Troll", 201),
+    ("Antarctica/Vostok", 202),
+    ("Arctic/Longyearbyen", 344),
+    ("Asia/Aden", 203),
+    ("Asia/Almaty", 204),
+    ("Asia/Amman", 205),
+    ("Asia/Anadyr", 206),
+    ("Asia/Aqtau", 207),
+    ("Asia/Aqtobe", 208),
+    ("Asia/Ashgabat", 209),
+    ("Asia/Ashkhabad", 209),
+    ("Asia/Atyrau", 210),
+    ("Asia/Baghdad", 211),
+    ("Asia/Bahrain", 212),
+    ("Asia/Baku", 213),
+    ("Asia/Bangkok", 214),
+    ("Asia/Barnaul", 215),
+    ("Asia/Beirut", 216),
+    ("Asia/Bishkek", 217),
+    ("Asia/Brunei", 218),
+    ("Asia/Calcutta", 241),
+    ("Asia/Chita", 219),
+    ("Asia/Choibalsan", 276),
+    ("Asia/Chongqing", 266),
+    ("Asia/Chungking", 266),
+    ("Asia/Colombo", 220),
+    ("Asia/Dacca", 222),
+    ("Asia/Damascus", 221),
+    ("Asia/Dhaka", 222),
+    ("Asia/Dili", 223),
+    ("Asia/Dubai", 224),
+    ("Asia/Dushanbe", 225),
+    ("Asia/Famagusta", 226),
+    ("Asia/Gaza", 227),
+    ("Asia/Harbin", 266),
+    ("Asia/Hebron", 228),
+    ("Asia/Ho_Chi_Minh", 229),
+    ("Asia/Hong_Kong", 230),
+    ("Asia/Hovd", 231),
+    ("Asia/Irkutsk", 232),
+    ("Asia/Istanbul", 355),
+    ("Asia/Jakarta", 233),
+    ("Asia/Jayapura", 234),
+    ("Asia/Jerusalem", 235),
+    ("Asia/Kabul", 236),
+    ("Asia/Kamchatka", 237),
+    ("Asia/Karachi", 238),
+    ("Asia/Kashgar", 277),
+    ("Asia/Kathmandu", 239),
+    ("Asia/Katmandu", 239),
+    ("Asia/Khandyga", 240),
+    ("Asia/Kolkata", 241),
+    ("Asia/Krasnoyarsk", 242),
+    ("Asia/Kuala_Lumpur", 243),
+    ("Asia/Kuching", 244),
+    ("Asia/Kuwait", 245),
+    ("Asia/Macao", 246),
+    ("Asia/Macau", 246),
+    ("Asia/Magadan", 247),
+    ("Asia/Makassar", 248),
+    ("Asia/Manila", 249),
+    ("Asia/Muscat", 250),
+    ("Asia/Nicosia", 251),
+    ("Asia/Novokuznetsk", 252),
+    ("Asia/Novosibirsk", 253),
+    ("Asia/Omsk", 254),
+    ("Asia/Oral", 255),
+    ("Asia/Phnom_Penh", 256),
+    ("Asia/Pontianak", 257),
+    ("Asia/Pyongyang", 258),
+    ("Asia/Qatar", 259),
+    ("Asia/Qostanay", 260),
+    ("Asia/Qyzylorda", 261),
+    ("Asia/Rangoon", 282),
+    ("Asia/Riyadh", 262),
+    ("Asia/Saigon", 229),
+    ("Asia/Sakhalin", 263),
+    ("Asia/Samarkand", 264),
+    ("Asia/Seoul", 265),
+    ("Asia/Shanghai", 266),
+    ("Asia/Singapore", 267),
+    ("Asia/Srednekolymsk", 268),
+    ("Asia/Taipei", 269),
+    ("Asia/Tashkent", 270),
+    ("Asia/Tbilisi", 271),
+    ("Asia/Tehran", 272),
+    ("Asia/Tel_Aviv", 235),
+    ("Asia/Thimbu", 273),
+    ("Asia/Thimphu", 273),
+    ("Asia/Tokyo", 274),
+    ("Asia/Tomsk", 275),
+    ("Asia/Ujung_Pandang", 248),
+    ("Asia/Ulaanbaatar", 276),
+    ("Asia/Ulan_Bator", 276),
+    ("Asia/Urumqi", 277),
+    ("Asia/Ust-Nera", 278),
+    ("Asia/Vientiane", 279),
+    ("Asia/Vladivostok", 280),
+    ("Asia/Yakutsk", 281),
+    ("Asia/Yangon", 282),
+    ("Asia/Yekaterinburg", 283),
+    ("Asia/Yerevan", 284),
+    ("Atlantic/Azores", 285),
+    ("Atlantic/Bermuda", 286),
+    ("Atlantic/Canary", 287),
+    ("Atlantic/Cape_Verde", 288),
+    ("Atlantic/Faeroe", 289),
+    ("Atlantic/Faroe", 289),
+    ("Atlantic/Jan_Mayen", 344),
+    ("Atlantic/Madeira", 290),
+    ("Atlantic/Reykjavik", 291),
+    ("Atlantic/South_Georgia", 292),
+    ("Atlantic/St_Helena", 293),
+    ("Atlantic/Stanley", 294),
+    ("Australia/ACT", 305),
+    ("Australia/Adelaide", 295),
+    ("Australia/Brisbane", 296),
+    ("Australia/Broken_Hill", 297),
+    ("Australia/Canberra", 305),
+    ("Australia/Currie", 300),
+    ("Australia/Darwin", 298),
+    ("Australia/Eucla", 299),
+    ("Australia/Hobart", 300),
+    ("Australia/LHI", 302),
+    ("Australia/Lindeman", 301),
+    ("Australia/Lord_Howe", 302),
+    ("Australia/Melbourne", 303),
+    ("Australia/NSW", 305),
+    ("Australia/North", 298),
+    ("Australia/Perth", 304),
+    ("Australia/Queensland", 296),
+    ("Australia/South", 295),
+    ("Australia/Sydney", 305),
+    ("Australia/Tasmania", 300),
+    ("Australia/Victoria", 303),
+    ("Australia/West", 304),
+    ("Australia/Yancowinna", 297),
+    ("Brazil/Acre", 170),
+    ("Brazil/DeNoronha", 152),
+    ("Brazil/East", 174),
+    ("Brazil/West", 136),
+    ("CET", 306),
+    ("CST6CDT", 307),
+    ("Canada/Atlantic", 114),
+    ("Canada/Central", 190),
+    ("Canada/Eastern", 186),
+    ("Canada/Mountain", 101),
+    ("Canada/Newfoundland", 177),
+    ("Canada/Pacific", 188),
+    ("Canada/Saskatchewan", 168),
+    ("Canada/Yukon", 189),
+    ("Chile/Continental", 172),
+    ("Chile/EasterIsland", 413),
+    ("Cuba", 115),
+    ("EET", 308),
+    ("EST", 309),
+    ("EST5EDT", 310),
+    ("Egypt", 12),
+    ("Eire", 350),
+    ("Etc/GMT", 311),
+    ("Etc/GMT+0", 311),
+    ("Etc/GMT+1", 312),
+    ("Etc/GMT+10", 313),
+    ("Etc/GMT+11", 314),
+    ("Etc/GMT+12", 315),
+    ("Etc/GMT+2", 316),
+    ("Etc/GMT+3", 317),
+    ("Etc/GMT+4", 318),
+    ("Etc/GMT+5", 319),
+    ("Etc/GMT+6", 320),
+    ("Etc/GMT+7", 321),
+    ("Etc/GMT+8", 322),
+    ("Etc/GMT+9", 323),
+    ("Etc/GMT-0", 311),
+    ("Etc/GMT-1", 324),
+    ("Etc/GMT-10", 325),
+    ("Etc/GMT-11", 326),
+    ("Etc/GMT-12", 327),
+    ("Etc/GMT-13", 328),
+    ("Etc/GMT-14", 329),
+    ("Etc/GMT-2", 330),
+    ("Etc/GMT-3", 331),
+    ("Etc/GMT-4", 332),
+    ("Etc/GMT-5", 333),
+    ("Etc/GMT-6", 334),
+    ("Etc/GMT-7", 335),
+    ("Etc/GMT-8", 336),
+    ("Etc/GMT-9", 337),
+    ("Etc/GMT0", 311),
+    ("Etc/Greenwich", 311),
+    ("Etc/UCT", 338),
+    ("Etc/UTC", 338),
+    ("Etc/Universal", 338),
+    ("Etc/Zulu", 338),
+    ("Europe/Amsterdam", 339),
+    ("Europe/Andorra", 340),
+    ("Europe/Astrakhan", 341),
+    ("Europe/Athens", 342),
+    ("Europe/Belfast", 362),
+    ("Europe/Belgrade", 343),
+    ("Europe/Berlin", 344),
+    ("Europe/Bratislava", 371),
+    ("Europe/Brussels", 345),
+    ("Europe/Bucharest", 346),
+    ("Europe/Budapest", 347),
+    ("Europe/Busingen", 390),
+    ("Europe/Chisinau", 348),
+    ("Europe/Copenhagen", 349),
+    ("Europe/Dublin", 350),
+    ("Europe/Gibraltar", 351),
+    ("Europe/Guernsey", 352),
+    ("Europe/Helsinki", 353),
+    ("Europe/Isle_of_Man", 354),
+    ("Europe/Istanbul", 355),
+    ("Europe/Jersey", 356),
+    ("Europe/Kaliningrad", 357),
+    ("Europe/Kiev", 359),
+    ("Europe/Kirov", 358),
+    ("Europe/Kyiv", 359),
+    ("Europe/Lisbon", 360),
+    ("Europe/Ljubljana", 361),
+    ("Europe/London", 362),
+    ("Europe/Luxembourg", 363),
+    ("Europe/Madrid", 364),
+    ("Europe/Malta", 365),
+    ("Europe/Mariehamn", 353),
+    ("Europe/Minsk", 366),
+    ("Europe/Monaco", 367),
+    ("Europe/Moscow", 368),
+    ("Europe/Nicosia", 251),
+    ("Europe/Oslo", 369),
+    ("Europe/Paris", 370),
+    ("Europe/Podgorica", 343),
+    ("Europe/Prague", 371),
+    ("Europe/Riga", 372),
+    ("Europe/Rome", 373),
+    ("Europe/Samara", 374),
+    ("Europe/San_Marino", 373),
+    ("Europe/Sarajevo", 375),
+    ("Europe/Saratov", 376),
+    ("Europe/Simferopol", 377),
+    ("Europe/Skopje", 378),
+    ("Europe/Sofia", 379),
+    ("Europe/Stockholm", 380),
+    ("Europe/Tallinn", 381),
+    ("Europe/Tirane", 382),
+    ("Europe/Tiraspol", 348),
+    ("Europe/Ulyanovsk", 383),
+    ("Europe/Uzhgorod", 359),
+    ("Europe/Vaduz", 384),
+    ("Europe/Vatican", 373),
+    ("Europe/Vienna", 385),
+    ("Europe/Vilnius", 386),
+    ("Europe/Volgograd", 387),
+    ("Europe/Warsaw", 388),
+    ("Europe/Zagreb", 389),
+    ("Europe/Zaporozhye", 359),
+    ("Europe/Zurich", 390),
+    ("Factory", 391),
+    ("GB", 362),
+    ("GB-Eire", 362),
+    ("GMT", 311),
+    ("GMT+0", 311),
+    ("GMT-0", 311),
+    ("GMT0", 311),
+    ("Greenwich", 311),
+    ("HST", 392),
+    ("Hongkong", 230),
+    ("Iceland", 0),
+    ("Indian/Antananarivo", 393),
+    ("Indian/Chagos", 394),
+    ("Indian/Christmas", 395),
+    ("Indian/Cocos", 396),
+    ("Indian/Comoro", 397),
+    ("Indian/Kerguelen", 398),
+    ("Indian/Mahe", 399),
+    ("Indian/Maldives", 400),
+    ("Indian/Mauritius", 401),
+    ("Indian/Mayotte", 402),
+    ("Indian/Reunion", 403),
+    ("Iran", 272),
+    ("Israel", 235),
+    ("Jamaica", 127),
+    ("Japan", 274),
+    ("Kwajalein", 426),
+    ("Libya", 49),
+    ("MET", 404),
+    ("MST", 405),
+    ("MST7MDT", 406),
+    ("Mexico/BajaNorte", 185),
+    ("Mexico/BajaSur", 139),
+    ("Mexico/General", 143),
+    ("NZ", 409),
+    ("NZ-CHAT", 411),
+    ("Navajo", 98),
+    ("PRC", 266),
+    ("PST8PDT", 407),
+    ("Pacific/Apia", 408),
+    ("Pacific/Auckland", 409),
+    ("Pacific/Bougainville", 410),
+    ("Pacific/Chatham", 411),
+    ("Pacific/Chuuk", 412),
+    ("Pacific/Easter", 413),
+    ("Pacific/Efate", 414),
+    ("Pacific/Enderbury", 423),
+    ("Pacific/Fakaofo", 415),
+    ("Pacific/Fiji", 416),
+    ("Pacific/Funafuti", 417),
+    ("Pacific/Galapagos", 418),
+    ("Pacific/Gambier", 419),
+    ("Pacific/Guadalcanal", 420),
+    ("Pacific/Guam", 421),
+    ("Pacific/Honolulu", 422),
+    ("Pacific/Johnston", 422),
+    ("Pacific/Kanton", 423),
+    ("Pacific/Kiritimati", 424),
+    ("Pacific/Kosrae", 425),
+    ("Pacific/Kwajalein", 426),
+    ("Pacific/Majuro", 427),
+    ("Pacific/Marquesas", 428),
+    ("Pacific/Midway", 429),
+    ("Pacific/Nauru", 430),
+    ("Pacific/Niue", 431),
+    ("Pacific/Norfolk", 432),
+    ("Pacific/Noumea", 433),
+    ("Pacific/Pago_Pago", 434),
+    ("Pacific/Palau", 435),
+    ("Pacific/Pitcairn", 436),
+    ("Pacific/Pohnpei", 437),
+    ("Pacific/Ponape", 420),
+    ("Pacific/Port_Moresby", 438),
+    ("Pacific/Rarotonga", 439),
+    ("Pacific/Saipan", 440),
+    ("Pacific/Samoa", 434),
+    ("Pacific/Tahiti", 441),
+    ("Pacific/Tarawa", 442),
+    ("Pacific/Tongatapu", 443),
+    ("Pacific/Truk", 438),
+    ("Pacific/Wake", 444),
+    ("Pacific/Wallis", 445),
+    ("Pacific/Yap", 438),
+    ("Poland", 388),
+    ("Portugal", 360),
+    ("ROC", 269),
+    ("ROK", 265),
+    ("Singapore", 267),
+    ("Turkey", 355),
+    ("UCT", 338),
+    ("US/Alaska", 53),
+    ("US/Aleutian", 52),
+    ("US/Arizona", 160),
+    ("US/Central", 87),
+    ("US/East-Indiana", 117),
+    ("US/Eastern", 150),
+    ("US/Hawaii", 422),
+    ("US/Indiana-Starke", 118),
+    ("US/Michigan", 99),
+    ("US/Mountain", 98),
+    ("US/Pacific", 133),
+    ("US/Samoa", 434),
+    ("UTC", 338),
+    ("Universal", 338),
+    ("W-SU", 368),
+    ("WET", 446),
+    ("Zulu", 338),
+];
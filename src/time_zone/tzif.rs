@@ -0,0 +1,240 @@
+//! Parsing of the Time Zone Information Format (`TZif`), as described in [RFC 8536].
+//!
+//! [RFC 8536]: https://tools.ietf.org/html/rfc8536
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error;
+use crate::time_zone::{LocalTimeType, PosixTz, Transition};
+use crate::UtcOffset;
+
+/// The magic number that every `TZif` file begins with.
+const MAGIC: &[u8] = b"TZif";
+
+/// The contents of a `TZif` file that are relevant to determining the local time.
+#[derive(Debug)]
+pub(crate) struct Tzif {
+    /// All transitions, sorted in ascending order.
+    pub(crate) transitions: Vec<Transition>,
+    /// The local time types that transitions refer to.
+    pub(crate) local_time_types: Vec<LocalTimeType>,
+    /// The rules in effect after the last transition, if present.
+    pub(crate) posix_tz: Option<PosixTz>,
+}
+
+/// The counts stored in the header of a `TZif` file.
+#[derive(Debug, Clone, Copy)]
+struct Header {
+    /// The version of the file, where `0` is version 1.
+    version: u8,
+    /// The number of UT/local indicators.
+    isutcnt: usize,
+    /// The number of standard/wall indicators.
+    isstdcnt: usize,
+    /// The number of leap second records.
+    leapcnt: usize,
+    /// The number of transition times.
+    timecnt: usize,
+    /// The number of local time type records.
+    typecnt: usize,
+    /// The number of bytes used for abbreviations.
+    charcnt: usize,
+}
+
+/// A cursor over the remaining bytes of a `TZif` file.
+#[derive(Debug)]
+struct Cursor<'a>(&'a [u8]);
+
+impl<'a> Cursor<'a> {
+    /// Consume the next `len` bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8], error::TimeZone> {
+        if self.0.len() < len {
+            return Err(error::TimeZone::InvalidTzif("unexpected end of data"));
+        }
+        let (taken, remaining) = self.0.split_at(len);
+        self.0 = remaining;
+        Ok(taken)
+    }
+
+    /// Consume `count` records of `size` bytes each.
+    fn take_records(&mut self, count: usize, size: usize) -> Result<&'a [u8], error::TimeZone> {
+        let len = count
+            .checked_mul(size)
+            .ok_or(error::TimeZone::InvalidTzif("unexpected end of data"))?;
+        self.take(len)
+    }
+
+    /// Consume a single byte.
+    fn read_u8(&mut self) -> Result<u8, error::TimeZone> {
+        Ok(self.take(1)?[0])
+    }
+
+    /// Consume a big-endian, unsigned 32-bit integer.
+    fn read_u32(&mut self) -> Result<u32, error::TimeZone> {
+        Ok(read_be(self.take(4)?) as u32)
+    }
+}
+
+/// Interpret up to eight big-endian bytes as a two's complement integer of the same width.
+fn read_be(bytes: &[u8]) -> i64 {
+    let value = bytes
+        .iter()
+        .fold(0_u64, |value, &byte| value << 8 | byte as u64);
+    let shift = 64 - 8 * bytes.len() as u32;
+    (value << shift) as i64 >> shift
+}
+
+/// Parse the header of a `TZif` data block.
+fn parse_header(cursor: &mut Cursor<'_>) -> Result<Header, error::TimeZone> {
+    if cursor.take(4)? != MAGIC {
+        return Err(error::TimeZone::InvalidTzif("missing magic number"));
+    }
+    let version = match cursor.read_u8()? {
+        0 => 0,
+        version @ b'2'..=b'4' => version - b'0',
+        _ => return Err(error::TimeZone::InvalidTzif("unsupported version")),
+    };
+    cursor.take(15)?;
+
+    let header = Header {
+        version,
+        isutcnt: cursor.read_u32()? as _,
+        isstdcnt: cursor.read_u32()? as _,
+        leapcnt: cursor.read_u32()? as _,
+        timecnt: cursor.read_u32()? as _,
+        typecnt: cursor.read_u32()? as _,
+        charcnt: cursor.read_u32()? as _,
+    };
+
+    if header.typecnt == 0 || header.typecnt > 256 {
+        return Err(error::TimeZone::InvalidTzif(
+            "invalid number of local time types",
+        ));
+    }
+    if header.charcnt == 0 {
+        return Err(error::TimeZone::InvalidTzif(
+            "missing time zone abbreviations",
+        ));
+    }
+    if (header.isutcnt != 0 && header.isutcnt != header.typecnt)
+        || (header.isstdcnt != 0 && header.isstdcnt != header.typecnt)
+    {
+        return Err(error::TimeZone::InvalidTzif("invalid number of indicators"));
+    }
+
+    Ok(header)
+}
+
+/// Parse a `TZif` data block, where transition times are `time_size` bytes wide.
+fn parse_data_block(
+    cursor: &mut Cursor<'_>,
+    header: Header,
+    time_size: usize,
+) -> Result<Tzif, error::TimeZone> {
+    let transition_times = cursor.take_records(header.timecnt, time_size)?;
+    let transition_types = cursor.take_records(header.timecnt, 1)?;
+    let local_time_type_records = cursor.take_records(header.typecnt, 6)?;
+    let abbreviations = cursor.take_records(header.charcnt, 1)?;
+    cursor.take_records(header.leapcnt, time_size + 4)?;
+    cursor.take_records(header.isstdcnt, 1)?;
+    cursor.take_records(header.isutcnt, 1)?;
+
+    let mut transitions: Vec<Transition> = Vec::with_capacity(header.timecnt);
+    for (time, &local_time_type) in transition_times
+        .chunks_exact(time_size)
+        .zip(transition_types)
+    {
+        let unix_timestamp = read_be(time);
+        if (local_time_type as usize) >= header.typecnt {
+            return Err(error::TimeZone::InvalidTzif(
+                "invalid local time type index",
+            ));
+        }
+        if let Some(previous) = transitions.last() {
+            if previous.unix_timestamp >= unix_timestamp {
+                return Err(error::TimeZone::InvalidTzif(
+                    "transitions are not in ascending order",
+                ));
+            }
+        }
+        transitions.push(Transition {
+            unix_timestamp,
+            local_time_type: local_time_type as _,
+        });
+    }
+
+    let local_time_types = local_time_type_records
+        .chunks_exact(6)
+        .map(|record| {
+            let offset = UtcOffset::from_whole_seconds(read_be(&record[..4]) as _)
+                .map_err(|_| error::TimeZone::InvalidTzif("UTC offset out of range"))?;
+            let is_dst = match record[4] {
+                0 => false,
+                1 => true,
+                _ => return Err(error::TimeZone::InvalidTzif("invalid DST indicator")),
+            };
+            let abbreviation = abbreviations
+                .get(record[5] as usize..)
+                .and_then(|bytes| Some(&bytes[..bytes.iter().position(|&byte| byte == 0)?]))
+                .and_then(|bytes| core::str::from_utf8(bytes).ok())
+                .ok_or(error::TimeZone::InvalidTzif("invalid abbreviation"))?;
+
+            Ok(LocalTimeType {
+                offset,
+                is_dst,
+                abbreviation: String::from(abbreviation),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Tzif {
+        transitions,
+        local_time_types,
+        posix_tz: None,
+    })
+}
+
+/// Parse the footer of a version 2+ `TZif` file. The footer is a POSIX TZ string enclosed in
+/// newlines, and may be empty.
+fn parse_footer<'a>(cursor: &mut Cursor<'a>) -> Result<&'a str, error::TimeZone> {
+    if cursor.read_u8()? != b'\n' {
+        return Err(error::TimeZone::InvalidTzif("invalid footer"));
+    }
+    let len = cursor
+        .0
+        .iter()
+        .position(|&byte| byte == b'\n')
+        .ok_or(error::TimeZone::InvalidTzif("invalid footer"))?;
+    let footer = cursor.take(len)?;
+    cursor.take(1)?;
+
+    match core::str::from_utf8(footer) {
+        Ok(footer) if footer.is_ascii() => Ok(footer),
+        _ => Err(error::TimeZone::InvalidTzif("invalid footer")),
+    }
+}
+
+/// Parse a complete `TZif` file.
+///
+/// For version 2+ files, the version 1 data block is skipped in favor of the 64-bit data block.
+/// Leap second records are ignored.
+pub(crate) fn parse(bytes: &[u8]) -> Result<Tzif, error::TimeZone> {
+    let mut cursor = Cursor(bytes);
+
+    let header = parse_header(&mut cursor)?;
+    let v1 = parse_data_block(&mut cursor, header, 4)?;
+    if header.version == 0 {
+        return Ok(v1);
+    }
+
+    let header = parse_header(&mut cursor)?;
+    let mut tzif = parse_data_block(&mut cursor, header, 8)?;
+    let footer = parse_footer(&mut cursor)?;
+    if !footer.is_empty() {
+        tzif.posix_tz = Some(
+            PosixTz::parse(footer).map_err(|_| error::TimeZone::InvalidTzif("invalid footer"))?,
+        );
+    }
+    Ok(tzif)
+}
@@ -0,0 +1,56 @@
+use crate::Duration;
+
+/// A unit of time that a [`Time`](crate::Time), [`PrimitiveDateTime`](crate::PrimitiveDateTime),
+/// or [`OffsetDateTime`](crate::OffsetDateTime) can be truncated or rounded to.
+///
+/// Units of a day or longer have no effect on a `Time` other than resetting it to midnight.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    #[allow(clippy::missing_docs_in_private_items)]
+    Microsecond,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Millisecond,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Second,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Minute,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Hour,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Day,
+    /// A week beginning on Monday, as with ISO weeks.
+    IsoWeek,
+    /// A week beginning on Sunday.
+    SundayWeek,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Month,
+    /// Three months, beginning in January, April, July, or October.
+    Quarter,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Year,
+}
+
+impl Unit {
+    /// Get the length of the unit, if it is fixed.
+    pub(crate) const fn fixed_duration(self) -> Option<Duration> {
+        match self {
+            Self::Microsecond => Some(Duration::MICROSECOND),
+            Self::Millisecond => Some(Duration::MILLISECOND),
+            Self::Second => Some(Duration::SECOND),
+            Self::Minute => Some(Duration::MINUTE),
+            Self::Hour => Some(Duration::HOUR),
+            Self::Day => Some(Duration::DAY),
+            Self::IsoWeek | Self::SundayWeek => Some(Duration::WEEK),
+            Self::Month | Self::Quarter | Self::Year => None,
+        }
+    }
+
+    /// Whether the unit is shorter than a day.
+    pub(crate) const fn is_time(self) -> bool {
+        matches!(
+            self,
+            Self::Microsecond | Self::Millisecond | Self::Second | Self::Minute | Self::Hour
+        )
+    }
+}
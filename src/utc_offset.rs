@@ -94,6 +94,24 @@ impl UtcOffset {
 
         Ok(Self::__from_hms_unchecked(hours, minutes, seconds))
     }
+
+    /// Create a `UtcOffset` representing an offset by the number of seconds provided.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::from_whole_seconds(3_723)?.as_hms(), (1, 2, 3));
+    /// assert_eq!(UtcOffset::from_whole_seconds(-3_723)?.as_hms(), (-1, -2, -3));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn from_whole_seconds(seconds: i32) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(seconds in -86_399 => 86_399);
+
+        Ok(Self::__from_hms_unchecked(
+            (seconds / 3_600) as _,
+            ((seconds / 60) % 60) as _,
+            (seconds % 60) as _,
+        ))
+    }
     // endregion constructors
 
     // region: getters
@@ -223,8 +241,16 @@ impl UtcOffset {
     /// # }
     /// ```
     ///
-    /// Due to a [soundness bug](https://github.com/time-rs/time/issues/293), the error value is
-    /// currently always returned on Unix-like platforms.
+    /// On Unix-like platforms, the time zone is determined from the `TZ` environment variable if it
+    /// is set, or `/etc/localtime` otherwise. `TZ` may be any of the following, optionally preceded
+    /// by `:`:
+    ///
+    /// - an empty string, for UTC
+    /// - the absolute path of a `TZif` file
+    /// - the name of a time zone, such as `America/New_York`, which is loaded from `TZDIR`
+    ///   (`/usr/share/zoneinfo` by default) or, with the `tzdb-embedded` feature, the embedded
+    ///   time zone database
+    /// - a POSIX TZ string, such as `EST5EDT,M3.2.0,M11.1.0`
     #[cfg(feature = "local-offset")]
     #[cfg_attr(__time_03_docs, doc(cfg(feature = "local-offset")))]
     pub fn local_offset_at(datetime: OffsetDateTime) -> Result<Self, error::IndeterminateOffset> {
@@ -262,7 +288,7 @@ impl UtcOffset {
         output: &mut impl io::Write,
         format: &F,
     ) -> Result<usize, F::Error> {
-        format.format_into(output, None, None, Some(self))
+        format.format_into(output, None, None, Some(self), None)
     }
 
     /// Format the `UtcOffset` using the provided format description. The format description will
@@ -276,7 +302,7 @@ impl UtcOffset {
     /// # Ok::<_, time::Error>(())
     /// ```
     pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
-        format.format(None, None, Some(self))
+        format.format(None, None, Some(self), None)
     }
 }
 
@@ -319,7 +345,9 @@ impl fmt::Display for UtcOffset {
         ];
         match self.format(&FORMAT) {
             Ok(ref s) => f.write_str(s),
-            Err(error::Format::InvalidComponent(_)) => {
+            Err(error::Format::InvalidComponent(_))
+            | Err(error::Format::Rfc2822YearBefore1900)
+            | Err(error::Format::Rfc2822OffsetSecond) => {
                 unreachable!("A well-known format is not used")
             }
             Err(error::Format::InsufficientTypeInformation) => {
@@ -345,14 +373,21 @@ impl Neg for UtcOffset {
 #[cfg_attr(__time_03_docs, doc(cfg(feature = "local-offset")))]
 #[allow(clippy::too_many_lines, clippy::missing_const_for_fn)]
 fn local_offset_at(datetime: OffsetDateTime) -> Option<UtcOffset> {
-    // See #293 for details.
+    // Determine the time zone the same way the C library does, but without calling into it. This
+    // avoids the soundness issues of `localtime_r` (see #293).
+    #[cfg(target_family = "unix")]
+    {
+        if let Ok(time_zone) = crate::TimeZone::local() {
+            return Some(time_zone.offset_at(datetime));
+        }
+    }
     #[cfg(all(target_family = "unix", not(unsound_local_offset)))]
     {
-        let _ = datetime;
         None
     }
-    // Let a user explicitly opt-in to unsound behavior. As this is not done via feature flags, it
-    // can only be enabled by the end user. It must be explicitly passed on each compilation.
+    // Let a user explicitly opt-in to unsound behavior as a fallback. As this is not done via
+    // feature flags, it can only be enabled by the end user. It must be explicitly passed on each
+    // compilation.
     #[cfg(all(target_family = "unix", unsound_local_offset))]
     {
         use core::mem::MaybeUninit;
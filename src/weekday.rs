@@ -61,6 +61,26 @@ impl Weekday {
         }
     }
 
+    /// Get the weekday `n` days after this one.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::Monday.nth_next(1), Weekday::Tuesday);
+    /// assert_eq!(Weekday::Sunday.nth_next(10), Weekday::Wednesday);
+    /// assert_eq!(Weekday::Friday.nth_next(7), Weekday::Friday);
+    /// ```
+    pub const fn nth_next(self, n: u8) -> Self {
+        match (self.number_days_from_monday() + n % 7) % 7 {
+            0 => Monday,
+            1 => Tuesday,
+            2 => Wednesday,
+            3 => Thursday,
+            4 => Friday,
+            5 => Saturday,
+            _ => Sunday,
+        }
+    }
+
     /// Get the one-indexed number of days from Monday.
     ///
     /// ```rust
@@ -0,0 +1,633 @@
+//! The [`ZonedDateTime`] struct and its associated `impl`s.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::time::Duration as StdDuration;
+#[cfg(feature = "formatting")]
+use std::io;
+
+#[cfg(feature = "parsing")]
+use crate::error;
+#[cfg(feature = "formatting")]
+use crate::formatting::formattable::sealed::Formattable;
+#[cfg(feature = "parsing")]
+use crate::parsing::parsable::sealed::Parsable;
+use crate::time_zone::LocalTimeType;
+use crate::{
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, TimeZone, UtcOffset, Weekday,
+};
+
+/// A [`PrimitiveDateTime`] in a [`TimeZone`].
+///
+/// Unlike an [`OffsetDateTime`], the offset of a `ZonedDateTime` is not fixed. After any
+/// arithmetic, the offset is that of the time zone at the resulting moment.
+///
+/// ```rust
+/// # use time::{macros::{datetime, offset, time}, time_zone::PosixTz, Duration, TimeZone};
+/// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+/// let datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york) + Duration::days(1);
+/// assert_eq!(datetime.time(), time!("13:00"));
+/// assert_eq!(datetime.offset(), offset!("-4"));
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// All comparisons are performed using the UTC time.
+// Internally, a `ZonedDateTime` is an `OffsetDateTime` whose offset is always the one in effect in
+// the time zone at that moment. Every operation that changes the moment re-resolves the offset.
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Eq)]
+pub struct ZonedDateTime<'a> {
+    /// The moment, in the offset of the time zone at that moment.
+    pub(crate) datetime: OffsetDateTime,
+    /// The time zone that determines the offset.
+    pub(crate) time_zone: &'a TimeZone,
+}
+
+impl<'a> ZonedDateTime<'a> {
+    // region: now
+    /// Create a new `ZonedDateTime` with the current date and time in the provided time zone.
+    ///
+    /// ```rust
+    /// # use time::{TimeZone, ZonedDateTime};
+    /// # let time_zone = TimeZone::from(time::time_zone::PosixTz::parse("UTC0")?);
+    /// assert!(ZonedDateTime::now(&time_zone).year() >= 2019);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(__time_03_docs, doc(cfg(feature = "std")))]
+    pub fn now(time_zone: &'a TimeZone) -> Self {
+        OffsetDateTime::now_utc().to_zoned(time_zone)
+    }
+    // endregion now
+
+    /// Convert the `ZonedDateTime` to another time zone, preserving the moment.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, time}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let paris = TimeZone::from(PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?);
+    /// let datetime = datetime!("2021-06-01 12:00 UTC").to_zoned(&new_york);
+    /// assert_eq!(datetime.time(), time!("8:00"));
+    /// assert_eq!(datetime.to_timezone(&paris).time(), time!("14:00"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn to_timezone(self, time_zone: &TimeZone) -> ZonedDateTime<'_> {
+        self.datetime.to_zoned(time_zone)
+    }
+
+    /// Convert the `ZonedDateTime` to an [`OffsetDateTime`] with the provided offset, preserving
+    /// the moment.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// assert_eq!(
+    ///     datetime!("2021-06-01 12:00 UTC")
+    ///         .to_zoned(&new_york)
+    ///         .to_offset(offset!("UTC")),
+    ///     datetime!("2021-06-01 12:00 UTC"),
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn to_offset(self, offset: UtcOffset) -> OffsetDateTime {
+        self.datetime.to_offset(offset)
+    }
+
+    /// Convert the `ZonedDateTime` to an [`OffsetDateTime`] with the offset currently in effect.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-06-01 12:00 UTC").to_zoned(&new_york);
+    /// assert_eq!(datetime.to_offset_date_time().offset(), offset!("-4"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn to_offset_date_time(self) -> OffsetDateTime {
+        self.datetime
+    }
+
+    // region: getters
+    /// Get the [`TimeZone`].
+    pub const fn time_zone(self) -> &'a TimeZone {
+        self.time_zone
+    }
+
+    /// Get the [`LocalTimeType`] in effect.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-06-01 12:00 UTC").to_zoned(&new_york);
+    /// assert_eq!(datetime.local_time_type().abbreviation(), "EDT");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn local_time_type(self) -> &'a LocalTimeType {
+        self.time_zone.local_time_type_at(self.datetime)
+    }
+
+    /// Get the [`UtcOffset`] in effect.
+    ///
+    /// ```rust
+    /// # use time::{macros::{datetime, offset}, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// assert_eq!(
+    ///     datetime!("2021-01-01 12:00 UTC").to_zoned(&new_york).offset(),
+    ///     offset!("-5"),
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn offset(self) -> UtcOffset {
+        self.datetime.offset()
+    }
+
+    /// Get the [Unix timestamp](https://en.wikipedia.org/wiki/Unix_time).
+    pub const fn unix_timestamp(self) -> i64 {
+        self.datetime.unix_timestamp()
+    }
+
+    /// Get the Unix timestamp in nanoseconds.
+    pub const fn unix_timestamp_nanos(self) -> i128 {
+        self.datetime.unix_timestamp_nanos()
+    }
+
+    /// Get the local [`PrimitiveDateTime`].
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// assert_eq!(
+    ///     datetime!("2021-01-01 12:00 UTC").to_zoned(&new_york).datetime(),
+    ///     datetime!("2021-01-01 7:00"),
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn datetime(self) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(self.datetime.date(), self.datetime.time())
+    }
+
+    /// Get the local [`Date`].
+    pub const fn date(self) -> Date {
+        self.datetime.date()
+    }
+
+    /// Get the local [`Time`].
+    pub const fn time(self) -> Time {
+        self.datetime.time()
+    }
+
+    // region: date getters
+    /// Get the year of the local date.
+    pub const fn year(self) -> i32 {
+        self.datetime.year()
+    }
+
+    /// Get the month of the local date.
+    pub const fn month(self) -> Month {
+        self.datetime.month()
+    }
+
+    /// Get the day of the local date. The returned value will always be in the range `1..=31`.
+    pub const fn day(self) -> u8 {
+        self.datetime.day()
+    }
+
+    /// Get the day of the year of the local date. The returned value will always be in the range
+    /// `1..=366`.
+    pub const fn ordinal(self) -> u16 {
+        self.datetime.ordinal()
+    }
+
+    /// Get the weekday of the local date.
+    pub const fn weekday(self) -> Weekday {
+        self.datetime.weekday()
+    }
+    // endregion date getters
+
+    // region: time getters
+    /// Get the clock hour of the local time. The returned value will always be in the range
+    /// `0..24`.
+    pub const fn hour(self) -> u8 {
+        self.datetime.hour()
+    }
+
+    /// Get the minute within the hour of the local time. The returned value will always be in the
+    /// range `0..60`.
+    pub const fn minute(self) -> u8 {
+        self.datetime.minute()
+    }
+
+    /// Get the second within the minute of the local time. The returned value will always be in
+    /// the range `0..60`.
+    pub const fn second(self) -> u8 {
+        self.datetime.second()
+    }
+
+    /// Get the nanoseconds within the second of the local time. The returned value will always be
+    /// in the range `0..1_000_000_000`.
+    pub const fn nanosecond(self) -> u32 {
+        self.datetime.nanosecond()
+    }
+    // endregion time getters
+    // endregion getters
+}
+
+// region: checked and saturating arithmetic
+/// Methods that add or subtract a duration without panicking. As with the operators, the offset is
+/// re-resolved in the time zone afterwards.
+impl<'a> ZonedDateTime<'a> {
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::PosixTz, Duration, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york);
+    /// assert_eq!(
+    ///     datetime.checked_add(Duration::days(1)),
+    ///     Some(datetime!("2021-03-14 13:00 -4").to_zoned(&new_york))
+    /// );
+    /// assert_eq!(datetime.checked_add(Duration::MAX), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        self.checked_rezone(self.datetime.checked_add(duration)?)
+    }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalStdDuration, macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york);
+    /// assert_eq!(
+    ///     datetime.checked_add_std(1.std_days()),
+    ///     Some(datetime!("2021-03-14 13:00 -4").to_zoned(&new_york))
+    /// );
+    /// assert_eq!(datetime.checked_add_std(std::time::Duration::MAX), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        self.checked_rezone(self.datetime.checked_add_std(duration)?)
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::PosixTz, Duration, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-03-14 13:00 -4").to_zoned(&new_york);
+    /// assert_eq!(
+    ///     datetime.checked_sub(Duration::days(1)),
+    ///     Some(datetime!("2021-03-13 12:00 -5").to_zoned(&new_york))
+    /// );
+    /// assert_eq!(datetime.checked_sub(Duration::MAX), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        self.checked_rezone(self.datetime.checked_sub(duration)?)
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalStdDuration, macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-03-14 13:00 -4").to_zoned(&new_york);
+    /// assert_eq!(
+    ///     datetime.checked_sub_std(1.std_days()),
+    ///     Some(datetime!("2021-03-13 12:00 -5").to_zoned(&new_york))
+    /// );
+    /// assert_eq!(datetime.checked_sub_std(std::time::Duration::MAX), None);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        self.checked_rezone(self.datetime.checked_sub_std(duration)?)
+    }
+
+    /// Computes `self + duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::PosixTz, Duration, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york);
+    /// assert_eq!(
+    ///     datetime.saturating_add(Duration::days(1)),
+    ///     datetime!("2021-03-14 13:00 -4").to_zoned(&new_york)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[must_use = "This method does not mutate the original `ZonedDateTime`."]
+    pub fn saturating_add(self, duration: Duration) -> Self {
+        match self.checked_add(duration) {
+            Some(datetime) => datetime,
+            None if duration.is_negative() => Self::min_in(self.time_zone),
+            None => Self::max_in(self.time_zone),
+        }
+    }
+
+    /// Computes `self + duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalStdDuration, macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york);
+    /// assert_eq!(
+    ///     datetime.saturating_add_std(1.std_days()),
+    ///     datetime!("2021-03-14 13:00 -4").to_zoned(&new_york)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[must_use = "This method does not mutate the original `ZonedDateTime`."]
+    pub fn saturating_add_std(self, duration: StdDuration) -> Self {
+        self.checked_add_std(duration)
+            .unwrap_or_else(|| Self::max_in(self.time_zone))
+    }
+
+    /// Computes `self - duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{macros::datetime, time_zone::PosixTz, Duration, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-03-14 13:00 -4").to_zoned(&new_york);
+    /// assert_eq!(
+    ///     datetime.saturating_sub(Duration::days(1)),
+    ///     datetime!("2021-03-13 12:00 -5").to_zoned(&new_york)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[must_use = "This method does not mutate the original `ZonedDateTime`."]
+    pub fn saturating_sub(self, duration: Duration) -> Self {
+        match self.checked_sub(duration) {
+            Some(datetime) => datetime,
+            None if duration.is_negative() => Self::max_in(self.time_zone),
+            None => Self::min_in(self.time_zone),
+        }
+    }
+
+    /// Computes `self - duration`, saturating value on overflow.
+    ///
+    /// ```rust
+    /// # use time::{ext::NumericalStdDuration, macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let datetime = datetime!("2021-03-14 13:00 -4").to_zoned(&new_york);
+    /// assert_eq!(
+    ///     datetime.saturating_sub_std(1.std_days()),
+    ///     datetime!("2021-03-13 12:00 -5").to_zoned(&new_york)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[must_use = "This method does not mutate the original `ZonedDateTime`."]
+    pub fn saturating_sub_std(self, duration: StdDuration) -> Self {
+        self.checked_sub_std(duration)
+            .unwrap_or_else(|| Self::min_in(self.time_zone))
+    }
+
+    /// Re-resolve the offset of the provided moment in the time zone, returning `None` if the
+    /// local datetime is out of range.
+    fn checked_rezone(self, datetime: OffsetDateTime) -> Option<Self> {
+        Some(Self {
+            datetime: datetime.checked_to_offset(self.time_zone.offset_at(datetime))?,
+            time_zone: self.time_zone,
+        })
+    }
+
+    /// The earliest moment that can be represented in the time zone.
+    fn min_in(time_zone: &'a TimeZone) -> Self {
+        let offset = time_zone.offset_at(PrimitiveDateTime::MIN.assume_utc());
+        OffsetDateTime::min_in_offset(offset).to_zoned(time_zone)
+    }
+
+    /// The latest moment that can be represented in the time zone.
+    fn max_in(time_zone: &'a TimeZone) -> Self {
+        let offset = time_zone.offset_at(PrimitiveDateTime::MAX.assume_utc());
+        OffsetDateTime::max_in_offset(offset).to_zoned(time_zone)
+    }
+}
+// endregion checked and saturating arithmetic
+
+// region: formatting & parsing
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl ZonedDateTime<'_> {
+    /// Format the `ZonedDateTime` using the provided format description. The formatted value will
+    /// be output to the provided writer. The format description will typically be parsed by using
+    /// [`format_description::parse`](crate::format_description::parse()).
+    pub fn format_into<F: Formattable>(
+        self,
+        output: &mut impl io::Write,
+        format: &F,
+    ) -> Result<usize, F::Error> {
+        format.format_into(
+            output,
+            Some(self.date()),
+            Some(self.time()),
+            Some(self.offset()),
+            Some(self.time_zone),
+        )
+    }
+
+    /// Format the `ZonedDateTime` using the provided format description. The format description
+    /// will typically be parsed by using
+    /// [`format_description::parse`](crate::format_description::parse()).
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::datetime, time_zone::PosixTz, TimeZone};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let format = format_description::parse(
+    ///     "[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory] [tz_abbrev]",
+    /// )?;
+    /// assert_eq!(
+    ///     datetime!("2021-06-01 12:00 UTC")
+    ///         .to_zoned(&new_york)
+    ///         .format(&format)?,
+    ///     "2021-06-01 08:00 -04 EDT"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format<F: Formattable>(self, format: &F) -> Result<String, F::Error> {
+        format.format(
+            Some(self.date()),
+            Some(self.time()),
+            Some(self.offset()),
+            Some(self.time_zone),
+        )
+    }
+}
+
+#[cfg(feature = "parsing")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "parsing")))]
+impl<'a> ZonedDateTime<'a> {
+    /// Parse a `ZonedDateTime` in the provided time zone from the input using the provided format
+    /// description. The format description will typically be parsed by using
+    /// [`format_description::parse`](crate::format_description::parse()).
+    ///
+    /// The format description must contain a date and time. If it also contains an offset, the
+    /// parsed value is converted to the time zone. Otherwise, the parsed value is the local
+    /// datetime, which is resolved using
+    /// [`LocalResult::shift_forward`](crate::time_zone::LocalResult::shift_forward). To handle
+    /// ambiguous and nonexistent datetimes differently, parse a [`PrimitiveDateTime`] and use
+    /// [`PrimitiveDateTime::assume_timezone`].
+    ///
+    /// If the input contains the name of a time zone other than the provided one, the local
+    /// datetime is resolved in the named time zone before being converted. Use
+    /// [`TimeZone::parse`] to obtain the named time zone itself.
+    ///
+    /// ```rust
+    /// # use time::{format_description, macros::offset, time_zone::PosixTz};
+    /// # use time::{TimeZone, ZonedDateTime};
+    /// let new_york = TimeZone::from(PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    /// let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]")?;
+    /// let datetime = ZonedDateTime::parse("2021-11-07 01:30", &format, &new_york)?;
+    /// assert_eq!(datetime.offset(), offset!("-4"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(
+        input: &str,
+        description: &impl Parsable,
+        time_zone: &'a TimeZone,
+    ) -> Result<Self, error::Parse> {
+        use core::convert::TryFrom;
+
+        use crate::error::TryFromParsed;
+
+        let parsed = description.parse(input.as_bytes())?;
+        let datetime = PrimitiveDateTime::try_from(parsed)?;
+        match UtcOffset::try_from(parsed) {
+            Ok(offset) => Ok(datetime.assume_offset(offset).to_zoned(time_zone)),
+            Err(TryFromParsed::InsufficientInformation) => {
+                #[cfg(feature = "tzdb-embedded")]
+                if parsed.time_zone_name.is_some() && parsed.time_zone_name != time_zone.name() {
+                    let named = TimeZone::try_from(parsed)?;
+                    return Ok(datetime
+                        .assume_timezone(&named)
+                        .shift_forward()
+                        .to_timezone(time_zone));
+                }
+                Ok(datetime.assume_timezone(time_zone).shift_forward())
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(feature = "formatting")]
+#[cfg_attr(__time_03_docs, doc(cfg(feature = "formatting")))]
+impl fmt::Display for ZonedDateTime<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.datetime)?;
+        match self.time_zone.name() {
+            Some(name) => write!(f, " {}", name),
+            None => write!(f, " {}", self.local_time_type().abbreviation()),
+        }
+    }
+}
+// endregion formatting & parsing
+
+// region: trait impls
+impl fmt::Debug for ZonedDateTime<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The full transition table of the time zone is not useful here.
+        f.debug_struct("ZonedDateTime")
+            .field("datetime", &self.datetime)
+            .field("time_zone", &self.time_zone.name())
+            .finish()
+    }
+}
+
+impl PartialEq for ZonedDateTime<'_> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.datetime.eq(&rhs.datetime)
+    }
+}
+
+impl PartialOrd for ZonedDateTime<'_> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for ZonedDateTime<'_> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.datetime.cmp(&rhs.datetime)
+    }
+}
+
+impl Hash for ZonedDateTime<'_> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        // We need to distinguish this from an `OffsetDateTime`, which would otherwise conflict.
+        hasher.write(b"ZonedDateTime");
+        self.datetime.hash(hasher);
+    }
+}
+
+impl Add<Duration> for ZonedDateTime<'_> {
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self::Output {
+        (self.datetime + duration).to_zoned(self.time_zone)
+    }
+}
+
+impl Add<StdDuration> for ZonedDateTime<'_> {
+    type Output = Self;
+
+    fn add(self, duration: StdDuration) -> Self::Output {
+        (self.datetime + duration).to_zoned(self.time_zone)
+    }
+}
+
+impl AddAssign<Duration> for ZonedDateTime<'_> {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl AddAssign<StdDuration> for ZonedDateTime<'_> {
+    fn add_assign(&mut self, duration: StdDuration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for ZonedDateTime<'_> {
+    type Output = Self;
+
+    fn sub(self, duration: Duration) -> Self::Output {
+        (self.datetime - duration).to_zoned(self.time_zone)
+    }
+}
+
+impl Sub<StdDuration> for ZonedDateTime<'_> {
+    type Output = Self;
+
+    fn sub(self, duration: StdDuration) -> Self::Output {
+        (self.datetime - duration).to_zoned(self.time_zone)
+    }
+}
+
+impl SubAssign<Duration> for ZonedDateTime<'_> {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl SubAssign<StdDuration> for ZonedDateTime<'_> {
+    fn sub_assign(&mut self, duration: StdDuration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub<ZonedDateTime<'_>> for ZonedDateTime<'_> {
+    type Output = Duration;
+
+    fn sub(self, rhs: ZonedDateTime<'_>) -> Self::Output {
+        self.datetime - rhs.datetime
+    }
+}
+
+impl From<ZonedDateTime<'_>> for OffsetDateTime {
+    fn from(datetime: ZonedDateTime<'_>) -> Self {
+        datetime.datetime
+    }
+}
+// endregion trait impls
@@ -0,0 +1,187 @@
+use std::collections::{BTreeSet, HashSet};
+
+use time::macros::date;
+use time::{BusinessCalendar, Date, HolidaySet, Weekday, Weekday::*};
+
+const EVERY_DAY: [Weekday; 7] = [
+    Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+];
+
+#[test]
+fn holiday_set() {
+    let holidays = vec![date!("2021-12-24"), date!("2021-12-31")];
+    assert!(holidays.is_holiday(date!("2021-12-24")));
+    assert!(!holidays.is_holiday(date!("2021-12-25")));
+    assert!(holidays[..].is_holiday(date!("2021-12-31")));
+    assert!((&holidays).is_holiday(date!("2021-12-31")));
+
+    let btree_set = holidays.iter().copied().collect::<BTreeSet<_>>();
+    assert!(btree_set.is_holiday(date!("2021-12-24")));
+    assert!(!btree_set.is_holiday(date!("2021-12-25")));
+
+    let hash_set = holidays.iter().copied().collect::<HashSet<_>>();
+    assert!(hash_set.is_holiday(date!("2021-12-24")));
+    assert!(!hash_set.is_holiday(date!("2021-12-25")));
+
+    assert!(!().is_holiday(date!("2021-12-25")));
+}
+
+#[test]
+fn is_weekend() {
+    let calendar = BusinessCalendar::SATURDAY_SUNDAY;
+    assert!(!calendar.is_weekend(Monday));
+    assert!(!calendar.is_weekend(Friday));
+    assert!(calendar.is_weekend(Saturday));
+    assert!(calendar.is_weekend(Sunday));
+
+    let calendar = BusinessCalendar::new(&[Friday, Saturday], ());
+    assert!(calendar.is_weekend(Friday));
+    assert!(calendar.is_weekend(Saturday));
+    assert!(!calendar.is_weekend(Sunday));
+
+    assert!(!BusinessCalendar::new(&[], ()).is_weekend(Sunday));
+}
+
+#[test]
+fn is_business_day() {
+    let holidays = [date!("2021-12-24")];
+    let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(&holidays[..]);
+    assert_eq!(calendar.holidays(), &&holidays[..]);
+    assert!(calendar.is_business_day(date!("2021-12-23")));
+    assert!(!calendar.is_business_day(date!("2021-12-24")));
+    assert!(!calendar.is_business_day(date!("2021-12-25")));
+    assert!(!calendar.is_business_day(date!("2021-12-26")));
+    assert!(calendar.is_business_day(date!("2021-12-27")));
+}
+
+#[test]
+fn next_business_day() {
+    let holidays = [date!("2021-12-24"), date!("2021-12-27")];
+    let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(&holidays[..]);
+    assert_eq!(
+        calendar.next_business_day(date!("2021-12-22")),
+        Some(date!("2021-12-23"))
+    );
+    assert_eq!(
+        calendar.next_business_day(date!("2021-12-23")),
+        Some(date!("2021-12-28"))
+    );
+    assert_eq!(
+        calendar.next_business_day(date!("2021-12-25")),
+        Some(date!("2021-12-28"))
+    );
+    assert_eq!(calendar.next_business_day(Date::MAX), None);
+    assert_eq!(
+        BusinessCalendar::new(&EVERY_DAY, ()).next_business_day(date!("2021-12-25")),
+        None
+    );
+}
+
+#[test]
+fn previous_business_day() {
+    let holidays = [date!("2021-12-24"), date!("2021-12-27")];
+    let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(&holidays[..]);
+    assert_eq!(
+        calendar.previous_business_day(date!("2021-12-28")),
+        Some(date!("2021-12-23"))
+    );
+    assert_eq!(
+        calendar.previous_business_day(date!("2021-12-26")),
+        Some(date!("2021-12-23"))
+    );
+    assert_eq!(calendar.previous_business_day(Date::MIN), None);
+    assert_eq!(
+        BusinessCalendar::new(&EVERY_DAY, ()).previous_business_day(date!("2021-12-25")),
+        None
+    );
+}
+
+#[test]
+fn add_business_days() {
+    let holidays = [date!("2021-12-24"), date!("2021-12-31")];
+    let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(&holidays[..]);
+    assert_eq!(
+        calendar.add_business_days(date!("2021-12-22"), 0),
+        Some(date!("2021-12-22"))
+    );
+    assert_eq!(
+        calendar.add_business_days(date!("2021-12-25"), 0),
+        Some(date!("2021-12-25"))
+    );
+    assert_eq!(
+        calendar.add_business_days(date!("2021-12-22"), 2),
+        Some(date!("2021-12-27"))
+    );
+    assert_eq!(
+        calendar.add_business_days(date!("2021-12-25"), 1),
+        Some(date!("2021-12-27"))
+    );
+    assert_eq!(
+        calendar.add_business_days(date!("2021-12-27"), 5),
+        Some(date!("2022-01-04"))
+    );
+    assert_eq!(
+        calendar.add_business_days(date!("2022-01-04"), -5),
+        Some(date!("2021-12-27"))
+    );
+    assert_eq!(
+        calendar.add_business_days(date!("2021-12-26"), -1),
+        Some(date!("2021-12-23"))
+    );
+    assert_eq!(calendar.add_business_days(Date::MAX, 1), None);
+    assert_eq!(calendar.add_business_days(Date::MIN, -1), None);
+    assert_eq!(calendar.add_business_days(Date::MAX, 0), Some(Date::MAX));
+}
+
+#[test]
+fn business_days_between() {
+    let holidays = [date!("2021-12-24"), date!("2021-12-31")];
+    let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(&holidays[..]);
+    assert_eq!(
+        calendar.business_days_between(date!("2021-12-22"), date!("2021-12-22")),
+        0
+    );
+    assert_eq!(
+        calendar.business_days_between(date!("2021-12-22"), date!("2021-12-27")),
+        2
+    );
+    assert_eq!(
+        calendar.business_days_between(date!("2021-12-25"), date!("2021-12-27")),
+        1
+    );
+    assert_eq!(
+        calendar.business_days_between(date!("2021-12-27"), date!("2022-01-04")),
+        5
+    );
+    assert_eq!(
+        calendar.business_days_between(date!("2022-01-04"), date!("2021-12-27")),
+        -5
+    );
+    assert_eq!(
+        calendar.business_days_between(date!("2021-12-26"), date!("2021-12-23")),
+        -1
+    );
+    assert_eq!(
+        calendar.business_days_between(date!("2021-12-23"), date!("2021-12-26")),
+        0
+    );
+
+    let starts = [
+        date!("2021-12-22"),
+        date!("2021-12-25"),
+        date!("2022-01-01"),
+    ];
+    let ends = [
+        date!("2021-12-23"),
+        date!("2021-12-27"),
+        date!("2022-01-07"),
+    ];
+    for start in starts.iter() {
+        for end in ends.iter() {
+            assert_eq!(
+                calendar.add_business_days(*start, calendar.business_days_between(*start, *end)),
+                Some(*end)
+            );
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use time::macros::datetime;
+use time::{error, Cron, OffsetDateTime, PrimitiveDateTime};
+
+/// Get the first `n` times the expression fires after `start`.
+fn take(cron: &str, start: OffsetDateTime, n: usize) -> Vec<OffsetDateTime> {
+    Cron::parse(cron)
+        .expect("expression is valid")
+        .occurrences(start)
+        .take(n)
+        .collect()
+}
+
+#[test]
+fn parse_err() {
+    let err = |s: &str| Cron::parse(s).expect_err("expression is invalid");
+    assert_eq!(err(""), error::Cron::InvalidFieldCount);
+    assert_eq!(err("* * * *"), error::Cron::InvalidFieldCount);
+    assert_eq!(err("* * * * * * * *"), error::Cron::InvalidFieldCount);
+    assert_eq!(err("@fortnightly"), error::Cron::UnknownMacro);
+    assert_eq!(err("60 * * * * *"), error::Cron::InvalidField("second"));
+    assert_eq!(err("60 * * * *"), error::Cron::InvalidField("minute"));
+    assert_eq!(err("* 24 * * *"), error::Cron::InvalidField("hour"));
+    assert_eq!(err("* * 0 * *"), error::Cron::InvalidField("day of month"));
+    assert_eq!(err("* * 32 * *"), error::Cron::InvalidField("day of month"));
+    assert_eq!(
+        err("* * L-31 * *"),
+        error::Cron::InvalidField("day of month")
+    );
+    assert_eq!(err("* * 0W * *"), error::Cron::InvalidField("day of month"));
+    assert_eq!(err("* * * 0 *"), error::Cron::InvalidField("month"));
+    assert_eq!(err("* * * JANUARY *"), error::Cron::InvalidField("month"));
+    assert_eq!(err("* * * ? *"), error::Cron::InvalidField("month"));
+    assert_eq!(err("* * * * 8"), error::Cron::InvalidField("day of week"));
+    assert_eq!(err("* * * * L"), error::Cron::InvalidField("day of week"));
+    assert_eq!(
+        err("* * * * MON#6"),
+        error::Cron::InvalidField("day of week")
+    );
+    assert_eq!(
+        err("* * * * MON#0"),
+        error::Cron::InvalidField("day of week")
+    );
+    assert_eq!(
+        err("0 * * * * * 2030-2020"),
+        error::Cron::InvalidField("year")
+    );
+    assert_eq!(
+        err("0 * * * * * 1000000"),
+        error::Cron::InvalidField("year")
+    );
+    assert_eq!(err("*/0 * * * *"), error::Cron::InvalidField("minute"));
+    assert_eq!(err("1-2-3 * * * *"), error::Cron::InvalidField("minute"));
+    assert_eq!(err("+1 * * * *"), error::Cron::InvalidField("minute"));
+    assert_eq!(err("1, * * * *"), error::Cron::InvalidField("minute"));
+}
+
+#[test]
+fn basic() {
+    let cron = Cron::parse("*/15 9-17 * * MON-FRI").expect("expression is valid");
+    assert_eq!(
+        cron.next_after(datetime!("2021-01-01 9:07 UTC")),
+        Some(datetime!("2021-01-01 9:15 UTC"))
+    );
+    assert_eq!(
+        cron.next_after(datetime!("2021-01-01 9:15 UTC")),
+        Some(datetime!("2021-01-01 9:30 UTC"))
+    );
+    assert_eq!(
+        cron.next_after(datetime!("2021-01-01 17:50 UTC")),
+        Some(datetime!("2021-01-04 9:00 UTC"))
+    );
+    assert_eq!(
+        cron.next_after(datetime!("2021-12-31 23:59:59.999999999 UTC")),
+        Some(datetime!("2022-01-03 9:00 UTC"))
+    );
+
+    assert_eq!(
+        take("@hourly", datetime!("2021-01-01 23:30 UTC"), 2),
+        [
+            datetime!("2021-01-02 0:00 UTC"),
+            datetime!("2021-01-02 1:00 UTC")
+        ]
+    );
+    assert_eq!(
+        take("@yearly", datetime!("2021-01-01 0:00 UTC"), 1),
+        [datetime!("2022-01-01 0:00 UTC")]
+    );
+    assert_eq!(
+        take("@WEEKLY", datetime!("2021-01-01 0:00 UTC"), 1),
+        [datetime!("2021-01-03 0:00 UTC")]
+    );
+}
+
+#[test]
+fn seconds() {
+    let cron = Cron::parse("30 * * * * *").expect("expression is valid");
+    assert_eq!(
+        cron.next_after(datetime!("2021-01-01 0:00:30 UTC")),
+        Some(datetime!("2021-01-01 0:01:30 UTC"))
+    );
+    assert_eq!(
+        cron.next_after(datetime!("2021-01-01 0:00:29.5 UTC")),
+        Some(datetime!("2021-01-01 0:00:30 UTC"))
+    );
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-01 0:00:30 UTC")),
+        Some(datetime!("2020-12-31 23:59:30 UTC"))
+    );
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-01 0:00:30.5 UTC")),
+        Some(datetime!("2021-01-01 0:00:30 UTC"))
+    );
+    assert_eq!(
+        take("*/20 0 0 * * *", datetime!("2021-01-01 0:00 UTC"), 3),
+        [
+            datetime!("2021-01-01 0:00:20 UTC"),
+            datetime!("2021-01-01 0:00:40 UTC"),
+            datetime!("2021-01-02 0:00:00 UTC"),
+        ]
+    );
+}
+
+#[test]
+fn lists_ranges_and_steps() {
+    assert_eq!(
+        take("0 0 1 JAN-DEC/3 *", datetime!("2021-01-01 0:00 UTC"), 4),
+        [
+            datetime!("2021-04-01 0:00 UTC"),
+            datetime!("2021-07-01 0:00 UTC"),
+            datetime!("2021-10-01 0:00 UTC"),
+            datetime!("2022-01-01 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("5,10/20 0 * * *", datetime!("2021-01-01 0:00 UTC"), 4),
+        [
+            datetime!("2021-01-01 0:05 UTC"),
+            datetime!("2021-01-01 0:10 UTC"),
+            datetime!("2021-01-01 0:30 UTC"),
+            datetime!("2021-01-01 0:50 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 * * fri-mon", datetime!("2021-01-05 0:00 UTC"), 5),
+        [
+            datetime!("2021-01-08 0:00 UTC"),
+            datetime!("2021-01-09 0:00 UTC"),
+            datetime!("2021-01-10 0:00 UTC"),
+            datetime!("2021-01-11 0:00 UTC"),
+            datetime!("2021-01-15 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 22-2/2 * * *", datetime!("2021-01-01 0:00 UTC"), 3),
+        [
+            datetime!("2021-01-01 2:00 UTC"),
+            datetime!("2021-01-01 22:00 UTC"),
+            datetime!("2021-01-02 0:00 UTC"),
+        ]
+    );
+    // Both zero and seven are Sunday.
+    assert_eq!(Cron::parse("0 0 * * 0"), Cron::parse("0 0 * * 7"));
+    assert_eq!(Cron::parse("0 0 * * *"), Cron::parse("0 0 ? * ?"));
+}
+
+#[test]
+fn last_day_of_month() {
+    assert_eq!(
+        take("0 0 L * *", datetime!("2020-01-31 0:00 UTC"), 3),
+        [
+            datetime!("2020-02-29 0:00 UTC"),
+            datetime!("2020-03-31 0:00 UTC"),
+            datetime!("2020-04-30 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 L-1,1 * *", datetime!("2021-02-01 0:00 UTC"), 3),
+        [
+            datetime!("2021-02-27 0:00 UTC"),
+            datetime!("2021-03-01 0:00 UTC"),
+            datetime!("2021-03-30 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 LW * *", datetime!("2021-07-01 0:00 UTC"), 4),
+        [
+            datetime!("2021-07-30 0:00 UTC"),
+            datetime!("2021-08-31 0:00 UTC"),
+            datetime!("2021-09-30 0:00 UTC"),
+            datetime!("2021-10-29 0:00 UTC"),
+        ]
+    );
+}
+
+#[test]
+fn nearest_weekday() {
+    let cron = Cron::parse("0 0 15W * *").expect("expression is valid");
+    assert_eq!(
+        cron.next_after(datetime!("2021-05-01 0:00 UTC")),
+        Some(datetime!("2021-05-14 0:00 UTC"))
+    );
+    assert_eq!(
+        cron.next_after(datetime!("2021-08-01 0:00 UTC")),
+        Some(datetime!("2021-08-16 0:00 UTC"))
+    );
+    assert_eq!(
+        cron.next_after(datetime!("2021-09-01 0:00 UTC")),
+        Some(datetime!("2021-09-15 0:00 UTC"))
+    );
+
+    // The nearest weekday is never in another month.
+    assert_eq!(
+        take("0 0 1W * *", datetime!("2021-05-01 0:00 UTC"), 1),
+        [datetime!("2021-05-03 0:00 UTC")]
+    );
+    assert_eq!(
+        take("0 0 31W * *", datetime!("2021-10-01 0:00 UTC"), 2),
+        [
+            datetime!("2021-10-29 0:00 UTC"),
+            datetime!("2021-12-31 0:00 UTC"),
+        ]
+    );
+}
+
+#[test]
+fn nth_and_last_weekday() {
+    assert_eq!(
+        take("0 0 * * MON#2", datetime!("2021-01-01 0:00 UTC"), 2),
+        [
+            datetime!("2021-01-11 0:00 UTC"),
+            datetime!("2021-02-08 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 * * 5#5", datetime!("2021-01-01 0:00 UTC"), 2),
+        [
+            datetime!("2021-01-29 0:00 UTC"),
+            datetime!("2021-04-30 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 * * FRIL", datetime!("2021-01-01 0:00 UTC"), 2),
+        [
+            datetime!("2021-01-29 0:00 UTC"),
+            datetime!("2021-02-26 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 * * 1#1,0L", datetime!("2021-01-01 0:00 UTC"), 3),
+        [
+            datetime!("2021-01-04 0:00 UTC"),
+            datetime!("2021-01-31 0:00 UTC"),
+            datetime!("2021-02-01 0:00 UTC"),
+        ]
+    );
+}
+
+#[test]
+fn day_of_month_or_day_of_week() {
+    assert_eq!(
+        take("0 0 13 * FRI", datetime!("2021-01-01 0:00 UTC"), 3),
+        [
+            datetime!("2021-01-08 0:00 UTC"),
+            datetime!("2021-01-13 0:00 UTC"),
+            datetime!("2021-01-15 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 13 * ?", datetime!("2021-01-01 0:00 UTC"), 2),
+        [
+            datetime!("2021-01-13 0:00 UTC"),
+            datetime!("2021-02-13 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 * * FRI", datetime!("2021-01-01 0:00 UTC"), 2),
+        [
+            datetime!("2021-01-08 0:00 UTC"),
+            datetime!("2021-01-15 0:00 UTC"),
+        ]
+    );
+}
+
+#[test]
+fn years() {
+    assert_eq!(
+        take(
+            "0 0 0 29 2 ? 2021-2030",
+            datetime!("2021-01-01 0:00 UTC"),
+            3
+        ),
+        [
+            datetime!("2024-02-29 0:00 UTC"),
+            datetime!("2028-02-29 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take("0 0 0 1 1 ? */100", datetime!("2021-01-01 0:00 UTC"), 2),
+        [
+            datetime!("2100-01-01 0:00 UTC"),
+            datetime!("2200-01-01 0:00 UTC"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "0 0 0 1 1 ? 2023/5,2022",
+            datetime!("2021-01-01 0:00 UTC"),
+            3
+        ),
+        [
+            datetime!("2022-01-01 0:00 UTC"),
+            datetime!("2023-01-01 0:00 UTC"),
+            datetime!("2028-01-01 0:00 UTC"),
+        ]
+    );
+
+    let cron = Cron::parse("0 0 0 1 1 ? 2000-2010/4").expect("expression is valid");
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-01 0:00 UTC")),
+        Some(datetime!("2008-01-01 0:00 UTC"))
+    );
+    assert_eq!(cron.previous_before(datetime!("2000-01-01 0:00 UTC")), None);
+    assert_eq!(cron.next_after(datetime!("2008-01-01 0:00 UTC")), None);
+}
+
+#[test]
+fn never() {
+    let cron = Cron::parse("0 0 30 2 *").expect("expression is valid");
+    assert_eq!(cron.next_after(datetime!("2021-01-01 0:00 UTC")), None);
+    assert_eq!(cron.previous_before(datetime!("2021-01-01 0:00 UTC")), None);
+    assert_eq!(
+        cron.occurrences(datetime!("2021-01-01 0:00 UTC")).next(),
+        None
+    );
+}
+
+#[test]
+fn previous_before() {
+    let cron = Cron::parse("0 9 * * MON-FRI").expect("expression is valid");
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-04 9:00 UTC")),
+        Some(datetime!("2021-01-01 9:00 UTC"))
+    );
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-04 9:00:00.5 UTC")),
+        Some(datetime!("2021-01-04 9:00 UTC"))
+    );
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-04 0:00 UTC")),
+        Some(datetime!("2021-01-01 9:00 UTC"))
+    );
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-04 23:00 UTC")),
+        Some(datetime!("2021-01-04 9:00 UTC"))
+    );
+
+    let cron = Cron::parse("0 0 L * *").expect("expression is valid");
+    assert_eq!(
+        cron.previous_before(datetime!("2021-03-01 0:00 UTC")),
+        Some(datetime!("2021-02-28 0:00 UTC"))
+    );
+
+    let cron = Cron::parse("*/10 1,2 * * * *").expect("expression is valid");
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-01 0:03 UTC")),
+        Some(datetime!("2021-01-01 0:02:50 UTC"))
+    );
+    assert_eq!(
+        cron.previous_before(datetime!("2021-01-01 0:01 UTC")),
+        Some(datetime!("2020-12-31 23:02:50 UTC"))
+    );
+}
+
+#[test]
+fn offset() {
+    assert_eq!(
+        take("0 9 * * *", datetime!("2021-01-01 9:00 -5"), 2),
+        [
+            datetime!("2021-01-02 9:00 -5"),
+            datetime!("2021-01-03 9:00 -5"),
+        ]
+    );
+    assert_eq!(
+        Cron::parse("0 9 * * *")
+            .expect("expression is valid")
+            .previous_before(datetime!("2021-01-01 10:00 +5:30")),
+        Some(datetime!("2021-01-01 9:00 +5:30"))
+    );
+}
+
+#[test]
+fn bounds() {
+    let cron = Cron::parse("* * * * *").expect("expression is valid");
+    assert_eq!(cron.next_after(PrimitiveDateTime::MAX.assume_utc()), None);
+    assert_eq!(
+        cron.previous_before(PrimitiveDateTime::MIN.assume_utc()),
+        None
+    );
+    assert_eq!(
+        cron.next_after(PrimitiveDateTime::MIN.assume_utc()),
+        Some(
+            PrimitiveDateTime::MIN
+                .replace_time(time::macros::time!("0:01"))
+                .assume_utc()
+        )
+    );
+    assert_eq!(
+        cron.previous_before(PrimitiveDateTime::MAX.assume_utc()),
+        Some(
+            PrimitiveDateTime::MAX
+                .replace_time(time::macros::time!("23:59"))
+                .assume_utc()
+        )
+    );
+
+    let mut occurrences = Cron::parse("@yearly")
+        .expect("expression is valid")
+        .occurrences(PrimitiveDateTime::MAX.assume_utc());
+    assert_eq!(occurrences.next(), None);
+    assert_eq!(occurrences.next(), None);
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        error::Cron::InvalidFieldCount.to_string(),
+        "cron expressions must have five, six, or seven fields"
+    );
+    assert_eq!(
+        time::Error::from(error::Cron::InvalidField("hour")).to_string(),
+        "invalid hour field in cron expression"
+    );
+    assert_eq!(error::Cron::UnknownMacro.to_string(), "unknown cron macro");
+}
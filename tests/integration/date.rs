@@ -1,10 +1,11 @@
 use core::i32;
 use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::time::Duration as StdDuration;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, time};
-use time::{util, Date, Weekday};
+use time::{util, Date, DayOverflow, Duration, Month, Period, Weekday};
 
 #[test]
 fn debug() {
@@ -444,10 +445,10 @@ fn year() {
 
 #[test]
 fn month() {
-    assert_eq!(date!("2019-002").month(), 1);
-    assert_eq!(date!("2020-002").month(), 1);
-    assert_eq!(date!("2019-060").month(), 3);
-    assert_eq!(date!("2020-060").month(), 2);
+    assert_eq!(date!("2019-002").month(), Month::January);
+    assert_eq!(date!("2020-002").month(), Month::January);
+    assert_eq!(date!("2019-060").month(), Month::March);
+    assert_eq!(date!("2020-060").month(), Month::February);
 }
 
 #[test]
@@ -458,6 +459,38 @@ fn day() {
     assert_eq!(date!("2020-060").day(), 29);
 }
 
+#[test]
+fn quarter() {
+    assert_eq!(date!("2019-01-01").quarter(), 1);
+    assert_eq!(date!("2019-03-31").quarter(), 1);
+    assert_eq!(date!("2019-04-01").quarter(), 2);
+    assert_eq!(date!("2019-07-01").quarter(), 3);
+    assert_eq!(date!("2019-09-30").quarter(), 3);
+    assert_eq!(date!("2019-10-01").quarter(), 4);
+    assert_eq!(date!("2019-12-31").quarter(), 4);
+}
+
+#[test]
+fn quarter_start() {
+    assert_eq!(date!("2019-01-01").quarter_start(), date!("2019-01-01"));
+    assert_eq!(date!("2019-03-31").quarter_start(), date!("2019-01-01"));
+    assert_eq!(date!("2020-03-31").quarter_start(), date!("2020-01-01"));
+    assert_eq!(date!("2020-06-15").quarter_start(), date!("2020-04-01"));
+    assert_eq!(date!("2020-09-30").quarter_start(), date!("2020-07-01"));
+    assert_eq!(date!("2020-12-31").quarter_start(), date!("2020-10-01"));
+    assert_eq!(Date::MIN.quarter_start(), Date::MIN);
+}
+
+#[test]
+fn quarter_end() {
+    assert_eq!(date!("2019-01-01").quarter_end(), date!("2019-03-31"));
+    assert_eq!(date!("2020-02-29").quarter_end(), date!("2020-03-31"));
+    assert_eq!(date!("2020-04-01").quarter_end(), date!("2020-06-30"));
+    assert_eq!(date!("2020-07-01").quarter_end(), date!("2020-09-30"));
+    assert_eq!(date!("2020-10-01").quarter_end(), date!("2020-12-31"));
+    assert_eq!(Date::MAX.quarter_end(), Date::MAX);
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(date!("2019-01-01").iso_week(), 1);
@@ -469,18 +502,54 @@ fn iso_week() {
 
 #[test]
 fn to_calendar_date() {
-    assert_eq!(date!("2019-01-02").to_calendar_date(), (2019, 1, 2));
-    assert_eq!(date!("2019-02-02").to_calendar_date(), (2019, 2, 2));
-    assert_eq!(date!("2019-03-02").to_calendar_date(), (2019, 3, 2));
-    assert_eq!(date!("2019-04-02").to_calendar_date(), (2019, 4, 2));
-    assert_eq!(date!("2019-05-02").to_calendar_date(), (2019, 5, 2));
-    assert_eq!(date!("2019-06-02").to_calendar_date(), (2019, 6, 2));
-    assert_eq!(date!("2019-07-02").to_calendar_date(), (2019, 7, 2));
-    assert_eq!(date!("2019-08-02").to_calendar_date(), (2019, 8, 2));
-    assert_eq!(date!("2019-09-02").to_calendar_date(), (2019, 9, 2));
-    assert_eq!(date!("2019-10-02").to_calendar_date(), (2019, 10, 2));
-    assert_eq!(date!("2019-11-02").to_calendar_date(), (2019, 11, 2));
-    assert_eq!(date!("2019-12-02").to_calendar_date(), (2019, 12, 2));
+    assert_eq!(
+        date!("2019-01-02").to_calendar_date(),
+        (2019, Month::January, 2)
+    );
+    assert_eq!(
+        date!("2019-02-02").to_calendar_date(),
+        (2019, Month::February, 2)
+    );
+    assert_eq!(
+        date!("2019-03-02").to_calendar_date(),
+        (2019, Month::March, 2)
+    );
+    assert_eq!(
+        date!("2019-04-02").to_calendar_date(),
+        (2019, Month::April, 2)
+    );
+    assert_eq!(
+        date!("2019-05-02").to_calendar_date(),
+        (2019, Month::May, 2)
+    );
+    assert_eq!(
+        date!("2019-06-02").to_calendar_date(),
+        (2019, Month::June, 2)
+    );
+    assert_eq!(
+        date!("2019-07-02").to_calendar_date(),
+        (2019, Month::July, 2)
+    );
+    assert_eq!(
+        date!("2019-08-02").to_calendar_date(),
+        (2019, Month::August, 2)
+    );
+    assert_eq!(
+        date!("2019-09-02").to_calendar_date(),
+        (2019, Month::September, 2)
+    );
+    assert_eq!(
+        date!("2019-10-02").to_calendar_date(),
+        (2019, Month::October, 2)
+    );
+    assert_eq!(
+        date!("2019-11-02").to_calendar_date(),
+        (2019, Month::November, 2)
+    );
+    assert_eq!(
+        date!("2019-12-02").to_calendar_date(),
+        (2019, Month::December, 2)
+    );
 }
 
 #[test]
@@ -569,6 +638,545 @@ fn from_julian_day() {
     assert!(Date::from_julian_day(i32::MAX).is_err());
 }
 
+#[test]
+fn replace_year() {
+    assert_eq!(
+        date!("2022-02-18").replace_year(2019),
+        Ok(date!("2019-02-18"))
+    );
+    assert_eq!(
+        date!("2020-02-29").replace_year(2024),
+        Ok(date!("2024-02-29"))
+    );
+    assert_eq!(
+        date!("2020-12-31").replace_year(2021),
+        Ok(date!("2021-12-31"))
+    );
+    assert!(date!("2022-02-18").replace_year(-1_000_000_000).is_err());
+    assert!(date!("2022-02-18").replace_year(1_000_000_000).is_err());
+
+    let error = date!("2020-02-29").replace_year(2019).unwrap_err();
+    assert_eq!(error.name, "day");
+    assert_eq!(error.maximum, 28);
+    assert!(error.conditional_range);
+}
+
+#[test]
+fn replace_month() {
+    assert_eq!(
+        date!("2022-02-18").replace_month(Month::January),
+        Ok(date!("2022-01-18"))
+    );
+    assert_eq!(
+        date!("2022-01-31").replace_month(Month::March),
+        Ok(date!("2022-03-31"))
+    );
+    assert!(date!("2022-01-31").replace_month(Month::April).is_err());
+    assert!(date!("2022-01-29").replace_month(Month::February).is_err());
+    assert_eq!(
+        date!("2020-01-29").replace_month(Month::February),
+        Ok(date!("2020-02-29"))
+    );
+}
+
+#[test]
+fn replace_day() {
+    assert_eq!(date!("2022-02-18").replace_day(1), Ok(date!("2022-02-01")));
+    assert_eq!(date!("2020-02-18").replace_day(29), Ok(date!("2020-02-29")));
+    assert!(date!("2022-02-18").replace_day(0).is_err());
+    assert!(date!("2022-02-18").replace_day(29).is_err());
+    assert!(date!("2022-01-18").replace_day(32).is_err());
+}
+
+#[test]
+fn replace_ordinal() {
+    assert_eq!(
+        date!("2022-02-18").replace_ordinal(1),
+        Ok(date!("2022-001"))
+    );
+    assert_eq!(
+        date!("2022-02-18").replace_ordinal(365),
+        Ok(date!("2022-365"))
+    );
+    assert_eq!(
+        date!("2020-02-18").replace_ordinal(366),
+        Ok(date!("2020-366"))
+    );
+    assert!(date!("2022-02-18").replace_ordinal(0).is_err());
+    assert!(date!("2022-02-18").replace_ordinal(366).is_err());
+}
+
+#[test]
+fn checked_add_months() {
+    assert_eq!(
+        date!("2021-01-15").checked_add_months(0, DayOverflow::Error),
+        Some(date!("2021-01-15"))
+    );
+    assert_eq!(
+        date!("2021-01-15").checked_add_months(1, DayOverflow::Error),
+        Some(date!("2021-02-15"))
+    );
+    assert_eq!(
+        date!("2021-12-15").checked_add_months(1, DayOverflow::Error),
+        Some(date!("2022-01-15"))
+    );
+    assert_eq!(
+        date!("2021-01-15").checked_add_months(25, DayOverflow::Error),
+        Some(date!("2023-02-15"))
+    );
+    assert_eq!(
+        date!("2021-01-15").checked_add_months(-1, DayOverflow::Error),
+        Some(date!("2020-12-15"))
+    );
+    assert_eq!(
+        date!("2021-01-15").checked_add_months(-25, DayOverflow::Error),
+        Some(date!("2018-12-15"))
+    );
+
+    assert_eq!(
+        date!("2021-01-31").checked_add_months(1, DayOverflow::Clamp),
+        Some(date!("2021-02-28"))
+    );
+    assert_eq!(
+        date!("2020-01-31").checked_add_months(1, DayOverflow::Clamp),
+        Some(date!("2020-02-29"))
+    );
+    assert_eq!(
+        date!("2021-03-31").checked_add_months(1, DayOverflow::Clamp),
+        Some(date!("2021-04-30"))
+    );
+    assert_eq!(
+        date!("2021-01-31").checked_add_months(1, DayOverflow::Rollover),
+        Some(date!("2021-03-03"))
+    );
+    assert_eq!(
+        date!("2020-01-31").checked_add_months(1, DayOverflow::Rollover),
+        Some(date!("2020-03-02"))
+    );
+    assert_eq!(
+        date!("2021-03-31").checked_add_months(1, DayOverflow::Rollover),
+        Some(date!("2021-05-01"))
+    );
+    assert_eq!(
+        date!("2021-01-31").checked_add_months(1, DayOverflow::Error),
+        None
+    );
+
+    assert_eq!(Date::MAX.checked_add_months(1, DayOverflow::Clamp), None);
+    assert_eq!(Date::MIN.checked_add_months(-1, DayOverflow::Clamp), None);
+    assert_eq!(
+        date!("2021-01-01").checked_add_months(i32::MAX, DayOverflow::Clamp),
+        None
+    );
+    assert_eq!(
+        date!("2021-01-01").checked_add_months(i32::MIN, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_months() {
+    assert_eq!(
+        date!("2021-01-15").checked_sub_months(1, DayOverflow::Error),
+        Some(date!("2020-12-15"))
+    );
+    assert_eq!(
+        date!("2021-01-15").checked_sub_months(-1, DayOverflow::Error),
+        Some(date!("2021-02-15"))
+    );
+    assert_eq!(
+        date!("2021-03-31").checked_sub_months(1, DayOverflow::Clamp),
+        Some(date!("2021-02-28"))
+    );
+    assert_eq!(
+        date!("2021-03-31").checked_sub_months(1, DayOverflow::Rollover),
+        Some(date!("2021-03-03"))
+    );
+    assert_eq!(
+        date!("2021-03-31").checked_sub_months(1, DayOverflow::Error),
+        None
+    );
+    assert_eq!(Date::MIN.checked_sub_months(1, DayOverflow::Clamp), None);
+    assert_eq!(
+        date!("2021-01-01").checked_sub_months(i32::MIN, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn checked_add_years() {
+    assert_eq!(
+        date!("2021-01-15").checked_add_years(1, DayOverflow::Error),
+        Some(date!("2022-01-15"))
+    );
+    assert_eq!(
+        date!("2021-01-15").checked_add_years(-1, DayOverflow::Error),
+        Some(date!("2020-01-15"))
+    );
+    assert_eq!(
+        date!("2020-02-29").checked_add_years(4, DayOverflow::Error),
+        Some(date!("2024-02-29"))
+    );
+    assert_eq!(
+        date!("2020-02-29").checked_add_years(1, DayOverflow::Clamp),
+        Some(date!("2021-02-28"))
+    );
+    assert_eq!(
+        date!("2020-02-29").checked_add_years(1, DayOverflow::Rollover),
+        Some(date!("2021-03-01"))
+    );
+    assert_eq!(
+        date!("2020-02-29").checked_add_years(1, DayOverflow::Error),
+        None
+    );
+    assert_eq!(Date::MAX.checked_add_years(1, DayOverflow::Clamp), None);
+    assert_eq!(
+        date!("2021-01-01").checked_add_years(i32::MAX, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_years() {
+    assert_eq!(
+        date!("2021-01-15").checked_sub_years(1, DayOverflow::Error),
+        Some(date!("2020-01-15"))
+    );
+    assert_eq!(
+        date!("2020-02-29").checked_sub_years(1, DayOverflow::Clamp),
+        Some(date!("2019-02-28"))
+    );
+    assert_eq!(
+        date!("2020-02-29").checked_sub_years(1, DayOverflow::Rollover),
+        Some(date!("2019-03-01"))
+    );
+    assert_eq!(
+        date!("2020-02-29").checked_sub_years(1, DayOverflow::Error),
+        None
+    );
+    assert_eq!(Date::MIN.checked_sub_years(1, DayOverflow::Clamp), None);
+    assert_eq!(
+        date!("2021-01-01").checked_sub_years(i32::MIN, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn next_occurrence() {
+    assert_eq!(
+        date!("2021-06-02").next_occurrence(Weekday::Wednesday),
+        Some(date!("2021-06-09"))
+    );
+    assert_eq!(
+        date!("2021-06-02").next_occurrence(Weekday::Thursday),
+        Some(date!("2021-06-03"))
+    );
+    assert_eq!(
+        date!("2021-06-02").next_occurrence(Weekday::Tuesday),
+        Some(date!("2021-06-08"))
+    );
+    assert_eq!(
+        date!("2021-12-30").next_occurrence(Weekday::Monday),
+        Some(date!("2022-01-03"))
+    );
+    assert_eq!(Date::MAX.next_occurrence(Date::MAX.weekday().next()), None);
+}
+
+#[test]
+fn previous_occurrence() {
+    assert_eq!(
+        date!("2021-06-02").previous_occurrence(Weekday::Wednesday),
+        Some(date!("2021-05-26"))
+    );
+    assert_eq!(
+        date!("2021-06-02").previous_occurrence(Weekday::Tuesday),
+        Some(date!("2021-06-01"))
+    );
+    assert_eq!(
+        date!("2021-06-02").previous_occurrence(Weekday::Thursday),
+        Some(date!("2021-05-27"))
+    );
+    assert_eq!(
+        date!("2022-01-03").previous_occurrence(Weekday::Friday),
+        Some(date!("2021-12-31"))
+    );
+    assert_eq!(
+        Date::MIN.previous_occurrence(Date::MIN.weekday().previous()),
+        None
+    );
+}
+
+#[test]
+fn nth_weekday_of_month() {
+    assert_eq!(
+        Date::nth_weekday_of_month(2021, Month::June, 1, Weekday::Tuesday),
+        Ok(date!("2021-06-01"))
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2021, Month::June, 1, Weekday::Monday),
+        Ok(date!("2021-06-07"))
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2021, Month::June, 3, Weekday::Tuesday),
+        Ok(date!("2021-06-15"))
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2021, Month::June, 5, Weekday::Wednesday),
+        Ok(date!("2021-06-30"))
+    );
+    assert_eq!(
+        Date::nth_weekday_of_month(2020, Month::February, 5, Weekday::Saturday),
+        Ok(date!("2020-02-29"))
+    );
+    assert!(Date::nth_weekday_of_month(2021, Month::February, 5, Weekday::Monday).is_err());
+    assert!(Date::nth_weekday_of_month(2021, Month::June, 0, Weekday::Monday).is_err());
+    assert!(Date::nth_weekday_of_month(1_000_000, Month::June, 1, Weekday::Monday).is_err());
+
+    let error = Date::nth_weekday_of_month(2021, Month::June, 5, Weekday::Thursday).unwrap_err();
+    assert_eq!(error.name, "n");
+    assert_eq!(error.maximum, 4);
+    assert!(error.conditional_range);
+}
+
+#[test]
+fn last_weekday_of_month() {
+    assert_eq!(
+        Date::last_weekday_of_month(2021, Month::May, Weekday::Monday),
+        Ok(date!("2021-05-31"))
+    );
+    assert_eq!(
+        Date::last_weekday_of_month(2021, Month::May, Weekday::Sunday),
+        Ok(date!("2021-05-30"))
+    );
+    assert_eq!(
+        Date::last_weekday_of_month(2021, Month::May, Weekday::Tuesday),
+        Ok(date!("2021-05-25"))
+    );
+    assert_eq!(
+        Date::last_weekday_of_month(2020, Month::February, Weekday::Saturday),
+        Ok(date!("2020-02-29"))
+    );
+    assert!(Date::last_weekday_of_month(1_000_000, Month::May, Weekday::Monday).is_err());
+}
+
+#[test]
+fn period_between() {
+    assert_eq!(
+        Date::period_between(date!("2021-01-15"), date!("2021-01-15")),
+        Period::ZERO
+    );
+    assert_eq!(
+        Date::period_between(date!("2021-01-15"), date!("2021-01-20")),
+        Period::new(0, 0, 5)
+    );
+    assert_eq!(
+        Date::period_between(date!("2021-01-15"), date!("2022-03-20")),
+        Period::new(1, 2, 5)
+    );
+    assert_eq!(
+        Date::period_between(date!("2021-01-15"), date!("2021-03-10")),
+        Period::new(0, 1, 23)
+    );
+    assert_eq!(
+        Date::period_between(date!("2021-01-31"), date!("2021-03-01")),
+        Period::new(0, 1, 1)
+    );
+    assert_eq!(
+        Date::period_between(date!("2020-12-31"), date!("2021-01-30")),
+        Period::new(0, 0, 30)
+    );
+    assert_eq!(
+        Date::period_between(date!("2020-12-15"), date!("2021-01-10")),
+        Period::new(0, 0, 26)
+    );
+    assert_eq!(
+        Date::period_between(date!("2020-02-29"), date!("2021-02-28")),
+        Period::new(0, 11, 30)
+    );
+    assert_eq!(
+        Date::period_between(date!("2021-03-10"), date!("2021-01-15")),
+        Period::new(0, -1, -26)
+    );
+    assert_eq!(
+        Date::period_between(date!("2022-03-20"), date!("2021-01-15")),
+        Period::new(-1, -2, -5)
+    );
+    assert_eq!(
+        Date::period_between(date!("2020-03-30"), date!("2020-01-31")),
+        Period::new(0, -1, -29)
+    );
+    assert_eq!(
+        Date::period_between(date!("2020-03-31"), date!("2020-02-29")),
+        Period::new(0, -1, 0)
+    );
+    assert_eq!(
+        Date::period_between(date!("2021-05-31"), date!("2021-04-01")),
+        Period::new(0, -1, -29)
+    );
+    assert_eq!(
+        Date::period_between(Date::MIN, Date::MAX),
+        Period::new(Date::MAX.year() - Date::MIN.year(), 11, 30)
+    );
+
+    for &(start, end) in &[
+        (date!("2021-01-15"), date!("2022-03-20")),
+        (date!("2021-01-15"), date!("2021-03-10")),
+        (date!("2021-01-31"), date!("2021-03-01")),
+        (date!("2020-01-31"), date!("2021-03-30")),
+        (date!("2021-03-10"), date!("2021-01-15")),
+        (date!("2022-03-20"), date!("2021-01-15")),
+        (date!("2020-03-30"), date!("2020-01-31")),
+        (date!("2020-03-31"), date!("2020-02-29")),
+        (date!("2021-05-31"), date!("2021-04-01")),
+    ] {
+        assert_eq!(start + Date::period_between(start, end), end);
+    }
+}
+
+#[test]
+fn period_between_round_trip() {
+    // Every pair of dates in a span covering a leap day and every month end, in both directions.
+    let dates = (0..400)
+        .map(|day| date!("2019-11-01") + day.days())
+        .collect::<Vec<_>>();
+    for &start in &dates {
+        for &end in &dates {
+            assert_eq!(
+                start + Date::period_between(start, end),
+                end,
+                "{} -> {}",
+                start,
+                end
+            );
+        }
+    }
+}
+
+#[test]
+fn add_period() {
+    assert_eq!(date!("2021-01-15") + Period::ZERO, date!("2021-01-15"));
+    assert_eq!(
+        date!("2021-01-15") + Period::new(1, 2, 3),
+        date!("2022-03-18")
+    );
+    assert_eq!(
+        date!("2021-01-31") + Period::new(0, 1, 0),
+        date!("2021-02-28")
+    );
+    assert_eq!(
+        date!("2021-01-31") + Period::new(0, 1, 1),
+        date!("2021-03-01")
+    );
+    assert_eq!(
+        date!("2020-02-29") + Period::new(1, 0, 0),
+        date!("2021-02-28")
+    );
+    assert_eq!(
+        date!("2020-02-29") + Period::new(0, 12, 0),
+        date!("2021-02-28")
+    );
+    assert_eq!(
+        date!("2021-03-15") + Period::new(0, -1, -15),
+        date!("2021-01-31")
+    );
+    assert_eq!(
+        date!("2021-01-01") + Period::new(0, 0, 365),
+        date!("2022-01-01")
+    );
+}
+
+#[test]
+#[should_panic]
+fn add_period_overflow() {
+    let _ = Date::MAX + Period::new(0, 0, 1);
+}
+
+#[test]
+fn add_assign_period() {
+    let mut date = date!("2021-01-31");
+    date += Period::new(0, 1, 0);
+    assert_eq!(date, date!("2021-02-28"));
+}
+
+#[test]
+fn sub_period() {
+    assert_eq!(
+        date!("2022-03-18") - Period::new(1, 2, 3),
+        date!("2021-01-15")
+    );
+    assert_eq!(
+        date!("2021-03-31") - Period::new(0, 1, 0),
+        date!("2021-02-28")
+    );
+    assert_eq!(
+        date!("2021-01-31") - Period::new(0, -1, 0),
+        date!("2021-02-28")
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_period_overflow() {
+    let _ = Date::MIN - Period::new(0, 1, 0);
+}
+
+#[test]
+fn sub_assign_period() {
+    let mut date = date!("2021-03-31");
+    date -= Period::new(0, 1, 0);
+    assert_eq!(date, date!("2021-02-28"));
+}
+
+#[test]
+fn steps_between() {
+    assert_eq!(
+        Date::steps_between(date!("2021-01-01"), date!("2021-03-01")),
+        Some(59)
+    );
+    assert_eq!(
+        Date::steps_between(date!("2021-01-01"), date!("2021-01-01")),
+        Some(0)
+    );
+    assert_eq!(
+        Date::steps_between(date!("2021-01-02"), date!("2021-01-01")),
+        None
+    );
+    assert_eq!(
+        Date::steps_between(Date::MIN, Date::MAX),
+        Some((Date::MAX.to_julian_day() - Date::MIN.to_julian_day()) as usize)
+    );
+}
+
+#[test]
+fn forward_checked() {
+    assert_eq!(
+        Date::forward_checked(date!("2021-01-01"), 0),
+        Some(date!("2021-01-01"))
+    );
+    assert_eq!(
+        Date::forward_checked(date!("2021-01-01"), 59),
+        Some(date!("2021-03-01"))
+    );
+    assert_eq!(Date::forward_checked(Date::MAX, 0), Some(Date::MAX));
+    assert_eq!(Date::forward_checked(Date::MAX, 1), None);
+    assert_eq!(Date::forward_checked(Date::MIN, usize::MAX), None);
+}
+
+#[test]
+fn backward_checked() {
+    assert_eq!(
+        Date::backward_checked(date!("2021-01-01"), 0),
+        Some(date!("2021-01-01"))
+    );
+    assert_eq!(
+        Date::backward_checked(date!("2021-03-01"), 59),
+        Some(date!("2021-01-01"))
+    );
+    assert_eq!(Date::backward_checked(Date::MIN, 0), Some(Date::MIN));
+    assert_eq!(Date::backward_checked(Date::MIN, 1), None);
+    assert_eq!(Date::backward_checked(Date::MAX, usize::MAX), None);
+}
+
 #[test]
 fn midnight() {
     assert_eq!(date!("1970-01-01").midnight(), datetime!("1970-01-01 0:00"));
@@ -618,6 +1226,128 @@ fn with_hms_nano() {
     assert!(date!("1970-01-01").with_hms_nano(24, 0, 0, 0).is_err());
 }
 
+#[test]
+fn checked_add_duration() {
+    assert_eq!(
+        date!("2019-01-01").checked_add(5.days()),
+        Some(date!("2019-01-06"))
+    );
+    assert_eq!(
+        date!("2019-12-31").checked_add(1.days()),
+        Some(date!("2020-01-01"))
+    );
+    assert_eq!(
+        date!("2019-12-31").checked_add(23.hours()),
+        Some(date!("2019-12-31"))
+    );
+    assert_eq!(
+        date!("2020-01-01").checked_add((-1).days()),
+        Some(date!("2019-12-31"))
+    );
+    assert_eq!(Date::MAX.checked_add(1.days()), None);
+    assert_eq!(Date::MIN.checked_add((-1).days()), None);
+    assert_eq!(date!("2019-01-01").checked_add(Duration::MAX), None);
+    assert_eq!(date!("2019-01-01").checked_add(Duration::MIN), None);
+}
+
+#[test]
+fn checked_add_std_duration() {
+    assert_eq!(
+        date!("2019-01-01").checked_add_std(5.std_days()),
+        Some(date!("2019-01-06"))
+    );
+    assert_eq!(
+        date!("2019-12-31").checked_add_std(1.std_days()),
+        Some(date!("2020-01-01"))
+    );
+    assert_eq!(Date::MAX.checked_add_std(1.std_days()), None);
+    assert_eq!(date!("2019-01-01").checked_add_std(StdDuration::MAX), None);
+}
+
+#[test]
+fn checked_sub_duration() {
+    assert_eq!(
+        date!("2019-01-06").checked_sub(5.days()),
+        Some(date!("2019-01-01"))
+    );
+    assert_eq!(
+        date!("2020-01-01").checked_sub(1.days()),
+        Some(date!("2019-12-31"))
+    );
+    assert_eq!(
+        date!("2019-12-31").checked_sub((-1).days()),
+        Some(date!("2020-01-01"))
+    );
+    assert_eq!(Date::MIN.checked_sub(1.days()), None);
+    assert_eq!(Date::MAX.checked_sub((-1).days()), None);
+    assert_eq!(date!("2019-01-01").checked_sub(Duration::MAX), None);
+    assert_eq!(date!("2019-01-01").checked_sub(Duration::MIN), None);
+}
+
+#[test]
+fn checked_sub_std_duration() {
+    assert_eq!(
+        date!("2019-01-06").checked_sub_std(5.std_days()),
+        Some(date!("2019-01-01"))
+    );
+    assert_eq!(
+        date!("2020-01-01").checked_sub_std(1.std_days()),
+        Some(date!("2019-12-31"))
+    );
+    assert_eq!(Date::MIN.checked_sub_std(1.std_days()), None);
+    assert_eq!(date!("2019-01-01").checked_sub_std(StdDuration::MAX), None);
+}
+
+#[test]
+fn saturating_add_duration() {
+    assert_eq!(
+        date!("2019-01-01").saturating_add(5.days()),
+        date!("2019-01-06")
+    );
+    assert_eq!(Date::MAX.saturating_add(1.days()), Date::MAX);
+    assert_eq!(Date::MIN.saturating_add((-1).days()), Date::MIN);
+    assert_eq!(date!("2019-01-01").saturating_add(Duration::MAX), Date::MAX);
+    assert_eq!(date!("2019-01-01").saturating_add(Duration::MIN), Date::MIN);
+}
+
+#[test]
+fn saturating_add_std_duration() {
+    assert_eq!(
+        date!("2019-01-01").saturating_add_std(5.std_days()),
+        date!("2019-01-06")
+    );
+    assert_eq!(Date::MAX.saturating_add_std(1.std_days()), Date::MAX);
+    assert_eq!(
+        date!("2019-01-01").saturating_add_std(StdDuration::MAX),
+        Date::MAX
+    );
+}
+
+#[test]
+fn saturating_sub_duration() {
+    assert_eq!(
+        date!("2019-01-06").saturating_sub(5.days()),
+        date!("2019-01-01")
+    );
+    assert_eq!(Date::MIN.saturating_sub(1.days()), Date::MIN);
+    assert_eq!(Date::MAX.saturating_sub((-1).days()), Date::MAX);
+    assert_eq!(date!("2019-01-01").saturating_sub(Duration::MAX), Date::MIN);
+    assert_eq!(date!("2019-01-01").saturating_sub(Duration::MIN), Date::MAX);
+}
+
+#[test]
+fn saturating_sub_std_duration() {
+    assert_eq!(
+        date!("2019-01-06").saturating_sub_std(5.std_days()),
+        date!("2019-01-01")
+    );
+    assert_eq!(Date::MIN.saturating_sub_std(1.std_days()), Date::MIN);
+    assert_eq!(
+        date!("2019-01-01").saturating_sub_std(StdDuration::MAX),
+        Date::MIN
+    );
+}
+
 #[test]
 fn add() {
     assert_eq!(date!("2019-01-01") + 5.days(), date!("2019-01-06"));
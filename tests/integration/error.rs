@@ -2,7 +2,7 @@ use std::error::Error as _;
 
 use time::error::{
     ComponentRange, ConversionRange, Error, Format, IndeterminateOffset, InvalidFormatDescription,
-    Parse, ParseFromDescription, TryFromParsed,
+    Parse, ParseFromDescription, TimeZone, TryFromParsed,
 };
 use time::format_description::modifier::{self, Padding};
 use time::format_description::{Component, FormatItem};
@@ -75,6 +75,14 @@ fn display() {
         Format::InvalidComponent("a"),
         Error::from(Format::InvalidComponent("a"))
     );
+    assert_display_eq!(
+        Format::Rfc2822YearBefore1900,
+        Error::from(Format::Rfc2822YearBefore1900)
+    );
+    assert_display_eq!(
+        Format::Rfc2822OffsetSecond,
+        Error::from(Format::Rfc2822OffsetSecond)
+    );
     assert_display_eq!(
         ParseFromDescription::InvalidComponent("a"),
         Error::from(Parse::from(ParseFromDescription::InvalidComponent("a")))
@@ -99,6 +107,15 @@ fn display() {
         InvalidFormatDescription::UnclosedOpeningBracket { index: 0 },
         Error::from(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
     );
+    assert_display_eq!(TimeZone::InvalidName, Error::from(TimeZone::InvalidName));
+    assert_display_eq!(
+        TimeZone::InvalidTzif("a"),
+        Error::from(TimeZone::InvalidTzif("a"))
+    );
+    assert_display_eq!(
+        TimeZone::InvalidPosixTz("a"),
+        Error::from(TimeZone::InvalidPosixTz("a"))
+    );
 }
 
 #[test]
@@ -109,6 +126,8 @@ fn source() {
     assert_source!(TryFromParsed::InsufficientInformation, None);
     assert_source!(insufficient_type_information(), None);
     assert_source!(Format::InvalidComponent("a"), None);
+    assert_source!(Format::Rfc2822YearBefore1900, None);
+    assert_source!(Format::Rfc2822OffsetSecond, None);
     assert_source!(Error::from(insufficient_type_information()), Format);
     assert_source!(Error::from(IndeterminateOffset), IndeterminateOffset);
     assert_source!(
@@ -133,4 +152,11 @@ fn source() {
         Error::from(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 }),
         InvalidFormatDescription
     );
+    assert_source!(TimeZone::InvalidName, None);
+    assert_source!(TimeZone::InvalidPosixTz("a"), None);
+    assert_source!(
+        TimeZone::from(std::io::Error::from(std::io::ErrorKind::NotFound)),
+        std::io::Error
+    );
+    assert_source!(Error::from(TimeZone::InvalidName), TimeZone);
 }
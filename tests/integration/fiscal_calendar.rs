@@ -0,0 +1,89 @@
+use time::macros::date;
+use time::{FiscalCalendar, FiscalYearNaming, Month};
+
+#[test]
+fn default() {
+    assert_eq!(FiscalCalendar::default(), FiscalCalendar::CALENDAR_YEAR);
+    assert_eq!(FiscalCalendar::CALENDAR_YEAR.start(), Month::January);
+    assert_eq!(
+        FiscalCalendar::CALENDAR_YEAR.naming(),
+        FiscalYearNaming::EndYear
+    );
+}
+
+#[test]
+fn year() {
+    let calendar = FiscalCalendar::CALENDAR_YEAR;
+    assert_eq!(calendar.year(date!("2021-01-01")), 2021);
+    assert_eq!(calendar.year(date!("2021-12-31")), 2021);
+
+    let calendar = FiscalCalendar::new(Month::January, FiscalYearNaming::StartYear);
+    assert_eq!(calendar.year(date!("2021-01-01")), 2021);
+    assert_eq!(calendar.year(date!("2021-12-31")), 2021);
+
+    let calendar = FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear);
+    assert_eq!(calendar.year(date!("2021-03-31")), 2020);
+    assert_eq!(calendar.year(date!("2021-04-01")), 2021);
+    assert_eq!(calendar.year(date!("2021-12-31")), 2021);
+
+    let calendar = FiscalCalendar::new(Month::October, FiscalYearNaming::EndYear);
+    assert_eq!(calendar.year(date!("2020-09-30")), 2020);
+    assert_eq!(calendar.year(date!("2020-10-01")), 2021);
+    assert_eq!(calendar.year(date!("2021-01-01")), 2021);
+}
+
+#[test]
+fn quarter() {
+    let calendar = FiscalCalendar::CALENDAR_YEAR;
+    assert_eq!(calendar.quarter(date!("2021-01-01")), 1);
+    assert_eq!(calendar.quarter(date!("2021-12-31")), 4);
+
+    let calendar = FiscalCalendar::new(Month::October, FiscalYearNaming::EndYear);
+    assert_eq!(calendar.quarter(date!("2020-10-01")), 1);
+    assert_eq!(calendar.quarter(date!("2020-12-31")), 1);
+    assert_eq!(calendar.quarter(date!("2021-01-01")), 2);
+    assert_eq!(calendar.quarter(date!("2021-04-01")), 3);
+    assert_eq!(calendar.quarter(date!("2021-09-30")), 4);
+}
+
+#[test]
+fn period() {
+    let calendar = FiscalCalendar::CALENDAR_YEAR;
+    assert_eq!(calendar.period(date!("2021-01-01")), 1);
+    assert_eq!(calendar.period(date!("2021-12-31")), 12);
+
+    let calendar = FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear);
+    assert_eq!(calendar.period(date!("2021-04-01")), 1);
+    assert_eq!(calendar.period(date!("2021-12-01")), 9);
+    assert_eq!(calendar.period(date!("2022-01-01")), 10);
+    assert_eq!(calendar.period(date!("2022-03-31")), 12);
+}
+
+#[test]
+fn first_day() {
+    let calendar = FiscalCalendar::CALENDAR_YEAR;
+    assert_eq!(calendar.first_day(2021), Ok(date!("2021-01-01")));
+
+    let calendar = FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear);
+    assert_eq!(calendar.first_day(2021), Ok(date!("2021-04-01")));
+
+    let calendar = FiscalCalendar::new(Month::October, FiscalYearNaming::EndYear);
+    assert_eq!(calendar.first_day(2021), Ok(date!("2020-10-01")));
+    assert!(calendar.first_day(i32::MIN).is_err());
+    assert!(calendar.first_day(i32::MAX).is_err());
+}
+
+#[test]
+fn last_day() {
+    let calendar = FiscalCalendar::CALENDAR_YEAR;
+    assert_eq!(calendar.last_day(2021), Ok(date!("2021-12-31")));
+
+    let calendar = FiscalCalendar::new(Month::March, FiscalYearNaming::StartYear);
+    assert_eq!(calendar.last_day(2023), Ok(date!("2024-02-29")));
+
+    let calendar = FiscalCalendar::new(Month::October, FiscalYearNaming::EndYear);
+    assert_eq!(calendar.last_day(2021), Ok(date!("2021-09-30")));
+
+    let calendar = FiscalCalendar::new(Month::October, FiscalYearNaming::StartYear);
+    assert!(calendar.last_day(time::Date::MAX.year()).is_err());
+}
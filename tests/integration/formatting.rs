@@ -1,8 +1,12 @@
 use std::io;
 
-use time::format_description::well_known::Rfc3339;
+use time::format_description::well_known::iso8601::{
+    Config, DateKind, FormattedComponents, OffsetPrecision, TimePrecision,
+};
+use time::format_description::well_known::{HttpDate, Iso8601, Rfc2822, Rfc3339};
 use time::macros::{date, datetime, format_description as fd, offset, time};
-use time::{format_description, Time};
+use time::time_zone::PosixTz;
+use time::{format_description, Time, TimeZone};
 
 #[test]
 fn rfc_3339() -> time::Result<()> {
@@ -67,6 +71,164 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn rfc_2822() -> time::Result<()> {
+    assert_eq!(
+        datetime!("2003-07-01 10:52:37 +02:00").format(&Rfc2822)?,
+        "Tue, 01 Jul 2003 10:52:37 +0200"
+    );
+    assert_eq!(
+        datetime!("1997-11-21 09:55:06.123 -06:00").format(&Rfc2822)?,
+        "Fri, 21 Nov 1997 09:55:06 -0600"
+    );
+    assert_eq!(
+        datetime!("2021-01-02 03:04:05 UTC").format(&Rfc2822)?,
+        "Sat, 02 Jan 2021 03:04:05 +0000"
+    );
+    assert_eq!(
+        datetime!("1900-12-31 23:59:59 -00:30").format(&Rfc2822)?,
+        "Mon, 31 Dec 1900 23:59:59 -0030"
+    );
+
+    assert!(matches!(
+        datetime!("1899-12-31 0:00 UTC").format(&Rfc2822),
+        Err(time::error::Format::Rfc2822YearBefore1900)
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00 +00:00:01").format(&Rfc2822),
+        Err(time::error::Format::Rfc2822OffsetSecond)
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00").format(&Rfc2822),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        datetime!("1994-11-06 08:49:37 UTC").format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!("2021-01-01 01:30:15.5 +02:00").format(&HttpDate)?,
+        "Thu, 31 Dec 2020 23:30:15 GMT"
+    );
+    assert_eq!(
+        datetime!("0001-01-01 0:00 -00:00:01").format(&HttpDate)?,
+        "Mon, 01 Jan 0001 00:00:01 GMT"
+    );
+
+    assert!(matches!(
+        datetime!("+10000-01-01 0:00 UTC").format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!("0000-01-01 0:00 +01:00").format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00").format(&HttpDate),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601() -> time::Result<()> {
+    const BASIC: Iso8601 = Iso8601::with_config(
+        Config::DEFAULT
+            .set_use_separators(false)
+            .set_time_precision(TimePrecision::Second { decimal_digits: 0 }),
+    );
+    const WEEK: Iso8601 = Iso8601::with_config(
+        Config::DEFAULT
+            .set_date_kind(DateKind::Week)
+            .set_time_precision(TimePrecision::Minute { decimal_digits: 0 })
+            .set_offset_precision(OffsetPrecision::Hour),
+    );
+    const ORDINAL: Iso8601 = Iso8601::with_config(
+        Config::DEFAULT
+            .set_formatted_components(FormattedComponents::Date)
+            .set_date_kind(DateKind::Ordinal),
+    );
+    const SIX_DIGIT_YEAR: Iso8601 = Iso8601::with_config(
+        Config::DEFAULT
+            .set_formatted_components(FormattedComponents::DateTime)
+            .set_year_is_six_digits(true)
+            .set_time_precision(TimePrecision::Second { decimal_digits: 3 }),
+    );
+    const HOUR_FRACTION: Iso8601 = Iso8601::with_config(
+        Config::DEFAULT
+            .set_formatted_components(FormattedComponents::Time)
+            .set_time_precision(TimePrecision::Hour { decimal_digits: 2 }),
+    );
+    const MINUTE_FRACTION: Iso8601 = Iso8601::with_config(
+        Config::DEFAULT
+            .set_formatted_components(FormattedComponents::TimeOffset)
+            .set_use_separators(false)
+            .set_time_precision(TimePrecision::Minute { decimal_digits: 1 }),
+    );
+
+    assert_eq!(
+        datetime!("2021-03-01 12:30:15.123 +01:00").format(&Iso8601::DEFAULT)?,
+        "2021-03-01T12:30:15.123000000+01:00"
+    );
+    assert_eq!(
+        datetime!("2021-03-01 12:00 UTC").format(&BASIC)?,
+        "20210301T120000Z"
+    );
+    assert_eq!(
+        datetime!("2021-03-01 12:00 -05:30").format(&BASIC)?,
+        "20210301T120000-0530"
+    );
+    assert_eq!(
+        datetime!("2021-03-01 12:00:59 +01:00").format(&WEEK)?,
+        "2021-W09-1T12:00+01"
+    );
+    assert_eq!(
+        datetime!("2021-01-03 0:00 UTC").format(&WEEK)?,
+        "2020-W53-7T00:00Z"
+    );
+    assert_eq!(date!("2021-03-01").format(&ORDINAL)?, "2021-060");
+    assert_eq!(
+        datetime!("2021-03-01 12:30:15.1239").format(&SIX_DIGIT_YEAR)?,
+        "+002021-03-01T12:30:15.123"
+    );
+    assert_eq!(
+        datetime!("-1234-03-01 0:00").format(&SIX_DIGIT_YEAR)?,
+        "-001234-03-01T00:00:00.000"
+    );
+    assert_eq!(time!("12:45").format(&HOUR_FRACTION)?, "12.75");
+    assert_eq!(time!("12:00:35.9").format(&HOUR_FRACTION)?, "12.00");
+    assert_eq!(
+        datetime!("2021-03-01 12:30:15 +01:00").format(&MINUTE_FRACTION)?,
+        "1230.2+0100"
+    );
+
+    assert!(matches!(
+        datetime!("+10000-01-01 0:00 UTC").format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00 +01:30").format(&WEEK),
+        Err(time::error::Format::InvalidComponent("offset_minute"))
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00 +00:00:01").format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+    assert!(matches!(
+        datetime!("2021-01-01 0:00").format(&Iso8601::DEFAULT),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn format_time() -> time::Result<()> {
     let format_output = [
@@ -115,6 +277,7 @@ fn format_time() -> time::Result<()> {
     }
 
     assert_eq!(time!("1:02:03").format(&fd!("[period]"))?, "AM");
+    assert_eq!(time!("1:02:03").format(&fd!("[[[hour]]"))?, "[01]");
 
     Ok(())
 }
@@ -158,6 +321,13 @@ fn format_date() -> time::Result<()> {
         (fd!("[year base:iso_week sign:mandatory]"), "+2020"),
         (fd!("[year repr:last_two]"), "19"),
         (fd!("[year base:iso_week repr:last_two]"), "20"),
+        (fd!("[quarter]"), "4"),
+        (fd!("[quarter start:october]"), "1"),
+        (fd!("[fiscal_year]"), "2019"),
+        (fd!("[fiscal_year start:october]"), "2020"),
+        (fd!("[fiscal_year start:october naming:start]"), "2019"),
+        (fd!("[fiscal_year start:april repr:last_two]"), "20"),
+        (fd!("[fiscal_year start:april sign:mandatory]"), "+2020"),
     ];
 
     for &(format_description, output) in &format_output {
@@ -296,10 +466,81 @@ fn display_odt() {
     );
 }
 
+#[test]
+fn format_zdt() -> time::Result<()> {
+    let paris = TimeZone::get("Europe/Paris").unwrap();
+    let format = fd!("[year]-[month]-[day] [hour]:[minute] [tz_abbrev] [tz_name]");
+
+    assert_eq!(
+        datetime!("2021-07-01 12:00 UTC")
+            .to_zoned(&paris)
+            .format(&format)?,
+        "2021-07-01 14:00 CEST Europe/Paris"
+    );
+    assert_eq!(
+        datetime!("2021-01-01 12:00 UTC")
+            .to_zoned(&paris)
+            .format(&format)?,
+        "2021-01-01 13:00 CET Europe/Paris"
+    );
+    assert!(
+        datetime!("2021-01-01 12:00 UTC")
+            .to_zoned(&paris)
+            .format_into(&mut io::sink(), &format)
+            .is_ok()
+    );
+
+    let unnamed = TimeZone::from(PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?);
+    assert_eq!(
+        datetime!("2021-07-01 12:00 UTC")
+            .to_zoned(&unnamed)
+            .format(&fd!("[tz_abbrev]"))?,
+        "CEST"
+    );
+    assert!(matches!(
+        datetime!("2021-07-01 12:00 UTC")
+            .to_zoned(&unnamed)
+            .format(&fd!("[tz_name]")),
+        Err(time::error::Format::InvalidComponent("tz_name"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_optional_and_first() -> time::Result<()> {
+    assert_eq!(
+        time!("12:00:05").format(&fd!("[hour]:[minute][optional [:[second]]]"))?,
+        "12:00:05"
+    );
+    assert_eq!(
+        datetime!("2021-01-02 03:04:05 +01:00").format(&fd!(
+            "[first [[offset_hour sign:mandatory]:[offset_minute]] [Z]]"
+        ))?,
+        "+01:00"
+    );
+
+    let format = format_description::parse_owned("[hour]:[minute][optional [:[second]]]")?;
+    assert_eq!(time!("12:00:05").format(&format)?, "12:00:05");
+    let format = format_description::parse_owned("[first [[hour]] [[minute]]]")?;
+    assert_eq!(time!("12:34").format(&format)?, "12");
+    assert!(time!("12:34").format_into(&mut io::sink(), &format).is_ok());
+    let format = format_description::parse("[hour]:[minute][optional [:[second]]]")?;
+    assert_eq!(time!("12:00:05").format(&format)?, "12:00:05");
+    let format = format_description::parse("[first [[hour]] [[minute]]]")?;
+    assert_eq!(time!("12:34").format(&format)?, "12");
+
+    Ok(())
+}
+
 #[test]
 fn insufficient_type_information() {
     assert!(matches!(
         Time::MIDNIGHT.format(&fd!("[year]")),
         Err(time::error::Format::InsufficientTypeInformation { .. })
     ));
+    assert!(matches!(
+        datetime!("2021-07-01 12:00 UTC").format(&fd!("[tz_abbrev]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
 }
@@ -0,0 +1,210 @@
+use time::holiday::{self, Computus, Holiday, HolidayRule, Observance};
+use time::macros::date;
+use time::{BusinessCalendar, Date, HolidaySet, Month, Weekday};
+
+#[test]
+fn easter_gregorian() {
+    assert_eq!(Computus::Gregorian.easter(1818), Ok(date!("1818-03-22")));
+    assert_eq!(Computus::Gregorian.easter(1943), Ok(date!("1943-04-25")));
+    assert_eq!(Computus::Gregorian.easter(2000), Ok(date!("2000-04-23")));
+    assert_eq!(Computus::Gregorian.easter(2021), Ok(date!("2021-04-04")));
+    assert_eq!(Computus::Gregorian.easter(2022), Ok(date!("2022-04-17")));
+    assert_eq!(Computus::Gregorian.easter(2024), Ok(date!("2024-03-31")));
+    assert_eq!(Computus::Gregorian.easter(2285), Ok(date!("2285-03-22")));
+
+    for year in -9999..=9999 {
+        let easter = Computus::Gregorian.easter(year).unwrap();
+        assert_eq!(easter.weekday(), Weekday::Sunday);
+        assert!(easter >= Date::from_calendar_date(year, Month::March, 22).unwrap());
+        assert!(easter <= Date::from_calendar_date(year, Month::April, 25).unwrap());
+    }
+}
+
+#[test]
+fn easter_orthodox() {
+    assert_eq!(Computus::Orthodox.easter(2008), Ok(date!("2008-04-27")));
+    assert_eq!(Computus::Orthodox.easter(2021), Ok(date!("2021-05-02")));
+    assert_eq!(Computus::Orthodox.easter(2022), Ok(date!("2022-04-24")));
+    assert_eq!(Computus::Orthodox.easter(2023), Ok(date!("2023-04-16")));
+    assert_eq!(Computus::Orthodox.easter(2024), Ok(date!("2024-05-05")));
+    assert_eq!(Computus::Orthodox.easter(2025), Ok(date!("2025-04-20")));
+
+    for year in -9999..=9999 {
+        assert_eq!(
+            Computus::Orthodox.easter(year).unwrap().weekday(),
+            Weekday::Sunday
+        );
+    }
+}
+
+#[test]
+fn easter_out_of_range() {
+    assert!(Computus::Gregorian.easter(1_000_000).is_err());
+    assert!(Computus::Orthodox.easter(-1_000_000).is_err());
+}
+
+#[test]
+fn rule_date() {
+    let rule = HolidayRule::Fixed {
+        month: Month::July,
+        day: 4,
+    };
+    assert_eq!(rule.date(2021), Ok(date!("2021-07-04")));
+    let rule = HolidayRule::Fixed {
+        month: Month::February,
+        day: 29,
+    };
+    assert_eq!(rule.date(2020), Ok(date!("2020-02-29")));
+    assert!(rule.date(2021).is_err());
+
+    let rule = HolidayRule::NthWeekday {
+        month: Month::January,
+        n: 3,
+        weekday: Weekday::Monday,
+    };
+    assert_eq!(rule.date(2022), Ok(date!("2022-01-17")));
+    let rule = HolidayRule::NthWeekday {
+        month: Month::January,
+        n: 5,
+        weekday: Weekday::Monday,
+    };
+    assert_eq!(rule.date(2024), Ok(date!("2024-01-29")));
+    assert!(rule.date(2021).is_err());
+
+    let rule = HolidayRule::LastWeekday {
+        month: Month::May,
+        weekday: Weekday::Monday,
+    };
+    assert_eq!(rule.date(2021), Ok(date!("2021-05-31")));
+    assert_eq!(rule.date(2022), Ok(date!("2022-05-30")));
+
+    let rule = HolidayRule::Easter {
+        computus: Computus::Gregorian,
+        offset: -2,
+    };
+    assert_eq!(rule.date(2021), Ok(date!("2021-04-02")));
+    let rule = HolidayRule::Easter {
+        computus: Computus::Orthodox,
+        offset: -48,
+    };
+    assert_eq!(rule.date(2021), Ok(date!("2021-03-15")));
+    assert!(rule.date(1_000_000).is_err());
+}
+
+#[test]
+fn observance() {
+    let saturday = date!("2021-12-25");
+    let sunday = date!("2021-12-26");
+    let monday = date!("2021-12-27");
+
+    assert_eq!(Observance::Actual.apply(saturday), Some(saturday));
+    assert_eq!(Observance::Actual.apply(sunday), Some(sunday));
+
+    assert_eq!(
+        Observance::NearestWeekday.apply(saturday),
+        Some(date!("2021-12-24"))
+    );
+    assert_eq!(Observance::NearestWeekday.apply(sunday), Some(monday));
+    assert_eq!(Observance::NearestWeekday.apply(monday), Some(monday));
+
+    assert_eq!(Observance::NextMonday.apply(saturday), Some(monday));
+    assert_eq!(Observance::NextMonday.apply(sunday), Some(monday));
+    assert_eq!(Observance::NextMonday.apply(monday), Some(monday));
+
+    assert_eq!(Observance::SundayToMonday.apply(saturday), Some(saturday));
+    assert_eq!(Observance::SundayToMonday.apply(sunday), Some(monday));
+
+    assert_eq!(Observance::NextMonday.apply(Date::MAX), Some(Date::MAX));
+}
+
+#[test]
+fn holiday_dates() {
+    let holiday = Holiday {
+        name: "New Year's Day",
+        rule: HolidayRule::Fixed {
+            month: Month::January,
+            day: 1,
+        },
+        observance: Observance::NearestWeekday,
+    };
+    assert_eq!(holiday.actual_date(2022), Ok(date!("2022-01-01")));
+    assert_eq!(holiday.observed_date(2022), Some(date!("2021-12-31")));
+    assert_eq!(holiday.observed_date(2023), Some(date!("2023-01-02")));
+    assert_eq!(holiday.observed_date(1_000_000), None);
+}
+
+#[test]
+fn holiday_set() {
+    let holidays = holiday::US_FEDERAL;
+    assert!(holidays.is_holiday(date!("2021-07-05")));
+    assert!(!holidays.is_holiday(date!("2021-07-04")));
+    assert!(holidays.is_holiday(date!("2021-11-25")));
+    // New Year's Day 2022, observed in the previous year.
+    assert!(holidays.is_holiday(date!("2021-12-31")));
+    assert!(!holidays.is_holiday(date!("2022-01-01")));
+    assert!(!holidays.is_holiday(date!("2021-12-30")));
+
+    let calendar = BusinessCalendar::SATURDAY_SUNDAY.with_holidays(holiday::US_FEDERAL);
+    assert_eq!(
+        calendar.add_business_days(date!("2021-12-23"), 2),
+        Some(date!("2021-12-28"))
+    );
+}
+
+#[test]
+fn rule_sets() {
+    let dates = |holidays: &[Holiday], year| {
+        holidays
+            .iter()
+            .map(|holiday| holiday.observed_date(year).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        dates(holiday::US_FEDERAL, 2021),
+        [
+            date!("2021-01-01"),
+            date!("2021-01-18"),
+            date!("2021-02-15"),
+            date!("2021-05-31"),
+            date!("2021-06-18"),
+            date!("2021-07-05"),
+            date!("2021-09-06"),
+            date!("2021-10-11"),
+            date!("2021-11-11"),
+            date!("2021-11-25"),
+            date!("2021-12-24"),
+        ]
+    );
+    assert_eq!(
+        dates(holiday::GERMANY, 2021),
+        [
+            date!("2021-01-01"),
+            date!("2021-04-02"),
+            date!("2021-04-05"),
+            date!("2021-05-01"),
+            date!("2021-05-13"),
+            date!("2021-05-24"),
+            date!("2021-10-03"),
+            date!("2021-12-25"),
+            date!("2021-12-26"),
+        ]
+    );
+    assert_eq!(
+        dates(holiday::GREECE, 2021),
+        [
+            date!("2021-01-01"),
+            date!("2021-01-06"),
+            date!("2021-03-15"),
+            date!("2021-03-25"),
+            date!("2021-04-30"),
+            date!("2021-05-03"),
+            date!("2021-05-01"),
+            date!("2021-06-21"),
+            date!("2021-08-15"),
+            date!("2021-10-28"),
+            date!("2021-12-25"),
+            date!("2021-12-26"),
+        ]
+    );
+}
@@ -37,20 +37,30 @@
     clippy::cognitive_complexity
 )]
 
+mod business_calendar;
+mod cron;
 mod date;
 mod duration;
 mod error;
 mod ext;
+mod fiscal_calendar;
 mod formatting;
+mod holiday;
 mod instant;
+mod month;
 mod offset_date_time;
 mod parse_format_description;
 mod parsing;
+mod period;
 mod primitive_date_time;
 mod quickcheck;
 mod rand;
+mod range;
+mod rrule;
 mod serde;
 mod time;
+mod time_zone;
 mod utc_offset;
 mod util;
 mod weekday;
+mod zoned_date_time;
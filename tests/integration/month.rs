@@ -0,0 +1,86 @@
+use std::convert::TryFrom;
+
+use time::Month::{self, *};
+
+#[test]
+fn previous() {
+    assert_eq!(January.previous(), December);
+    assert_eq!(February.previous(), January);
+    assert_eq!(March.previous(), February);
+    assert_eq!(April.previous(), March);
+    assert_eq!(May.previous(), April);
+    assert_eq!(June.previous(), May);
+    assert_eq!(July.previous(), June);
+    assert_eq!(August.previous(), July);
+    assert_eq!(September.previous(), August);
+    assert_eq!(October.previous(), September);
+    assert_eq!(November.previous(), October);
+    assert_eq!(December.previous(), November);
+}
+
+#[test]
+fn next() {
+    assert_eq!(January.next(), February);
+    assert_eq!(February.next(), March);
+    assert_eq!(March.next(), April);
+    assert_eq!(April.next(), May);
+    assert_eq!(May.next(), June);
+    assert_eq!(June.next(), July);
+    assert_eq!(July.next(), August);
+    assert_eq!(August.next(), September);
+    assert_eq!(September.next(), October);
+    assert_eq!(October.next(), November);
+    assert_eq!(November.next(), December);
+    assert_eq!(December.next(), January);
+}
+
+#[test]
+fn length() {
+    assert_eq!(January.length(2019), 31);
+    assert_eq!(February.length(2019), 28);
+    assert_eq!(February.length(2020), 29);
+    assert_eq!(February.length(2100), 28);
+    assert_eq!(February.length(2000), 29);
+    assert_eq!(March.length(2019), 31);
+    assert_eq!(April.length(2019), 30);
+    assert_eq!(May.length(2019), 31);
+    assert_eq!(June.length(2019), 30);
+    assert_eq!(July.length(2019), 31);
+    assert_eq!(August.length(2019), 31);
+    assert_eq!(September.length(2019), 30);
+    assert_eq!(October.length(2019), 31);
+    assert_eq!(November.length(2019), 30);
+    assert_eq!(December.length(2019), 31);
+}
+
+#[test]
+fn display() {
+    assert_eq!(January.to_string(), "January");
+    assert_eq!(February.to_string(), "February");
+    assert_eq!(March.to_string(), "March");
+    assert_eq!(April.to_string(), "April");
+    assert_eq!(May.to_string(), "May");
+    assert_eq!(June.to_string(), "June");
+    assert_eq!(July.to_string(), "July");
+    assert_eq!(August.to_string(), "August");
+    assert_eq!(September.to_string(), "September");
+    assert_eq!(October.to_string(), "October");
+    assert_eq!(November.to_string(), "November");
+    assert_eq!(December.to_string(), "December");
+}
+
+#[test]
+fn to_u8() {
+    assert_eq!(u8::from(January), 1);
+    assert_eq!(u8::from(June), 6);
+    assert_eq!(u8::from(December), 12);
+}
+
+#[test]
+fn try_from_u8() {
+    assert_eq!(Month::try_from(1), Ok(January));
+    assert_eq!(Month::try_from(6), Ok(June));
+    assert_eq!(Month::try_from(12), Ok(December));
+    assert!(Month::try_from(0).is_err());
+    assert!(Month::try_from(13).is_err());
+}
@@ -1,9 +1,11 @@
 use std::cmp::Ordering;
-use std::time::SystemTime;
+use std::time::{Duration as StdDuration, SystemTime};
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
-use time::{OffsetDateTime, Weekday};
+use time::{
+    Date, DayOverflow, Duration, Month, OffsetDateTime, Period, PrimitiveDateTime, Unit, Weekday,
+};
 
 #[test]
 fn now_utc() {
@@ -136,12 +138,12 @@ fn year() {
 
 #[test]
 fn month() {
-    assert_eq!(datetime!("2019-01-01 0:00 UTC").month(), 1);
+    assert_eq!(datetime!("2019-01-01 0:00 UTC").month(), Month::January);
     assert_eq!(
         datetime!("2019-12-31 23:00 UTC")
             .to_offset(offset!("+1"))
             .month(),
-        1,
+        Month::January,
     );
 }
 
@@ -195,7 +197,7 @@ fn monday_based_week() {
 fn to_calendar_date() {
     assert_eq!(
         datetime!("2019-01-02 0:00 UTC").to_calendar_date(),
-        (2019, 1, 2)
+        (2019, Month::January, 2)
     );
 }
 
@@ -378,6 +380,125 @@ fn replace_date_time() {
     );
 }
 
+#[test]
+fn checked_add_months() {
+    assert_eq!(
+        datetime!("2021-01-31 23:00 -5").checked_add_months(1, DayOverflow::Clamp),
+        Some(datetime!("2021-02-28 23:00 -5"))
+    );
+    assert_eq!(
+        datetime!("2021-01-31 0:00 +1").checked_add_months(1, DayOverflow::Rollover),
+        Some(datetime!("2021-03-03 0:00 +1"))
+    );
+    assert_eq!(
+        datetime!("2021-01-31 0:00 +1").checked_add_months(1, DayOverflow::Error),
+        None
+    );
+    // The UTC datetime would be out of range.
+    assert_eq!(
+        (PrimitiveDateTime::MAX - 61.days())
+            .assume_offset(offset!("-5"))
+            .checked_add_months(2, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_months() {
+    assert_eq!(
+        datetime!("2021-03-31 0:00 +1").checked_sub_months(1, DayOverflow::Clamp),
+        Some(datetime!("2021-02-28 0:00 +1"))
+    );
+    assert_eq!(
+        datetime!("2021-03-31 0:00 +1").checked_sub_months(1, DayOverflow::Error),
+        None
+    );
+    assert_eq!(
+        (PrimitiveDateTime::MIN + 59.days())
+            .assume_offset(offset!("+5"))
+            .checked_sub_months(2, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn checked_add_years() {
+    assert_eq!(
+        datetime!("2020-02-29 23:00 -5").checked_add_years(1, DayOverflow::Clamp),
+        Some(datetime!("2021-02-28 23:00 -5"))
+    );
+    assert_eq!(
+        datetime!("2020-02-29 23:00 -5").checked_add_years(1, DayOverflow::Error),
+        None
+    );
+    assert_eq!(
+        (PrimitiveDateTime::MAX - 365.days())
+            .assume_offset(offset!("-5"))
+            .checked_add_years(1, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_years() {
+    assert_eq!(
+        datetime!("2020-02-29 0:00 +1").checked_sub_years(1, DayOverflow::Rollover),
+        Some(datetime!("2019-03-01 0:00 +1"))
+    );
+    assert_eq!(
+        datetime!("2020-02-29 0:00 +1").checked_sub_years(1, DayOverflow::Error),
+        None
+    );
+    assert_eq!(
+        (PrimitiveDateTime::MIN + 365.days())
+            .assume_offset(offset!("+5"))
+            .checked_sub_years(1, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn add_period() {
+    assert_eq!(
+        datetime!("2021-01-31 23:00 -5") + Period::new(0, 1, 1),
+        datetime!("2021-03-01 23:00 -5")
+    );
+}
+
+#[test]
+#[should_panic]
+fn add_period_overflow() {
+    let _ = (PrimitiveDateTime::MAX - 1.days()).assume_offset(offset!("-5")) + Period::new(0, 0, 1);
+}
+
+#[test]
+fn add_assign_period() {
+    let mut datetime = datetime!("2021-01-31 0:00 +1");
+    datetime += Period::new(0, 1, 0);
+    assert_eq!(datetime, datetime!("2021-02-28 0:00 +1"));
+}
+
+#[test]
+fn sub_period() {
+    assert_eq!(
+        datetime!("2021-03-31 0:00 +1") - Period::new(0, 1, 1),
+        datetime!("2021-02-27 0:00 +1")
+    );
+}
+
+#[test]
+#[should_panic]
+fn sub_period_overflow() {
+    let _ = (PrimitiveDateTime::MIN + 1.days()).assume_offset(offset!("+5")) - Period::new(0, 0, 1);
+}
+
+#[test]
+fn sub_assign_period() {
+    let mut datetime = datetime!("2021-03-31 23:00 -5");
+    datetime -= Period::new(0, 1, 0);
+    assert_eq!(datetime, datetime!("2021-02-28 23:00 -5"));
+}
+
 #[test]
 fn replace_offset() {
     assert_eq!(
@@ -386,6 +507,215 @@ fn replace_offset() {
     );
 }
 
+#[test]
+fn replace_date_components() {
+    // The components are those in the stored offset, not UTC.
+    let datetime = datetime!("2020-03-01 0:30 +1");
+    assert_eq!(
+        datetime.replace_year(2021),
+        Ok(datetime!("2021-03-01 0:30 +1"))
+    );
+    assert_eq!(
+        datetime.replace_month(Month::April),
+        Ok(datetime!("2020-04-01 0:30 +1"))
+    );
+    assert_eq!(
+        datetime.replace_day(31),
+        Ok(datetime!("2020-03-31 0:30 +1"))
+    );
+    assert_eq!(
+        datetime.replace_ordinal(1),
+        Ok(datetime!("2020-001 0:30 +1"))
+    );
+
+    let datetime = datetime!("2020-02-29 12:00 -5");
+    assert!(datetime.replace_year(2019).is_err());
+    assert!(datetime!("2020-01-31 0:00 -5")
+        .replace_month(Month::April)
+        .is_err());
+    assert!(datetime.replace_day(30).is_err());
+    assert!(datetime.replace_ordinal(367).is_err());
+}
+
+#[test]
+fn replace_time_components() {
+    let datetime = datetime!("2020-01-01 23:02:03.004_005_006 -5");
+    assert_eq!(
+        datetime.replace_hour(7),
+        Ok(datetime!("2020-01-01 7:02:03.004_005_006 -5"))
+    );
+    assert!(datetime.replace_hour(24).is_err());
+    assert_eq!(
+        datetime.replace_minute(7),
+        Ok(datetime!("2020-01-01 23:07:03.004_005_006 -5"))
+    );
+    assert!(datetime.replace_minute(60).is_err());
+    assert_eq!(
+        datetime.replace_second(7),
+        Ok(datetime!("2020-01-01 23:02:07.004_005_006 -5"))
+    );
+    assert!(datetime.replace_second(60).is_err());
+    assert_eq!(
+        datetime.replace_millisecond(7),
+        Ok(datetime!("2020-01-01 23:02:03.007 -5"))
+    );
+    assert!(datetime.replace_millisecond(1_000).is_err());
+    assert_eq!(
+        datetime.replace_microsecond(7_008),
+        Ok(datetime!("2020-01-01 23:02:03.007_008 -5"))
+    );
+    assert!(datetime.replace_microsecond(1_000_000).is_err());
+    assert_eq!(
+        datetime.replace_nanosecond(7_008_009),
+        Ok(datetime!("2020-01-01 23:02:03.007_008_009 -5"))
+    );
+    assert!(datetime.replace_nanosecond(1_000_000_000).is_err());
+}
+
+#[test]
+fn replace_components_utc_out_of_range() -> time::Result<()> {
+    // The datetime in the stored offset is in range, but the UTC datetime would not be.
+    let max = PrimitiveDateTime::MAX.assume_utc().to_offset(offset!("-5"));
+    assert_eq!(max.replace_hour(18).map(|datetime| datetime.hour()), Ok(18));
+    assert_eq!(max.replace_hour(23).map_err(|err| err.name), Err("year"));
+    assert!((max - 1.days()).replace_hour(23).is_ok());
+    assert!((max - 1.days()).replace_day(31).is_ok());
+    assert!((max - 1.days()).replace_hour(23)?.replace_day(31).is_err());
+    assert!(datetime!("2021-12-31 23:00 -5")
+        .replace_year(Date::MAX.year())
+        .is_err());
+
+    let min = PrimitiveDateTime::MIN.assume_utc().to_offset(offset!("+5"));
+    assert_eq!(min.replace_hour(5).map(|datetime| datetime.hour()), Ok(5));
+    assert_eq!(min.replace_hour(4).map_err(|err| err.name), Err("year"));
+    assert!((min + 1.days())
+        .replace_hour(0)?
+        .replace_ordinal(1)
+        .is_err());
+    assert!(datetime!("2021-01-01 1:00 +5")
+        .replace_year(Date::MIN.year())
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn truncate() {
+    let datetime = datetime!("2021-08-19 1:45:30 +3");
+    assert_eq!(
+        datetime.truncate(Unit::Minute),
+        Some(datetime!("2021-08-19 1:45 +3"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Day),
+        Some(datetime!("2021-08-19 0:00 +3"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::IsoWeek),
+        Some(datetime!("2021-08-16 0:00 +3"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Month),
+        Some(datetime!("2021-08-01 0:00 +3"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Year),
+        Some(datetime!("2021-01-01 0:00 +3"))
+    );
+    // Midnight in the offset is out of range in UTC.
+    assert_eq!(
+        Date::MIN
+            .with_time(time!("12:00"))
+            .assume_offset(offset!("+3"))
+            .truncate(Unit::Day),
+        None
+    );
+}
+
+#[test]
+fn ceil() {
+    let datetime = datetime!("2021-08-19 1:45:30 +3");
+    assert_eq!(
+        datetime.ceil(Unit::Minute),
+        Some(datetime!("2021-08-19 1:46 +3"))
+    );
+    assert_eq!(
+        datetime.ceil(Unit::Day),
+        Some(datetime!("2021-08-20 0:00 +3"))
+    );
+    assert_eq!(
+        datetime.ceil(Unit::Quarter),
+        Some(datetime!("2021-10-01 0:00 +3"))
+    );
+    assert_eq!(
+        datetime!("2021-08-19 0:00 +3").ceil(Unit::Day),
+        Some(datetime!("2021-08-19 0:00 +3"))
+    );
+}
+
+#[test]
+fn round() {
+    let datetime = datetime!("2021-08-19 1:45:30 +3");
+    assert_eq!(
+        datetime.round(Unit::Hour),
+        Some(datetime!("2021-08-19 2:00 +3"))
+    );
+    assert_eq!(
+        datetime.round(Unit::Day),
+        Some(datetime!("2021-08-19 0:00 +3"))
+    );
+    assert_eq!(
+        datetime.round(Unit::Month),
+        Some(datetime!("2021-09-01 0:00 +3"))
+    );
+}
+
+#[test]
+fn truncate_to_multiple() {
+    let origin = datetime!("2021-01-01 0:00 UTC");
+    assert_eq!(
+        datetime!("2021-08-19 13:44 +0:30").truncate_to_multiple(1.hours(), origin),
+        Some(datetime!("2021-08-19 13:30 +0:30"))
+    );
+    assert_eq!(
+        datetime!("2021-08-19 13:44 UTC").truncate_to_multiple(15.minutes(), origin),
+        Some(datetime!("2021-08-19 13:30 UTC"))
+    );
+}
+
+#[test]
+fn ceil_to_multiple() {
+    let origin = datetime!("2021-01-01 0:00 UTC");
+    assert_eq!(
+        datetime!("2021-08-19 13:44 +0:30").ceil_to_multiple(1.hours(), origin),
+        Some(datetime!("2021-08-19 14:30 +0:30"))
+    );
+    assert_eq!(
+        datetime!("2021-08-19 13:30 +0:30").ceil_to_multiple(1.hours(), origin),
+        Some(datetime!("2021-08-19 13:30 +0:30"))
+    );
+}
+
+#[test]
+fn round_to_multiple() {
+    let origin = datetime!("2021-01-01 0:00 UTC");
+    assert_eq!(
+        datetime!("2021-08-19 13:59 +0:30").round_to_multiple(1.hours(), origin),
+        Some(datetime!("2021-08-19 13:30 +0:30"))
+    );
+    assert_eq!(
+        datetime!("2021-08-19 14:00 +0:30").round_to_multiple(1.hours(), origin),
+        Some(datetime!("2021-08-19 14:30 +0:30"))
+    );
+    assert_eq!(
+        datetime!("2020-12-31 0:00 UTC").round_to_multiple(Duration::MAX, origin),
+        Some(origin)
+    );
+    assert_eq!(
+        datetime!("2021-01-02 0:00 UTC").round_to_multiple(Duration::MAX, origin),
+        Some(origin)
+    );
+}
+
 #[test]
 fn partial_eq() {
     assert_eq!(
@@ -448,6 +778,157 @@ fn hash() {
     );
 }
 
+#[test]
+fn checked_add_duration() {
+    assert_eq!(
+        datetime!("2019-12-31 23:59:59 +1").checked_add(2.seconds()),
+        Some(datetime!("2020-01-01 0:00:01 +1"))
+    );
+    assert_eq!(
+        datetime!("2020-01-01 0:00:01 UTC").checked_add((-2).seconds()),
+        Some(datetime!("2019-12-31 23:59:59 UTC"))
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").checked_add(Duration::MAX),
+        None
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").checked_add(Duration::MIN),
+        None
+    );
+
+    // The datetime in the stored offset would be out of range.
+    let max = PrimitiveDateTime::MAX.assume_offset(offset!("+1"));
+    assert_eq!(max.checked_add(1.nanoseconds()), None);
+    let max = PrimitiveDateTime::MAX.assume_offset(offset!("-1"));
+    assert_eq!(max.checked_add(1.nanoseconds()), None);
+}
+
+#[test]
+fn checked_add_std_duration() {
+    assert_eq!(
+        datetime!("2019-12-31 23:59:59 +1").checked_add_std(2.std_seconds()),
+        Some(datetime!("2020-01-01 0:00:01 +1"))
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").checked_add_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_duration() {
+    assert_eq!(
+        datetime!("2020-01-01 0:00:01 +1").checked_sub(2.seconds()),
+        Some(datetime!("2019-12-31 23:59:59 +1"))
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").checked_sub(Duration::MAX),
+        None
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").checked_sub(Duration::MIN),
+        None
+    );
+
+    let min = PrimitiveDateTime::MIN.assume_offset(offset!("-1"));
+    assert_eq!(min.checked_sub(1.nanoseconds()), None);
+    let min = PrimitiveDateTime::MIN.assume_offset(offset!("+1"));
+    assert_eq!(min.checked_sub(1.nanoseconds()), None);
+}
+
+#[test]
+fn checked_sub_std_duration() {
+    assert_eq!(
+        datetime!("2020-01-01 0:00:01 +1").checked_sub_std(2.std_seconds()),
+        Some(datetime!("2019-12-31 23:59:59 +1"))
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").checked_sub_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn saturating_add_duration() {
+    assert_eq!(
+        datetime!("2019-01-01 0:00 +1").saturating_add(5.days()),
+        datetime!("2019-01-06 0:00 +1")
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").saturating_add(Duration::MAX),
+        PrimitiveDateTime::MAX.assume_utc()
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").saturating_add(Duration::MIN),
+        PrimitiveDateTime::MIN.assume_utc()
+    );
+
+    // Both the UTC datetime and the datetime in the stored offset must be in range.
+    let saturated = datetime!("2019-01-01 0:00 +1").saturating_add(Duration::MAX);
+    assert_eq!(
+        saturated,
+        PrimitiveDateTime::MAX.assume_offset(offset!("+1"))
+    );
+    assert_eq!(saturated.offset(), offset!("+1"));
+    let saturated = datetime!("2019-01-01 0:00 -1").saturating_add(Duration::MAX);
+    assert_eq!(saturated, PrimitiveDateTime::MAX.assume_utc());
+    assert_eq!(saturated.offset(), offset!("-1"));
+    let saturated = datetime!("2019-01-01 0:00 +1").saturating_add(Duration::MIN);
+    assert_eq!(saturated, PrimitiveDateTime::MIN.assume_utc());
+    assert_eq!(saturated.offset(), offset!("+1"));
+    let saturated = datetime!("2019-01-01 0:00 -1").saturating_add(Duration::MIN);
+    assert_eq!(
+        saturated,
+        PrimitiveDateTime::MIN.assume_offset(offset!("-1"))
+    );
+    assert_eq!(saturated.offset(), offset!("-1"));
+}
+
+#[test]
+fn saturating_add_std_duration() {
+    assert_eq!(
+        datetime!("2019-01-01 0:00 +1").saturating_add_std(5.std_days()),
+        datetime!("2019-01-06 0:00 +1")
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").saturating_add_std(StdDuration::MAX),
+        PrimitiveDateTime::MAX.assume_utc()
+    );
+}
+
+#[test]
+fn saturating_sub_duration() {
+    assert_eq!(
+        datetime!("2019-01-06 0:00 +1").saturating_sub(5.days()),
+        datetime!("2019-01-01 0:00 +1")
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").saturating_sub(Duration::MAX),
+        PrimitiveDateTime::MIN.assume_utc()
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").saturating_sub(Duration::MIN),
+        PrimitiveDateTime::MAX.assume_utc()
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 -1").saturating_sub(Duration::MAX),
+        PrimitiveDateTime::MIN.assume_offset(offset!("-1"))
+    );
+}
+
+#[test]
+fn saturating_sub_std_duration() {
+    assert_eq!(
+        datetime!("2019-01-06 0:00 +1").saturating_sub_std(5.std_days()),
+        datetime!("2019-01-01 0:00 +1")
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00 UTC").saturating_sub_std(StdDuration::MAX),
+        PrimitiveDateTime::MIN.assume_utc()
+    );
+}
+
 #[test]
 fn add_duration() {
     assert_eq!(
@@ -2,6 +2,7 @@ mod iterator {
     use time::format_description::modifier::{
         MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
     };
+    use time::{FiscalYearNaming, Month};
 
     pub(super) fn padding() -> Vec<(Padding, &'static str)> {
         vec![
@@ -77,13 +78,39 @@ mod iterator {
     pub(super) fn weekday_is_one_indexed() -> Vec<(bool, &'static str)> {
         vec![(true, "one_indexed:true"), (false, "one_indexed:false")]
     }
+
+    pub(super) fn fiscal_year_start() -> Vec<(Month, &'static str)> {
+        vec![
+            (Month::January, "start:january"),
+            (Month::February, "start:february"),
+            (Month::March, "start:march"),
+            (Month::April, "start:april"),
+            (Month::May, "start:may"),
+            (Month::June, "start:june"),
+            (Month::July, "start:july"),
+            (Month::August, "start:august"),
+            (Month::September, "start:september"),
+            (Month::October, "start:october"),
+            (Month::November, "start:november"),
+            (Month::December, "start:december"),
+        ]
+    }
+
+    pub(super) fn fiscal_year_naming() -> Vec<(FiscalYearNaming, &'static str)> {
+        vec![
+            (FiscalYearNaming::StartYear, "naming:start"),
+            (FiscalYearNaming::EndYear, "naming:end"),
+        ]
+    }
 }
 
 use time::error::InvalidFormatDescription;
 use time::format_description::modifier::{
     self, MonthRepr, Padding, SubsecondDigits, WeekNumberRepr, WeekdayRepr, YearRepr,
 };
-use time::format_description::{self, Component, FormatItem};
+use time::format_description::{self, Component, FormatItem, OwnedFormatItem};
+use time::macros::format_description;
+use time::{FiscalCalendar, FiscalYearNaming};
 
 #[test]
 fn empty() {
@@ -120,6 +147,15 @@ fn only_literal() {
             FormatItem::Literal(b"bar")
         ])
     );
+    assert_eq!(format_description!("[["), &[FormatItem::Literal(b"[")]);
+    assert_eq!(
+        format_description!("foo[[bar"),
+        &[
+            FormatItem::Literal(b"foo"),
+            FormatItem::Literal(b"["),
+            FormatItem::Literal(b"bar")
+        ]
+    );
 }
 
 #[test]
@@ -181,6 +217,18 @@ fn simple_component() {
             }
         ))])
     );
+    assert_eq!(
+        format_description::parse("[tz_abbrev]"),
+        Ok(vec![FormatItem::Component(Component::TzAbbrev(
+            modifier::TzAbbrev
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[tz_name]"),
+        Ok(vec![FormatItem::Component(Component::TzName(
+            modifier::TzName
+        ))])
+    );
     assert_eq!(
         format_description::parse("[ordinal]"),
         Ok(vec![FormatItem::Component(Component::Ordinal(
@@ -240,6 +288,25 @@ fn simple_component() {
             }
         ))])
     );
+    assert_eq!(
+        format_description::parse("[quarter]"),
+        Ok(vec![FormatItem::Component(Component::Quarter(
+            modifier::Quarter {
+                calendar: FiscalCalendar::CALENDAR_YEAR
+            }
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[fiscal_year]"),
+        Ok(vec![FormatItem::Component(Component::FiscalYear(
+            modifier::FiscalYear {
+                padding: Padding::Zero,
+                repr: YearRepr::Full,
+                sign_is_mandatory: false,
+                calendar: FiscalCalendar::CALENDAR_YEAR
+            }
+        ))])
+    );
 }
 
 #[test]
@@ -266,6 +333,20 @@ fn errors() {
             index: 5
         })
     );
+    assert_eq!(
+        format_description::parse("[tz_name padding:zero]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "padding:zero".to_owned(),
+            index: 9
+        })
+    );
+    assert_eq!(
+        format_description::parse("[quarter naming:end]"),
+        Err(InvalidFormatDescription::InvalidModifier {
+            value: "naming:end".to_owned(),
+            index: 9
+        })
+    );
 }
 
 #[test]
@@ -400,6 +481,183 @@ fn component_with_modifiers() {
             );
         }
     }
+
+    for (start, start_str) in iterator::fiscal_year_start() {
+        assert_eq!(
+            format_description::parse(&format!("[quarter {}]", start_str)),
+            Ok(vec![FormatItem::Component(Component::Quarter(
+                modifier::Quarter {
+                    calendar: FiscalCalendar::new(start, FiscalYearNaming::EndYear)
+                }
+            ))])
+        );
+
+        for (naming, naming_str) in iterator::fiscal_year_naming() {
+            for (padding, padding_str) in iterator::padding() {
+                for (repr, repr_str) in iterator::year_repr() {
+                    for (sign_is_mandatory, sign_is_mandatory_str) in iterator::sign_is_mandatory()
+                    {
+                        assert_eq!(
+                            format_description::parse(&format!(
+                                "[fiscal_year {} {} {} {} {}]",
+                                start_str, naming_str, padding_str, repr_str, sign_is_mandatory_str
+                            )),
+                            Ok(vec![FormatItem::Component(Component::FiscalYear(
+                                modifier::FiscalYear {
+                                    padding,
+                                    repr,
+                                    sign_is_mandatory,
+                                    calendar: FiscalCalendar::new(start, naming)
+                                }
+                            ))])
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn optional_and_first() {
+    let hour = Component::Hour(modifier::Hour {
+        padding: Padding::Zero,
+        is_12_hour_clock: false,
+    });
+    let minute = Component::Minute(modifier::Minute {
+        padding: Padding::Zero,
+    });
+
+    assert_eq!(
+        format_description::parse_owned("[hour][optional [:[minute]]]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![
+                OwnedFormatItem::Component(hour),
+                OwnedFormatItem::Optional(Box::new(OwnedFormatItem::Compound(
+                    vec![
+                        OwnedFormatItem::Literal(Box::new(*b":")),
+                        OwnedFormatItem::Component(minute),
+                    ]
+                    .into()
+                ))),
+            ]
+            .into()
+        ))
+    );
+    assert_eq!(
+        format_description::parse_owned("[ first [Z] [[[[hour]]  ]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![OwnedFormatItem::First(
+                vec![
+                    OwnedFormatItem::Compound(
+                        vec![OwnedFormatItem::Literal(Box::new(*b"Z"))].into()
+                    ),
+                    OwnedFormatItem::Compound(
+                        vec![
+                            OwnedFormatItem::Literal(Box::new(*b"[")),
+                            OwnedFormatItem::Component(hour),
+                        ]
+                        .into()
+                    ),
+                ]
+                .into()
+            )]
+            .into()
+        ))
+    );
+    assert_eq!(
+        format_description::parse_owned("[first [a] [b]]]"),
+        Ok(OwnedFormatItem::Compound(
+            vec![
+                OwnedFormatItem::First(
+                    vec![
+                        OwnedFormatItem::Compound(
+                            vec![OwnedFormatItem::Literal(Box::new(*b"a"))].into()
+                        ),
+                        OwnedFormatItem::Compound(
+                            vec![OwnedFormatItem::Literal(Box::new(*b"b"))].into()
+                        ),
+                    ]
+                    .into()
+                ),
+                OwnedFormatItem::Literal(Box::new(*b"]")),
+            ]
+            .into()
+        ))
+    );
+    assert_eq!(
+        format_description::parse_owned("[hour]"),
+        Ok(OwnedFormatItem::from(&[FormatItem::Component(hour)][..]))
+    );
+    assert_eq!(
+        OwnedFormatItem::from(FormatItem::Optional(&FormatItem::Compound(&[
+            FormatItem::Literal(b":")
+        ]))),
+        OwnedFormatItem::Optional(Box::new(OwnedFormatItem::Compound(
+            vec![OwnedFormatItem::Literal(Box::new(*b":"))].into()
+        )))
+    );
+
+    assert_eq!(
+        format_description::parse("[hour][optional [:[minute]]]"),
+        Ok(vec![
+            FormatItem::Component(hour),
+            FormatItem::Owned(Box::new(OwnedFormatItem::Optional(Box::new(
+                OwnedFormatItem::Compound(
+                    vec![
+                        OwnedFormatItem::Literal(Box::new(*b":")),
+                        OwnedFormatItem::Component(minute),
+                    ]
+                    .into()
+                )
+            ))))
+        ])
+    );
+    assert_eq!(
+        format_description::parse("[first [a]]"),
+        Ok(vec![FormatItem::Owned(Box::new(OwnedFormatItem::First(
+            vec![OwnedFormatItem::Compound(
+                vec![OwnedFormatItem::Literal(Box::new(*b"a"))].into()
+            )]
+            .into()
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[optional]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "nested format description",
+            index: 9
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned("[optional]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "nested format description",
+            index: 9
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned("[optional [a] [b]]"),
+        Err(InvalidFormatDescription::Expected {
+            what: "closing bracket",
+            index: 14
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned("[optional [a]"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 0 })
+    );
+    assert_eq!(
+        format_description::parse_owned("[first [a] [b"),
+        Err(InvalidFormatDescription::UnclosedOpeningBracket { index: 11 })
+    );
+    assert_eq!(
+        format_description::parse_owned("[optional [[invalid]]]"),
+        Err(InvalidFormatDescription::InvalidComponentName {
+            name: "invalid".to_owned(),
+            index: 12
+        })
+    );
 }
 
 #[test]
@@ -428,6 +686,14 @@ fn error_display() {
         InvalidFormatDescription::MissingComponentName { index: 4 }.to_string(),
         "missing component name at byte index 4"
     );
+    assert_eq!(
+        InvalidFormatDescription::Expected {
+            what: "closing bracket",
+            index: 5
+        }
+        .to_string(),
+        "expected closing bracket at byte index 5"
+    );
 }
 
 #[test]
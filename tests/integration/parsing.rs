@@ -1,10 +1,15 @@
 use core::convert::{TryFrom, TryInto};
+use core::num::{NonZeroU16, NonZeroU8};
 
-use time::format_description::well_known::Rfc3339;
+use time::format_description::well_known::iso8601::{Config, FormattedComponents};
+use time::format_description::well_known::{HttpDate, Iso8601, Rfc2822, Rfc3339};
 use time::format_description::{modifier, Component};
-use time::macros::{date, datetime, time};
+use time::macros::{date, datetime, format_description, offset, time};
 use time::parsing::Parsed;
-use time::{format_description as fd, Date, OffsetDateTime, Time, UtcOffset, Weekday};
+use time::{
+    format_description as fd, Date, FiscalCalendar, FiscalYearNaming, Month, OffsetDateTime,
+    PrimitiveDateTime, Time, TimeZone, UtcOffset, Weekday,
+};
 
 #[test]
 fn rfc_3339() -> time::Result<()> {
@@ -77,6 +82,341 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn rfc_2822() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("Tue, 1 Jul 2003 10:52:37 +0200", &Rfc2822)?,
+        datetime!("2003-07-01 10:52:37 +02:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Fri, 21 Nov 1997 09:55:06 -0600", &Rfc2822)?,
+        datetime!("1997-11-21 09:55:06 -06:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("21 Nov 1997 09:55 -0630", &Rfc2822)?,
+        datetime!("1997-11-21 09:55 -06:30"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("sat, 02 JAN 2021 03:04:60 +0000", &Rfc2822)?,
+        datetime!("2021-01-02 03:04:59 UTC"),
+    );
+
+    // obsolete syntax
+    assert_eq!(
+        OffsetDateTime::parse(
+            " Thu (comment)\r\n ,\r\n 13\r\n   Feb\r\n  1969\r\n 23:32\r\n   :  54 -0330 \
+             (Newfoundland Time (\\) nested))",
+            &Rfc2822
+        )?,
+        datetime!("1969-02-13 23:32:54 -03:30"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("21 Nov 97 09:55:06 GMT", &Rfc2822)?,
+        datetime!("1997-11-21 09:55:06 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("21 Nov 49 09:55:06 UT", &Rfc2822)?,
+        datetime!("2049-11-21 09:55:06 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("21 Nov 103 09:55:06 z", &Rfc2822)?,
+        datetime!("2003-11-21 09:55:06 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("21 Nov 1997 09:55:06 A", &Rfc2822)?,
+        datetime!("1997-11-21 09:55:06 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("21 Nov 1997 09:55:06 -0000", &Rfc2822)?,
+        datetime!("1997-11-21 09:55:06 UTC"),
+    );
+    for &(zone, offset) in &[
+        ("EST", offset!("-5")),
+        ("EDT", offset!("-4")),
+        ("CST", offset!("-6")),
+        ("CDT", offset!("-5")),
+        ("MST", offset!("-7")),
+        ("MDT", offset!("-6")),
+        ("PST", offset!("-8")),
+        ("PDT", offset!("-7")),
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(&format!("21 Nov 1997 09:55:06 {}", zone), &Rfc2822)?,
+            datetime!("1997-11-21 09:55:06").assume_offset(offset),
+        );
+    }
+
+    assert_eq!(
+        Date::parse("Tue, 1 Jul 2003 10:52:37 +0200", &Rfc2822)?,
+        date!("2003-07-01"),
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("Tue, 1 Jul 2003 10:52:37 +0200", &Rfc2822)?,
+        datetime!("2003-07-01 10:52:37"),
+    );
+
+    let err = |input| OffsetDateTime::parse(input, &Rfc2822).unwrap_err();
+    assert!(matches!(
+        err("Wed, 1 Jul 2003 10:52:37 +0200"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("weekday")
+        )
+    ));
+    assert!(matches!(
+        Date::parse("Wed, 1 Jul 2003 10:52:37 +0200", &Rfc2822),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("weekday")
+        ))
+    ));
+    assert!(matches!(
+        err("Tue 1 Jul 2003 10:52:37 +0200"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidLiteral { .. }
+        )
+    ));
+    assert!(matches!(
+        err("0 Jul 2003 10:52:37 +0200"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("day")
+        )
+    ));
+    assert!(matches!(
+        err("1 July 2003 10:52:37 +0200"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        )
+    ));
+    assert!(matches!(
+        err("1 Jul 1899 10:52:37 +0200"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        )
+    ));
+    assert!(matches!(
+        err("1 Jul 2003 10:52:37 +0260"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset_minute")
+        )
+    ));
+    assert!(matches!(
+        err("1 Jul 2003 10:52:37 J"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset_hour")
+        )
+    ));
+    assert!(matches!(
+        err("1 Jul 2003 10:52:37"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset_hour")
+        )
+    ));
+    assert!(matches!(
+        err("1 Jul 2003 10:52:37 +0200 (unterminated"),
+        time::error::Parse::UnexpectedTrailingCharacters { .. }
+    ));
+    assert!(matches!(
+        err("31 Jun 2003 10:52:37 +0200"),
+        time::error::Parse::TryFromParsed(time::error::TryFromParsed::ComponentRange(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        datetime!("1994-11-06 08:49:37 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+        datetime!("1994-11-06 08:49:37 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+        datetime!("1994-11-06 08:49:37 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Wed Nov 16 08:49:60 1994", &HttpDate)?,
+        datetime!("1994-11-16 08:49:59 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Friday, 01-Jan-21 00:00:00 GMT", &HttpDate)?,
+        datetime!("2021-01-01 0:00 UTC"),
+    );
+    assert_eq!(
+        Date::parse("Thu, 31 Dec 2020 23:30:15 GMT", &HttpDate)?,
+        date!("2020-12-31"),
+    );
+
+    let err = |input| OffsetDateTime::parse(input, &HttpDate).unwrap_err();
+    assert!(matches!(
+        err("Mon, 06 Nov 1994 08:49:37 GMT"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("weekday")
+        )
+    ));
+    assert!(matches!(
+        err("sun, 06 Nov 1994 08:49:37 GMT"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("weekday")
+        )
+    ));
+    assert!(matches!(
+        err("Sun, 6 Nov 1994 08:49:37 GMT"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("day")
+        )
+    ));
+    assert!(matches!(
+        err("Sun, 06 November 1994 08:49:37 GMT"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidLiteral { .. }
+        )
+    ));
+    assert!(matches!(
+        err("Sun, 06 Nov 94 08:49:37 GMT"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        )
+    ));
+    assert!(matches!(
+        err("Sun, 06 Nov 1994 08:49 GMT"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidLiteral { .. }
+        )
+    ));
+    assert!(matches!(
+        err("Sun, 06 Nov 1994 08:49:37 +0000"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidLiteral { .. }
+        )
+    ));
+    assert!(matches!(
+        err("Sun Nov 6 08:49:37 1994"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("day")
+        )
+    ));
+    assert!(matches!(
+        err("Sat, 31 Jun 2021 08:49:37 GMT"),
+        time::error::Parse::TryFromParsed(time::error::TryFromParsed::ComponentRange(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601() -> time::Result<()> {
+    const DATE: Iso8601 =
+        Iso8601::with_config(Config::DEFAULT.set_formatted_components(FormattedComponents::Date));
+    const TIME: Iso8601 =
+        Iso8601::with_config(Config::DEFAULT.set_formatted_components(FormattedComponents::Time));
+    const DATE_TIME: Iso8601 = Iso8601::with_config(
+        Config::DEFAULT.set_formatted_components(FormattedComponents::DateTime),
+    );
+    const OFFSET: Iso8601 =
+        Iso8601::with_config(Config::DEFAULT.set_formatted_components(FormattedComponents::Offset));
+
+    assert_eq!(
+        OffsetDateTime::parse("20210301T120000Z", &Iso8601::DEFAULT)?,
+        datetime!("2021-03-01 12:00 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-03-01T12:30:15.123+01:00", &Iso8601::DEFAULT)?,
+        datetime!("2021-03-01 12:30:15.123 +01:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-W09-1T12:00+01", &Iso8601::DEFAULT)?,
+        datetime!("2021-03-01 12:00 +01:00"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021W091T1200-0530", &Iso8601::DEFAULT)?,
+        datetime!("2021-03-01 12:00 -05:30"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-060t12,5z", &Iso8601::DEFAULT)?,
+        datetime!("2021-03-01 12:30 UTC"),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("+002021-03-01T12:30:60Z", &Iso8601::DEFAULT)?,
+        datetime!("2021-03-01 12:30:59 UTC"),
+    );
+    assert_eq!(Date::parse("2021-060", &DATE)?, date!("2021-03-01"));
+    assert_eq!(Date::parse("2021060", &DATE)?, date!("2021-03-01"));
+    assert_eq!(Date::parse("20210301", &DATE)?, date!("2021-03-01"));
+    assert_eq!(Date::parse("2020-W53-7", &DATE)?, date!("2021-01-03"));
+    assert_eq!(Date::parse("-001234-03-01", &DATE)?, date!("-1234-03-01"));
+    assert_eq!(Time::parse("T12:30", &TIME)?, time!("12:30"));
+    assert_eq!(Time::parse("12.755", &TIME)?, time!("12:45:18"));
+    assert_eq!(Time::parse("1230.25", &TIME)?, time!("12:30:15"));
+    assert_eq!(
+        Time::parse("12:30:15,1234567891", &TIME)?,
+        time!("12:30:15.123456789")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-03-01T12", &DATE_TIME)?,
+        datetime!("2021-03-01 12:00"),
+    );
+    assert_eq!(UtcOffset::parse("-03", &OFFSET)?, offset!("-3"));
+    assert_eq!(UtcOffset::parse("+0530", &OFFSET)?, offset!("+5:30"));
+
+    let err = |input| OffsetDateTime::parse(input, &Iso8601::DEFAULT).unwrap_err();
+    assert!(matches!(
+        err("21-03-01T12:00Z"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("year")
+        )
+    ));
+    assert!(matches!(
+        err("2021-3-01T12:00Z"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("month")
+        )
+    ));
+    assert!(matches!(
+        err("2021-W09-8T12:00Z"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("weekday")
+        )
+    ));
+    assert!(matches!(
+        err("2021-03-01 12:00Z"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidLiteral { .. }
+        )
+    ));
+    assert!(matches!(
+        err("2021-03-01T12:Z"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("minute")
+        )
+    ));
+    assert!(matches!(
+        err("2021-03-01T12:00"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset_hour")
+        )
+    ));
+    assert!(matches!(
+        err("2021-03-01T12:00+01:"),
+        time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidComponent("offset_minute")
+        )
+    ));
+    assert!(matches!(
+        err("2021-02-29T12:00Z"),
+        time::error::Parse::TryFromParsed(time::error::TryFromParsed::ComponentRange(_))
+    ));
+    // The end of the day is not supported.
+    assert!(matches!(
+        err("2021-03-01T24:00Z"),
+        time::error::Parse::TryFromParsed(time::error::TryFromParsed::ComponentRange(_))
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn parse_time() -> time::Result<()> {
     let format_input_output = [
@@ -189,6 +529,47 @@ fn parse_date() -> time::Result<()> {
         Err(time::error::TryFromParsed::InsufficientInformation { .. })
     ));
 
+    // The fiscal year and quarter must agree with the date.
+    let err = |input, format: &Vec<_>| match Date::parse(input, format) {
+        Err(time::error::Parse::TryFromParsed(time::error::TryFromParsed::ComponentRange(err))) => {
+            Some(err.name)
+        }
+        _ => None,
+    };
+    let format = fd::parse(
+        "FY[fiscal_year start:april naming:end] Q[quarter start:april] [year]-[month]-[day]",
+    )?;
+    assert_eq!(
+        Date::parse("FY2022 Q1 2021-04-01", &format)?,
+        date!("2021-04-01")
+    );
+    assert_eq!(err("FY2021 Q1 2021-04-01", &format), Some("fiscal_year"));
+    assert_eq!(err("FY2022 Q2 2021-04-01", &format), Some("quarter"));
+    let format =
+        fd::parse("FY[fiscal_year repr:last_two start:april naming:start] [year]-[month]-[day]")?;
+    assert_eq!(
+        Date::parse("FY21 2021-04-01", &format)?,
+        date!("2021-04-01")
+    );
+    assert_eq!(
+        err("FY22 2021-04-01", &format),
+        Some("fiscal_year_last_two")
+    );
+    let format = fd::parse("Q[quarter] [year]-[month]-[day]")?;
+    assert_eq!(Date::parse("Q1 2021-02-01", &format)?, date!("2021-02-01"));
+    assert_eq!(err("Q2 2021-02-01", &format), Some("quarter"));
+
+    let mut parsed = Parsed::new();
+    parsed.year = Some(2021);
+    parsed.ordinal = NonZeroU16::new(200);
+    parsed.quarter = NonZeroU8::new(1);
+    assert!(matches!(
+        Date::try_from(parsed),
+        Err(time::error::TryFromParsed::ComponentRange(err)) if err.name == "quarter"
+    ));
+    parsed.quarter = NonZeroU8::new(3);
+    assert_eq!(Date::try_from(parsed), Ok(date!("2021-200")));
+
     Ok(())
 }
 
@@ -254,13 +635,40 @@ fn parse_components() -> time::Result<()> {
         b"21",
         _.iso_year_last_two == Some(21)
     );
+    parse_component!(
+        Component::FiscalYear(modifier::FiscalYear {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::Full,
+            sign_is_mandatory: false,
+            calendar: FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear),
+        }),
+        b"2021",
+        _.fiscal_year == Some(2021)
+    );
+    parse_component!(
+        Component::FiscalYear(modifier::FiscalYear {
+            padding: modifier::Padding::Zero,
+            repr: modifier::YearRepr::LastTwo,
+            sign_is_mandatory: false,
+            calendar: FiscalCalendar::new(Month::April, FiscalYearNaming::StartYear),
+        }),
+        b"21",
+        _.fiscal_year_last_two == Some(21)
+    );
+    parse_component!(
+        Component::Quarter(modifier::Quarter {
+            calendar: FiscalCalendar::CALENDAR_YEAR,
+        }),
+        b"3",
+        _.quarter == NonZeroU8::new(3)
+    );
     parse_component!(
         Component::Month(modifier::Month {
             padding: modifier::Padding::Space,
             repr: modifier::MonthRepr::Numerical,
         }),
         b" 1",
-        _.month == 1.try_into().ok()
+        _.month == Some(Month::January)
     );
     parse_component!(
         Component::Month(modifier::Month {
@@ -268,7 +676,7 @@ fn parse_components() -> time::Result<()> {
             repr: modifier::MonthRepr::Short,
         }),
         b"Jan",
-        _.month == 1.try_into().ok()
+        _.month == Some(Month::January)
     );
     parse_component!(
         Component::Month(modifier::Month {
@@ -276,7 +684,7 @@ fn parse_components() -> time::Result<()> {
             repr: modifier::MonthRepr::Long,
         }),
         b"January",
-        _.month == 1.try_into().ok()
+        _.month == Some(Month::January)
     );
     parse_component!(
         Component::Ordinal(modifier::Ordinal {
@@ -427,6 +835,132 @@ fn parse_components() -> time::Result<()> {
         b"123456789",
         _.subsecond == Some(123_456_789)
     );
+    parse_component!(
+        Component::TzName(modifier::TzName),
+        b"America/New_York",
+        _.time_zone_name == Some("America/New_York")
+    );
+    parse_component!(
+        Component::TzName(modifier::TzName),
+        b"America/Indiana/Indianapolis",
+        _.time_zone_name == Some("America/Indiana/Indianapolis")
+    );
+    parse_component!(
+        Component::TzName(modifier::TzName),
+        b"EST5EDT",
+        _.time_zone_name == Some("EST5EDT")
+    );
+
+    let mut parsed = Parsed::new();
+    assert_eq!(
+        parsed.parse_component(
+            b"5",
+            Component::Quarter(modifier::Quarter {
+                calendar: FiscalCalendar::CALENDAR_YEAR
+            })
+        ),
+        Err(time::error::ParseFromDescription::InvalidComponent(
+            "quarter"
+        ))
+    );
+    assert_eq!(
+        parsed.parse_component(b"CEST 2021", Component::TzAbbrev(modifier::TzAbbrev)),
+        Ok(&b" 2021"[..])
+    );
+    assert_eq!(
+        parsed.parse_component(b"-03", Component::TzAbbrev(modifier::TzAbbrev)),
+        Ok(&b""[..])
+    );
+    assert_eq!(
+        parsed.parse_component(b"Z", Component::TzAbbrev(modifier::TzAbbrev)),
+        Err(time::error::ParseFromDescription::InvalidComponent(
+            "time zone abbreviation"
+        ))
+    );
+    assert_eq!(
+        parsed.parse_component(b"Europe/Atlantis", Component::TzName(modifier::TzName)),
+        Err(time::error::ParseFromDescription::InvalidComponent(
+            "time zone name"
+        ))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_time_zone() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    parsed.parse_component(b"Asia/Tokyo", Component::TzName(modifier::TzName))?;
+    assert_eq!(TimeZone::try_from(parsed)?.name(), Some("Asia/Tokyo"));
+    assert_eq!(
+        TimeZone::try_from(Parsed::new()),
+        Err(time::error::TryFromParsed::InsufficientInformation)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_optional_and_first() -> time::Result<()> {
+    let format = format_description!("[hour]:[minute][optional [:[second]]]");
+    assert_eq!(Time::parse("12:00", &format)?, time!("12:00"));
+    assert_eq!(Time::parse("12:00:05", &format)?, time!("12:00:05"));
+    assert!(matches!(
+        Time::parse("12:00:", &format),
+        Err(time::error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+
+    let format = format_description!(
+        "[year]-[month]-[day]T[hour]:[minute][first [Z] [[offset_hour]:[offset_minute]]]"
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04Z", &format),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation
+        ))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04+01:00", &format)?,
+        datetime!("2021-01-02 03:04 +01:00")
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04+01", &format),
+        Err(time::error::Parse::ParseFromDescription(
+            time::error::ParseFromDescription::InvalidLiteral { .. }
+        ))
+    ));
+
+    let format = fd::parse_owned(
+        "[year]-[month]-[day]T[hour]:[minute][first [[offset_hour]:[offset_minute]] [Z]]",
+    )?;
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04+01:00", &format)?,
+        datetime!("2021-01-02 03:04 +01:00")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T03:04Z", &format)?,
+        datetime!("2021-01-02 03:04")
+    );
+    let format = fd::parse_owned("[hour][optional [:[minute]]]")?;
+    assert_eq!(Time::parse("12:30", &format)?, time!("12:30"));
+    assert!(matches!(
+        Time::parse("12", &format),
+        Err(time::error::Parse::TryFromParsed(
+            time::error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+
+    let format = fd::parse("[hour][optional [:[minute]]]")?;
+    assert_eq!(Time::parse("12:30", &format)?, time!("12:30"));
+    let format = fd::parse("[year]-[month]-[day]T[hour]:[minute][first [[offset_hour]] [Z]]")?;
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04+01", &format)?,
+        datetime!("2021-01-02 03:04 +01:00")
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02T03:04Z", &format)?,
+        datetime!("2021-01-02 03:04")
+    );
 
     Ok(())
 }
@@ -0,0 +1,102 @@
+use time::Period;
+
+#[test]
+fn zero() {
+    assert_eq!(Period::ZERO, Period::new(0, 0, 0));
+    assert_eq!(Period::ZERO, Period::default());
+    assert!(Period::ZERO.is_zero());
+}
+
+#[test]
+fn getters() {
+    let period = Period::new(1, -2, 3);
+    assert_eq!(period.years(), 1);
+    assert_eq!(period.months(), -2);
+    assert_eq!(period.days(), 3);
+}
+
+#[test]
+fn is_zero() {
+    assert!(Period::new(0, 0, 0).is_zero());
+    assert!(!Period::new(1, 0, 0).is_zero());
+    assert!(!Period::new(0, 1, 0).is_zero());
+    assert!(!Period::new(0, 0, 1).is_zero());
+}
+
+#[test]
+fn normalized() {
+    assert_eq!(Period::new(0, 12, 0).normalized(), Period::new(1, 0, 0));
+    assert_eq!(Period::new(1, 14, 40).normalized(), Period::new(2, 2, 40));
+    assert_eq!(Period::new(1, -3, 0).normalized(), Period::new(0, 9, 0));
+    assert_eq!(Period::new(-1, 3, 0).normalized(), Period::new(0, -9, 0));
+    assert_eq!(Period::new(-1, -14, 0).normalized(), Period::new(-2, -2, 0));
+    assert_eq!(
+        Period::new(i32::MAX, 11, 0).normalized(),
+        Period::new(i32::MAX, 11, 0)
+    );
+}
+
+#[test]
+#[should_panic]
+fn normalized_overflow() {
+    let _ = Period::new(i32::MAX, 12, 0).normalized();
+}
+
+#[test]
+fn display() {
+    assert_eq!(Period::ZERO.to_string(), "P0D");
+    assert_eq!(Period::new(1, 2, 3).to_string(), "P1Y2M3D");
+    assert_eq!(Period::new(0, 1, 0).to_string(), "P1M");
+    assert_eq!(Period::new(3, 0, 2).to_string(), "P3Y2D");
+    assert_eq!(Period::new(0, -1, -15).to_string(), "P-1M-15D");
+}
+
+#[test]
+fn add() {
+    assert_eq!(
+        Period::new(1, 2, 3) + Period::new(4, 5, 6),
+        Period::new(5, 7, 9)
+    );
+    assert_eq!(Period::new(1, 2, 3) + Period::new(-1, -2, -3), Period::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn add_overflow() {
+    let _ = Period::new(i32::MAX, 0, 0) + Period::new(1, 0, 0);
+}
+
+#[test]
+fn add_assign() {
+    let mut period = Period::new(1, 2, 3);
+    period += Period::new(4, 5, 6);
+    assert_eq!(period, Period::new(5, 7, 9));
+}
+
+#[test]
+fn sub() {
+    assert_eq!(
+        Period::new(5, 7, 9) - Period::new(4, 5, 6),
+        Period::new(1, 2, 3)
+    );
+    assert_eq!(Period::new(1, 2, 3) - Period::new(1, 2, 3), Period::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn sub_overflow() {
+    let _ = Period::new(0, 0, i32::MIN) - Period::new(0, 0, 1);
+}
+
+#[test]
+fn sub_assign() {
+    let mut period = Period::new(5, 7, 9);
+    period -= Period::new(4, 5, 6);
+    assert_eq!(period, Period::new(1, 2, 3));
+}
+
+#[test]
+fn neg() {
+    assert_eq!(-Period::new(1, -2, 3), Period::new(-1, 2, -3));
+    assert_eq!(-Period::ZERO, Period::ZERO);
+}
@@ -1,8 +1,12 @@
 use std::cmp::Ordering;
+use std::time::Duration as StdDuration;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
-use time::{PrimitiveDateTime, Weekday};
+use time::time_zone::{LocalResult, PosixTz};
+use time::{
+    Date, DayOverflow, Duration, Month, Period, PrimitiveDateTime, TimeZone, Unit, Weekday,
+};
 
 #[test]
 fn new() {
@@ -31,8 +35,8 @@ fn year() {
 
 #[test]
 fn month() {
-    assert_eq!(datetime!("2019-01-01 0:00").month(), 1);
-    assert_eq!(datetime!("2019-12-31 0:00").month(), 12);
+    assert_eq!(datetime!("2019-01-01 0:00").month(), Month::January);
+    assert_eq!(datetime!("2019-12-31 0:00").month(), Month::December);
 }
 
 #[test]
@@ -76,7 +80,7 @@ fn monday_based_week() {
 fn to_calendar_date() {
     assert_eq!(
         datetime!("2019-01-02 0:00").to_calendar_date(),
-        (2019, 1, 2)
+        (2019, Month::January, 2)
     );
 }
 
@@ -234,6 +238,69 @@ fn assume_utc() {
     );
 }
 
+#[test]
+fn assume_timezone() -> time::Result<()> {
+    let new_york = TimeZone::from_tzif(&crate::time_zone::new_york(b'2'))?;
+    assert_eq!(
+        datetime!("2021-07-01 12:00").assume_timezone(&new_york),
+        LocalResult::Single(datetime!("2021-07-01 12:00 -4").to_zoned(&new_york)),
+    );
+    assert_eq!(
+        datetime!("2021-11-07 1:30").assume_timezone(&new_york),
+        LocalResult::Ambiguous(
+            datetime!("2021-11-07 1:30 -4").to_zoned(&new_york),
+            datetime!("2021-11-07 1:30 -5").to_zoned(&new_york),
+        ),
+    );
+    assert_eq!(
+        datetime!("2021-11-07 2:00").assume_timezone(&new_york),
+        LocalResult::Single(datetime!("2021-11-07 2:00 -5").to_zoned(&new_york)),
+    );
+    assert_eq!(
+        datetime!("2021-03-14 2:00").assume_timezone(&new_york),
+        LocalResult::Gap {
+            before: datetime!("2021-03-14 1:00 -5").to_zoned(&new_york),
+            after: datetime!("2021-03-14 3:00 -4").to_zoned(&new_york),
+        },
+    );
+    assert_eq!(
+        datetime!("2021-03-14 3:00").assume_timezone(&new_york),
+        LocalResult::Single(datetime!("2021-03-14 3:00 -4").to_zoned(&new_york)),
+    );
+
+    // after the last transition
+    assert_eq!(
+        datetime!("2030-11-03 1:30").assume_timezone(&new_york),
+        LocalResult::Ambiguous(
+            datetime!("2030-11-03 1:30 -4").to_zoned(&new_york),
+            datetime!("2030-11-03 1:30 -5").to_zoned(&new_york),
+        ),
+    );
+
+    let auckland = TimeZone::from(PosixTz::parse("NZST-12NZDT,M9.5.0,M4.1.0/3")?);
+    assert_eq!(
+        datetime!("2021-04-04 2:30").assume_timezone(&auckland),
+        LocalResult::Ambiguous(
+            datetime!("2021-04-04 2:30 +13").to_zoned(&auckland),
+            datetime!("2021-04-04 2:30 +12").to_zoned(&auckland),
+        ),
+    );
+    assert_eq!(
+        datetime!("2021-09-26 2:30").assume_timezone(&auckland),
+        LocalResult::Gap {
+            before: datetime!("2021-09-26 1:30 +12").to_zoned(&auckland),
+            after: datetime!("2021-09-26 3:30 +13").to_zoned(&auckland),
+        },
+    );
+
+    let utc = TimeZone::from(PosixTz::parse("UTC0")?);
+    assert_eq!(
+        datetime!("2021-03-14 2:00").assume_timezone(&utc),
+        LocalResult::Single(datetime!("2021-03-14 2:00 UTC").to_zoned(&utc)),
+    );
+    Ok(())
+}
+
 #[test]
 fn replace_time() {
     assert_eq!(
@@ -250,6 +317,552 @@ fn replace_date() {
     );
 }
 
+#[test]
+fn replace_date_components() {
+    let datetime = datetime!("2020-02-29 12:34:56.789");
+    assert_eq!(
+        datetime.replace_year(2024),
+        Ok(datetime!("2024-02-29 12:34:56.789"))
+    );
+    assert!(datetime.replace_year(2019).is_err());
+    assert_eq!(
+        datetime.replace_month(Month::January),
+        Ok(datetime!("2020-01-29 12:34:56.789"))
+    );
+    assert!(datetime!("2020-01-31 0:00")
+        .replace_month(Month::April)
+        .is_err());
+    assert_eq!(
+        datetime.replace_day(1),
+        Ok(datetime!("2020-02-01 12:34:56.789"))
+    );
+    assert!(datetime.replace_day(30).is_err());
+    assert_eq!(
+        datetime.replace_ordinal(366),
+        Ok(datetime!("2020-366 12:34:56.789"))
+    );
+    assert!(datetime.replace_ordinal(367).is_err());
+}
+
+#[test]
+fn replace_time_components() {
+    let datetime = datetime!("2020-02-29 1:02:03.004_005_006");
+    assert_eq!(
+        datetime.replace_hour(7),
+        Ok(datetime!("2020-02-29 7:02:03.004_005_006"))
+    );
+    assert!(datetime.replace_hour(24).is_err());
+    assert_eq!(
+        datetime.replace_minute(7),
+        Ok(datetime!("2020-02-29 1:07:03.004_005_006"))
+    );
+    assert!(datetime.replace_minute(60).is_err());
+    assert_eq!(
+        datetime.replace_second(7),
+        Ok(datetime!("2020-02-29 1:02:07.004_005_006"))
+    );
+    assert!(datetime.replace_second(60).is_err());
+    assert_eq!(
+        datetime.replace_millisecond(7),
+        Ok(datetime!("2020-02-29 1:02:03.007"))
+    );
+    assert!(datetime.replace_millisecond(1_000).is_err());
+    assert_eq!(
+        datetime.replace_microsecond(7_008),
+        Ok(datetime!("2020-02-29 1:02:03.007_008"))
+    );
+    assert!(datetime.replace_microsecond(1_000_000).is_err());
+    assert_eq!(
+        datetime.replace_nanosecond(7_008_009),
+        Ok(datetime!("2020-02-29 1:02:03.007_008_009"))
+    );
+    assert!(datetime.replace_nanosecond(1_000_000_000).is_err());
+}
+
+#[test]
+fn truncate() {
+    // Thursday
+    let datetime = datetime!("2021-08-19 13:45:30.5");
+    assert_eq!(
+        datetime.truncate(Unit::Second),
+        Some(datetime!("2021-08-19 13:45:30"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Minute),
+        Some(datetime!("2021-08-19 13:45"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Hour),
+        Some(datetime!("2021-08-19 13:00"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Day),
+        Some(datetime!("2021-08-19 0:00"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::IsoWeek),
+        Some(datetime!("2021-08-16 0:00"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::SundayWeek),
+        Some(datetime!("2021-08-15 0:00"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Month),
+        Some(datetime!("2021-08-01 0:00"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Quarter),
+        Some(datetime!("2021-07-01 0:00"))
+    );
+    assert_eq!(
+        datetime.truncate(Unit::Year),
+        Some(datetime!("2021-01-01 0:00"))
+    );
+    assert_eq!(
+        datetime!("2020-12-31 12:00").truncate(Unit::Quarter),
+        Some(datetime!("2020-10-01 0:00"))
+    );
+    assert_eq!(
+        datetime!("2020-03-31 12:00").truncate(Unit::Quarter),
+        Some(datetime!("2020-01-01 0:00"))
+    );
+    assert_eq!(
+        datetime!("2021-08-15 12:00").truncate(Unit::SundayWeek),
+        Some(datetime!("2021-08-15 0:00"))
+    );
+    assert_eq!(
+        datetime!("2021-08-15 12:00").truncate(Unit::IsoWeek),
+        Some(datetime!("2021-08-09 0:00"))
+    );
+    // `Date::MIN` is a Monday.
+    assert_eq!(
+        PrimitiveDateTime::MIN.truncate(Unit::IsoWeek),
+        Some(PrimitiveDateTime::MIN)
+    );
+    assert_eq!(PrimitiveDateTime::MIN.truncate(Unit::SundayWeek), None);
+    assert_eq!(
+        PrimitiveDateTime::MAX.truncate(Unit::Year),
+        Some(Date::MAX.replace_ordinal(1).unwrap().midnight())
+    );
+}
+
+#[test]
+fn ceil() {
+    let datetime = datetime!("2021-08-19 13:45:30.5");
+    assert_eq!(
+        datetime.ceil(Unit::Second),
+        Some(datetime!("2021-08-19 13:45:31"))
+    );
+    assert_eq!(datetime.ceil(Unit::Day), Some(datetime!("2021-08-20 0:00")));
+    assert_eq!(
+        datetime.ceil(Unit::IsoWeek),
+        Some(datetime!("2021-08-23 0:00"))
+    );
+    assert_eq!(
+        datetime.ceil(Unit::SundayWeek),
+        Some(datetime!("2021-08-22 0:00"))
+    );
+    assert_eq!(
+        datetime.ceil(Unit::Month),
+        Some(datetime!("2021-09-01 0:00"))
+    );
+    assert_eq!(
+        datetime.ceil(Unit::Quarter),
+        Some(datetime!("2021-10-01 0:00"))
+    );
+    assert_eq!(
+        datetime.ceil(Unit::Year),
+        Some(datetime!("2022-01-01 0:00"))
+    );
+    assert_eq!(
+        datetime!("2021-08-01 0:00").ceil(Unit::Month),
+        Some(datetime!("2021-08-01 0:00"))
+    );
+    assert_eq!(
+        datetime!("2021-12-31 23:59:59.5").ceil(Unit::Second),
+        Some(datetime!("2022-01-01 0:00"))
+    );
+    assert_eq!(PrimitiveDateTime::MAX.ceil(Unit::Second), None);
+    assert_eq!(PrimitiveDateTime::MAX.ceil(Unit::Year), None);
+}
+
+#[test]
+fn round() {
+    let datetime = datetime!("2021-08-19 13:45:30.5");
+    assert_eq!(
+        datetime.round(Unit::Second),
+        Some(datetime!("2021-08-19 13:45:31"))
+    );
+    assert_eq!(
+        datetime.round(Unit::Minute),
+        Some(datetime!("2021-08-19 13:46"))
+    );
+    assert_eq!(
+        datetime.round(Unit::Day),
+        Some(datetime!("2021-08-20 0:00"))
+    );
+    assert_eq!(
+        datetime.round(Unit::IsoWeek),
+        Some(datetime!("2021-08-23 0:00"))
+    );
+    assert_eq!(
+        datetime.round(Unit::Month),
+        Some(datetime!("2021-09-01 0:00"))
+    );
+    assert_eq!(
+        datetime.round(Unit::Quarter),
+        Some(datetime!("2021-10-01 0:00"))
+    );
+    assert_eq!(
+        datetime.round(Unit::Year),
+        Some(datetime!("2022-01-01 0:00"))
+    );
+    // February 2021 has 28 days, so the midpoint is the start of the 15th.
+    assert_eq!(
+        datetime!("2021-02-15 0:00").round(Unit::Month),
+        Some(datetime!("2021-03-01 0:00"))
+    );
+    assert_eq!(
+        datetime!("2021-02-14 23:59:59.999_999_999").round(Unit::Month),
+        Some(datetime!("2021-02-01 0:00"))
+    );
+    assert_eq!(PrimitiveDateTime::MAX.round(Unit::Hour), None);
+    let last_year = Date::MAX.replace_ordinal(1).unwrap().midnight();
+    assert_eq!(last_year.round(Unit::Year), Some(last_year));
+    assert_eq!(PrimitiveDateTime::MAX.round(Unit::Year), None);
+}
+
+#[test]
+fn truncate_to_multiple() {
+    let origin = datetime!("2021-01-01 0:00");
+    let step = 15.minutes();
+    assert_eq!(
+        datetime!("2021-08-19 13:44").truncate_to_multiple(step, origin),
+        Some(datetime!("2021-08-19 13:30"))
+    );
+    assert_eq!(
+        datetime!("2021-08-19 13:45").truncate_to_multiple(step, origin),
+        Some(datetime!("2021-08-19 13:45"))
+    );
+    assert_eq!(
+        datetime!("2020-12-31 23:59").truncate_to_multiple(step, origin),
+        Some(datetime!("2020-12-31 23:45"))
+    );
+    assert_eq!(
+        datetime!("2021-08-19 13:44").truncate_to_multiple(step, datetime!("2021-01-01 0:05")),
+        Some(datetime!("2021-08-19 13:35"))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.truncate_to_multiple(step, origin + 1.minutes()),
+        None
+    );
+}
+
+#[test]
+fn ceil_to_multiple() {
+    let origin = datetime!("2021-01-01 0:00");
+    let step = 15.minutes();
+    assert_eq!(
+        datetime!("2021-08-19 13:31").ceil_to_multiple(step, origin),
+        Some(datetime!("2021-08-19 13:45"))
+    );
+    assert_eq!(
+        datetime!("2021-08-19 13:30").ceil_to_multiple(step, origin),
+        Some(datetime!("2021-08-19 13:30"))
+    );
+    assert_eq!(
+        datetime!("2020-12-31 23:59").ceil_to_multiple(step, origin),
+        Some(datetime!("2021-01-01 0:00"))
+    );
+    assert_eq!(PrimitiveDateTime::MAX.ceil_to_multiple(step, origin), None);
+}
+
+#[test]
+fn round_to_multiple() {
+    let origin = datetime!("2021-01-01 0:00");
+    let step = 15.minutes();
+    assert_eq!(
+        datetime!("2021-08-19 13:37:29").round_to_multiple(step, origin),
+        Some(datetime!("2021-08-19 13:30"))
+    );
+    assert_eq!(
+        datetime!("2021-08-19 13:37:30").round_to_multiple(step, origin),
+        Some(datetime!("2021-08-19 13:45"))
+    );
+    assert_eq!(
+        datetime!("2020-12-31 23:52:30").round_to_multiple(step, origin),
+        Some(datetime!("2021-01-01 0:00"))
+    );
+    assert_eq!(
+        datetime!("2020-12-31 23:52:29").round_to_multiple(step, origin),
+        Some(datetime!("2020-12-31 23:45"))
+    );
+    assert_eq!(
+        datetime!("2020-12-31 0:00").round_to_multiple(Duration::MAX, origin),
+        Some(origin)
+    );
+    assert_eq!(
+        datetime!("2021-01-02 0:00").round_to_multiple(Duration::MAX, origin),
+        Some(origin)
+    );
+}
+
+#[test]
+#[should_panic]
+fn truncate_to_multiple_zero_step() {
+    let _ = datetime!("2021-01-01 0:00").truncate_to_multiple(0.seconds(), PrimitiveDateTime::MIN);
+}
+
+#[test]
+fn checked_add_months() {
+    assert_eq!(
+        datetime!("2021-01-31 12:34").checked_add_months(1, DayOverflow::Clamp),
+        Some(datetime!("2021-02-28 12:34"))
+    );
+    assert_eq!(
+        datetime!("2021-01-31 12:34").checked_add_months(1, DayOverflow::Rollover),
+        Some(datetime!("2021-03-03 12:34"))
+    );
+    assert_eq!(
+        datetime!("2021-01-31 12:34").checked_add_months(1, DayOverflow::Error),
+        None
+    );
+    assert_eq!(
+        Date::MAX
+            .midnight()
+            .checked_add_months(1, DayOverflow::Clamp),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_months() {
+    assert_eq!(
+        datetime!("2021-03-31 12:34").checked_sub_months(1, DayOverflow::Clamp),
+        Some(datetime!("2021-02-28 12:34"))
+    );
+    assert_eq!(
+        datetime!("2021-03-31 12:34").checked_sub_months(1, DayOverflow::Error),
+        None
+    );
+}
+
+#[test]
+fn checked_add_years() {
+    assert_eq!(
+        datetime!("2020-02-29 12:34").checked_add_years(1, DayOverflow::Rollover),
+        Some(datetime!("2021-03-01 12:34"))
+    );
+    assert_eq!(
+        datetime!("2020-02-29 12:34").checked_add_years(1, DayOverflow::Error),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_years() {
+    assert_eq!(
+        datetime!("2020-02-29 12:34").checked_sub_years(1, DayOverflow::Clamp),
+        Some(datetime!("2019-02-28 12:34"))
+    );
+    assert_eq!(
+        datetime!("2020-02-29 12:34").checked_sub_years(1, DayOverflow::Error),
+        None
+    );
+}
+
+#[test]
+fn add_period() {
+    assert_eq!(
+        datetime!("2021-01-31 12:34") + Period::new(0, 1, 1),
+        datetime!("2021-03-01 12:34")
+    );
+}
+
+#[test]
+fn add_assign_period() {
+    let mut datetime = datetime!("2021-01-31 12:34");
+    datetime += Period::new(0, 1, 0);
+    assert_eq!(datetime, datetime!("2021-02-28 12:34"));
+}
+
+#[test]
+fn sub_period() {
+    assert_eq!(
+        datetime!("2021-03-31 12:34") - Period::new(0, 1, 1),
+        datetime!("2021-02-27 12:34")
+    );
+}
+
+#[test]
+fn sub_assign_period() {
+    let mut datetime = datetime!("2021-03-31 12:34");
+    datetime -= Period::new(0, 1, 0);
+    assert_eq!(datetime, datetime!("2021-02-28 12:34"));
+}
+
+#[test]
+fn min_max() {
+    assert_eq!(PrimitiveDateTime::MIN, Date::MIN.midnight());
+    assert_eq!(
+        PrimitiveDateTime::MAX,
+        Date::MAX.with_hms_nano(23, 59, 59, 999_999_999).unwrap()
+    );
+}
+
+#[test]
+fn checked_add_duration() {
+    assert_eq!(
+        datetime!("2019-01-01 0:00").checked_add(5.days()),
+        Some(datetime!("2019-01-06 0:00"))
+    );
+    assert_eq!(
+        datetime!("2019-12-31 23:59:59").checked_add(2.seconds()),
+        Some(datetime!("2020-01-01 0:00:01"))
+    );
+    assert_eq!(
+        datetime!("2020-01-01 0:00:01").checked_add((-2).seconds()),
+        Some(datetime!("2019-12-31 23:59:59"))
+    );
+    assert_eq!(PrimitiveDateTime::MAX.checked_add(1.nanoseconds()), None);
+    assert_eq!(PrimitiveDateTime::MIN.checked_add((-1).nanoseconds()), None);
+    assert_eq!(
+        datetime!("2019-01-01 0:00").checked_add(Duration::MAX),
+        None
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").checked_add(Duration::MIN),
+        None
+    );
+}
+
+#[test]
+fn checked_add_std_duration() {
+    assert_eq!(
+        datetime!("2019-12-31 23:59:59").checked_add_std(2.std_seconds()),
+        Some(datetime!("2020-01-01 0:00:01"))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.checked_add_std(1.std_nanoseconds()),
+        None
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").checked_add_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_duration() {
+    assert_eq!(
+        datetime!("2020-01-01 0:00:01").checked_sub(2.seconds()),
+        Some(datetime!("2019-12-31 23:59:59"))
+    );
+    assert_eq!(
+        datetime!("2019-12-31 23:59:59").checked_sub((-2).seconds()),
+        Some(datetime!("2020-01-01 0:00:01"))
+    );
+    assert_eq!(PrimitiveDateTime::MIN.checked_sub(1.nanoseconds()), None);
+    assert_eq!(PrimitiveDateTime::MAX.checked_sub((-1).nanoseconds()), None);
+    assert_eq!(
+        datetime!("2019-01-01 0:00").checked_sub(Duration::MAX),
+        None
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").checked_sub(Duration::MIN),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_std_duration() {
+    assert_eq!(
+        datetime!("2020-01-01 0:00:01").checked_sub_std(2.std_seconds()),
+        Some(datetime!("2019-12-31 23:59:59"))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.checked_sub_std(1.std_nanoseconds()),
+        None
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").checked_sub_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn saturating_add_duration() {
+    assert_eq!(
+        datetime!("2019-01-01 0:00").saturating_add(5.days()),
+        datetime!("2019-01-06 0:00")
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.saturating_add(1.nanoseconds()),
+        PrimitiveDateTime::MAX
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.saturating_add((-1).nanoseconds()),
+        PrimitiveDateTime::MIN
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").saturating_add(Duration::MAX),
+        PrimitiveDateTime::MAX
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").saturating_add(Duration::MIN),
+        PrimitiveDateTime::MIN
+    );
+}
+
+#[test]
+fn saturating_add_std_duration() {
+    assert_eq!(
+        datetime!("2019-01-01 0:00").saturating_add_std(5.std_days()),
+        datetime!("2019-01-06 0:00")
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").saturating_add_std(StdDuration::MAX),
+        PrimitiveDateTime::MAX
+    );
+}
+
+#[test]
+fn saturating_sub_duration() {
+    assert_eq!(
+        datetime!("2019-01-06 0:00").saturating_sub(5.days()),
+        datetime!("2019-01-01 0:00")
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.saturating_sub(1.nanoseconds()),
+        PrimitiveDateTime::MIN
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.saturating_sub((-1).nanoseconds()),
+        PrimitiveDateTime::MAX
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").saturating_sub(Duration::MAX),
+        PrimitiveDateTime::MIN
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").saturating_sub(Duration::MIN),
+        PrimitiveDateTime::MAX
+    );
+}
+
+#[test]
+fn saturating_sub_std_duration() {
+    assert_eq!(
+        datetime!("2019-01-06 0:00").saturating_sub_std(5.std_days()),
+        datetime!("2019-01-01 0:00")
+    );
+    assert_eq!(
+        datetime!("2019-01-01 0:00").saturating_sub_std(StdDuration::MAX),
+        PrimitiveDateTime::MIN
+    );
+}
+
 #[test]
 fn add_duration() {
     assert_eq!(
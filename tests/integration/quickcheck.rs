@@ -1,5 +1,7 @@
 use quickcheck_dep::{quickcheck, Arbitrary, TestResult};
-use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use time::{
+    Date, Duration, Month, OffsetDateTime, Period, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
 
 macro_rules! test_shrink {
     ($type:ty,
@@ -39,6 +41,10 @@ quickcheck! {
         Duration::new(d.whole_seconds(), d.subsec_nanoseconds()) == d
     }
 
+    fn period_roundtrip(p: Period) -> bool {
+        Period::new(p.years(), p.months(), p.days()) == p
+    }
+
     fn time_roundtrip(t: Time) -> bool {
         Time::from_hms_nano(t.hour(), t.minute(), t.second(), t.nanosecond()) == Ok(t)
     }
@@ -86,6 +92,17 @@ quickcheck! {
             _ => w.shrink().next() == Some(w.previous())
         }
     }
+
+    fn month_supports_arbitrary(m: Month) -> bool {
+        (1..=12).contains(&u8::from(m))
+    }
+
+    fn month_can_shrink(m: Month) -> bool {
+        match m {
+            Month::January => m.shrink().next() == None,
+            _ => m.shrink().next() == Some(m.previous())
+        }
+    }
 }
 
 test_shrink!(Date, date_can_shrink_year, year());
@@ -94,6 +111,10 @@ test_shrink!(Date, date_can_shrink_ordinal, ordinal(), min = 1);
 test_shrink!(Duration, duration_can_shrink_seconds, whole_seconds());
 test_shrink!(Duration, duration_can_shrink_ns, subsec_nanoseconds());
 
+test_shrink!(Period, period_can_shrink_years, years());
+test_shrink!(Period, period_can_shrink_months, months());
+test_shrink!(Period, period_can_shrink_days, days());
+
 test_shrink!(Time, time_can_shrink_hour, hour());
 test_shrink!(Time, time_can_shrink_minute, minute());
 test_shrink!(Time, time_can_shrink_second, second());
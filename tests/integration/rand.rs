@@ -1,5 +1,7 @@
 use rand::Rng;
-use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use time::{
+    Date, Duration, Month, OffsetDateTime, Period, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
 
 #[test]
 fn support() {
@@ -12,7 +14,11 @@ fn support() {
     let _ = rng.gen::<PrimitiveDateTime>();
     let _ = rng.gen::<OffsetDateTime>();
     let _ = rng.gen::<Duration>();
+    let _ = rng.gen::<Period>();
     for _ in 0..7 {
         let _ = rng.gen::<Weekday>();
     }
+    for _ in 0..12 {
+        let _ = rng.gen::<Month>();
+    }
 }
@@ -0,0 +1,326 @@
+use time::macros::{date, datetime, offset};
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime};
+
+#[test]
+fn date_range() {
+    let dates: Vec<_> = Date::range(date!("2020-02-27"), date!("2020-03-02")).collect();
+    assert_eq!(
+        dates,
+        [
+            date!("2020-02-27"),
+            date!("2020-02-28"),
+            date!("2020-02-29"),
+            date!("2020-03-01"),
+        ]
+    );
+
+    assert_eq!(
+        Date::range(date!("2020-01-01"), date!("2020-01-01")).next(),
+        None
+    );
+    assert_eq!(
+        Date::range(date!("2020-01-02"), date!("2020-01-01")).next(),
+        None
+    );
+    assert_eq!(
+        Date::range(date!("2020-01-02"), date!("2020-01-01")).len(),
+        0
+    );
+}
+
+#[test]
+fn date_range_bounds() {
+    let mut range = Date::range(Date::MAX.previous_day().unwrap(), Date::MAX);
+    assert_eq!(range.next(), Date::MAX.previous_day());
+    assert_eq!(range.next(), None);
+
+    let mut range = Date::range(Date::MIN, Date::MIN.next_day().unwrap());
+    assert_eq!(range.next_back(), Some(Date::MIN));
+    assert_eq!(range.next_back(), None);
+
+    assert_eq!(
+        Date::range(Date::MIN, Date::MAX).len(),
+        (Date::MAX.to_julian_day() - Date::MIN.to_julian_day()) as usize
+    );
+}
+
+#[test]
+fn date_range_accessors() {
+    let mut range = Date::range(date!("2021-01-01"), date!("2021-02-01"));
+    assert_eq!(range.start(), date!("2021-01-01"));
+    assert_eq!(range.end(), date!("2021-02-01"));
+    assert!(range.contains(date!("2021-01-01")));
+    assert!(!range.contains(date!("2021-02-01")));
+    assert!(!range.contains(date!("2020-12-31")));
+
+    range.next();
+    assert_eq!(range.start(), date!("2021-01-02"));
+    assert!(!range.contains(date!("2021-01-01")));
+}
+
+#[test]
+fn date_range_double_ended() {
+    let mut range = Date::range(date!("2021-01-01"), date!("2021-01-05"));
+    assert_eq!(range.len(), 4);
+    assert_eq!(range.next_back(), Some(date!("2021-01-04")));
+    assert_eq!(range.next(), Some(date!("2021-01-01")));
+    assert_eq!(range.len(), 2);
+    assert_eq!(range.next_back(), Some(date!("2021-01-03")));
+    assert_eq!(range.next_back(), Some(date!("2021-01-02")));
+    assert_eq!(range.next_back(), None);
+    assert_eq!(range.next(), None);
+
+    let reversed: Vec<_> = Date::range(date!("2021-01-01"), date!("2021-01-04"))
+        .rev()
+        .collect();
+    assert_eq!(
+        reversed,
+        [
+            date!("2021-01-03"),
+            date!("2021-01-02"),
+            date!("2021-01-01")
+        ]
+    );
+}
+
+#[test]
+fn date_range_nth() {
+    let mut range = Date::range(date!("2021-01-01"), date!("2021-01-10"));
+    assert_eq!(range.nth(2), Some(date!("2021-01-03")));
+    assert_eq!(range.nth_back(2), Some(date!("2021-01-07")));
+    assert_eq!(range.len(), 3);
+    assert_eq!(range.nth(3), None);
+    assert_eq!(range.len(), 0);
+    assert_eq!(range.next_back(), None);
+
+    let range = Date::range(date!("2021-01-01"), date!("2021-01-10"));
+    assert_eq!(range.clone().last(), Some(date!("2021-01-09")));
+    assert_eq!(range.clone().min(), Some(date!("2021-01-01")));
+    assert_eq!(range.max(), Some(date!("2021-01-09")));
+}
+
+#[test]
+fn step_by_days() {
+    let dates: Vec<_> = Date::range(date!("2021-01-01"), date!("2021-01-10"))
+        .step_by_days(3)
+        .collect();
+    assert_eq!(
+        dates,
+        [
+            date!("2021-01-01"),
+            date!("2021-01-04"),
+            date!("2021-01-07")
+        ]
+    );
+
+    let mut step = Date::range(date!("2021-01-01"), date!("2021-01-11")).step_by_days(3);
+    assert_eq!(step.len(), 4);
+    assert_eq!(step.next_back(), Some(date!("2021-01-10")));
+    assert_eq!(step.nth(1), Some(date!("2021-01-04")));
+    assert_eq!(step.len(), 1);
+    assert_eq!(step.next_back(), Some(date!("2021-01-07")));
+    assert_eq!(step.next(), None);
+
+    assert_eq!(
+        Date::range(date!("2021-01-01"), date!("2021-01-02"))
+            .step_by_days(u32::MAX)
+            .len(),
+        1
+    );
+    assert_eq!(
+        Date::range(date!("2021-01-02"), date!("2021-01-01"))
+            .step_by_days(1)
+            .len(),
+        0
+    );
+}
+
+#[test]
+#[should_panic]
+fn step_by_zero_days() {
+    let _ = Date::range(date!("2021-01-01"), date!("2021-01-02")).step_by_days(0);
+}
+
+#[test]
+fn step_by_weeks() {
+    let dates: Vec<_> = Date::range(date!("2021-01-01"), date!("2021-01-16"))
+        .step_by_weeks(1)
+        .rev()
+        .collect();
+    assert_eq!(
+        dates,
+        [
+            date!("2021-01-15"),
+            date!("2021-01-08"),
+            date!("2021-01-01")
+        ]
+    );
+    assert_eq!(
+        Date::range(date!("2021-01-01"), date!("2021-01-15"))
+            .step_by_weeks(2)
+            .len(),
+        1
+    );
+}
+
+#[test]
+#[should_panic]
+fn step_by_weeks_overflow() {
+    let _ = Date::range(date!("2021-01-01"), date!("2021-01-02")).step_by_weeks(u32::MAX);
+}
+
+#[test]
+fn step_by_months() {
+    let dates: Vec<_> = Date::range(date!("2020-01-31"), date!("2020-05-31"))
+        .step_by_months(1)
+        .collect();
+    assert_eq!(
+        dates,
+        [
+            date!("2020-01-31"),
+            date!("2020-02-29"),
+            date!("2020-03-31"),
+            date!("2020-04-30"),
+        ]
+    );
+
+    let mut step = Date::range(date!("2020-01-31"), date!("2021-01-01")).step_by_months(3);
+    assert_eq!(step.len(), 4);
+    assert_eq!(step.next_back(), Some(date!("2020-10-31")));
+    assert_eq!(step.next_back(), Some(date!("2020-07-31")));
+    assert_eq!(step.next(), Some(date!("2020-01-31")));
+    assert_eq!(step.next(), Some(date!("2020-04-30")));
+    assert_eq!(step.next(), None);
+
+    // The end is in the same month as the last step, but before it.
+    assert_eq!(
+        Date::range(date!("2020-01-15"), date!("2020-03-10"))
+            .step_by_months(1)
+            .len(),
+        2
+    );
+    assert_eq!(
+        Date::range(date!("2020-01-15"), date!("2020-03-16"))
+            .step_by_months(1)
+            .len(),
+        3
+    );
+    assert_eq!(
+        Date::range(date!("2020-01-15"), date!("2020-01-15"))
+            .step_by_months(1)
+            .len(),
+        0
+    );
+    assert_eq!(
+        Date::range(Date::MIN, Date::MAX).step_by_months(12).last(),
+        Some(Date::MAX.replace_ordinal(1).unwrap())
+    );
+}
+
+#[test]
+#[should_panic]
+fn step_by_zero_months() {
+    let _ = Date::range(date!("2021-01-01"), date!("2021-01-02")).step_by_months(0);
+}
+
+#[test]
+fn primitive_date_time_range_step_by() {
+    let datetimes: Vec<_> = PrimitiveDateTime::range_step_by(
+        datetime!("2021-01-01 0:00"),
+        datetime!("2021-01-01 1:00"),
+        Duration::minutes(20),
+    )
+    .collect();
+    assert_eq!(
+        datetimes,
+        [
+            datetime!("2021-01-01 0:00"),
+            datetime!("2021-01-01 0:20"),
+            datetime!("2021-01-01 0:40"),
+        ]
+    );
+
+    let mut step = PrimitiveDateTime::range_step_by(
+        datetime!("2021-01-01 0:00"),
+        datetime!("2021-01-01 1:00:01"),
+        Duration::minutes(20),
+    );
+    assert_eq!(step.size_hint(), (4, Some(4)));
+    assert_eq!(step.next_back(), Some(datetime!("2021-01-01 1:00")));
+    assert_eq!(step.next(), Some(datetime!("2021-01-01 0:00")));
+    assert_eq!(step.next_back(), Some(datetime!("2021-01-01 0:40")));
+    assert_eq!(step.next(), Some(datetime!("2021-01-01 0:20")));
+    assert_eq!(step.next(), None);
+    assert_eq!(step.next_back(), None);
+
+    assert_eq!(
+        PrimitiveDateTime::range_step_by(
+            datetime!("2021-01-01 1:00"),
+            datetime!("2021-01-01 0:00"),
+            Duration::minutes(20),
+        )
+        .next(),
+        None
+    );
+    assert_eq!(
+        PrimitiveDateTime::range_step_by(
+            PrimitiveDateTime::MIN,
+            PrimitiveDateTime::MAX,
+            Duration::days(1),
+        )
+        .last(),
+        Some(Date::MAX.midnight())
+    );
+}
+
+#[test]
+#[should_panic]
+fn primitive_date_time_range_step_by_zero() {
+    let _ = PrimitiveDateTime::range_step_by(
+        datetime!("2021-01-01 0:00"),
+        datetime!("2021-01-01 1:00"),
+        Duration::ZERO,
+    );
+}
+
+#[test]
+fn offset_date_time_range_step_by() {
+    let datetimes: Vec<_> = OffsetDateTime::range_step_by(
+        datetime!("2021-01-01 0:00 +1"),
+        datetime!("2021-01-01 0:00 UTC"),
+        Duration::minutes(30),
+    )
+    .collect();
+    assert_eq!(
+        datetimes,
+        [
+            datetime!("2021-01-01 0:00 +1"),
+            datetime!("2021-01-01 0:30 +1")
+        ]
+    );
+    assert!(datetimes
+        .iter()
+        .all(|datetime| datetime.offset() == offset!("+1")));
+
+    let mut step = OffsetDateTime::range_step_by(
+        datetime!("2021-01-01 0:00 UTC"),
+        datetime!("2021-01-01 1:00 UTC"),
+        Duration::minutes(25),
+    );
+    assert_eq!(step.next_back(), Some(datetime!("2021-01-01 0:50 UTC")));
+    assert_eq!(step.next_back(), Some(datetime!("2021-01-01 0:25 UTC")));
+    assert_eq!(step.next_back(), Some(datetime!("2021-01-01 0:00 UTC")));
+    assert_eq!(step.next_back(), None);
+}
+
+#[test]
+fn size_hint_overflow() {
+    let step = PrimitiveDateTime::range_step_by(
+        PrimitiveDateTime::MIN,
+        PrimitiveDateTime::MAX,
+        Duration::nanoseconds(1),
+    );
+    if (usize::MAX as u128) < 1_000_000_000 * 86_400 * 365 * 20_000 {
+        assert_eq!(step.size_hint(), (usize::MAX, None));
+    }
+}
@@ -0,0 +1,1070 @@
+use time::error::Recurrence;
+use time::macros::{datetime, offset};
+use time::rrule::{Frequency, RecurrenceSet};
+use time::{Date, PrimitiveDateTime, RRule, Weekday};
+
+/// Get the first `n` occurrences of the rule.
+fn take(rule: &str, start: PrimitiveDateTime, n: usize) -> Vec<PrimitiveDateTime> {
+    RRule::parse(rule)
+        .expect("rule is valid")
+        .occurrences(start)
+        .take(n)
+        .collect()
+}
+
+/// Get every occurrence of the rule.
+fn all(rule: &str, start: PrimitiveDateTime) -> Vec<PrimitiveDateTime> {
+    take(rule, start, usize::MAX)
+}
+
+#[test]
+fn parse() {
+    let rule =
+        RRule::parse("FREQ=WEEKLY;INTERVAL=2;COUNT=10;WKST=SU;BYDAY=TU,TH").expect("rule is valid");
+    assert_eq!(rule.frequency(), Frequency::Weekly);
+    assert_eq!(rule.interval(), 2);
+    assert_eq!(rule.count(), Some(10));
+    assert_eq!(rule.week_start(), Weekday::Sunday);
+
+    let rule = RRule::parse("rrule:freq=daily").expect("rule is valid");
+    assert_eq!(rule.frequency(), Frequency::Daily);
+    assert_eq!(rule.interval(), 1);
+    assert_eq!(rule.count(), None);
+    assert_eq!(rule.week_start(), Weekday::Monday);
+}
+
+#[test]
+fn parse_err() {
+    let err = |s: &str| RRule::parse(s).expect_err("rule is invalid");
+    assert_eq!(err(""), Recurrence::InvalidRRule("missing value"));
+    assert_eq!(err("FREQ"), Recurrence::InvalidRRule("missing value"));
+    assert_eq!(err("COUNT=1"), Recurrence::InvalidRRule("missing FREQ"));
+    assert_eq!(
+        err("FREQ=FORTNIGHTLY"),
+        Recurrence::InvalidRRule("invalid FREQ")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;FREQ=DAILY"),
+        Recurrence::InvalidRRule("duplicate part")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;FOO=1"),
+        Recurrence::InvalidRRule("unknown part")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;INTERVAL=0"),
+        Recurrence::InvalidRRule("invalid INTERVAL")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;COUNT=-1"),
+        Recurrence::InvalidRRule("invalid COUNT")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;UNTIL=19970230"),
+        Recurrence::InvalidRRule("invalid UNTIL")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;UNTIL=19970902T0900"),
+        Recurrence::InvalidRRule("invalid UNTIL")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;BYSECOND=60"),
+        Recurrence::InvalidRRule("invalid BYSECOND")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;BYMINUTE=60"),
+        Recurrence::InvalidRRule("invalid BYMINUTE")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;BYHOUR=24"),
+        Recurrence::InvalidRRule("invalid BYHOUR")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;BYDAY=XX"),
+        Recurrence::InvalidRRule("invalid BYDAY")
+    );
+    assert_eq!(
+        err("FREQ=MONTHLY;BYDAY=0MO"),
+        Recurrence::InvalidRRule("invalid BYDAY")
+    );
+    assert_eq!(
+        err("FREQ=MONTHLY;BYDAY=54MO"),
+        Recurrence::InvalidRRule("invalid BYDAY")
+    );
+    assert_eq!(
+        err("FREQ=MONTHLY;BYDAY=é"),
+        Recurrence::InvalidRRule("invalid BYDAY")
+    );
+    assert_eq!(
+        err("FREQ=MONTHLY;BYMONTHDAY=32"),
+        Recurrence::InvalidRRule("invalid BYMONTHDAY")
+    );
+    assert_eq!(
+        err("FREQ=YEARLY;BYYEARDAY=0"),
+        Recurrence::InvalidRRule("invalid BYYEARDAY")
+    );
+    assert_eq!(
+        err("FREQ=YEARLY;BYWEEKNO=-54"),
+        Recurrence::InvalidRRule("invalid BYWEEKNO")
+    );
+    assert_eq!(
+        err("FREQ=YEARLY;BYMONTH=13"),
+        Recurrence::InvalidRRule("invalid BYMONTH")
+    );
+    assert_eq!(
+        err("FREQ=YEARLY;BYSETPOS=+367"),
+        Recurrence::InvalidRRule("invalid BYSETPOS")
+    );
+    assert_eq!(
+        err("FREQ=YEARLY;WKST=MONDAY"),
+        Recurrence::InvalidRRule("invalid WKST")
+    );
+    assert_eq!(
+        err("FREQ=DAILY;COUNT=1;UNTIL=19970902"),
+        Recurrence::InvalidRRule("COUNT and UNTIL are mutually exclusive")
+    );
+}
+
+#[test]
+fn display() {
+    for rule in [
+        "FREQ=DAILY",
+        "FREQ=WEEKLY;COUNT=10;INTERVAL=2;BYDAY=TU,TH;WKST=SU",
+        "FREQ=MONTHLY;UNTIL=19971224T000000Z;BYDAY=1SU,-1SU",
+        "FREQ=YEARLY;UNTIL=20000131;BYMONTH=1",
+        "FREQ=YEARLY;UNTIL=20000131T090000;BYYEARDAY=1,-366;BYWEEKNO=20,-1",
+        "FREQ=MINUTELY;BYSECOND=0,30;BYMINUTE=0;BYHOUR=9,17;BYMONTHDAY=-3;BYSETPOS=1,-1",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            RRule::parse(rule).expect("rule is valid").to_string(),
+            *rule
+        );
+    }
+    assert_eq!(
+        RRule::parse("BYDAY=+1mo;freq=monthly;wkst=MO")
+            .expect("rule is valid")
+            .to_string(),
+        "FREQ=MONTHLY;BYDAY=1MO"
+    );
+}
+
+// The following tests are the examples given in RFC 5545, section 3.8.5.3. Unlike the RFC, the
+// start of the recurrence is not included unless it matches the rule.
+
+#[test]
+fn daily() {
+    let start = datetime!("1997-09-02 9:00");
+    assert_eq!(
+        all("FREQ=DAILY;COUNT=10", start),
+        (2..=11)
+            .map(
+                |day| Date::from_calendar_date(1997, time::Month::September, day)
+                    .expect("date is valid")
+                    .with_hms(9, 0, 0)
+                    .expect("time is valid")
+            )
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(all("FREQ=DAILY;UNTIL=19971224T000000Z", start).len(), 113);
+    assert_eq!(
+        take("FREQ=DAILY;INTERVAL=2", start, 3),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-04 9:00"),
+            datetime!("1997-09-06 9:00"),
+        ]
+    );
+    assert_eq!(
+        all("FREQ=DAILY;INTERVAL=10;COUNT=5", start),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-12 9:00"),
+            datetime!("1997-09-22 9:00"),
+            datetime!("1997-10-02 9:00"),
+            datetime!("1997-10-12 9:00"),
+        ]
+    );
+
+    let january = all(
+        "FREQ=YEARLY;UNTIL=20000131T140000Z;BYMONTH=1;BYDAY=SU,MO,TU,WE,TH,FR,SA",
+        datetime!("1998-01-01 9:00"),
+    );
+    assert_eq!(january.len(), 93);
+    assert_eq!(january[0], datetime!("1998-01-01 9:00"));
+    assert_eq!(january[31], datetime!("1999-01-01 9:00"));
+    assert_eq!(january[92], datetime!("2000-01-31 9:00"));
+    assert_eq!(
+        january,
+        all(
+            "FREQ=DAILY;UNTIL=20000131T140000Z;BYMONTH=1",
+            datetime!("1998-01-01 9:00")
+        )
+    );
+}
+
+#[test]
+fn weekly() {
+    let start = datetime!("1997-09-02 9:00");
+    assert_eq!(
+        all("FREQ=WEEKLY;COUNT=10", start),
+        take("FREQ=DAILY;INTERVAL=7", start, 10)
+    );
+    assert_eq!(all("FREQ=WEEKLY;UNTIL=19971224T000000Z", start).len(), 17);
+    assert_eq!(
+        all("FREQ=WEEKLY;COUNT=10;WKST=SU;BYDAY=TU,TH", start),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-04 9:00"),
+            datetime!("1997-09-09 9:00"),
+            datetime!("1997-09-11 9:00"),
+            datetime!("1997-09-16 9:00"),
+            datetime!("1997-09-18 9:00"),
+            datetime!("1997-09-23 9:00"),
+            datetime!("1997-09-25 9:00"),
+            datetime!("1997-09-30 9:00"),
+            datetime!("1997-10-02 9:00"),
+        ]
+    );
+
+    let every_other = all(
+        "FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000Z;WKST=SU;BYDAY=MO,WE,FR",
+        start,
+    );
+    assert_eq!(every_other.len(), 24);
+    assert_eq!(
+        every_other[..6],
+        [
+            datetime!("1997-09-03 9:00"),
+            datetime!("1997-09-05 9:00"),
+            datetime!("1997-09-15 9:00"),
+            datetime!("1997-09-17 9:00"),
+            datetime!("1997-09-19 9:00"),
+            datetime!("1997-09-29 9:00"),
+        ]
+    );
+    assert_eq!(every_other[23], datetime!("1997-12-22 9:00"));
+
+    assert_eq!(
+        all("FREQ=WEEKLY;INTERVAL=2;COUNT=8;WKST=SU;BYDAY=TU,TH", start),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-04 9:00"),
+            datetime!("1997-09-16 9:00"),
+            datetime!("1997-09-18 9:00"),
+            datetime!("1997-09-30 9:00"),
+            datetime!("1997-10-02 9:00"),
+            datetime!("1997-10-14 9:00"),
+            datetime!("1997-10-16 9:00"),
+        ]
+    );
+}
+
+#[test]
+fn week_start() {
+    let start = datetime!("1997-08-05 9:00");
+    assert_eq!(
+        all("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO", start),
+        [
+            datetime!("1997-08-05 9:00"),
+            datetime!("1997-08-10 9:00"),
+            datetime!("1997-08-19 9:00"),
+            datetime!("1997-08-24 9:00"),
+        ]
+    );
+    assert_eq!(
+        all("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU", start),
+        [
+            datetime!("1997-08-05 9:00"),
+            datetime!("1997-08-17 9:00"),
+            datetime!("1997-08-19 9:00"),
+            datetime!("1997-08-31 9:00"),
+        ]
+    );
+}
+
+#[test]
+fn monthly() {
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;COUNT=10;BYDAY=1FR",
+            datetime!("1997-09-05 9:00")
+        ),
+        [
+            datetime!("1997-09-05 9:00"),
+            datetime!("1997-10-03 9:00"),
+            datetime!("1997-11-07 9:00"),
+            datetime!("1997-12-05 9:00"),
+            datetime!("1998-01-02 9:00"),
+            datetime!("1998-02-06 9:00"),
+            datetime!("1998-03-06 9:00"),
+            datetime!("1998-04-03 9:00"),
+            datetime!("1998-05-01 9:00"),
+            datetime!("1998-06-05 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;UNTIL=19971224T000000Z;BYDAY=1FR",
+            datetime!("1997-09-05 9:00")
+        ),
+        [
+            datetime!("1997-09-05 9:00"),
+            datetime!("1997-10-03 9:00"),
+            datetime!("1997-11-07 9:00"),
+            datetime!("1997-12-05 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU",
+            datetime!("1997-09-07 9:00")
+        ),
+        [
+            datetime!("1997-09-07 9:00"),
+            datetime!("1997-09-28 9:00"),
+            datetime!("1997-11-02 9:00"),
+            datetime!("1997-11-30 9:00"),
+            datetime!("1998-01-04 9:00"),
+            datetime!("1998-01-25 9:00"),
+            datetime!("1998-03-01 9:00"),
+            datetime!("1998-03-29 9:00"),
+            datetime!("1998-05-03 9:00"),
+            datetime!("1998-05-31 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;COUNT=6;BYDAY=-2MO",
+            datetime!("1997-09-22 9:00")
+        ),
+        [
+            datetime!("1997-09-22 9:00"),
+            datetime!("1997-10-20 9:00"),
+            datetime!("1997-11-17 9:00"),
+            datetime!("1997-12-22 9:00"),
+            datetime!("1998-01-19 9:00"),
+            datetime!("1998-02-16 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=MONTHLY;BYMONTHDAY=-3",
+            datetime!("1997-09-28 9:00"),
+            6
+        ),
+        [
+            datetime!("1997-09-28 9:00"),
+            datetime!("1997-10-29 9:00"),
+            datetime!("1997-11-28 9:00"),
+            datetime!("1997-12-29 9:00"),
+            datetime!("1998-01-29 9:00"),
+            datetime!("1998-02-26 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;COUNT=10;BYMONTHDAY=2,15",
+            datetime!("1997-09-02 9:00")
+        ),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-15 9:00"),
+            datetime!("1997-10-02 9:00"),
+            datetime!("1997-10-15 9:00"),
+            datetime!("1997-11-02 9:00"),
+            datetime!("1997-11-15 9:00"),
+            datetime!("1997-12-02 9:00"),
+            datetime!("1997-12-15 9:00"),
+            datetime!("1998-01-02 9:00"),
+            datetime!("1998-01-15 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;COUNT=10;BYMONTHDAY=1,-1",
+            datetime!("1997-09-30 9:00")
+        )[..4],
+        [
+            datetime!("1997-09-30 9:00"),
+            datetime!("1997-10-01 9:00"),
+            datetime!("1997-10-31 9:00"),
+            datetime!("1997-11-01 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;INTERVAL=18;COUNT=10;BYMONTHDAY=10,11,12,13,14,15",
+            datetime!("1997-09-10 9:00")
+        )[4..8],
+        [
+            datetime!("1997-09-14 9:00"),
+            datetime!("1997-09-15 9:00"),
+            datetime!("1999-03-10 9:00"),
+            datetime!("1999-03-11 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=MONTHLY;INTERVAL=2;BYDAY=TU",
+            datetime!("1997-09-02 9:00"),
+            6
+        ),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-09 9:00"),
+            datetime!("1997-09-16 9:00"),
+            datetime!("1997-09-23 9:00"),
+            datetime!("1997-09-30 9:00"),
+            datetime!("1997-11-04 9:00"),
+        ]
+    );
+}
+
+#[test]
+fn monthly_invalid_dates() {
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;BYMONTHDAY=15,30;COUNT=5",
+            datetime!("2007-01-15 9:00")
+        ),
+        [
+            datetime!("2007-01-15 9:00"),
+            datetime!("2007-01-30 9:00"),
+            datetime!("2007-02-15 9:00"),
+            datetime!("2007-03-15 9:00"),
+            datetime!("2007-03-30 9:00"),
+        ]
+    );
+}
+
+#[test]
+fn yearly() {
+    assert_eq!(
+        all(
+            "FREQ=YEARLY;COUNT=10;BYMONTH=6,7",
+            datetime!("1997-06-10 9:00")
+        ),
+        [
+            datetime!("1997-06-10 9:00"),
+            datetime!("1997-07-10 9:00"),
+            datetime!("1998-06-10 9:00"),
+            datetime!("1998-07-10 9:00"),
+            datetime!("1999-06-10 9:00"),
+            datetime!("1999-07-10 9:00"),
+            datetime!("2000-06-10 9:00"),
+            datetime!("2000-07-10 9:00"),
+            datetime!("2001-06-10 9:00"),
+            datetime!("2001-07-10 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=YEARLY;INTERVAL=2;COUNT=10;BYMONTH=1,2,3",
+            datetime!("1997-03-10 9:00")
+        ),
+        [
+            datetime!("1997-03-10 9:00"),
+            datetime!("1999-01-10 9:00"),
+            datetime!("1999-02-10 9:00"),
+            datetime!("1999-03-10 9:00"),
+            datetime!("2001-01-10 9:00"),
+            datetime!("2001-02-10 9:00"),
+            datetime!("2001-03-10 9:00"),
+            datetime!("2003-01-10 9:00"),
+            datetime!("2003-02-10 9:00"),
+            datetime!("2003-03-10 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200",
+            datetime!("1997-01-01 9:00")
+        ),
+        [
+            datetime!("1997-01-01 9:00"),
+            datetime!("1997-04-10 9:00"),
+            datetime!("1997-07-19 9:00"),
+            datetime!("2000-01-01 9:00"),
+            datetime!("2000-04-09 9:00"),
+            datetime!("2000-07-18 9:00"),
+            datetime!("2003-01-01 9:00"),
+            datetime!("2003-04-10 9:00"),
+            datetime!("2003-07-19 9:00"),
+            datetime!("2006-01-01 9:00"),
+        ]
+    );
+    assert_eq!(
+        take("FREQ=YEARLY;BYDAY=20MO", datetime!("1997-05-19 9:00"), 3),
+        [
+            datetime!("1997-05-19 9:00"),
+            datetime!("1998-05-18 9:00"),
+            datetime!("1999-05-17 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO",
+            datetime!("1997-05-12 9:00"),
+            3
+        ),
+        [
+            datetime!("1997-05-12 9:00"),
+            datetime!("1998-05-11 9:00"),
+            datetime!("1999-05-17 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;BYMONTH=3;BYDAY=TH",
+            datetime!("1997-03-13 9:00"),
+            11
+        ),
+        [
+            datetime!("1997-03-13 9:00"),
+            datetime!("1997-03-20 9:00"),
+            datetime!("1997-03-27 9:00"),
+            datetime!("1998-03-05 9:00"),
+            datetime!("1998-03-12 9:00"),
+            datetime!("1998-03-19 9:00"),
+            datetime!("1998-03-26 9:00"),
+            datetime!("1999-03-04 9:00"),
+            datetime!("1999-03-11 9:00"),
+            datetime!("1999-03-18 9:00"),
+            datetime!("1999-03-25 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;BYDAY=TH;BYMONTH=6,7,8",
+            datetime!("1997-06-05 9:00"),
+            14
+        )[12..],
+        [datetime!("1997-08-28 9:00"), datetime!("1998-06-04 9:00")]
+    );
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8",
+            datetime!("1996-11-05 9:00"),
+            3
+        ),
+        [
+            datetime!("1996-11-05 9:00"),
+            datetime!("2000-11-07 9:00"),
+            datetime!("2004-11-02 9:00"),
+        ]
+    );
+}
+
+#[test]
+fn week_numbers() {
+    // Week one of 1998 begins on 1997-12-29, and 1998 has 53 weeks.
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;BYWEEKNO=1;BYDAY=MO",
+            datetime!("1997-01-01 9:00"),
+            3
+        ),
+        [
+            datetime!("1997-12-29 9:00"),
+            datetime!("1999-01-04 9:00"),
+            datetime!("2000-01-03 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;BYWEEKNO=53;BYDAY=MO",
+            datetime!("1997-01-01 9:00"),
+            3
+        ),
+        [
+            datetime!("1998-12-28 9:00"),
+            datetime!("2004-12-27 9:00"),
+            datetime!("2009-12-28 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;BYWEEKNO=-1;BYDAY=MO",
+            datetime!("1997-01-01 9:00"),
+            3
+        ),
+        [
+            datetime!("1997-12-22 9:00"),
+            datetime!("1998-12-28 9:00"),
+            datetime!("1999-12-27 9:00"),
+        ]
+    );
+    // With the week starting on Sunday, week one of 1998 begins on 1998-01-04.
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;BYWEEKNO=1;BYDAY=SU;WKST=SU",
+            datetime!("1997-01-01 9:00"),
+            2
+        ),
+        [datetime!("1998-01-04 9:00"), datetime!("1999-01-03 9:00")]
+    );
+}
+
+#[test]
+fn by_set_pos() {
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3",
+            datetime!("1997-09-04 9:00")
+        ),
+        [
+            datetime!("1997-09-04 9:00"),
+            datetime!("1997-10-07 9:00"),
+            datetime!("1997-11-06 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2",
+            datetime!("1997-09-29 9:00"),
+            7
+        ),
+        [
+            datetime!("1997-09-29 9:00"),
+            datetime!("1997-10-30 9:00"),
+            datetime!("1997-11-27 9:00"),
+            datetime!("1997-12-30 9:00"),
+            datetime!("1998-01-29 9:00"),
+            datetime!("1998-02-26 9:00"),
+            datetime!("1998-03-30 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=DAILY;BYHOUR=9,12,17;BYSETPOS=1,-1,5",
+            datetime!("1997-09-02 9:00"),
+            4
+        ),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-02 17:00"),
+            datetime!("1997-09-03 9:00"),
+            datetime!("1997-09-03 17:00"),
+        ]
+    );
+}
+
+#[test]
+fn friday_the_thirteenth() {
+    assert_eq!(
+        take(
+            "FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13",
+            datetime!("1997-09-02 9:00"),
+            5
+        ),
+        [
+            datetime!("1998-02-13 9:00"),
+            datetime!("1998-03-13 9:00"),
+            datetime!("1998-11-13 9:00"),
+            datetime!("1999-08-13 9:00"),
+            datetime!("2000-10-13 9:00"),
+        ]
+    );
+    assert_eq!(
+        take(
+            "FREQ=MONTHLY;BYDAY=SA;BYMONTHDAY=7,8,9,10,11,12,13",
+            datetime!("1997-09-13 9:00"),
+            4
+        ),
+        [
+            datetime!("1997-09-13 9:00"),
+            datetime!("1997-10-11 9:00"),
+            datetime!("1997-11-08 9:00"),
+            datetime!("1997-12-13 9:00"),
+        ]
+    );
+}
+
+#[test]
+fn sub_daily() {
+    let start = datetime!("1997-09-02 9:00");
+    assert_eq!(
+        all("FREQ=HOURLY;INTERVAL=3;UNTIL=19970902T170000Z", start),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-02 12:00"),
+            datetime!("1997-09-02 15:00"),
+        ]
+    );
+    assert_eq!(
+        all("FREQ=MINUTELY;INTERVAL=15;COUNT=6", start),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-02 9:15"),
+            datetime!("1997-09-02 9:30"),
+            datetime!("1997-09-02 9:45"),
+            datetime!("1997-09-02 10:00"),
+            datetime!("1997-09-02 10:15"),
+        ]
+    );
+    assert_eq!(
+        all("FREQ=MINUTELY;INTERVAL=90;COUNT=4", start),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-02 10:30"),
+            datetime!("1997-09-02 12:00"),
+            datetime!("1997-09-02 13:30"),
+        ]
+    );
+
+    let daily = take(
+        "FREQ=DAILY;BYHOUR=9,10,11,12,13,14,15,16;BYMINUTE=0,20,40",
+        start,
+        30,
+    );
+    assert_eq!(daily[23], datetime!("1997-09-02 16:40"));
+    assert_eq!(daily[24], datetime!("1997-09-03 9:00"));
+    assert_eq!(
+        daily,
+        take(
+            "FREQ=MINUTELY;INTERVAL=20;BYHOUR=9,10,11,12,13,14,15,16",
+            start,
+            30
+        )
+    );
+
+    assert_eq!(
+        take(
+            "FREQ=SECONDLY;BYHOUR=9;BYMINUTE=0;BYSECOND=0;BYDAY=MO",
+            start,
+            2
+        ),
+        [datetime!("1997-09-08 9:00"), datetime!("1997-09-15 9:00")]
+    );
+    assert_eq!(
+        take("FREQ=SECONDLY;INTERVAL=7;BYHOUR=10", start, 2),
+        [
+            datetime!("1997-09-02 10:00:05"),
+            datetime!("1997-09-02 10:00:12")
+        ]
+    );
+}
+
+#[test]
+fn nanoseconds() {
+    assert_eq!(
+        take(
+            "FREQ=HOURLY;BYMINUTE=0,30",
+            datetime!("2021-01-01 0:10:01.5"),
+            2
+        ),
+        [
+            datetime!("2021-01-01 0:30:01.5"),
+            datetime!("2021-01-01 1:00:01.5"),
+        ]
+    );
+}
+
+#[test]
+fn until() {
+    assert_eq!(
+        all("FREQ=DAILY;UNTIL=19970904", datetime!("1997-09-02 9:00")),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-03 9:00"),
+            datetime!("1997-09-04 9:00"),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=DAILY;UNTIL=19970904T090000",
+            datetime!("1997-09-02 9:00")
+        )
+        .len(),
+        3
+    );
+    assert_eq!(
+        all(
+            "FREQ=DAILY;UNTIL=19970904T085959",
+            datetime!("1997-09-02 9:00")
+        )
+        .len(),
+        2
+    );
+    assert!(all("FREQ=YEARLY;UNTIL=19970101", datetime!("1997-09-02 9:00")).is_empty());
+    // Rules that never match end at the until.
+    assert!(all(
+        "FREQ=SECONDLY;BYMONTH=2;BYMONTHDAY=30;UNTIL=20210101",
+        datetime!("1997-09-02 9:00")
+    )
+    .is_empty());
+}
+
+#[test]
+fn bounds() {
+    assert_eq!(
+        all("FREQ=YEARLY", PrimitiveDateTime::MAX),
+        [PrimitiveDateTime::MAX]
+    );
+    assert_eq!(
+        all(
+            "FREQ=WEEKLY;BYDAY=MO,SU;WKST=SU",
+            PrimitiveDateTime::MAX.replace_date(Date::MAX.previous_day().expect("date is valid"))
+        )
+        .len(),
+        0
+    );
+    assert_eq!(
+        take("FREQ=WEEKLY;WKST=SU", PrimitiveDateTime::MIN, 2),
+        [
+            PrimitiveDateTime::MIN,
+            PrimitiveDateTime::MIN.replace_date(
+                Date::MIN
+                    .next_occurrence(Weekday::Monday)
+                    .expect("date is valid")
+            ),
+        ]
+    );
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;BYMONTHDAY=31",
+            datetime!("2021-12-31 0:00").replace_date(Date::MAX)
+        ),
+        [datetime!("2021-12-31 0:00").replace_date(Date::MAX)]
+    );
+    assert_eq!(
+        take("FREQ=DAILY;INTERVAL=4294967295", PrimitiveDateTime::MIN, 2),
+        [PrimitiveDateTime::MIN]
+    );
+}
+
+#[test]
+fn never_matches() {
+    // No period begins at an allowed time of day.
+    assert_eq!(
+        all(
+            "FREQ=MINUTELY;INTERVAL=2;BYMINUTE=1",
+            datetime!("2021-01-01 0:00")
+        ),
+        []
+    );
+    assert_eq!(
+        all(
+            "FREQ=HOURLY;INTERVAL=2;BYHOUR=1",
+            datetime!("2021-01-01 0:00")
+        ),
+        []
+    );
+    assert_eq!(
+        all(
+            "FREQ=SECONDLY;INTERVAL=7200;BYMINUTE=30",
+            datetime!("2021-01-01 0:00")
+        ),
+        []
+    );
+    // No period has an occurrence in an entire 400-year cycle.
+    assert_eq!(
+        all(
+            "FREQ=YEARLY;INTERVAL=4;BYMONTH=2;BYMONTHDAY=29",
+            datetime!("2021-01-01 0:00")
+        ),
+        []
+    );
+    assert_eq!(
+        all(
+            "FREQ=MONTHLY;BYMONTHDAY=31;BYMONTH=4",
+            datetime!("2021-01-01 0:00")
+        ),
+        []
+    );
+    assert_eq!(
+        all(
+            "FREQ=WEEKLY;INTERVAL=2;BYSETPOS=2",
+            datetime!("2021-01-01 0:00")
+        ),
+        []
+    );
+    assert_eq!(
+        all("FREQ=DAILY;BYSETPOS=-2", datetime!("2021-01-01 0:00")),
+        []
+    );
+    assert_eq!(
+        all(
+            "FREQ=HOURLY;INTERVAL=168;BYDAY=MO",
+            datetime!("2021-01-01 0:00")
+        ),
+        []
+    );
+
+    // Occurrences that are rare, but possible, are still found.
+    assert_eq!(
+        take(
+            "FREQ=HOURLY;INTERVAL=2;BYHOUR=1",
+            datetime!("2021-01-01 1:00"),
+            2
+        ),
+        [datetime!("2021-01-01 1:00"), datetime!("2021-01-02 1:00")]
+    );
+    assert_eq!(
+        take(
+            "FREQ=HOURLY;INTERVAL=25;BYHOUR=0",
+            datetime!("2021-01-01 1:00"),
+            2
+        ),
+        [datetime!("2021-01-25 0:00"), datetime!("2021-02-19 0:00")]
+    );
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29",
+            datetime!("2097-01-01 0:00"),
+            1
+        ),
+        [datetime!("2104-02-29 0:00")]
+    );
+    assert_eq!(
+        take(
+            "FREQ=YEARLY;INTERVAL=100;BYMONTH=2;BYMONTHDAY=29",
+            datetime!("2100-01-01 0:00"),
+            1
+        ),
+        [datetime!("2400-02-29 0:00")]
+    );
+}
+
+#[test]
+fn offset_occurrences() {
+    let rule = RRule::parse("FREQ=DAILY;UNTIL=19970904T130000Z").expect("rule is valid");
+    assert_eq!(
+        rule.offset_occurrences(datetime!("1997-09-02 9:00 -4"))
+            .collect::<Vec<_>>(),
+        [
+            datetime!("1997-09-02 9:00 -4"),
+            datetime!("1997-09-03 9:00 -4"),
+            datetime!("1997-09-04 9:00 -4"),
+        ]
+    );
+    assert_eq!(
+        rule.offset_occurrences(datetime!("1997-09-02 9:00 -5"))
+            .count(),
+        2
+    );
+    assert!(rule
+        .offset_occurrences(datetime!("1997-09-02 9:00 +2"))
+        .all(|datetime| datetime.offset() == offset!("+2")));
+
+    let rule = RRule::parse("FREQ=DAILY;UNTIL=19970904").expect("rule is valid");
+    assert_eq!(
+        rule.offset_occurrences(datetime!("1997-09-02 23:00 -4"))
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn recurrence_set() {
+    let set = RecurrenceSet::new(datetime!("1997-09-02 9:00"))
+        .with_rrule(RRule::parse("FREQ=WEEKLY;COUNT=3;BYDAY=WE").expect("rule is valid"))
+        .with_rrule(RRule::parse("FREQ=WEEKLY;COUNT=3;BYDAY=TH,WE").expect("rule is valid"))
+        .with_rdate(datetime!("1997-09-03 9:00"))
+        .with_rdate(datetime!("1997-08-01 9:00"))
+        .with_rdate(datetime!("1997-10-01 9:00"))
+        .with_exdate(datetime!("1997-09-04 9:00"));
+    assert_eq!(set.start(), datetime!("1997-09-02 9:00"));
+    assert_eq!(set.rrules().len(), 2);
+    assert_eq!(
+        set.occurrences().collect::<Vec<_>>(),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-09-03 9:00"),
+            datetime!("1997-09-10 9:00"),
+            datetime!("1997-09-17 9:00"),
+            datetime!("1997-10-01 9:00"),
+        ]
+    );
+    assert_eq!(
+        RecurrenceSet::new(datetime!("1997-09-02 9:00"))
+            .occurrences()
+            .collect::<Vec<_>>(),
+        [datetime!("1997-09-02 9:00")]
+    );
+    assert_eq!(
+        RecurrenceSet::new(datetime!("1997-09-02 9:00"))
+            .with_exdate(datetime!("1997-09-02 9:00"))
+            .occurrences()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn recurrence_set_parse() {
+    let set = RecurrenceSet::parse(
+        "DTSTART;TZID=America/New_York:19970902T090000\r\n\
+         RRULE:FREQ=YEARLY;COUNT=2;BYMONTH=9\r\n\
+         RRULE:FREQ=MONTHLY;COUNT=2\r\n\
+         RDATE;VALUE=DATE:19971225,19971226\r\n\
+         EXDATE:19971002T090000\r\n",
+    )
+    .expect("set is valid");
+    assert_eq!(
+        set,
+        RecurrenceSet::new(datetime!("1997-09-02 9:00"))
+            .with_rrule(RRule::parse("FREQ=YEARLY;COUNT=2;BYMONTH=9").expect("rule is valid"))
+            .with_rrule(RRule::parse("FREQ=MONTHLY;COUNT=2").expect("rule is valid"))
+            .with_rdate(datetime!("1997-12-25 0:00"))
+            .with_rdate(datetime!("1997-12-26 0:00"))
+            .with_exdate(datetime!("1997-10-02 9:00"))
+    );
+    assert_eq!(
+        set.occurrences().collect::<Vec<_>>(),
+        [
+            datetime!("1997-09-02 9:00"),
+            datetime!("1997-12-25 0:00"),
+            datetime!("1997-12-26 0:00"),
+            datetime!("1998-09-02 9:00"),
+        ]
+    );
+
+    let err = |s: &str| RecurrenceSet::parse(s).expect_err("set is invalid");
+    assert_eq!(
+        err("RRULE:FREQ=DAILY"),
+        Recurrence::InvalidRecurrenceSet("missing DTSTART")
+    );
+    assert_eq!(
+        err("DTSTART:19970902T090000\nDTSTART:19970902T090000"),
+        Recurrence::InvalidRecurrenceSet("duplicate DTSTART")
+    );
+    assert_eq!(
+        err("DTSTART:1997"),
+        Recurrence::InvalidRecurrenceSet("invalid DTSTART")
+    );
+    assert_eq!(
+        err("DTSTART"),
+        Recurrence::InvalidRecurrenceSet("missing value")
+    );
+    assert_eq!(
+        err("DTSTART:19970902\nEXRULE:FREQ=DAILY"),
+        Recurrence::InvalidRecurrenceSet("unknown property")
+    );
+    assert_eq!(
+        err("DTSTART:19970902\nRDATE:19970902,1997"),
+        Recurrence::InvalidRecurrenceSet("invalid date or datetime")
+    );
+    assert_eq!(
+        err("DTSTART:19970902\nRDATE;VALUE=PERIOD:19970902T090000Z/PT1H"),
+        Recurrence::InvalidRecurrenceSet("PERIOD values are not supported")
+    );
+    assert_eq!(
+        err("DTSTART:19970902\nRRULE:FREQ=DAILY;COUNT=x"),
+        Recurrence::InvalidRRule("invalid COUNT")
+    );
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        Recurrence::InvalidRRule("missing FREQ").to_string(),
+        "invalid recurrence rule: missing FREQ"
+    );
+    assert_eq!(
+        time::Error::from(Recurrence::InvalidRecurrenceSet("missing DTSTART")).to_string(),
+        "invalid recurrence set: missing DTSTART"
+    );
+}
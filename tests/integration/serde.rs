@@ -1,6 +1,6 @@
 use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token};
 use time::macros::{date, datetime, offset, time};
-use time::{Duration, Time, Weekday};
+use time::{Duration, Month, Period, Time, Weekday};
 
 #[test]
 fn time() {
@@ -307,3 +307,49 @@ fn weekday() {
         r#"invalid value: string "NotADay", expected a day of the week"#,
     );
 }
+
+#[test]
+fn month() {
+    assert_tokens(&Month::January.compact(), &[Token::U8(1)]);
+    assert_tokens(&Month::June.compact(), &[Token::U8(6)]);
+    assert_tokens(&Month::December.compact(), &[Token::U8(12)]);
+    assert_de_tokens_error::<Compact<Month>>(
+        &[Token::U8(0)],
+        "invalid value: integer `0`, expected a value in the range 1..=12",
+    );
+
+    assert_tokens(&Month::January.readable(), &[Token::BorrowedStr("January")]);
+    assert_tokens(&Month::June.readable(), &[Token::BorrowedStr("June")]);
+    assert_tokens(
+        &Month::December.readable(),
+        &[Token::BorrowedStr("December")],
+    );
+    assert_de_tokens_error::<Readable<Month>>(
+        &[Token::BorrowedStr("NotAMonth")],
+        r#"invalid value: string "NotAMonth", expected a month of the year"#,
+    );
+}
+
+#[test]
+fn period() {
+    assert_tokens(
+        &Period::new(1, -2, 3).compact(),
+        &[
+            Token::Tuple { len: 3 },
+            Token::I32(1),
+            Token::I32(-2),
+            Token::I32(3),
+            Token::TupleEnd,
+        ],
+    );
+    assert_tokens(
+        &Period::new(1, -2, 3).readable(),
+        &[
+            Token::Tuple { len: 3 },
+            Token::I32(1),
+            Token::I32(-2),
+            Token::I32(3),
+            Token::TupleEnd,
+        ],
+    );
+}
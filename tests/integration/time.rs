@@ -1,6 +1,6 @@
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::time;
-use time::{Result, Time};
+use time::{Result, Time, Unit};
 
 #[test]
 fn from_hms() -> Result<()> {
@@ -146,6 +146,108 @@ fn nanosecond() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn replace_hour() {
+    assert_eq!(
+        time!("1:02:03.004_005_006").replace_hour(7),
+        Ok(time!("7:02:03.004_005_006"))
+    );
+    assert!(time!("1:02:03.004_005_006").replace_hour(24).is_err());
+}
+
+#[test]
+fn replace_minute() {
+    assert_eq!(
+        time!("1:02:03.004_005_006").replace_minute(7),
+        Ok(time!("1:07:03.004_005_006"))
+    );
+    assert!(time!("1:02:03.004_005_006").replace_minute(60).is_err());
+}
+
+#[test]
+fn replace_second() {
+    assert_eq!(
+        time!("1:02:03.004_005_006").replace_second(7),
+        Ok(time!("1:02:07.004_005_006"))
+    );
+    assert!(time!("1:02:03.004_005_006").replace_second(60).is_err());
+}
+
+#[test]
+fn replace_millisecond() {
+    assert_eq!(
+        time!("1:02:03.004_005_006").replace_millisecond(7),
+        Ok(time!("1:02:03.007"))
+    );
+    assert!(time!("1:02:03.004_005_006")
+        .replace_millisecond(1_000)
+        .is_err());
+}
+
+#[test]
+fn replace_microsecond() {
+    assert_eq!(
+        time!("1:02:03.004_005_006").replace_microsecond(7_008),
+        Ok(time!("1:02:03.007_008"))
+    );
+    assert!(time!("1:02:03.004_005_006")
+        .replace_microsecond(1_000_000)
+        .is_err());
+}
+
+#[test]
+fn replace_nanosecond() {
+    assert_eq!(
+        time!("1:02:03.004_005_006").replace_nanosecond(7_008_009),
+        Ok(time!("1:02:03.007_008_009"))
+    );
+    assert!(time!("1:02:03.004_005_006")
+        .replace_nanosecond(1_000_000_000)
+        .is_err());
+}
+
+#[test]
+fn truncate() {
+    let time = time!("13:45:30.123_456_789");
+    assert_eq!(time.truncate(Unit::Microsecond), time!("13:45:30.123_456"));
+    assert_eq!(time.truncate(Unit::Millisecond), time!("13:45:30.123"));
+    assert_eq!(time.truncate(Unit::Second), time!("13:45:30"));
+    assert_eq!(time.truncate(Unit::Minute), time!("13:45"));
+    assert_eq!(time.truncate(Unit::Hour), time!("13:00"));
+    assert_eq!(time.truncate(Unit::Day), time!("0:00"));
+    assert_eq!(time.truncate(Unit::Year), time!("0:00"));
+}
+
+#[test]
+fn ceil() {
+    let time = time!("13:45:30.123_456_789");
+    assert_eq!(time.ceil(Unit::Microsecond), time!("13:45:30.123_457"));
+    assert_eq!(time.ceil(Unit::Millisecond), time!("13:45:30.124"));
+    assert_eq!(time.ceil(Unit::Second), time!("13:45:31"));
+    assert_eq!(time.ceil(Unit::Minute), time!("13:46"));
+    assert_eq!(time.ceil(Unit::Hour), time!("14:00"));
+    assert_eq!(time.ceil(Unit::Day), time!("0:00"));
+    assert_eq!(time!("13:45").ceil(Unit::Minute), time!("13:45"));
+    assert_eq!(time!("23:59:59.5").ceil(Unit::Second), time!("0:00"));
+}
+
+#[test]
+fn round() {
+    let time = time!("13:45:30.123_456_789");
+    assert_eq!(time.round(Unit::Microsecond), time!("13:45:30.123_457"));
+    assert_eq!(time.round(Unit::Millisecond), time!("13:45:30.123"));
+    assert_eq!(time.round(Unit::Second), time!("13:45:30"));
+    assert_eq!(time.round(Unit::Minute), time!("13:46"));
+    assert_eq!(time.round(Unit::Hour), time!("14:00"));
+    assert_eq!(time.round(Unit::Day), time!("0:00"));
+    assert_eq!(time!("13:30").round(Unit::Hour), time!("14:00"));
+    assert_eq!(
+        time!("13:29:59.999_999_999").round(Unit::Hour),
+        time!("13:00")
+    );
+    assert_eq!(time!("23:30").round(Unit::Hour), time!("0:00"));
+}
+
 #[test]
 fn add_duration() {
     assert_eq!(time!("0:00") + 1.seconds(), time!("0:00:01"));
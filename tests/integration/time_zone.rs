@@ -0,0 +1,842 @@
+use time::macros::{datetime, offset};
+use time::time_zone::{LocalResult, PosixTz};
+use time::{error, format_description, Duration, TimeZone};
+
+/// Build a TZif file. When `version` is `0`, only the version 1 data block is written.
+fn tzif(
+    version: u8,
+    transitions: &[(i64, u8)],
+    types: &[(i32, bool, &str)],
+    footer: &str,
+) -> Vec<u8> {
+    fn header(version: u8, timecnt: usize, typecnt: usize, charcnt: usize) -> Vec<u8> {
+        let mut bytes = b"TZif".to_vec();
+        bytes.push(version);
+        bytes.extend_from_slice(&[0; 15]);
+        for &count in &[0, 0, 0, timecnt, typecnt, charcnt] {
+            bytes.extend_from_slice(&(count as u32).to_be_bytes());
+        }
+        bytes
+    }
+
+    fn data_block(
+        transitions: &[(i64, u8)],
+        types: &[(i32, bool, &str)],
+        abbreviations: &[u8],
+        v1: bool,
+    ) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for &(time, _) in transitions {
+            if v1 {
+                bytes.extend_from_slice(&(time as i32).to_be_bytes());
+            } else {
+                bytes.extend_from_slice(&time.to_be_bytes());
+            }
+        }
+        bytes.extend(transitions.iter().map(|&(_, index)| index));
+        let mut abbreviation_index = 0;
+        for &(offset, is_dst, abbreviation) in types {
+            bytes.extend_from_slice(&offset.to_be_bytes());
+            bytes.push(is_dst as u8);
+            bytes.push(abbreviation_index);
+            abbreviation_index += abbreviation.len() as u8 + 1;
+        }
+        bytes.extend_from_slice(abbreviations);
+        bytes
+    }
+
+    let abbreviations = types
+        .iter()
+        .flat_map(|&(_, _, abbreviation)| abbreviation.bytes().chain(Some(0)))
+        .collect::<Vec<_>>();
+
+    let mut bytes = header(version, transitions.len(), types.len(), abbreviations.len());
+    bytes.extend(data_block(transitions, types, &abbreviations, true));
+    if version != 0 {
+        bytes.extend(header(
+            version,
+            transitions.len(),
+            types.len(),
+            abbreviations.len(),
+        ));
+        bytes.extend(data_block(transitions, types, &abbreviations, false));
+        bytes.push(b'\n');
+        bytes.extend_from_slice(footer.as_bytes());
+        bytes.push(b'\n');
+    }
+    bytes
+}
+
+/// A simplified version of America/New_York, covering 2021.
+pub(crate) fn new_york(version: u8) -> Vec<u8> {
+    tzif(
+        version,
+        &[(1_615_705_200, 1), (1_636_264_800, 0)],
+        &[(-18_000, false, "EST"), (-14_400, true, "EDT")],
+        "EST5EDT,M3.2.0,M11.1.0",
+    )
+}
+
+#[test]
+fn from_tzif() -> time::Result<()> {
+    for &version in &[0, b'2', b'3'] {
+        let time_zone = TimeZone::from_tzif(&new_york(version))?;
+        assert_eq!(time_zone.name(), None);
+        assert_eq!(time_zone.transition_table().len(), 2);
+        assert_eq!(time_zone.local_time_types().len(), 2);
+    }
+    Ok(())
+}
+
+#[test]
+fn from_tzif_invalid() {
+    assert!(matches!(
+        TimeZone::from_tzif(b""),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+    assert!(matches!(
+        TimeZone::from_tzif(b"TZip2"),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+
+    let mut bytes = new_york(b'2');
+    bytes[4] = b'9';
+    assert!(matches!(
+        TimeZone::from_tzif(&bytes),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+
+    let bytes = new_york(b'2');
+    assert!(matches!(
+        TimeZone::from_tzif(&bytes[..bytes.len() - 1]),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+
+    // transition refers to a nonexistent local time type
+    assert!(matches!(
+        TimeZone::from_tzif(&tzif(b'2', &[(0, 1)], &[(0, false, "UTC")], "")),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+    // transitions out of order
+    assert!(matches!(
+        TimeZone::from_tzif(&tzif(b'2', &[(1, 0), (0, 0)], &[(0, false, "UTC")], "")),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+    // offset out of range
+    assert!(matches!(
+        TimeZone::from_tzif(&tzif(b'2', &[], &[(86_400, false, "XXX")], "")),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+    // no local time types
+    assert!(matches!(
+        TimeZone::from_tzif(&tzif(b'2', &[], &[], "")),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+    // invalid footer
+    assert!(matches!(
+        TimeZone::from_tzif(&tzif(b'2', &[], &[(0, false, "UTC")], "UTC")),
+        Err(error::TimeZone::InvalidTzif(_))
+    ));
+}
+
+#[test]
+fn transition_table() -> time::Result<()> {
+    let time_zone = TimeZone::from_tzif(&new_york(b'2'))?;
+    let transitions = time_zone.transition_table();
+    assert_eq!(transitions[0].unix_timestamp(), 1_615_705_200);
+    assert_eq!(transitions[0].local_time_type(), 1);
+    assert_eq!(transitions[1].unix_timestamp(), 1_636_264_800);
+    assert_eq!(transitions[1].local_time_type(), 0);
+
+    let local_time_types = time_zone.local_time_types();
+    assert_eq!(local_time_types[0].offset(), offset!("-5"));
+    assert!(!local_time_types[0].is_dst());
+    assert_eq!(local_time_types[0].abbreviation(), "EST");
+    assert_eq!(local_time_types[1].offset(), offset!("-4"));
+    assert!(local_time_types[1].is_dst());
+    assert_eq!(local_time_types[1].abbreviation(), "EDT");
+    Ok(())
+}
+
+#[test]
+fn offset_at() -> time::Result<()> {
+    let time_zone = TimeZone::from_tzif(&new_york(b'2'))?;
+    assert_eq!(
+        time_zone.offset_at(datetime!("2021-01-01 0:00 UTC")),
+        offset!("-5")
+    );
+    assert_eq!(
+        time_zone.offset_at(datetime!("2021-03-14 6:59:59 UTC")),
+        offset!("-5")
+    );
+    assert_eq!(
+        time_zone.offset_at(datetime!("2021-03-14 7:00 UTC")),
+        offset!("-4")
+    );
+    assert_eq!(
+        time_zone.offset_at(datetime!("2021-03-14 3:00 -4")),
+        offset!("-4")
+    );
+    assert_eq!(
+        time_zone.offset_at(datetime!("2021-11-07 5:59:59 UTC")),
+        offset!("-4")
+    );
+    assert_eq!(
+        time_zone.offset_at(datetime!("2021-11-07 6:00 UTC")),
+        offset!("-5")
+    );
+    assert_eq!(
+        time_zone.offset_at(datetime!("2030-01-01 0:00 UTC")),
+        offset!("-5")
+    );
+    assert_eq!(
+        time_zone.offset_at(datetime!("2030-07-01 0:00 UTC")),
+        offset!("-4")
+    );
+
+    // Without a footer, the last local time type remains in effect.
+    let time_zone = TimeZone::from_tzif(&new_york(0))?;
+    assert_eq!(
+        time_zone.offset_at(datetime!("2030-07-01 0:00 UTC")),
+        offset!("-5")
+    );
+    Ok(())
+}
+
+#[test]
+fn local_time_type_at() -> time::Result<()> {
+    let time_zone = TimeZone::from_tzif(&new_york(b'2'))?;
+    assert_eq!(
+        time_zone
+            .local_time_type_at(datetime!("2021-07-01 0:00 UTC"))
+            .abbreviation(),
+        "EDT"
+    );
+    assert_eq!(
+        time_zone
+            .local_time_type_at(datetime!("2021-12-01 0:00 UTC"))
+            .abbreviation(),
+        "EST"
+    );
+    Ok(())
+}
+
+#[test]
+fn to_timezone() -> time::Result<()> {
+    let time_zone = TimeZone::from_tzif(&new_york(b'2'))?;
+    let datetime = datetime!("2021-07-01 12:00 UTC").to_timezone(&time_zone);
+    assert_eq!(datetime, datetime!("2021-07-01 8:00 -4"));
+    assert_eq!(datetime.offset(), offset!("-4"));
+    assert_eq!(datetime.hour(), 8);
+    Ok(())
+}
+
+#[test]
+fn load_from() -> time::Result<()> {
+    let directory = std::env::temp_dir().join("time-rs-tzdir");
+    std::fs::create_dir_all(directory.join("America"))
+        .and_then(|_| std::fs::write(directory.join("America/New_York"), new_york(b'2')))
+        .map_err(error::TimeZone::from)?;
+
+    let time_zone = TimeZone::load_from(&directory, "America/New_York")?;
+    assert_eq!(time_zone.name(), Some("America/New_York"));
+    assert_eq!(time_zone.transition_table().len(), 2);
+
+    let time_zone = TimeZone::from_file(directory.join("America/New_York"))?;
+    assert_eq!(time_zone.name(), None);
+
+    assert!(matches!(
+        TimeZone::load_from(&directory, "America/Nowhere"),
+        Err(error::TimeZone::StdIo(_))
+    ));
+    assert!(matches!(
+        TimeZone::load_from(&directory, "../America/New_York"),
+        Err(error::TimeZone::InvalidName)
+    ));
+    assert!(matches!(
+        TimeZone::load_from(&directory, "/America/New_York"),
+        Err(error::TimeZone::InvalidName)
+    ));
+    assert!(matches!(
+        TimeZone::load_from(&directory, ""),
+        Err(error::TimeZone::InvalidName)
+    ));
+    Ok(())
+}
+
+#[test]
+fn posix_tz() -> time::Result<()> {
+    let time_zone = TimeZone::from_tzif(&new_york(b'2'))?;
+    let posix_tz = time_zone.posix_tz().expect("footer is present");
+    assert_eq!(posix_tz, &PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?);
+    assert_eq!(TimeZone::from_tzif(&new_york(0))?.posix_tz(), None);
+    Ok(())
+}
+
+#[test]
+fn from_posix_tz() -> time::Result<()> {
+    let time_zone = TimeZone::from(PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?);
+    assert_eq!(time_zone.name(), None);
+    assert!(time_zone.transition_table().is_empty());
+    assert_eq!(time_zone.local_time_types().len(), 2);
+    assert_eq!(
+        time_zone.offset_at(datetime!("2021-01-01 0:00 UTC")),
+        offset!("+1")
+    );
+    assert_eq!(
+        time_zone.offset_at(datetime!("2021-07-01 0:00 UTC")),
+        offset!("+2")
+    );
+    Ok(())
+}
+
+#[test]
+fn posix_tz_parse() -> time::Result<()> {
+    let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+    assert_eq!(tz.standard_time().offset(), offset!("-5"));
+    assert_eq!(tz.standard_time().abbreviation(), "EST");
+    assert!(!tz.standard_time().is_dst());
+    let dst = tz.daylight_saving_time().expect("DST is present");
+    assert_eq!(dst.offset(), offset!("-4"));
+    assert_eq!(dst.abbreviation(), "EDT");
+    assert!(dst.is_dst());
+
+    let tz = PosixTz::parse("<+0330>-3:30")?;
+    assert_eq!(tz.standard_time().offset(), offset!("+3:30"));
+    assert_eq!(tz.standard_time().abbreviation(), "+0330");
+    assert_eq!(tz.daylight_saving_time(), None);
+
+    let tz = PosixTz::parse("<-01>+1:02:03<+00>-0")?;
+    assert_eq!(tz.standard_time().offset(), offset!("-1:02:03"));
+    assert_eq!(
+        tz.daylight_saving_time().map(|dst| dst.offset()),
+        Some(offset!("UTC"))
+    );
+
+    assert_eq!(
+        PosixTz::parse("EST5EDT")?,
+        PosixTz::parse("EST5EDT4,M3.2.0/2,M11.1.0/02:00:00")?
+    );
+    Ok(())
+}
+
+#[test]
+fn posix_tz_parse_invalid() {
+    for &s in &[
+        "",
+        "E5",
+        "EST",
+        "EST+",
+        "EST24",
+        "EST5:60",
+        "<EST5",
+        "<E>5",
+        "EST5EDT,",
+        "EST5EDT,M3.2.0",
+        "EST5EDT,M3.2.0,",
+        "EST5EDT,M0.2.0,M11.1.0",
+        "EST5EDT,M13.2.0,M11.1.0",
+        "EST5EDT,M3.0.0,M11.1.0",
+        "EST5EDT,M3.6.0,M11.1.0",
+        "EST5EDT,M3.2.7,M11.1.0",
+        "EST5EDT,J0,J300",
+        "EST5EDT,J366,J300",
+        "EST5EDT,366,300",
+        "EST5EDT,M3.2.0/168,M11.1.0",
+        "EST5EDT,M3.2.0,M11.1.0x",
+        "EST5 EDT",
+    ] {
+        assert!(
+            matches!(PosixTz::parse(s), Err(error::TimeZone::InvalidPosixTz(_))),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn posix_tz_offset_at() -> time::Result<()> {
+    let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0")?;
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-14 6:59:59 UTC")),
+        offset!("-5")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-14 7:00 UTC")),
+        offset!("-4")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-11-07 5:59:59 UTC")),
+        offset!("-4")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-11-07 6:00 UTC")),
+        offset!("-5")
+    );
+    assert_eq!(
+        tz.local_time_type_at(datetime!("2021-07-01 0:00 UTC"))
+            .abbreviation(),
+        "EDT"
+    );
+
+    // last week of the month
+    let tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3")?;
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-28 0:59:59 UTC")),
+        offset!("+1")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-28 1:00 UTC")),
+        offset!("+2")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-10-31 0:59:59 UTC")),
+        offset!("+2")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-10-31 1:00 UTC")),
+        offset!("+1")
+    );
+
+    // southern hemisphere
+    let tz = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3")?;
+    assert_eq!(
+        tz.offset_at(datetime!("2021-01-01 0:00 UTC")),
+        offset!("+11")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-04-03 15:59:59 UTC")),
+        offset!("+11")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-04-03 16:00 UTC")),
+        offset!("+10")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-10-02 15:59:59 UTC")),
+        offset!("+10")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-10-02 16:00 UTC")),
+        offset!("+11")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-12-31 23:00 UTC")),
+        offset!("+11")
+    );
+
+    // transition times outside of 0:00 to 24:00
+    let tz = PosixTz::parse("IST-2IDT,M3.4.4/26,M10.5.0")?;
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-25 23:59:59 UTC")),
+        offset!("+2")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-26 0:00 UTC")),
+        offset!("+3")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-10-30 22:59:59 UTC")),
+        offset!("+3")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-10-30 23:00 UTC")),
+        offset!("+2")
+    );
+
+    // Julian days, ignoring February 29
+    let tz = PosixTz::parse("XXX3YYY,J60/0,J300/0")?;
+    assert_eq!(
+        tz.offset_at(datetime!("2020-03-01 2:59:59 UTC")),
+        offset!("-3")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2020-03-01 3:00 UTC")),
+        offset!("-2")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-01 2:59:59 UTC")),
+        offset!("-3")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-01 3:00 UTC")),
+        offset!("-2")
+    );
+
+    // zero-based days, counting February 29
+    let tz = PosixTz::parse("XXX3YYY,59/0,299/0")?;
+    assert_eq!(
+        tz.offset_at(datetime!("2020-02-29 2:59:59 UTC")),
+        offset!("-3")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2020-02-29 3:00 UTC")),
+        offset!("-2")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-01 2:59:59 UTC")),
+        offset!("-3")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-03-01 3:00 UTC")),
+        offset!("-2")
+    );
+
+    // daylight saving time all year
+    let tz = PosixTz::parse("EST5EDT,0/0,J365/25")?;
+    assert_eq!(
+        tz.offset_at(datetime!("2021-01-01 0:00 UTC")),
+        offset!("-4")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-07-01 0:00 UTC")),
+        offset!("-4")
+    );
+    assert_eq!(
+        tz.offset_at(datetime!("2021-12-31 23:59 UTC")),
+        offset!("-4")
+    );
+
+    // no daylight saving time
+    let tz = PosixTz::parse("<+0330>-3:30")?;
+    assert_eq!(
+        tz.offset_at(datetime!("2021-07-01 0:00 UTC")),
+        offset!("+3:30")
+    );
+    Ok(())
+}
+
+#[test]
+fn local_result() {
+    let single = LocalResult::Single(1);
+    let ambiguous = LocalResult::Ambiguous(1, 2);
+    let gap = LocalResult::Gap {
+        before: 1,
+        after: 2,
+    };
+
+    assert_eq!(single.single(), Some(1));
+    assert_eq!(ambiguous.single(), None);
+    assert_eq!(gap.single(), None);
+
+    assert_eq!(single.earliest(), 1);
+    assert_eq!(ambiguous.earliest(), 1);
+    assert_eq!(gap.earliest(), 1);
+
+    assert_eq!(single.latest(), 1);
+    assert_eq!(ambiguous.latest(), 2);
+    assert_eq!(gap.latest(), 2);
+
+    assert_eq!(single.shift_forward(), 1);
+    assert_eq!(ambiguous.shift_forward(), 1);
+    assert_eq!(gap.shift_forward(), 2);
+}
+
+#[test]
+fn get() {
+    let berlin = TimeZone::get("Europe/Berlin").unwrap();
+    assert_eq!(berlin.name(), Some("Europe/Berlin"));
+    assert_eq!(
+        berlin.offset_at(datetime!("2021-01-01 12:00 UTC")),
+        offset!("+1")
+    );
+    assert_eq!(
+        berlin.offset_at(datetime!("2021-07-01 12:00 UTC")),
+        offset!("+2")
+    );
+    assert_eq!(
+        berlin.offset_at(datetime!("2100-07-01 12:00 UTC")),
+        offset!("+2")
+    );
+    assert_eq!(
+        berlin.offset_at(datetime!("1945-06-01 12:00 UTC")),
+        offset!("+3")
+    );
+
+    let eastern = TimeZone::get("US/Eastern").unwrap();
+    let new_york = TimeZone::get("America/New_York").unwrap();
+    assert_eq!(eastern.name(), Some("US/Eastern"));
+    assert_eq!(eastern.transition_table(), new_york.transition_table());
+    assert_eq!(eastern.local_time_types(), new_york.local_time_types());
+    assert_eq!(eastern.posix_tz(), new_york.posix_tz());
+
+    assert!(TimeZone::get("Europe/Atlantis").is_none());
+    assert!(TimeZone::get("europe/berlin").is_none());
+    assert!(TimeZone::get("").is_none());
+}
+
+#[test]
+fn names() {
+    let names = TimeZone::names().collect::<Vec<_>>();
+    assert!(names.windows(2).all(|names| names[0] < names[1]));
+    assert!(names.contains(&"UTC"));
+    assert!(names.contains(&"Europe/Paris"));
+    assert!(names.contains(&"US/Pacific"));
+    for name in names {
+        assert_eq!(TimeZone::get(name).unwrap().name(), Some(name));
+    }
+}
+
+#[test]
+fn tzdb_version() {
+    assert_eq!(TimeZone::TZDB_VERSION.len(), 5);
+    assert!(TimeZone::TZDB_VERSION.starts_with("20"));
+}
+
+#[test]
+fn parse() -> time::Result<()> {
+    let format = format_description::parse("[hour]:[minute] [tz_name]")?;
+    assert_eq!(
+        TimeZone::parse("12:00 Europe/Paris", &format)?.name(),
+        Some("Europe/Paris")
+    );
+    assert_eq!(
+        TimeZone::parse("12:00 US/Pacific", &format)?.name(),
+        Some("US/Pacific")
+    );
+    assert!(TimeZone::parse("12:00 Europe/Atlantis", &format).is_err());
+    assert!(TimeZone::parse("12:00", &format_description::parse("[hour]:[minute]")?).is_err());
+    Ok(())
+}
+
+#[test]
+fn transitions() -> time::Result<()> {
+    let new_york = TimeZone::from_tzif(&new_york(b'2'))?;
+
+    // The first two are from the transition table, the rest from the POSIX TZ string.
+    let transitions = new_york
+        .transitions(
+            datetime!("2020-01-01 0:00 UTC"),
+            datetime!("2023-01-01 0:00 UTC"),
+        )
+        .map(|transition| {
+            (
+                transition.datetime(),
+                transition.offset_before(),
+                transition.offset_after(),
+                transition.abbreviation(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        transitions,
+        [
+            (
+                datetime!("2021-03-14 3:00 -4"),
+                offset!("-5"),
+                offset!("-4"),
+                "EDT"
+            ),
+            (
+                datetime!("2021-11-07 1:00 -5"),
+                offset!("-4"),
+                offset!("-5"),
+                "EST"
+            ),
+            (
+                datetime!("2022-03-13 3:00 -4"),
+                offset!("-5"),
+                offset!("-4"),
+                "EDT"
+            ),
+            (
+                datetime!("2022-11-06 1:00 -5"),
+                offset!("-4"),
+                offset!("-5"),
+                "EST"
+            ),
+        ]
+    );
+
+    let backward = new_york
+        .transitions(
+            datetime!("2020-01-01 0:00 UTC"),
+            datetime!("2023-01-01 0:00 UTC"),
+        )
+        .rev()
+        .map(|transition| transition.datetime())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        backward,
+        [
+            datetime!("2022-11-06 1:00 -5"),
+            datetime!("2022-03-13 3:00 -4"),
+            datetime!("2021-11-07 1:00 -5"),
+            datetime!("2021-03-14 3:00 -4"),
+        ]
+    );
+
+    // The start is inclusive, and the end is exclusive.
+    let mut transitions = new_york.transitions(
+        datetime!("2021-03-14 3:00 -4"),
+        datetime!("2021-11-07 1:00 -5"),
+    );
+    assert_eq!(
+        transitions.next().map(|transition| transition.datetime()),
+        Some(datetime!("2021-03-14 3:00 -4"))
+    );
+    assert_eq!(transitions.next(), None);
+    assert_eq!(transitions.next_back(), None);
+
+    let mut transitions = new_york.transitions(
+        datetime!("2021-03-14 3:00:00.5 -4"),
+        datetime!("2021-11-07 1:00:00.5 -5"),
+    );
+    assert_eq!(
+        transitions.next().map(|transition| transition.datetime()),
+        Some(datetime!("2021-11-07 1:00 -5"))
+    );
+    assert_eq!(transitions.next_back(), None);
+
+    let transition = new_york
+        .transitions(
+            datetime!("2021-01-01 0:00 UTC"),
+            datetime!("2021-12-31 0:00 UTC"),
+        )
+        .next()
+        .unwrap();
+    assert_eq!(transition.local_time_type_before().abbreviation(), "EST");
+    assert!(!transition.local_time_type_before().is_dst());
+    assert_eq!(transition.local_time_type_after().abbreviation(), "EDT");
+    assert!(transition.local_time_type_after().is_dst());
+
+    assert_eq!(
+        new_york
+            .transitions(
+                datetime!("2023-01-01 0:00 UTC"),
+                datetime!("2020-01-01 0:00 UTC"),
+            )
+            .count(),
+        0
+    );
+    Ok(())
+}
+
+#[test]
+fn next_transition() -> time::Result<()> {
+    let new_york = TimeZone::from_tzif(&new_york(b'2'))?;
+    let next = |datetime| {
+        new_york
+            .next_transition(datetime)
+            .map(|transition| transition.datetime())
+    };
+    assert_eq!(
+        next(datetime!("1900-01-01 0:00 UTC")),
+        Some(datetime!("2021-03-14 3:00 -4"))
+    );
+    assert_eq!(
+        next(datetime!("2021-03-14 3:00 -4")),
+        Some(datetime!("2021-11-07 1:00 -5"))
+    );
+    assert_eq!(
+        next(datetime!("2021-11-07 1:00 -5")),
+        Some(datetime!("2022-03-13 3:00 -4"))
+    );
+    assert_eq!(
+        next(datetime!("2100-12-01 0:00 UTC")),
+        Some(datetime!("2101-03-13 3:00 -4"))
+    );
+
+    let tz = TimeZone::from(PosixTz::parse("<+0330>-3:30")?);
+    assert!(tz
+        .next_transition(datetime!("2021-01-01 0:00 UTC"))
+        .is_none());
+    // daylight saving time all year
+    let tz = TimeZone::from(PosixTz::parse("EST5EDT,0/0,J365/25")?);
+    assert!(tz
+        .next_transition(datetime!("2021-01-01 0:00 UTC"))
+        .is_none());
+    Ok(())
+}
+
+#[test]
+fn previous_transition() -> time::Result<()> {
+    let new_york = TimeZone::from_tzif(&new_york(b'2'))?;
+    let previous = |datetime| {
+        new_york
+            .previous_transition(datetime)
+            .map(|transition| transition.datetime())
+    };
+    assert_eq!(previous(datetime!("2021-03-14 3:00 -4")), None);
+    assert_eq!(
+        previous(datetime!("2021-03-14 3:00:00.5 -4")),
+        Some(datetime!("2021-03-14 3:00 -4"))
+    );
+    assert_eq!(
+        previous(datetime!("2022-01-01 0:00 UTC")),
+        Some(datetime!("2021-11-07 1:00 -5"))
+    );
+    assert_eq!(
+        previous(datetime!("2022-07-01 0:00 UTC")),
+        Some(datetime!("2022-03-13 3:00 -4"))
+    );
+    assert_eq!(
+        previous(datetime!("2101-01-01 0:00 UTC")),
+        Some(datetime!("2100-11-07 1:00 -5"))
+    );
+
+    let tz = TimeZone::from(PosixTz::parse("EST5EDT,0/0,J365/25")?);
+    assert!(tz
+        .previous_transition(datetime!("2021-01-01 0:00 UTC"))
+        .is_none());
+    let tz = TimeZone::from(PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3")?);
+    assert_eq!(
+        tz.previous_transition(datetime!("2021-01-01 0:00 UTC"))
+            .map(|transition| transition.datetime()),
+        Some(datetime!("2020-10-04 3:00 +11"))
+    );
+    Ok(())
+}
+
+#[test]
+fn transition_at_min_timestamp() -> time::Result<()> {
+    let time_zone = TimeZone::from_tzif(&tzif(
+        b'2',
+        &[(i64::MIN, 1), (1_615_705_200, 0)],
+        &[(-18_000, false, "EST"), (-14_400, true, "EDT")],
+        "",
+    ))?;
+    assert_eq!(
+        time_zone
+            .previous_transition(datetime!("2022-01-01 0:00 UTC"))
+            .map(|transition| transition.datetime()),
+        Some(datetime!("2021-03-14 2:00 -5"))
+    );
+    assert!(time_zone
+        .previous_transition(datetime!("2021-03-14 2:00 -5"))
+        .is_none());
+    assert_eq!(
+        time_zone
+            .transitions(
+                datetime!("2000-01-01 0:00 UTC"),
+                datetime!("2022-01-01 0:00 UTC")
+            )
+            .rev()
+            .count(),
+        1
+    );
+    Ok(())
+}
+
+#[test]
+fn transitions_embedded() {
+    let berlin = TimeZone::get("Europe/Berlin").unwrap();
+    let start = datetime!("1890-01-01 0:00 UTC");
+    let end = datetime!("2100-01-01 0:00 UTC");
+
+    let forward = berlin.transitions(start, end).collect::<Vec<_>>();
+    let mut backward = berlin.transitions(start, end).rev().collect::<Vec<_>>();
+    backward.reverse();
+    assert_eq!(forward, backward);
+    assert!(forward.len() > 200);
+
+    for transition in forward {
+        let datetime = transition.datetime();
+        assert_eq!(
+            berlin.offset_at(datetime - Duration::seconds(1)),
+            transition.offset_before()
+        );
+        assert_eq!(berlin.offset_at(datetime), transition.offset_after());
+        assert_eq!(datetime.offset(), transition.offset_after());
+    }
+}
@@ -23,6 +23,31 @@ fn from_hms() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_whole_seconds() {
+    assert_eq!(UtcOffset::from_whole_seconds(0), Ok(offset!("UTC")));
+    assert_eq!(UtcOffset::from_whole_seconds(1), Ok(offset!("+0:00:01")));
+    assert_eq!(UtcOffset::from_whole_seconds(-1), Ok(offset!("-0:00:01")));
+    assert_eq!(
+        UtcOffset::from_whole_seconds(3_723),
+        Ok(offset!("+1:02:03"))
+    );
+    assert_eq!(
+        UtcOffset::from_whole_seconds(-3_723),
+        Ok(offset!("-1:02:03"))
+    );
+    assert_eq!(
+        UtcOffset::from_whole_seconds(86_399),
+        Ok(offset!("+23:59:59"))
+    );
+    assert_eq!(
+        UtcOffset::from_whole_seconds(-86_399),
+        Ok(offset!("-23:59:59"))
+    );
+    assert!(UtcOffset::from_whole_seconds(86_400).is_err());
+    assert!(UtcOffset::from_whole_seconds(-86_400).is_err());
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(offset!("UTC").as_hms(), (0, 0, 0));
@@ -22,6 +22,24 @@ fn next() {
     assert_eq!(Saturday.next(), Sunday);
 }
 
+#[test]
+fn nth_next() {
+    assert_eq!(Monday.nth_next(0), Monday);
+    assert_eq!(Monday.nth_next(1), Tuesday);
+    assert_eq!(Monday.nth_next(6), Sunday);
+    assert_eq!(Monday.nth_next(7), Monday);
+    assert_eq!(Sunday.nth_next(1), Monday);
+    assert_eq!(Sunday.nth_next(10), Wednesday);
+    assert_eq!(Saturday.nth_next(u8::MAX), Tuesday);
+
+    for weekday in &[
+        Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+    ] {
+        assert_eq!(weekday.nth_next(1), weekday.next());
+        assert_eq!(weekday.nth_next(6), weekday.previous());
+    }
+}
+
 #[test]
 fn number_from_monday() {
     assert_eq!(Monday.number_from_monday(), 1);
@@ -0,0 +1,311 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration as StdDuration;
+
+use time::ext::NumericalStdDuration;
+use time::format_description::{self, well_known::Rfc3339};
+use time::macros::{date, datetime, offset, time};
+use time::{Duration, Month, OffsetDateTime, TimeZone, Weekday, ZonedDateTime};
+
+use crate::time_zone::new_york;
+
+fn hash(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn to_zoned() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-01-01 12:00 UTC").to_zoned(&new_york);
+    assert_eq!(datetime.offset(), offset!("-5"));
+    assert_eq!(datetime.datetime(), datetime!("2021-01-01 7:00"));
+    assert_eq!(
+        datetime!("2021-07-01 12:00 UTC")
+            .to_zoned(&new_york)
+            .offset(),
+        offset!("-4")
+    );
+}
+
+#[test]
+fn to_timezone() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let utc = TimeZone::from(time::time_zone::PosixTz::parse("UTC0").unwrap());
+    let datetime = datetime!("2021-07-01 8:00 -4").to_zoned(&new_york);
+    assert_eq!(datetime.to_timezone(&utc).time(), time!("12:00"));
+    assert_eq!(datetime.to_timezone(&utc), datetime);
+}
+
+#[test]
+fn to_offset() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-07-01 8:00 -4").to_zoned(&new_york);
+    assert_eq!(
+        datetime.to_offset(offset!("UTC")),
+        datetime!("2021-07-01 12:00 UTC")
+    );
+    assert_eq!(datetime.to_offset(offset!("UTC")).offset(), offset!("UTC"));
+    assert_eq!(datetime.to_offset_date_time().offset(), offset!("-4"));
+    assert_eq!(
+        OffsetDateTime::from(datetime),
+        datetime!("2021-07-01 8:00 -4")
+    );
+}
+
+#[test]
+fn getters() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-07-01 0:30:15.5 UTC").to_zoned(&new_york);
+    assert_eq!(datetime.time_zone(), &new_york);
+    assert_eq!(datetime.local_time_type().abbreviation(), "EDT");
+    assert!(datetime.local_time_type().is_dst());
+    assert_eq!(datetime.unix_timestamp(), 1_625_099_415);
+    assert_eq!(datetime.unix_timestamp_nanos(), 1_625_099_415_500_000_000);
+    assert_eq!(datetime.date(), date!("2021-06-30"));
+    assert_eq!(datetime.time(), time!("20:30:15.5"));
+    assert_eq!(datetime.year(), 2021);
+    assert_eq!(datetime.month(), Month::June);
+    assert_eq!(datetime.day(), 30);
+    assert_eq!(datetime.ordinal(), 181);
+    assert_eq!(datetime.weekday(), Weekday::Wednesday);
+    assert_eq!(datetime.hour(), 20);
+    assert_eq!(datetime.minute(), 30);
+    assert_eq!(datetime.second(), 15);
+    assert_eq!(datetime.nanosecond(), 500_000_000);
+}
+
+#[test]
+fn now() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let now = ZonedDateTime::now(&new_york);
+    assert!(now.year() >= 2019);
+    assert_eq!(now.offset(), new_york.offset_at(now.to_offset_date_time()));
+}
+
+#[test]
+fn add_duration() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york) + Duration::days(1);
+    assert_eq!(datetime.datetime(), datetime!("2021-03-14 13:00"));
+    assert_eq!(datetime.offset(), offset!("-4"));
+
+    let datetime = datetime!("2021-11-06 12:00 -4").to_zoned(&new_york) + Duration::days(1);
+    assert_eq!(datetime.datetime(), datetime!("2021-11-07 11:00"));
+    assert_eq!(datetime.offset(), offset!("-5"));
+
+    let datetime = datetime!("2021-03-14 1:59 -5").to_zoned(&new_york) + 1.std_minutes();
+    assert_eq!(datetime.datetime(), datetime!("2021-03-14 3:00"));
+    assert_eq!(datetime.offset(), offset!("-4"));
+}
+
+#[test]
+fn checked_add_duration() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york);
+    let added = datetime.checked_add(Duration::days(1)).unwrap();
+    assert_eq!(added.datetime(), datetime!("2021-03-14 13:00"));
+    assert_eq!(added.offset(), offset!("-4"));
+    let added = datetime.checked_add_std(1.std_days()).unwrap();
+    assert_eq!(added.datetime(), datetime!("2021-03-14 13:00"));
+    assert_eq!(added.offset(), offset!("-4"));
+
+    assert!(datetime.checked_add(Duration::MAX).is_none());
+    assert!(datetime.checked_add(Duration::MIN).is_none());
+    assert!(datetime.checked_add_std(StdDuration::MAX).is_none());
+}
+
+#[test]
+fn checked_sub_duration() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-11-07 1:30 -5").to_zoned(&new_york);
+    let subtracted = datetime.checked_sub(Duration::hours(1)).unwrap();
+    assert_eq!(subtracted.datetime(), datetime!("2021-11-07 1:30"));
+    assert_eq!(subtracted.offset(), offset!("-4"));
+    let subtracted = datetime.checked_sub_std(1.std_hours()).unwrap();
+    assert_eq!(subtracted.datetime(), datetime!("2021-11-07 1:30"));
+    assert_eq!(subtracted.offset(), offset!("-4"));
+
+    assert!(datetime.checked_sub(Duration::MAX).is_none());
+    assert!(datetime.checked_sub(Duration::MIN).is_none());
+    assert!(datetime.checked_sub_std(StdDuration::MAX).is_none());
+}
+
+#[test]
+fn saturating_add_duration() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york);
+    assert_eq!(
+        datetime.saturating_add(Duration::days(1)).datetime(),
+        datetime!("2021-03-14 13:00")
+    );
+
+    let max = datetime.saturating_add(Duration::MAX);
+    assert_eq!(max, datetime.saturating_add_std(StdDuration::MAX));
+    assert!(max.checked_add(Duration::nanoseconds(1)).is_none());
+    assert!(max.checked_sub(Duration::nanoseconds(1)).is_some());
+
+    let min = datetime.saturating_add(Duration::MIN);
+    assert!(min.checked_sub(Duration::nanoseconds(1)).is_none());
+    assert!(min.checked_add(Duration::nanoseconds(1)).is_some());
+}
+
+#[test]
+fn saturating_sub_duration() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-03-14 13:00 -4").to_zoned(&new_york);
+    assert_eq!(
+        datetime.saturating_sub(Duration::days(1)).datetime(),
+        datetime!("2021-03-13 12:00")
+    );
+
+    let min = datetime.saturating_sub(Duration::MAX);
+    assert_eq!(min, datetime.saturating_sub_std(StdDuration::MAX));
+    assert!(min.checked_sub(Duration::nanoseconds(1)).is_none());
+
+    let max = datetime.saturating_sub(Duration::MIN);
+    assert!(max.checked_add(Duration::nanoseconds(1)).is_none());
+}
+
+#[test]
+fn add_assign_duration() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let mut datetime = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york);
+    datetime += Duration::days(1);
+    assert_eq!(datetime.offset(), offset!("-4"));
+    datetime += StdDuration::from_secs(86_400 * 300);
+    assert_eq!(datetime.offset(), offset!("-5"));
+}
+
+#[test]
+fn sub_duration() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-03-14 13:00 -4").to_zoned(&new_york) - Duration::days(1);
+    assert_eq!(datetime.datetime(), datetime!("2021-03-13 12:00"));
+    assert_eq!(datetime.offset(), offset!("-5"));
+
+    let datetime = datetime!("2021-11-07 1:30 -5").to_zoned(&new_york) - 1.std_hours();
+    assert_eq!(datetime.datetime(), datetime!("2021-11-07 1:30"));
+    assert_eq!(datetime.offset(), offset!("-4"));
+}
+
+#[test]
+fn sub_assign_duration() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let mut datetime = datetime!("2021-11-07 12:00 -5").to_zoned(&new_york);
+    datetime -= Duration::days(1);
+    assert_eq!(datetime.offset(), offset!("-4"));
+    datetime -= StdDuration::from_secs(86_400 * 300);
+    assert_eq!(datetime.offset(), offset!("-5"));
+}
+
+#[test]
+fn sub_self() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let start = datetime!("2021-03-13 12:00 -5").to_zoned(&new_york);
+    let end = datetime!("2021-03-14 12:00 -4").to_zoned(&new_york);
+    assert_eq!(end - start, Duration::hours(23));
+    assert_eq!(start - end, Duration::hours(-23));
+}
+
+#[test]
+fn ord() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let utc = TimeZone::from(time::time_zone::PosixTz::parse("UTC0").unwrap());
+    let first = datetime!("2021-11-07 1:30 -4").to_zoned(&new_york);
+    let second = datetime!("2021-11-07 1:30 -5").to_zoned(&new_york);
+    assert!(first < second);
+    assert_eq!(first, first.to_timezone(&utc));
+    assert_eq!(hash(first), hash(first.to_timezone(&utc)));
+    assert_ne!(hash(first), hash(second));
+    assert_ne!(hash(first), hash(first.to_offset_date_time()));
+}
+
+#[test]
+fn format() -> time::Result<()> {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let format = format_description::parse(
+        "[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory]",
+    )?;
+    let datetime = datetime!("2021-11-07 6:30 UTC").to_zoned(&new_york);
+    assert_eq!(datetime.format(&format)?, "2021-11-07 01:30 -05");
+    assert_eq!(datetime.format(&Rfc3339)?, "2021-11-07T01:30:00-05:00");
+
+    let mut buf = Vec::new();
+    datetime.format_into(&mut buf, &format)?;
+    assert_eq!(buf, b"2021-11-07 01:30 -05");
+    Ok(())
+}
+
+#[test]
+fn display() {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let datetime = datetime!("2021-11-07 6:30 UTC").to_zoned(&new_york);
+    assert_eq!(datetime.to_string(), "2021-11-07 1:30:00.0 -05:00:00 EST");
+}
+
+#[test]
+fn parse() -> time::Result<()> {
+    let new_york = TimeZone::from_tzif(&new_york(b'2')).unwrap();
+    let format = format_description::parse("[year]-[month]-[day] [hour]:[minute]")?;
+    let with_offset = format_description::parse(
+        "[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory]",
+    )?;
+
+    let datetime = ZonedDateTime::parse("2021-07-01 12:00", &format, &new_york)?;
+    assert_eq!(
+        datetime,
+        datetime!("2021-07-01 12:00 -4").to_zoned(&new_york)
+    );
+
+    // Ambiguous
+    let datetime = ZonedDateTime::parse("2021-11-07 01:30", &format, &new_york)?;
+    assert_eq!(datetime.offset(), offset!("-4"));
+    let datetime = ZonedDateTime::parse("2021-11-07 01:30 -04", &with_offset, &new_york)?;
+    assert_eq!(datetime.offset(), offset!("-4"));
+    let datetime = ZonedDateTime::parse("2021-11-07 01:30 -05", &with_offset, &new_york)?;
+    assert_eq!(datetime.offset(), offset!("-5"));
+    assert_eq!(datetime.datetime(), datetime!("2021-11-07 1:30"));
+
+    // Gap
+    let datetime = ZonedDateTime::parse("2021-03-14 02:30", &format, &new_york)?;
+    assert_eq!(datetime.datetime(), datetime!("2021-03-14 3:30"));
+    assert_eq!(datetime.offset(), offset!("-4"));
+
+    let datetime = ZonedDateTime::parse("2021-07-01 12:00 +09", &with_offset, &new_york)?;
+    assert_eq!(datetime.datetime(), datetime!("2021-06-30 23:00"));
+    assert_eq!(datetime.offset(), offset!("-4"));
+
+    let datetime = ZonedDateTime::parse("2021-07-01T12:00:00Z", &Rfc3339, &new_york)?;
+    assert_eq!(
+        datetime,
+        datetime!("2021-07-01 8:00 -4").to_zoned(&new_york)
+    );
+
+    assert!(ZonedDateTime::parse("2021-07-01", &format, &new_york).is_err());
+    Ok(())
+}
+
+#[test]
+fn parse_tz_name() -> time::Result<()> {
+    let format = format_description::parse("[year]-[month]-[day] [hour]:[minute] [tz_name]")?;
+    let paris = TimeZone::get("Europe/Paris").unwrap();
+    let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+
+    let input = "2021-03-01 12:00 Europe/Paris";
+    let datetime = ZonedDateTime::parse(input, &format, &paris)?;
+    assert_eq!(datetime, datetime!("2021-03-01 11:00 UTC").to_zoned(&paris));
+    assert_eq!(datetime.format(&format)?, input);
+
+    // The local datetime is in the named time zone, not the provided one.
+    let datetime = ZonedDateTime::parse(input, &format, &tokyo)?;
+    assert_eq!(datetime.time_zone(), &tokyo);
+    assert_eq!(datetime.datetime(), datetime!("2021-03-01 20:00"));
+
+    let with_abbrev =
+        format_description::parse("[year]-[month]-[day] [hour]:[minute] [tz_abbrev]")?;
+    let datetime = ZonedDateTime::parse("2021-03-01 12:00 CET", &with_abbrev, &paris)?;
+    assert_eq!(datetime.format(&with_abbrev)?, "2021-03-01 12:00 CET");
+    Ok(())
+}
@@ -12,6 +12,8 @@ pub(crate) enum Component {
     Weekday(modifier::Weekday),
     WeekNumber(modifier::WeekNumber),
     Year(modifier::Year),
+    Quarter(modifier::Quarter),
+    FiscalYear(modifier::FiscalYear),
     Hour(modifier::Hour),
     Minute(modifier::Minute),
     Period(modifier::Period),
@@ -20,6 +22,8 @@ pub(crate) enum Component {
     OffsetHour(modifier::OffsetHour),
     OffsetMinute(modifier::OffsetMinute),
     OffsetSecond(modifier::OffsetSecond),
+    TzAbbrev(modifier::TzAbbrev),
+    TzName(modifier::TzName),
 }
 
 impl ToTokens for Component {
@@ -31,6 +35,8 @@ impl ToTokens for Component {
             Self::Weekday(modifier) => ("Weekday", modifier.to_internal_token_stream()),
             Self::WeekNumber(modifier) => ("WeekNumber", modifier.to_internal_token_stream()),
             Self::Year(modifier) => ("Year", modifier.to_internal_token_stream()),
+            Self::Quarter(modifier) => ("Quarter", modifier.to_internal_token_stream()),
+            Self::FiscalYear(modifier) => ("FiscalYear", modifier.to_internal_token_stream()),
             Self::Hour(modifier) => ("Hour", modifier.to_internal_token_stream()),
             Self::Minute(modifier) => ("Minute", modifier.to_internal_token_stream()),
             Self::Period(modifier) => ("Period", modifier.to_internal_token_stream()),
@@ -39,6 +45,8 @@ impl ToTokens for Component {
             Self::OffsetHour(modifier) => ("OffsetHour", modifier.to_internal_token_stream()),
             Self::OffsetMinute(modifier) => ("OffsetMinute", modifier.to_internal_token_stream()),
             Self::OffsetSecond(modifier) => ("OffsetSecond", modifier.to_internal_token_stream()),
+            Self::TzAbbrev(modifier) => ("TzAbbrev", modifier.to_internal_token_stream()),
+            Self::TzName(modifier) => ("TzName", modifier.to_internal_token_stream()),
         };
 
         tokens.extend(
@@ -71,6 +79,8 @@ pub(crate) enum NakedComponent {
     Weekday,
     WeekNumber,
     Year,
+    Quarter,
+    FiscalYear,
     Hour,
     Minute,
     Period,
@@ -79,6 +89,8 @@ pub(crate) enum NakedComponent {
     OffsetHour,
     OffsetMinute,
     OffsetSecond,
+    TzAbbrev,
+    TzName,
 }
 
 impl NakedComponent {
@@ -93,6 +105,8 @@ impl NakedComponent {
             "weekday" => Ok(Self::Weekday),
             "week_number" => Ok(Self::WeekNumber),
             "year" => Ok(Self::Year),
+            "quarter" => Ok(Self::Quarter),
+            "fiscal_year" => Ok(Self::FiscalYear),
             "hour" => Ok(Self::Hour),
             "minute" => Ok(Self::Minute),
             "period" => Ok(Self::Period),
@@ -101,6 +115,8 @@ impl NakedComponent {
             "offset_hour" => Ok(Self::OffsetHour),
             "offset_minute" => Ok(Self::OffsetMinute),
             "offset_second" => Ok(Self::OffsetSecond),
+            "tz_abbrev" => Ok(Self::TzAbbrev),
+            "tz_name" => Ok(Self::TzName),
             "" => Err(InvalidFormatDescription::MissingComponentName {
                 index: component_index,
             }),
@@ -137,6 +153,23 @@ impl NakedComponent {
                 iso_week_based: modifiers.year_is_iso_week_based.unwrap_or_default(),
                 sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
             }),
+            Self::Quarter => Component::Quarter(modifier::Quarter {
+                calendar: modifier::FiscalCalendar {
+                    start: modifiers.fiscal_year_start.unwrap_or("January"),
+                    naming: modifier::FiscalYearNaming::EndYear,
+                },
+            }),
+            Self::FiscalYear => Component::FiscalYear(modifier::FiscalYear {
+                padding: modifiers.padding.unwrap_or_default(),
+                repr: modifiers.year_repr.unwrap_or_default(),
+                sign_is_mandatory: modifiers.sign_is_mandatory.unwrap_or_default(),
+                calendar: modifier::FiscalCalendar {
+                    start: modifiers.fiscal_year_start.unwrap_or("January"),
+                    naming: modifiers
+                        .fiscal_year_naming
+                        .unwrap_or(modifier::FiscalYearNaming::EndYear),
+                },
+            }),
             Self::Hour => Component::Hour(modifier::Hour {
                 padding: modifiers.padding.unwrap_or_default(),
                 is_12_hour_clock: modifiers.hour_is_12_hour_clock.unwrap_or_default(),
@@ -163,6 +196,8 @@ impl NakedComponent {
             Self::OffsetSecond => Component::OffsetSecond(modifier::OffsetSecond {
                 padding: modifiers.padding.unwrap_or_default(),
             }),
+            Self::TzAbbrev => Component::TzAbbrev(modifier::TzAbbrev),
+            Self::TzName => Component::TzName(modifier::TzName),
         }
     }
 }
@@ -3,6 +3,8 @@ pub(crate) mod error;
 pub(crate) mod modifier;
 pub(crate) mod parse;
 
+use std::iter;
+
 use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
 
 pub(crate) use self::component::Component;
@@ -23,57 +25,81 @@ mod helper {
 pub(crate) enum FormatItem<'a> {
     Literal(&'a str),
     Component(Component),
+    Optional(Vec<Self>),
+    First(Vec<Vec<Self>>),
+}
+
+/// The path to a variant of `FormatItem`, followed by its parenthesized contents.
+fn format_item_variant(variant: &str, contents: TokenStream) -> TokenStream {
+    [
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("time", Span::mixed_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("FormatItem", Span::mixed_site())),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new(variant, Span::mixed_site())),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, contents)),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// A reference to a slice containing the provided items, as in `&[item, item]`.
+fn slice_ref(items: impl Iterator<Item = TokenStream>) -> TokenStream {
+    let mut contents = TokenStream::new();
+    for item in items {
+        contents.extend(item);
+        contents.extend(iter::once(TokenTree::from(Punct::new(',', Spacing::Alone))));
+    }
+
+    [
+        TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Bracket, contents)),
+    ]
+    .iter()
+    .cloned()
+    .collect()
+}
+
+/// A `FormatItem::Compound` containing the provided items.
+fn compound(items: &[FormatItem<'_>]) -> TokenStream {
+    format_item_variant(
+        "Compound",
+        slice_ref(items.iter().map(FormatItem::to_internal_token_stream)),
+    )
 }
 
 impl ToTokens for FormatItem<'_> {
     fn to_internal_tokens(&self, tokens: &mut TokenStream) {
-        match self {
-            FormatItem::Literal(s) => tokens.extend(
-                [
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("time", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("FormatItem", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("Literal", Span::mixed_site())),
-                    TokenTree::Group(Group::new(
-                        Delimiter::Parenthesis,
-                        TokenStream::from(TokenTree::Literal(Literal::byte_string(s.as_bytes()))),
-                    )),
-                ]
-                .iter()
-                .cloned()
-                .collect::<TokenStream>(),
+        tokens.extend(match self {
+            FormatItem::Literal(s) => format_item_variant(
+                "Literal",
+                TokenStream::from(TokenTree::Literal(Literal::byte_string(s.as_bytes()))),
             ),
-            FormatItem::Component(component) => tokens.extend(
+            FormatItem::Component(component) => {
+                format_item_variant("Component", component.to_internal_token_stream())
+            }
+            FormatItem::Optional(items) => format_item_variant(
+                "Optional",
                 [
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("time", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("FormatItem", Span::mixed_site())),
-                    TokenTree::Punct(Punct::new(':', Spacing::Joint)),
-                    TokenTree::Punct(Punct::new(':', Spacing::Alone)),
-                    TokenTree::Ident(Ident::new("Component", Span::mixed_site())),
-                    TokenTree::Group(Group::new(
-                        Delimiter::Parenthesis,
-                        component.to_internal_token_stream(),
-                    )),
+                    TokenStream::from(TokenTree::Punct(Punct::new('&', Spacing::Alone))),
+                    compound(items),
                 ]
                 .iter()
                 .cloned()
-                .collect::<TokenStream>(),
+                .collect(),
+            ),
+            FormatItem::First(branches) => format_item_variant(
+                "First",
+                slice_ref(branches.iter().map(|items| compound(items))),
             ),
-        }
+        });
     }
 }
@@ -1,3 +1,5 @@
+use std::iter;
+
 use proc_macro::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 
 use crate::format_description::error::InvalidFormatDescription;
@@ -68,6 +70,38 @@ macro_rules! to_tokens {
         }
     };
 
+    (
+        $(#[$struct_attr:meta])*
+        $struct_vis:vis struct $struct_name:ident;
+    ) => {
+        $(#[$struct_attr])*
+        $struct_vis struct $struct_name;
+
+        impl ToTokens for $struct_name {
+            fn to_internal_tokens(&self, tokens: &mut TokenStream) {
+                tokens.extend(
+                    [
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("time", Span::mixed_site())),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("format_description", Span::mixed_site())),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new("modifier", Span::mixed_site())),
+                        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                        TokenTree::Ident(Ident::new(stringify!($struct_name), Span::mixed_site())),
+                    ]
+                    .iter()
+                    .cloned()
+                    .collect::<TokenStream>(),
+                )
+            }
+        }
+    };
+
     (
         $(#[$enum_attr:meta])*
         $enum_vis:vis enum $enum_name:ident {
@@ -193,6 +227,68 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) struct Quarter {
+        pub(crate) calendar: FiscalCalendar,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct FiscalYear {
+        pub(crate) padding: Padding,
+        pub(crate) repr: YearRepr,
+        pub(crate) sign_is_mandatory: bool,
+        pub(crate) calendar: FiscalCalendar,
+    }
+}
+
+pub(crate) enum FiscalYearNaming {
+    StartYear,
+    EndYear,
+}
+
+pub(crate) struct FiscalCalendar {
+    pub(crate) start: &'static str,
+    pub(crate) naming: FiscalYearNaming,
+}
+
+impl ToTokens for FiscalCalendar {
+    fn to_internal_tokens(&self, tokens: &mut TokenStream) {
+        let naming = match self.naming {
+            FiscalYearNaming::StartYear => "StartYear",
+            FiscalYearNaming::EndYear => "EndYear",
+        };
+
+        tokens.extend(time_path(&["FiscalCalendar", "new"]));
+        tokens.extend(iter::once(TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            [
+                time_path(&["Month", self.start]),
+                TokenStream::from(TokenTree::Punct(Punct::new(',', Spacing::Alone))),
+                time_path(&["FiscalYearNaming", naming]),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        ))));
+    }
+}
+
+/// Create the tokens for a path beginning with `::time`.
+fn time_path(segments: &[&str]) -> TokenStream {
+    iter::once("time")
+        .chain(segments.iter().copied())
+        .flat_map(|segment| {
+            [
+                TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+                TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+                TokenTree::Ident(Ident::new(segment, Span::mixed_site())),
+            ]
+            .to_vec()
+        })
+        .collect()
+}
+
 to_tokens! {
     pub(crate) struct Hour {
         pub(crate) padding: Padding,
@@ -258,6 +354,14 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) struct TzAbbrev;
+}
+
+to_tokens! {
+    pub(crate) struct TzName;
+}
+
 to_tokens! {
     pub(crate) enum Padding {
         Space,
@@ -298,6 +402,8 @@ pub(crate) struct Modifiers {
     pub(crate) year_repr: Option<YearRepr>,
     pub(crate) year_is_iso_week_based: Option<bool>,
     pub(crate) sign_is_mandatory: Option<bool>,
+    pub(crate) fiscal_year_start: Option<&'static str>,
+    pub(crate) fiscal_year_naming: Option<FiscalYearNaming>,
 }
 
 impl Modifiers {
@@ -328,6 +434,7 @@ impl Modifiers {
 
             match (component_name, modifier) {
                 ("day", "padding:space")
+                | ("fiscal_year", "padding:space")
                 | ("hour", "padding:space")
                 | ("minute", "padding:space")
                 | ("month", "padding:space")
@@ -339,6 +446,7 @@ impl Modifiers {
                 | ("week_number", "padding:space")
                 | ("year", "padding:space") => modifiers.padding = Some(Padding::Space),
                 ("day", "padding:zero")
+                | ("fiscal_year", "padding:zero")
                 | ("hour", "padding:zero")
                 | ("minute", "padding:zero")
                 | ("month", "padding:zero")
@@ -350,6 +458,7 @@ impl Modifiers {
                 | ("week_number", "padding:zero")
                 | ("year", "padding:zero") => modifiers.padding = Some(Padding::Zero),
                 ("day", "padding:none")
+                | ("fiscal_year", "padding:none")
                 | ("hour", "padding:none")
                 | ("minute", "padding:none")
                 | ("month", "padding:none")
@@ -365,12 +474,12 @@ impl Modifiers {
                 ("month", "repr:numerical") => modifiers.month_repr = Some(MonthRepr::Numerical),
                 ("month", "repr:long") => modifiers.month_repr = Some(MonthRepr::Long),
                 ("month", "repr:short") => modifiers.month_repr = Some(MonthRepr::Short),
-                ("offset_hour", "sign:automatic") | ("year", "sign:automatic") => {
-                    modifiers.sign_is_mandatory = Some(false)
-                }
-                ("offset_hour", "sign:mandatory") | ("year", "sign:mandatory") => {
-                    modifiers.sign_is_mandatory = Some(true)
-                }
+                ("fiscal_year", "sign:automatic")
+                | ("offset_hour", "sign:automatic")
+                | ("year", "sign:automatic") => modifiers.sign_is_mandatory = Some(false),
+                ("fiscal_year", "sign:mandatory")
+                | ("offset_hour", "sign:mandatory")
+                | ("year", "sign:mandatory") => modifiers.sign_is_mandatory = Some(true),
                 ("period", "case:upper") => modifiers.period_is_uppercase = Some(true),
                 ("period", "case:lower") => modifiers.period_is_uppercase = Some(false),
                 ("subsecond", "digits:1") => {
@@ -418,10 +527,56 @@ impl Modifiers {
                 ("week_number", "repr:monday") => {
                     modifiers.week_number_repr = Some(WeekNumberRepr::Monday)
                 }
-                ("year", "repr:full") => modifiers.year_repr = Some(YearRepr::Full),
-                ("year", "repr:last_two") => modifiers.year_repr = Some(YearRepr::LastTwo),
+                ("fiscal_year", "repr:full") | ("year", "repr:full") => {
+                    modifiers.year_repr = Some(YearRepr::Full)
+                }
+                ("fiscal_year", "repr:last_two") | ("year", "repr:last_two") => {
+                    modifiers.year_repr = Some(YearRepr::LastTwo)
+                }
                 ("year", "base:calendar") => modifiers.year_is_iso_week_based = Some(false),
                 ("year", "base:iso_week") => modifiers.year_is_iso_week_based = Some(true),
+                ("fiscal_year", "start:january") | ("quarter", "start:january") => {
+                    modifiers.fiscal_year_start = Some("January")
+                }
+                ("fiscal_year", "start:february") | ("quarter", "start:february") => {
+                    modifiers.fiscal_year_start = Some("February")
+                }
+                ("fiscal_year", "start:march") | ("quarter", "start:march") => {
+                    modifiers.fiscal_year_start = Some("March")
+                }
+                ("fiscal_year", "start:april") | ("quarter", "start:april") => {
+                    modifiers.fiscal_year_start = Some("April")
+                }
+                ("fiscal_year", "start:may") | ("quarter", "start:may") => {
+                    modifiers.fiscal_year_start = Some("May")
+                }
+                ("fiscal_year", "start:june") | ("quarter", "start:june") => {
+                    modifiers.fiscal_year_start = Some("June")
+                }
+                ("fiscal_year", "start:july") | ("quarter", "start:july") => {
+                    modifiers.fiscal_year_start = Some("July")
+                }
+                ("fiscal_year", "start:august") | ("quarter", "start:august") => {
+                    modifiers.fiscal_year_start = Some("August")
+                }
+                ("fiscal_year", "start:september") | ("quarter", "start:september") => {
+                    modifiers.fiscal_year_start = Some("September")
+                }
+                ("fiscal_year", "start:october") | ("quarter", "start:october") => {
+                    modifiers.fiscal_year_start = Some("October")
+                }
+                ("fiscal_year", "start:november") | ("quarter", "start:november") => {
+                    modifiers.fiscal_year_start = Some("November")
+                }
+                ("fiscal_year", "start:december") | ("quarter", "start:december") => {
+                    modifiers.fiscal_year_start = Some("December")
+                }
+                ("fiscal_year", "naming:start") => {
+                    modifiers.fiscal_year_naming = Some(FiscalYearNaming::StartYear)
+                }
+                ("fiscal_year", "naming:end") => {
+                    modifiers.fiscal_year_naming = Some(FiscalYearNaming::EndYear)
+                }
                 _ => {
                     return Err(InvalidFormatDescription::InvalidModifier {
                         value: modifier.to_owned(),
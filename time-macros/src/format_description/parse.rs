@@ -28,8 +28,77 @@ fn parse_component(mut s: &str, index: &mut usize) -> Result<Component, InvalidF
         .attach_modifiers(modifier::Modifiers::parse(component_name, s, index)?))
 }
 
-fn parse_literal<'a>(s: &'a str, index: &mut usize) -> ParsedItem<'a> {
-    let loc = s.find('[').unwrap_or_else(|| s.len());
+fn parse_nested_item<'a>(
+    s: &'a str,
+    index: &mut usize,
+) -> Result<Option<ParsedItem<'a>>, InvalidFormatDescription> {
+    let opening_index = *index;
+    let mut name_index = opening_index + 1;
+    let s_after_bracket = helper::consume_whitespace(&s[1..], &mut name_index);
+    let name_len = s_after_bracket
+        .find(|c: char| c.is_whitespace() || c == '[' || c == ']')
+        .unwrap_or(s_after_bracket.len());
+    let is_optional = match &s_after_bracket[..name_len] {
+        "optional" => true,
+        "first" => false,
+        _ => return Ok(None),
+    };
+
+    *index = name_index + name_len;
+    let mut s = helper::consume_whitespace(&s_after_bracket[name_len..], index);
+
+    let mut branches = Vec::new();
+    while s.starts_with('[') {
+        if is_optional && !branches.is_empty() {
+            break;
+        }
+
+        let branch_index = *index;
+        *index += 1;
+        let (items, remaining) = parse_items(&s[1..], index, true)?;
+        if !remaining.starts_with(']') {
+            return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+                index: branch_index,
+            });
+        }
+        *index += 1;
+        branches.push(items);
+        s = helper::consume_whitespace(&remaining[1..], index);
+    }
+
+    if branches.is_empty() {
+        return Err(InvalidFormatDescription::Expected {
+            what: "nested format description",
+            index: *index,
+        });
+    }
+    if s.is_empty() {
+        return Err(InvalidFormatDescription::UnclosedOpeningBracket {
+            index: opening_index,
+        });
+    }
+    if !s.starts_with(']') {
+        return Err(InvalidFormatDescription::Expected {
+            what: "closing bracket",
+            index: *index,
+        });
+    }
+    *index += 1;
+
+    Ok(Some(ParsedItem {
+        item: if is_optional {
+            FormatItem::Optional(branches.remove(0))
+        } else {
+            FormatItem::First(branches)
+        },
+        remaining: &s[1..],
+    }))
+}
+
+fn parse_literal<'a>(s: &'a str, index: &mut usize, is_nested: bool) -> ParsedItem<'a> {
+    let loc = s
+        .find(|c| c == '[' || (is_nested && c == ']'))
+        .unwrap_or(s.len());
     *index += loc;
     ParsedItem {
         item: FormatItem::Literal(&s[..loc]),
@@ -41,16 +110,21 @@ fn parse_literal<'a>(s: &'a str, index: &mut usize) -> ParsedItem<'a> {
 fn parse_item<'a>(
     s: &'a str,
     index: &mut usize,
+    is_nested: bool,
 ) -> Result<ParsedItem<'a>, InvalidFormatDescription> {
     if let Some(remaining) = s.strip_prefix("[[") {
         *index += 2;
         return Ok(ParsedItem {
-            item: FormatItem::Literal("]"),
+            item: FormatItem::Literal("["),
             remaining,
         });
     }
 
     if s.starts_with('[') {
+        if let Some(item) = parse_nested_item(s, index)? {
+            return Ok(item);
+        }
+
         if let Some(bracket_index) = s.find(']') {
             *index += 1;
             let ret_val = ParsedItem {
@@ -63,19 +137,30 @@ fn parse_item<'a>(
             Err(InvalidFormatDescription::UnclosedOpeningBracket { index: *index })
         }
     } else {
-        Ok(parse_literal(s, index))
+        Ok(parse_literal(s, index, is_nested))
     }
 }
 
-pub(crate) fn parse(mut s: &str) -> Result<Vec<FormatItem<'_>>, Error> {
-    let mut compound = Vec::new();
-    let mut loc = 0;
+fn parse_items<'a>(
+    mut s: &'a str,
+    index: &mut usize,
+    is_nested: bool,
+) -> Result<(Vec<FormatItem<'a>>, &'a str), InvalidFormatDescription> {
+    let mut items = Vec::new();
 
     while !s.is_empty() {
-        let ParsedItem { item, remaining } = parse_item(s, &mut loc)?;
+        if is_nested && s.starts_with(']') {
+            break;
+        }
+
+        let ParsedItem { item, remaining } = parse_item(s, index, is_nested)?;
         s = remaining;
-        compound.push(item);
+        items.push(item);
     }
 
-    Ok(compound)
+    Ok((items, s))
+}
+
+pub(crate) fn parse(s: &str) -> Result<Vec<FormatItem<'_>>, Error> {
+    Ok(parse_items(s, &mut 0, false)?.0)
 }